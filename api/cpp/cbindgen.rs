@@ -118,6 +118,7 @@ fn builtin_structs(path: &Path) -> anyhow::Result<()> {
     writeln!(structs_priv, "#pragma once")?;
     writeln!(structs_priv, "// This file is auto-generated from {}", file!())?;
     writeln!(structs_priv, "#include \"slint_builtin_structs.h\"")?;
+    writeln!(structs_priv, "#include \"slint_point.h\"")?;
     writeln!(structs_priv, "#include \"slint_enums_internal.h\"")?;
     writeln!(structs_priv, "namespace slint::cbindgen_private {{")?;
     writeln!(structs_priv, "enum class KeyEventType : uint8_t;")?;
@@ -126,6 +127,10 @@ macro_rules! struct_file {
             writeln!(structs_priv, "using slint::StandardListViewItem;")?;
             &mut structs_pub
         }};
+        (StandardTreeViewItem) => {{
+            writeln!(structs_priv, "using slint::StandardTreeViewItem;")?;
+            &mut structs_pub
+        }};
         ($_:ident) => {
             &mut structs_priv
         };
@@ -218,6 +223,9 @@ fn default_config() -> cbindgen::Config {
             ("KeyEventArg".into(), "KeyEvent".into()),
             ("PointerEventArg".into(), "PointerEvent".into()),
             ("PointerScrollEventArg".into(), "PointerScrollEvent".into()),
+            ("DropEventArg".into(), "DropEvent".into()),
+            ("StringArg".into(), "SharedString".into()),
+            ("MenuEntryArg".into(), "MenuEntry".into()),
             ("PointArg".into(), "slint::LogicalPosition".into()),
             ("FloatArg".into(), "float".into()),
             ("IntArg".into(), "int".into()),
@@ -281,10 +289,20 @@ fn gen_corelib(
         "ClippedImage",
         "TouchArea",
         "FocusScope",
+        "FocusGroup",
+        "VirtualKeyboardKey",
         "SwipeGestureHandler",
+        "PinchGestureHandler",
+        "RotateGestureHandler",
+        "TwoFingerPanGestureHandler",
+        "DragArea",
+        "DropArea",
+        "Shortcut",
+        "ContextMenu",
         "Flickable",
         "SimpleText",
         "ComplexText",
+        "StyledText",
         "Path",
         "WindowItem",
         "TextInput",
@@ -337,6 +355,7 @@ fn gen_corelib(
         "GraphicsAPI",
         "CloseRequestResponse",
         "StandardListViewItem",
+        "StandardTreeViewItem",
         "Rgb8Pixel",
         "Rgba8Pixel",
     ];
@@ -368,6 +387,9 @@ fn gen_corelib(
         "KeyEventArg",
         "PointerEventArg",
         "PointerScrollEventArg",
+        "DropEventArg",
+        "StringArg",
+        "MenuEntryArg",
         "PointArg",
         "Point",
         "slint_color_brighter",
@@ -448,7 +470,7 @@ fn gen_corelib(
         .with_src(crate_dir.join("properties.rs"))
         .with_src(crate_dir.join("properties/ffi.rs"))
         .with_src(crate_dir.join("callbacks.rs"))
-        .with_after_include("namespace slint { class Color; class Brush; }")
+        .with_after_include("namespace slint { class Color; class Brush; struct SharedString; }")
         .generate()
         .context("Unable to generate bindings for slint_properties_internal.h")?
         .write_to_file(include_dir.join("slint_properties_internal.h"));
@@ -528,7 +550,14 @@ fn gen_corelib(
             "",
         ),
         (
-            vec!["Brush", "LinearGradient", "GradientStop", "RadialGradient"],
+            vec![
+                "Brush",
+                "LinearGradient",
+                "GradientStop",
+                "RadialGradient",
+                "ConicGradient",
+                "GradientSpread",
+            ],
             vec!["Color"],
             "slint_brush_internal.h",
             "",
@@ -549,6 +578,7 @@ fn gen_corelib(
             "slint_windowrc_set_scale_factor",
             "slint_windowrc_get_text_input_focused",
             "slint_windowrc_set_text_input_focused",
+            "slint_windowrc_get_text_input_type",
             "slint_windowrc_set_focus_item",
             "slint_windowrc_set_component",
             "slint_windowrc_show_popup",
@@ -566,6 +596,9 @@ fn gen_corelib(
             "slint_windowrc_default_font_size",
             "slint_windowrc_dispatch_pointer_event",
             "slint_windowrc_dispatch_key_event",
+            "slint_windowrc_dispatch_ime_preedit_event",
+            "slint_windowrc_dispatch_ime_commit_event",
+            "slint_windowrc_dispatch_ime_delete_surrounding_event",
             "slint_windowrc_dispatch_event",
             "slint_windowrc_set_fullscreen",
             "slint_windowrc_set_minimized",
@@ -573,6 +606,7 @@ fn gen_corelib(
             "slint_windowrc_is_fullscreen",
             "slint_windowrc_is_minimized",
             "slint_windowrc_is_maximized",
+            "slint_windowrc_export_svg",
             "slint_new_path_elements",
             "slint_new_path_events",
             "slint_color_brighter",
@@ -1008,6 +1042,7 @@ pub fn from_env() -> Self {
     renderer_skia_vulkan
     renderer_software
     gettext
+    binding_profiler
     accessibility
     system_testing
     freestanding
@@ -12,7 +12,7 @@ set_property(CACHE DEFAULT_SLINT_EMBED_RESOURCES PROPERTY STRINGS
 
 function(SLINT_TARGET_SOURCES target)
     # Parse the NAMESPACE argument
-    cmake_parse_arguments(SLINT_TARGET_SOURCES "" "NAMESPACE;COMPILATION_UNITS" "LIBRARY_PATHS" ${ARGN})
+    cmake_parse_arguments(SLINT_TARGET_SOURCES "" "NAMESPACE;COMPILATION_UNITS;BUNDLE_TRANSLATIONS" "LIBRARY_PATHS" ${ARGN})
 
     get_target_property(enabled_features Slint::Slint SLINT_ENABLED_FEATURES)
     if (("EXPERIMENTAL" IN_LIST enabled_features) AND ("SYSTEM_TESTING" IN_LIST enabled_features))
@@ -39,6 +39,11 @@ function(SLINT_TARGET_SOURCES target)
         set(compilation_units 1)
     endif()
 
+    if (DEFINED SLINT_TARGET_SOURCES_BUNDLE_TRANSLATIONS)
+        get_filename_component(_SLINT_BUNDLE_TRANSLATIONS ${SLINT_TARGET_SOURCES_BUNDLE_TRANSLATIONS} REALPATH BASE_DIR ${CMAKE_CURRENT_SOURCE_DIR})
+        set(_SLINT_BUNDLE_TRANSLATIONS_ARG "--bundle-translations=${_SLINT_BUNDLE_TRANSLATIONS}")
+    endif()
+
     while (SLINT_TARGET_SOURCES_LIBRARY_PATHS)
         list(POP_FRONT SLINT_TARGET_SOURCES_LIBRARY_PATHS name_and_path)
         list(APPEND _SLINT_CPP_LIBRARY_PATHS_ARG "-L")
@@ -74,6 +79,7 @@ function(SLINT_TARGET_SOURCES target)
                 --translation-domain="${target}"
                 ${_SLINT_CPP_NAMESPACE_ARG}
                 ${_SLINT_CPP_LIBRARY_PATHS_ARG}
+                ${_SLINT_BUNDLE_TRANSLATIONS_ARG}
                 ${scale_factor_arg}
                 ${cpp_files_arg}
             DEPENDS Slint::slint-compiler ${_SLINT_ABSOLUTE}
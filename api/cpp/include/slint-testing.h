@@ -257,6 +257,13 @@ public:
                 cbindgen_private::AccessibleStringProperty::PlaceholderText);
     }
 
+    /// Returns the accessible-keyboard-shortcut of that element, if any.
+    std::optional<SharedString> accessible_keyboard_shortcut() const
+    {
+        return get_accessible_string_property(
+                cbindgen_private::AccessibleStringProperty::KeyboardShortcut);
+    }
+
     /// Returns the accessible-description of that element, if any.
     std::optional<SharedString> accessible_description() const
     {
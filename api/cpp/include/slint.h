@@ -272,6 +272,48 @@ box_layout_info_ortho(cbindgen_private::Slice<cbindgen_private::BoxLayoutCellDat
     return cbindgen_private::slint_box_layout_info_ortho(cells, &padding);
 }
 
+inline SharedVector<float> solve_flexbox_layout(const cbindgen_private::FlexboxLayoutData &data,
+                                                cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flexbox_layout(&data, ri, &result);
+    return result;
+}
+
+inline SharedVector<float>
+solve_flexbox_layout_cross(const cbindgen_private::FlexboxLayoutData &data,
+                           cbindgen_private::Slice<cbindgen_private::BoxLayoutCellData> cells,
+                           float size, float spacing, const cbindgen_private::Padding &padding,
+                           cbindgen_private::FlexboxLayoutAlignItems align_items,
+                           cbindgen_private::Slice<int> repeater_indexes)
+{
+    SharedVector<float> result;
+    cbindgen_private::Slice<uint32_t> ri { reinterpret_cast<uint32_t *>(repeater_indexes.ptr),
+                                           repeater_indexes.len };
+    cbindgen_private::slint_solve_flexbox_layout_cross(&data, cells, size, spacing, &padding,
+                                                       align_items, ri, &result);
+    return result;
+}
+
+inline cbindgen_private::LayoutInfo
+flexbox_layout_info(cbindgen_private::Slice<cbindgen_private::BoxLayoutCellData> cells,
+                    float spacing, const cbindgen_private::Padding &padding,
+                    cbindgen_private::LayoutAlignment alignment,
+                    cbindgen_private::FlexboxLayoutWrap wrap)
+{
+    return cbindgen_private::slint_flexbox_layout_info(cells, spacing, &padding, alignment, wrap);
+}
+
+inline cbindgen_private::LayoutInfo
+flexbox_layout_info_cross(const cbindgen_private::FlexboxLayoutData &data,
+                          cbindgen_private::Slice<cbindgen_private::BoxLayoutCellData> cells,
+                          float spacing, const cbindgen_private::Padding &padding)
+{
+    return cbindgen_private::slint_flexbox_layout_info_cross(&data, cells, spacing, &padding);
+}
+
 /// Access the layout cache of an item within a repeater
 inline float layout_cache_access(const SharedVector<float> &cache, int offset, int repeater_index)
 {
@@ -1265,6 +1307,43 @@ inline SharedString translate(const SharedString &original, const SharedString &
     return result;
 }
 
+/// The function computing the index of the plural form from `n`
+using PluralRule = uintptr_t (*)(int32_t);
+
+inline cbindgen_private::Slice<const char *> translation_strings_to_slice(
+        std::span<const char8_t *const> strs)
+{
+    return { reinterpret_cast<const char *const *>(strs.data()), strs.size() };
+}
+
+inline SharedString translate_from_bundle(std::span<const char8_t *const> strs,
+                                          cbindgen_private::Slice<SharedString> arguments)
+{
+    SharedString result;
+    cbindgen_private::slint_translate_from_bundle(translation_strings_to_slice(strs), arguments,
+                                                  &result);
+    return result;
+}
+
+inline SharedString translate_from_bundle_with_plural(std::span<const char8_t *const> strs,
+                                                      std::span<const PluralRule> plural_rules,
+                                                      cbindgen_private::Slice<SharedString> arguments,
+                                                      int n)
+{
+    SharedString result;
+    cbindgen_private::slint_translate_from_bundle_with_plural(
+            translation_strings_to_slice(strs),
+            { const_cast<PluralRule *>(plural_rules.data()), plural_rules.size() }, arguments, n,
+            &result);
+    return result;
+}
+
+inline void set_bundled_languages(std::span<const char8_t *const> languages)
+{
+    cbindgen_private::slint_translate_set_bundled_languages(
+            translation_strings_to_slice(languages));
+}
+
 } // namespace private_api
 
 #ifdef SLINT_FEATURE_GETTEXT
@@ -1285,6 +1364,75 @@ inline void update_all_translations()
 }
 #endif
 
+/// Selects the language of the translations that were bundled in the binary with the
+/// `BUNDLE_TRANSLATIONS` argument of `slint_target_sources`, and re-translates all the strings.
+///
+/// The language is the name of the directory that contains the `.po` file, such as `"de"` or
+/// `"fr_CH"`. When there is no translation for `"fr_CH"`, the one for `"fr"` is used.
+/// An empty string selects the original strings.
+///
+/// Returns false if the language was not found, or no translations are bundled.
+/// This must be called after a component was created.
+inline bool select_bundled_translation(std::string_view language)
+{
+    return cbindgen_private::slint_translate_select_bundled_translation(
+            private_api::string_to_slice(language));
+}
+
+#if defined(SLINT_FEATURE_BINDING_PROFILER) || defined(DOXYGEN)
+/// The binding profiler records how many times, and for how long, the binding of each property
+/// is evaluated, and gives access to the dependencies between the properties.
+///
+/// The properties are named after the element id and the property name only if the .slint
+/// files were compiled with the `SLINT_EMIT_DEBUG_INFO` environment variable set.
+///
+/// The functions are only available when Slint is compiled with `SLINT_FEATURE_BINDING_PROFILER`.
+namespace binding_profiler {
+
+/// Starts recording the evaluations of the bindings, discarding the previously recorded
+/// statistics.
+inline void start()
+{
+    cbindgen_private::slint_binding_profiler_start();
+}
+
+/// Stops recording the evaluations of the bindings.
+inline void stop()
+{
+    cbindgen_private::slint_binding_profiler_stop();
+}
+
+/// Returns the statistics recorded since start() as a JSON array of objects with the `property`
+/// name, the number of `evaluations`, and the `total_ns`, `self_ns`, and `max_ns` durations in
+/// nanoseconds, sorted by decreasing total duration.
+inline SharedString statistics_as_json()
+{
+    SharedString out;
+    cbindgen_private::slint_binding_profiler_statistics_as_json(&out);
+    return out;
+}
+
+/// Returns the current dependencies between the properties in the DOT format of Graphviz.
+inline SharedString dependency_graph_as_dot()
+{
+    SharedString out;
+    cbindgen_private::slint_binding_profiler_dependency_graph(true, &out);
+    return out;
+}
+
+/// Returns the current dependencies between the properties as a JSON object with a `nodes` array
+/// of objects with a `name`, and an `edges` array of objects with the `from` and `to` indices of
+/// the nodes. An edge means that the binding of `to` reads the `from` property.
+inline SharedString dependency_graph_as_json()
+{
+    SharedString out;
+    cbindgen_private::slint_binding_profiler_dependency_graph(false, &out);
+    return out;
+}
+
+}
+#endif
+
 #if !defined(DOXYGEN)
 cbindgen_private::Flickable::Flickable()
 {
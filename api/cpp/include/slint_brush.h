@@ -11,8 +11,16 @@ namespace slint {
 
 namespace private_api {
 
+using cbindgen_private::types::GradientSpread;
 using cbindgen_private::types::GradientStop;
 
+/// \private
+/// Appends a fake stop to \a gradient that stores a parameter of the gradient in its position.
+inline void push_gradient_parameter(SharedVector<GradientStop> &gradient, float value)
+{
+    gradient.push_back({ {}, value });
+}
+
 /// \private
 /// LinearGradientBrush represents a gradient for a brush that is a linear sequence of color stops,
 /// that are aligned at a specific angle.
@@ -23,8 +31,9 @@ public:
     LinearGradientBrush() = default;
     /// Constructs a new linear gradient with the specified \a angle. The color stops will be
     /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
-    LinearGradientBrush(float angle, const GradientStop *firstStop, int stopCount)
-        : inner(make_linear_gradient(angle, firstStop, stopCount))
+    LinearGradientBrush(float angle, const GradientStop *firstStop, int stopCount,
+                        GradientSpread spread = GradientSpread::Pad)
+        : inner(make_linear_gradient(angle, firstStop, stopCount, spread))
     {
     }
 
@@ -35,11 +44,14 @@ public:
         return inner[0].position;
     }
 
+    /// Returns how the gradient fills the area outside of the range of its stops.
+    GradientSpread spread() const { return GradientSpread(uint8_t(inner[1].position)); }
+
     /// Returns the number of gradient stops.
-    int stopCount() const { return int(inner.size()) - 1; }
+    int stopCount() const { return int(inner.size()) - header_len; }
 
     /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
-    const GradientStop *stopsBegin() const { return inner.begin() + 1; }
+    const GradientStop *stopsBegin() const { return inner.begin() + header_len; }
     /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
     /// it can only be used for comparison.
     const GradientStop *stopsEnd() const { return inner.end(); }
@@ -49,11 +61,16 @@ private:
 
     friend class slint::Brush;
 
+    // The angle and the spread are stored in fake stops
+    static constexpr int header_len = 2;
+
     static SharedVector<private_api::GradientStop>
-    make_linear_gradient(float angle, const GradientStop *firstStop, int stopCount)
+    make_linear_gradient(float angle, const GradientStop *firstStop, int stopCount,
+                         GradientSpread spread)
     {
         SharedVector<private_api::GradientStop> gradient;
-        gradient.push_back({ Color::from_argb_encoded(0).inner, angle });
+        push_gradient_parameter(gradient, angle);
+        push_gradient_parameter(gradient, float(uint8_t(spread)));
         for (int i = 0; i < stopCount; ++i, ++firstStop)
             gradient.push_back(*firstStop);
         return gradient;
@@ -61,24 +78,36 @@ private:
 };
 
 /// \private
-/// RadialGradientBrush represents a circular gradient centered in the middle
+/// RadialGradientBrush represents a circular gradient
 class RadialGradientBrush
 {
 public:
     /// Constructs an empty linear gradient with no color stops.
     RadialGradientBrush() = default;
-    /// Constructs a new circular radial gradient . The color stops will be
+    /// Constructs a new circular radial gradient centered in the middle. The color stops will be
     /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
     RadialGradientBrush(const GradientStop *firstStop, int stopCount)
-        : inner(make_circle_gradient(firstStop, stopCount))
+        : RadialGradientBrush(0.5, 0.5, 0., firstStop, stopCount)
+    {
+    }
+    /// Constructs a new circular radial gradient centered at \a center_x and \a center_y, relative
+    /// to the size of the shape, with the given \a radius in logical pixels. A radius of zero
+    /// means half of the largest side of the shape.
+    RadialGradientBrush(float center_x, float center_y, float radius,
+                        const GradientStop *firstStop, int stopCount,
+                        GradientSpread spread = GradientSpread::Pad)
+        : inner(make_circle_gradient(center_x, center_y, radius, firstStop, stopCount, spread))
     {
     }
 
+    /// Returns how the gradient fills the area outside of the range of its stops.
+    GradientSpread spread() const { return GradientSpread(uint8_t(inner[3].position)); }
+
     /// Returns the number of gradient stops.
-    int stopCount() const { return int(inner.size()); }
+    int stopCount() const { return int(inner.size()) - header_len; }
 
     /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
-    const GradientStop *stopsBegin() const { return inner.begin(); }
+    const GradientStop *stopsBegin() const { return inner.begin() + header_len; }
     /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
     /// it can only be used for comparison.
     const GradientStop *stopsEnd() const { return inner.end(); }
@@ -88,10 +117,74 @@ private:
 
     friend class slint::Brush;
 
+    // The center, the radius and the spread are stored in fake stops
+    static constexpr int header_len = 4;
+
     static SharedVector<private_api::GradientStop>
-    make_circle_gradient(const GradientStop *firstStop, int stopCount)
+    make_circle_gradient(float center_x, float center_y, float radius,
+                         const GradientStop *firstStop, int stopCount, GradientSpread spread)
     {
         SharedVector<private_api::GradientStop> gradient;
+        push_gradient_parameter(gradient, center_x);
+        push_gradient_parameter(gradient, center_y);
+        push_gradient_parameter(gradient, radius);
+        push_gradient_parameter(gradient, float(uint8_t(spread)));
+        for (int i = 0; i < stopCount; ++i, ++firstStop)
+            gradient.push_back(*firstStop);
+        return gradient;
+    }
+};
+
+/// \private
+/// ConicGradientBrush represents a gradient whose colors sweep clockwise around a center point,
+/// starting at the top.
+class ConicGradientBrush
+{
+public:
+    /// Constructs an empty conic gradient with no color stops.
+    ConicGradientBrush() = default;
+    /// Constructs a new conic gradient starting at \a from_angle degrees, centered at
+    /// \a center_x and \a center_y relative to the size of the shape. The color stops will be
+    /// constructed from the stops array pointed to be \a firstStop, with the length \a stopCount.
+    ConicGradientBrush(float from_angle, float center_x, float center_y,
+                       const GradientStop *firstStop, int stopCount,
+                       GradientSpread spread = GradientSpread::Pad)
+        : inner(make_conic_gradient(from_angle, center_x, center_y, firstStop, stopCount, spread))
+    {
+    }
+
+    /// Returns the angle in degrees at which the gradient starts.
+    float from_angle() const { return inner[0].position; }
+
+    /// Returns how the gradient fills the area outside of the range of its stops.
+    GradientSpread spread() const { return GradientSpread(uint8_t(inner[3].position)); }
+
+    /// Returns the number of gradient stops.
+    int stopCount() const { return int(inner.size()) - header_len; }
+
+    /// Returns a pointer to the first gradient stop; undefined if the gradient has not stops.
+    const GradientStop *stopsBegin() const { return inner.begin() + header_len; }
+    /// Returns a pointer past the last gradient stop. The returned pointer cannot be dereferenced,
+    /// it can only be used for comparison.
+    const GradientStop *stopsEnd() const { return inner.end(); }
+
+private:
+    cbindgen_private::types::ConicGradientBrush inner;
+
+    friend class slint::Brush;
+
+    // The start angle, the center and the spread are stored in fake stops
+    static constexpr int header_len = 4;
+
+    static SharedVector<private_api::GradientStop>
+    make_conic_gradient(float from_angle, float center_x, float center_y,
+                        const GradientStop *firstStop, int stopCount, GradientSpread spread)
+    {
+        SharedVector<private_api::GradientStop> gradient;
+        push_gradient_parameter(gradient, from_angle);
+        push_gradient_parameter(gradient, center_x);
+        push_gradient_parameter(gradient, center_y);
+        push_gradient_parameter(gradient, float(uint8_t(spread)));
         for (int i = 0; i < stopCount; ++i, ++firstStop)
             gradient.push_back(*firstStop);
         return gradient;
@@ -101,7 +194,7 @@ private:
 }
 
 /// Brush is used to declare how to fill or outline shapes, such as rectangles, paths or text. A
-/// brush is either a solid color or a linear gradient.
+/// brush is either a solid color or a gradient.
 class Brush
 {
 public:
@@ -123,6 +216,13 @@ public:
     {
     }
 
+    /// \private
+    /// Constructs a new brush that is the gradient \a gradient.
+    Brush(const private_api::ConicGradientBrush &gradient)
+        : data(Inner::ConicGradient(gradient.inner))
+    {
+    }
+
     /// Returns the color of the brush. If the brush is a gradient, this function returns the color
     /// of the first stop.
     inline Color color() const;
@@ -168,13 +268,18 @@ Color Brush::color() const
         result.inner = data.solid_color._0;
         break;
     case Tag::LinearGradient:
-        if (data.linear_gradient._0.size() > 1) {
-            result.inner = data.linear_gradient._0[1].color;
+        if (data.linear_gradient._0.size() > 2) {
+            result.inner = data.linear_gradient._0[2].color;
         }
         break;
     case Tag::RadialGradient:
-        if (data.radial_gradient._0.size() > 0) {
-            result.inner = data.radial_gradient._0[0].color;
+        if (data.radial_gradient._0.size() > 4) {
+            result.inner = data.radial_gradient._0[4].color;
+        }
+        break;
+    case Tag::ConicGradient:
+        if (data.conic_gradient._0.size() > 4) {
+            result.inner = data.conic_gradient._0[4].color;
         }
         break;
     }
@@ -190,15 +295,21 @@ inline Brush Brush::brighter(float factor) const
                                                       &result.data.solid_color._0);
         break;
     case Tag::LinearGradient:
-        for (std::size_t i = 1; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 2; i < data.linear_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_brighter(&data.linear_gradient._0[i].color, factor,
-                                                          &result.data.linear_gradient._0[i].color);
+                    &result.data.linear_gradient._0[i].color);
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 4; i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_brighter(&data.radial_gradient._0[i].color, factor,
-                                                          &result.data.radial_gradient._0[i].color);
+                    &result.data.radial_gradient._0[i].color);
+        }
+        break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 4; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_brighter(&data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
         }
         break;
     }
@@ -214,15 +325,21 @@ inline Brush Brush::darker(float factor) const
                                                     &result.data.solid_color._0);
         break;
     case Tag::LinearGradient:
-        for (std::size_t i = 1; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 2; i < data.linear_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_darker(&data.linear_gradient._0[i].color, factor,
-                                                        &result.data.linear_gradient._0[i].color);
+                    &result.data.linear_gradient._0[i].color);
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
+        for (std::size_t i = 4; i < data.radial_gradient._0.size(); ++i) {
             cbindgen_private::types::slint_color_darker(&data.radial_gradient._0[i].color, factor,
-                                                        &result.data.radial_gradient._0[i].color);
+                    &result.data.radial_gradient._0[i].color);
+        }
+        break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 4; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_darker(&data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
         }
         break;
     }
@@ -238,19 +355,23 @@ inline Brush Brush::transparentize(float factor) const
                                                             &result.data.solid_color._0);
         break;
     case Tag::LinearGradient:
-        for (std::size_t i = 1; i < data.linear_gradient._0.size(); ++i) {
-            cbindgen_private::types::slint_color_transparentize(
-                    &data.linear_gradient._0[i].color, factor,
+        for (std::size_t i = 2; i < data.linear_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(&data.linear_gradient._0[i].color, factor,
                     &result.data.linear_gradient._0[i].color);
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
-            cbindgen_private::types::slint_color_transparentize(
-                    &data.radial_gradient._0[i].color, factor,
+        for (std::size_t i = 4; i < data.radial_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(&data.radial_gradient._0[i].color, factor,
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 4; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_transparentize(&data.conic_gradient._0[i].color, factor,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
@@ -264,19 +385,23 @@ inline Brush Brush::with_alpha(float alpha) const
                                                         &result.data.solid_color._0);
         break;
     case Tag::LinearGradient:
-        for (std::size_t i = 1; i < data.linear_gradient._0.size(); ++i) {
-            cbindgen_private::types::slint_color_with_alpha(
-                    &data.linear_gradient._0[i].color, alpha,
+        for (std::size_t i = 2; i < data.linear_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(&data.linear_gradient._0[i].color, alpha,
                     &result.data.linear_gradient._0[i].color);
         }
         break;
     case Tag::RadialGradient:
-        for (std::size_t i = 0; i < data.linear_gradient._0.size(); ++i) {
-            cbindgen_private::types::slint_color_with_alpha(
-                    &data.radial_gradient._0[i].color, alpha,
+        for (std::size_t i = 4; i < data.radial_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(&data.radial_gradient._0[i].color, alpha,
                     &result.data.radial_gradient._0[i].color);
         }
         break;
+    case Tag::ConicGradient:
+        for (std::size_t i = 4; i < data.conic_gradient._0.size(); ++i) {
+            cbindgen_private::types::slint_color_with_alpha(&data.conic_gradient._0[i].color, alpha,
+                    &result.data.conic_gradient._0[i].color);
+        }
+        break;
     }
     return result;
 }
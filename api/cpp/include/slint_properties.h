@@ -143,6 +143,13 @@ struct Property
     bool is_dirty() const { return cbindgen_private::slint_property_is_dirty(&inner); }
     void mark_dirty() const { cbindgen_private::slint_property_mark_dirty(&inner); }
 
+    /// Set the name of this property, as reported by the binding profiler. Does nothing if Slint
+    /// was not compiled with `SLINT_FEATURE_BINDING_PROFILER`.
+    void set_profiler_name(const char *name) const
+    {
+        cbindgen_private::slint_property_set_profiler_name(&inner, name);
+    }
+
     static void link_two_way(const Property<T> *p1, const Property<T> *p2)
     {
         auto value = p2->get();
@@ -84,6 +84,10 @@ public:
     {
         slint_windowrc_set_text_input_focused(&inner, value);
     }
+    cbindgen_private::InputType text_input_type() const
+    {
+        return slint_windowrc_get_text_input_type(&inner);
+    }
 
     template<typename Component, typename ItemArray>
     void unregister_item_tree(Component *c, ItemArray items) const
@@ -106,18 +110,23 @@ public:
     }
 
     template<typename Component, typename Parent, typename PosGetter>
-    void show_popup(const Parent *parent_component, PosGetter pos,
-                    cbindgen_private::PopupClosePolicy close_policy,
-                    cbindgen_private::ItemRc parent_item) const
+    uint32_t show_popup(const Parent *parent_component, PosGetter pos,
+                        cbindgen_private::PopupClosePolicy close_policy,
+                        cbindgen_private::ItemRc parent_item) const
     {
         auto popup = Component::create(parent_component);
         cbindgen_private::Point p = pos(popup);
         auto popup_dyn = popup.into_dyn();
-        cbindgen_private::slint_windowrc_show_popup(&inner, &popup_dyn, p, close_policy,
-                                                    &parent_item);
+        return cbindgen_private::slint_windowrc_show_popup(&inner, &popup_dyn, p, close_policy,
+                                                           &parent_item);
     }
 
-    void close_popup() const { cbindgen_private::slint_windowrc_close_popup(&inner); }
+    void close_popup(std::optional<uint32_t> popup_id) const
+    {
+        if (popup_id) {
+            cbindgen_private::slint_windowrc_close_popup(&inner, *popup_id);
+        }
+    }
 
     template<std::invocable<RenderingState, GraphicsAPI> F>
     std::optional<SetRenderingNotifierError> set_rendering_notifier(F callback) const
@@ -415,6 +424,19 @@ public:
         cbindgen_private::slint_windowrc_set_minimized(&inner.handle(), minimized);
     }
 
+    /// Renders the window contents as a SVG document and returns it as a string.
+    ///
+    /// Texts are kept as text, and paths, gradients, and clips are mapped to the corresponding
+    /// SVG elements. Only the images are embedded as bitmaps. The popups that are shown within
+    /// the window are included.
+    SharedString export_svg() const
+    {
+        private_api::assert_main_thread();
+        SharedString svg;
+        cbindgen_private::slint_windowrc_export_svg(&inner.handle(), &svg);
+        return svg;
+    }
+
     /// Dispatch a key press event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -451,6 +473,44 @@ public:
                 &inner.handle(), cbindgen_private::KeyEventType::KeyReleased, &text, false);
     }
 
+    /// Dispatch an input method event that updates the text being composed, also known as the
+    /// pre-edit text, to the scene.
+    ///
+    /// Use this when you're implementing your own backend and want to forward the text of an input
+    /// method, such as an on-screen keyboard.
+    ///
+    /// The \a text is shown at the cursor position of the focused text input, but not yet part of
+    /// its text. An empty text removes the pre-edit text. \a selection_start and \a selection_end
+    /// are the byte offsets in \a text of the range highlighted by the input method.
+    void dispatch_ime_preedit_event(const SharedString &text, size_t selection_start,
+                                    size_t selection_end)
+    {
+        private_api::assert_main_thread();
+        cbindgen_private::slint_windowrc_dispatch_ime_preedit_event(&inner.handle(), &text,
+                                                                     selection_start, selection_end);
+    }
+
+    /// Dispatch an input method event that commits \a text to the scene. The pre-edit text is
+    /// removed and \a text is inserted at the cursor position of the focused text input.
+    void dispatch_ime_commit_event(const SharedString &text)
+    {
+        private_api::assert_main_thread();
+        cbindgen_private::slint_windowrc_dispatch_ime_commit_event(&inner.handle(), &text);
+    }
+
+    /// Dispatch an input method event that deletes \a before_length bytes before and
+    /// \a after_length bytes after the cursor of the focused text input. This also removes the
+    /// pre-edit text.
+    ///
+    /// When the input method sends several changes at once, dispatch this event first, followed
+    /// by dispatch_ime_commit_event() and then dispatch_ime_preedit_event().
+    void dispatch_ime_delete_surrounding_event(size_t before_length, size_t after_length)
+    {
+        private_api::assert_main_thread();
+        cbindgen_private::slint_windowrc_dispatch_ime_delete_surrounding_event(
+                &inner.handle(), before_length, after_length);
+    }
+
     /// Dispatches a pointer or mouse press event to the scene.
     ///
     /// Use this function when you're implementing your own backend and want to forward user
@@ -533,6 +593,71 @@ public:
         inner.dispatch_pointer_event(event);
     }
 
+    /// Dispatches the event of a finger touching the screen to the scene.
+    ///
+    /// Use this function when you're implementing your own backend and want to forward the events
+    /// of a touch screen. The first finger is handled like the left button of a mouse, and two
+    /// fingers form pinch, rotation and pan gestures.
+    ///
+    /// \a touch_id identifies the finger until it is released.
+    /// \a pos represents the logical position of the finger relative to the window.
+    void dispatch_touch_press_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_pressed = WindowEvent::TouchPressed_Body {
+                                    .tag = WindowEvent::Tag::TouchPressed,
+                                    .touch_id = touch_id,
+                                    .position = pos } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches the move of a finger that touches the screen to the scene.
+    ///
+    /// \a touch_id is the id the finger had in dispatch_touch_press_event().
+    /// \a pos represents the logical position of the finger relative to the window.
+    void dispatch_touch_move_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_moved = WindowEvent::TouchMoved_Body {
+                                    .tag = WindowEvent::Tag::TouchMoved,
+                                    .touch_id = touch_id,
+                                    .position = pos } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches the event of a finger lifted from the screen to the scene.
+    ///
+    /// \a touch_id is the id the finger had in dispatch_touch_press_event().
+    /// \a pos represents the logical position of the finger relative to the window.
+    void dispatch_touch_release_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_released = WindowEvent::TouchReleased_Body {
+                                    .tag = WindowEvent::Tag::TouchReleased,
+                                    .touch_id = touch_id,
+                                    .position = pos } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
+    /// Dispatches the cancellation of the touch of a finger by the system to the scene. Unlike
+    /// dispatch_touch_release_event(), this doesn't click.
+    ///
+    /// \a touch_id is the id the finger had in dispatch_touch_press_event().
+    /// \a pos represents the last logical position of the finger relative to the window.
+    void dispatch_touch_cancel_event(uint64_t touch_id, LogicalPosition pos)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .touch_cancelled = WindowEvent::TouchCancelled_Body {
+                                    .tag = WindowEvent::Tag::TouchCancelled,
+                                    .touch_id = touch_id,
+                                    .position = pos } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
     /// Set the logical size of this window after a resize event
     ///
     /// The backend must send this event to ensure that the `width` and `height` property of the
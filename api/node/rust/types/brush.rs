@@ -302,6 +302,13 @@ pub fn to_string(&self) -> String {
                     gradient_stops_to_string(gradient.stops())
                 );
             }
+            Brush::ConicGradient(gradient) => {
+                return format!(
+                    "conic-gradient(from {}deg, {})",
+                    gradient.from_angle(),
+                    gradient_stops_to_string(gradient.stops())
+                );
+            }
             _ => String::default(),
         }
     }
@@ -11,8 +11,10 @@
 mod value;
 
 #[pyfunction]
-fn run_event_loop() -> Result<(), errors::PyPlatformError> {
-    slint_interpreter::run_event_loop().map_err(|e| e.into())
+fn run_event_loop(py: Python<'_>) -> Result<(), errors::PyPlatformError> {
+    // Release the GIL while waiting for events, so that other Python threads can run. Python callbacks
+    // invoked from the event loop acquire it again.
+    py.allow_threads(slint_interpreter::run_event_loop).map_err(|e| e.into())
 }
 
 #[pyfunction]
@@ -20,6 +22,20 @@ fn quit_event_loop() -> Result<(), errors::PyEventLoopError> {
     slint_interpreter::quit_event_loop().map_err(|e| e.into())
 }
 
+/// Schedules the callable to be invoked from the thread running the Slint event loop.
+/// This function can be called from any thread.
+#[pyfunction]
+fn invoke_from_event_loop(callable: PyObject) -> Result<(), errors::PyEventLoopError> {
+    slint_interpreter::invoke_from_event_loop(move || {
+        Python::with_gil(|py| {
+            if let Err(err) = callable.call0(py) {
+                eprintln!("Python: Invoking python callback from the event loop threw an exception: {err}");
+            }
+        })
+    })
+    .map_err(|e| e.into())
+}
+
 use pyo3::prelude::*;
 
 #[pymodule]
@@ -44,6 +60,7 @@ fn slint(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<value::PyStruct>()?;
     m.add_function(wrap_pyfunction!(run_event_loop, m)?)?;
     m.add_function(wrap_pyfunction!(quit_event_loop, m)?)?;
+    m.add_function(wrap_pyfunction!(invoke_from_event_loop, m)?)?;
 
     Ok(())
 }
@@ -9,7 +9,10 @@ import types
 import logging
 import importlib
 import copy
+import asyncio
+import inspect
 from . import models
+from .loop import SlintEventLoop, SlintEventLoopPolicy, run_event_loop_async
 
 
 class CompileError(Exception):
@@ -33,6 +36,25 @@ def _normalize_prop(name):
     return name.replace("-", "_")
 
 
+# Keep a reference to the tasks started by async callbacks, asyncio only keeps weak references
+_callback_tasks = set()
+
+
+def _callback_handler(callback):
+    """Return a handler for a Slint callback. The handler of `async def` functions runs them as
+    a task on the current asyncio loop, such as the loop of `run_event_loop_async()`. The Slint
+    callbacks run in between the steps of that loop, so it's not the running loop at that point."""
+    if not inspect.iscoroutinefunction(callback):
+        return callback
+
+    def start_task(*args, **kwargs):
+        task = asyncio.get_event_loop().create_task(callback(*args, **kwargs))
+        _callback_tasks.add(task)
+        task.add_done_callback(_callback_tasks.discard)
+
+    return start_task
+
+
 def _build_global_class(compdef, global_name):
     properties_and_callbacks = {}
 
@@ -69,7 +91,7 @@ def _build_global_class(compdef, global_name):
 
             def setter(self, value):
                 return self.__instance__.set_global_callback(
-                    global_name, callback_name, value)
+                    global_name, callback_name, _callback_handler(value))
 
             return property(getter, setter)
 
@@ -106,6 +128,10 @@ def _build_class(compdef):
                 def mk_callback(self, callback):
                     def invoke(*args, **kwargs):
                         return callback(self, *args, **kwargs)
+                    if inspect.iscoroutinefunction(callback):
+                        async def invoke_async(*args, **kwargs):
+                            return await callback(self, *args, **kwargs)
+                        return _callback_handler(invoke_async)
                     return invoke
 
                 if "global_name" in callback_info:
@@ -154,7 +180,7 @@ def _build_class(compdef):
 
             def setter(self, value):
                 return self.__instance__.set_callback(
-                    callback_name, value)
+                    callback_name, _callback_handler(value))
 
             return property(getter, setter)
 
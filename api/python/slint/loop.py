@@ -0,0 +1,157 @@
+# Copyright © SixtyFPS GmbH <info@slint.dev>
+# SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+import asyncio
+import heapq
+import selectors
+import time
+from datetime import timedelta
+from . import slint as native
+
+
+class SlintEventLoop(asyncio.SelectorEventLoop):
+    """An asyncio event loop that runs the Slint event loop. Coroutines and asyncio callbacks run in the
+    same thread as the Slint callbacks, so they can access the components. The loop runs until the Slint
+    event loop is quit, for example when the last window is closed, or until it is stopped.
+
+    The asyncio loop is stepped from a Slint timer: each step runs the callbacks that are ready, with
+    `run_forever()` and a `stop()` scheduled right away. The callbacks scheduled from the loop's thread
+    and from other threads start a step immediately. The sockets and pipes are only checked for events
+    during the steps, so while some are watched, the loop steps every few milliseconds."""
+
+    # The delay between two steps while sockets or pipes are watched, in seconds
+    _POLL_INTERVAL = 0.01
+
+    def __init__(self):
+        self._io_selector = selectors.DefaultSelector()
+        self._timer = native.Timer()
+        self._next_step = None
+        self._slint_running = False
+        self._stepping = False
+        self._step_again = False
+        # The times of the callbacks scheduled with call_at(), as a heap
+        self._deadlines = []
+        self._stop_requested = False
+        super().__init__(self._io_selector)
+        # The file objects the loop watches for its own purposes, such as its wake up pipe
+        self._internal_file_objects = len(self._io_selector.get_map())
+
+    def run_forever(self):
+        if self._slint_running:
+            raise RuntimeError("This event loop is already running")
+        self._slint_running = True
+        try:
+            self._schedule_step(0)
+            native.run_event_loop()
+        finally:
+            self._slint_running = False
+            self._stop_requested = False
+            self._timer.stop()
+            self._next_step = None
+
+    def stop(self):
+        self._stop_requested = True
+        if self._stepping:
+            super().stop()
+        elif self._slint_running:
+            native.quit_event_loop()
+
+    def call_soon(self, callback, *args, context=None):
+        handle = super().call_soon(callback, *args, context=context)
+        if self._stepping:
+            self._step_again = True
+        else:
+            self._schedule_step(0)
+        return handle
+
+    def call_at(self, when, callback, *args, context=None):
+        handle = super().call_at(when, callback, *args, context=context)
+        heapq.heappush(self._deadlines, when)
+        self._schedule_step(when - self.time())
+        return handle
+
+    def call_soon_threadsafe(self, callback, *args, context=None):
+        handle = super().call_soon_threadsafe(callback, *args, context=context)
+        native.invoke_from_event_loop(lambda: self._schedule_step(0))
+        return handle
+
+    def _schedule_step(self, delay):
+        """Step the asyncio loop from the Slint event loop after the delay in seconds, unless an
+        earlier step is already scheduled"""
+        if not self._slint_running or self._stepping:
+            return
+        delay = max(delay, 0)
+        deadline = time.monotonic() + delay
+        if self._next_step is not None and self._next_step <= deadline:
+            return
+        self._next_step = deadline
+        self._timer.start(native.TimerMode.SingleShot,
+                          timedelta(seconds=delay), self._step)
+
+    def _step(self):
+        if not self._slint_running:
+            return
+        self._timer.stop()
+        self._next_step = None
+        self._stepping = True
+        try:
+            # Run the callbacks that are ready, and the ones of the events of the sockets, once
+            super().call_soon(super().stop)
+            self._step_again = False
+            super().run_forever()
+        finally:
+            self._stepping = False
+        if self._stop_requested:
+            native.quit_event_loop()
+            return
+        if self._step_again:
+            self._schedule_step(0)
+            return
+        now = self.time()
+        while self._deadlines and self._deadlines[0] <= now:
+            heapq.heappop(self._deadlines)
+        if self._deadlines:
+            self._schedule_step(self._deadlines[0] - now)
+        if len(self._io_selector.get_map()) > self._internal_file_objects:
+            self._schedule_step(self._POLL_INTERVAL)
+
+
+class SlintEventLoopPolicy(asyncio.DefaultEventLoopPolicy):
+    """An asyncio event loop policy that creates SlintEventLoop instances.
+    Install it with `asyncio.set_event_loop_policy(slint.SlintEventLoopPolicy())`."""
+
+    def new_event_loop(self):
+        return SlintEventLoop()
+
+
+def run_event_loop_async(main=None):
+    """Runs the Slint event loop together with an asyncio event loop, until the Slint event loop is quit.
+
+    If `main` is a coroutine, it's run as a task. An exception raised by it stops the loop and is raised again
+    by this function."""
+    loop = SlintEventLoop()
+    asyncio.set_event_loop(loop)
+    main_task = None
+    try:
+        if main is not None:
+            main_task = loop.create_task(main)
+
+            def stop_on_error(task):
+                if not task.cancelled() and task.exception() is not None:
+                    loop.stop()
+
+            main_task.add_done_callback(stop_on_error)
+        loop.run_forever()
+        if main_task is not None and main_task.done() and not main_task.cancelled():
+            main_task.result()
+    finally:
+        try:
+            tasks = asyncio.all_tasks(loop)
+            for task in tasks:
+                task.cancel()
+            if tasks:
+                loop.run_until_complete(asyncio.gather(*tasks, return_exceptions=True))
+            loop.run_until_complete(loop.shutdown_asyncgens())
+        finally:
+            asyncio.set_event_loop(None)
+            loop.close()
@@ -0,0 +1,118 @@
+# Copyright © SixtyFPS GmbH <info@slint.dev>
+# SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+import asyncio
+import socket
+import threading
+import pytest
+import slint
+from slint import slint as native
+from datetime import timedelta
+
+
+def test_run_event_loop_async():
+    timer_fired = False
+
+    def on_timer():
+        nonlocal timer_fired
+        timer_fired = True
+
+    async def main():
+        native.Timer.single_shot(timedelta(milliseconds=10), on_timer)
+        await asyncio.sleep(0.1)
+        assert timer_fired
+
+        loop = asyncio.get_running_loop()
+        future = loop.create_future()
+        threading.Thread(target=lambda: loop.call_soon_threadsafe(
+            future.set_result, 42)).start()
+        assert await future == 42
+
+        native.quit_event_loop()
+
+    slint.run_event_loop_async(main())
+
+
+def test_run_event_loop_async_error():
+    async def main():
+        await asyncio.sleep(0.01)
+        raise ValueError("from main")
+
+    with pytest.raises(ValueError, match="from main"):
+        slint.run_event_loop_async(main())
+
+
+def test_async_callback():
+    compiler = native.Compiler()
+    compdef = compiler.build_from_source("""
+        export component Test {
+            callback clicked(int);
+            callback invoke-clicked(int);
+            invoke-clicked(value) => { self.clicked(value); }
+        }
+    """, "").component("Test")
+    instance = compdef.create()
+    received = []
+
+    async def clicked(value):
+        await asyncio.sleep(0.01)
+        received.append(value)
+        native.quit_event_loop()
+
+    instance.set_callback("clicked", slint._callback_handler(clicked))
+
+    async def main():
+        instance.invoke("invoke-clicked", 5)
+
+    slint.run_event_loop_async(main())
+    assert received == [5]
+
+
+def test_async_callback_from_slint_event():
+    compiler = native.Compiler()
+    compdef = compiler.build_from_source("""
+        export component Test {
+            callback clicked(int);
+        }
+    """, "").component("Test")
+    instance = compdef.create()
+    received = []
+
+    async def clicked(value):
+        await asyncio.sleep(0.01)
+        received.append(value)
+        native.quit_event_loop()
+
+    instance.set_callback("clicked", slint._callback_handler(clicked))
+
+    async def main():
+        # The timer fires from the Slint event loop, in between the steps of the asyncio loop
+        native.Timer.single_shot(timedelta(milliseconds=10),
+                                 lambda: instance.invoke("clicked", 7))
+
+    slint.run_event_loop_async(main())
+    assert received == [7]
+
+
+def test_sockets():
+    async def main():
+        loop = asyncio.get_running_loop()
+        reader, writer = socket.socketpair()
+        with reader, writer:
+            reader.setblocking(False)
+            threading.Timer(0.05, lambda: writer.send(b"hello")).start()
+            assert await loop.sock_recv(reader, 5) == b"hello"
+        native.quit_event_loop()
+
+    slint.run_event_loop_async(main())
+
+
+def test_event_loop_policy():
+    asyncio.set_event_loop_policy(slint.SlintEventLoopPolicy())
+    try:
+        async def main():
+            await asyncio.sleep(0.01)
+            return "done"
+        assert asyncio.run(main()) == "done"
+    finally:
+        asyncio.set_event_loop_policy(None)
@@ -173,6 +173,17 @@ pub fn with_scale_factor(self, factor: f32) -> Self {
         config.const_scale_factor = factor as f64;
         Self { config }
     }
+
+    /// Bundle the translations in the generated code instead of using gettext at runtime.
+    ///
+    /// The translations are read from the `<lang>/LC_MESSAGES/<crate>.po` files in the given directory
+    /// (relative to the crate's manifest directory), where `crate` is the package name. Select the language at runtime with `slint::select_bundled_translation()`.
+    #[must_use]
+    pub fn with_bundled_translations(self, path: impl Into<std::path::PathBuf>) -> Self {
+        let mut config = self.config;
+        config.translation_path_bundle = Some(path.into());
+        Self { config }
+    }
 }
 
 /// Error returned by the `compile` function
@@ -427,6 +438,12 @@ pub fn compile_with_output(
 
     let mut compiler_config = config.config;
     compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+    if let (Some(path), Some(manifest_dir)) = (
+        compiler_config.translation_path_bundle.as_mut(),
+        std::env::var_os("CARGO_MANIFEST_DIR"),
+    ) {
+        *path = Path::new(&manifest_dir).join(&*path);
+    }
 
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
 
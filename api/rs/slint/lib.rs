@@ -218,9 +218,13 @@ struct MyComponent{ /*...*/ }
     Brush, Color, Image, LoadImageError, Rgb8Pixel, Rgba8Pixel, RgbaColor, SharedPixelBuffer,
 };
 pub use i_slint_core::model::{
-    FilterModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc, ModelTracker,
-    ReverseModel, SortModel, StandardListViewItem, TableColumn, VecModel,
+    FilterModel, FlattenedTreeModel, MapModel, Model, ModelExt, ModelNotify, ModelPeer, ModelRc,
+    ModelTracker, ReverseModel, SortModel, StandardListViewItem, StandardTreeViewItem, TableColumn,
+    TreeModel, TreeModelChangeListener, TreeModelNotify, TreeModelTracker, TreeNode, VecModel,
+    VecTreeModel,
 };
+#[cfg(target_has_atomic = "ptr")]
+pub use i_slint_core::model::AsyncModel;
 pub use i_slint_core::sharedvector::SharedVector;
 pub use i_slint_core::timers::{Timer, TimerMode};
 pub use i_slint_core::{format, string::SharedString};
@@ -389,6 +393,30 @@ macro_rules! init_translations {
     };
 }
 
+/// Select the language of the translations that were bundled in the binary.
+///
+/// The translations are bundled when compiling with `slint_build::CompilerConfiguration::with_bundled_translations`:
+/// the `.po` files are read at build time and embedded in the generated code, so that no gettext runtime is needed.
+/// This function selects the language, and re-translates all the strings of the UI.
+///
+/// The language is the name of the directory in which the `.po` file was found, for example `"de"` or `"fr_CH"`.
+/// When there is no translation for `"fr_CH"`, the one for `"fr"` is used. An empty string selects the original
+/// strings from the `.slint` files.
+///
+/// This must be called after a component was created.
+///
+/// ### Example
+/// ```rust,no_run
+/// # slint::slint!{ export component App inherits Window {} }
+/// let app = App::new().unwrap();
+/// slint::select_bundled_translation("de").unwrap();
+/// ```
+pub fn select_bundled_translation(language: &str) -> Result<(), SelectBundledTranslationError> {
+    i_slint_core::translations::select_bundled_translation(language)
+}
+
+pub use i_slint_core::translations::SelectBundledTranslationError;
+
 /// This module contains items that you need to use or implement if you want use Slint in an environment without
 /// one of the supplied platform backends such as qt or winit.
 ///
@@ -411,6 +439,20 @@ pub mod femtovg_renderer {
     }
 }
 
+/// This module records the evaluations of the property bindings and exposes the dependency
+/// graph of the properties, to find out which bindings are re-evaluated when the UI is slow.
+///
+/// It is only enabled when the `binding-profiler` Slint feature is enabled. The properties
+/// are named after their component, element id and property name when the `.slint` code is
+/// compiled with the `SLINT_EMIT_DEBUG_INFO=1` environment variable.
+#[cfg(feature = "binding-profiler")]
+pub mod binding_profiler {
+    pub use i_slint_core::properties::profiler::{
+        dependency_graph, is_recording, start, statistics, statistics_as_json, stop,
+        BindingStatistics, DependencyGraph, DependencyNode,
+    };
+}
+
 #[cfg(any(
     doc,
     all(
@@ -191,8 +191,8 @@ pub mod re_exports {
     pub use i_slint_core::date_time::*;
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
-        key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyboardModifiers,
-        MouseEvent,
+        key_codes::Key, FocusEvent, FocusReason, InputEventResult, KeyEvent, KeyEventResult,
+        KeyboardModifiers, MouseEvent,
     };
     pub use i_slint_core::item_tree::{
         register_item_tree, unregister_item_tree, IndexRange, ItemTree, ItemTreeRefPin,
@@ -213,6 +213,9 @@ pub mod re_exports {
     };
     pub use i_slint_core::slice::Slice;
     pub use i_slint_core::timers::{Timer, TimerMode};
+    pub use i_slint_core::translations::{
+        set_bundled_languages, translate_from_bundle, translate_from_bundle_with_plural,
+    };
     pub use i_slint_core::window::{
         InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
     };
@@ -0,0 +1,65 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use slint::{AsyncModel, Model, SharedString};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn async_model() {
+    i_slint_backend_testing::init_integration_test_with_mock_time();
+
+    slint::invoke_from_event_loop(|| {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let model = Rc::new(AsyncModel::new(250, SharedString::from("..."), {
+            let requests = requests.clone();
+            move |range: std::ops::Range<usize>| {
+                requests.borrow_mut().push(range.clone());
+                async move { range.map(|row| slint::format!("row {row}")).collect() }
+            }
+        }));
+        model.set_page_size(100);
+
+        assert_eq!(model.row_count(), 250);
+        assert_eq!(model.row_data(150), Some("...".into()));
+        assert_eq!(model.row_data(160), Some("...".into()));
+        assert_eq!(model.row_data(249), Some("...".into()));
+        assert_eq!(model.row_data(250), None);
+        assert!(!model.is_row_loaded(150));
+
+        // The futures run in the order they were spawned, so this runs after the pages were loaded
+        slint::spawn_local(async move {
+            // Rows of a page that is loading don't request it again
+            assert_eq!(*requests.borrow(), vec![100..200, 200..250]);
+            assert!(model.is_row_loaded(150));
+            assert!(!model.is_row_loaded(50));
+            assert_eq!(model.row_data(150), Some("row 150".into()));
+            assert_eq!(model.row_data(249), Some("row 249".into()));
+            model.set_row_data(150, "changed".into());
+            assert_eq!(model.row_data(150), Some("changed".into()));
+
+            // The last page only had 50 rows, so it is fetched again
+            model.set_row_count(300);
+            assert_eq!(model.row_data(150), Some("changed".into()));
+            assert_eq!(model.row_data(280), Some("...".into()));
+
+            // The results of requests made before the reset are discarded
+            model.reset();
+            assert!(!model.is_row_loaded(150));
+            assert_eq!(model.row_data(10), Some("...".into()));
+
+            slint::spawn_local(async move {
+                // The request of the last page was made before the reset, so it was not fetched
+                assert_eq!(*requests.borrow(), vec![100..200, 200..250, 0..100]);
+                assert!(model.is_row_loaded(10));
+                assert!(!model.is_row_loaded(280));
+                assert_eq!(model.row_data(10), Some("row 10".into()));
+                slint::quit_event_loop().unwrap();
+            })
+            .unwrap();
+        })
+        .unwrap();
+    })
+    .unwrap();
+    slint::run_event_loop().unwrap();
+}
@@ -4,10 +4,13 @@
 //! This wasm library can be loaded from JS to load and display the content of .slint files
 #![cfg(target_arch = "wasm32")]
 
+use i_slint_compiler::langtype::Type;
 use std::path::Path;
 use wasm_bindgen::prelude::*;
 
-use slint_interpreter::ComponentHandle;
+use slint_interpreter::{ComponentHandle, Value};
+
+mod value;
 
 #[wasm_bindgen]
 #[allow(dead_code)]
@@ -245,6 +248,87 @@ pub fn hide(&self) -> Result<js_sys::Promise, JsValue> {
         self.invoke_from_event_loop_wrapped_in_promise(|instance| instance.hide())
     }
 
+    /// Returns the value of the public property with the given name.
+    #[wasm_bindgen]
+    pub fn get_property(&self, name: &str) -> Result<JsValue, JsValue> {
+        let value = self.0.get_property(name).map_err(|e| JsValue::from(e.to_string()))?;
+        value::to_js_value(&value)
+    }
+    /// Sets the value of the public property with the given name.
+    #[wasm_bindgen]
+    pub fn set_property(&self, name: &str, value: JsValue) -> Result<(), JsValue> {
+        let ty = find_type(self.0.definition().properties_and_callbacks(), name)?;
+        self.0
+            .set_property(name, value::to_value(value, &ty)?)
+            .map_err(|e| JsValue::from(e.to_string()))
+    }
+    /// Sets the handler for the public callback with the given name.
+    #[wasm_bindgen]
+    pub fn set_callback(&self, name: &str, callback: js_sys::Function) -> Result<(), JsValue> {
+        let ty = find_type(self.0.definition().properties_and_callbacks(), name)?;
+        self.0
+            .set_callback(name, js_callback(name, &ty, callback)?)
+            .map_err(|e| JsValue::from(e.to_string()))
+    }
+    /// Invokes the public callback or function with the given name.
+    #[wasm_bindgen]
+    pub fn invoke(&self, name: &str, args: js_sys::Array) -> Result<JsValue, JsValue> {
+        let ty = find_type(self.0.definition().properties_and_callbacks(), name)?;
+        let result = self
+            .0
+            .invoke(name, &arguments(name, &ty, args)?)
+            .map_err(|e| JsValue::from(e.to_string()))?;
+        value::to_js_value(&result)
+    }
+    /// Returns the value of a property of an exported global.
+    #[wasm_bindgen]
+    pub fn get_global_property(&self, global: &str, name: &str) -> Result<JsValue, JsValue> {
+        let value =
+            self.0.get_global_property(global, name).map_err(|e| JsValue::from(e.to_string()))?;
+        value::to_js_value(&value)
+    }
+    /// Sets the value of a property of an exported global.
+    #[wasm_bindgen]
+    pub fn set_global_property(
+        &self,
+        global: &str,
+        name: &str,
+        value: JsValue,
+    ) -> Result<(), JsValue> {
+        let ty = find_type(global_properties_and_callbacks(&self.0, global)?, name)?;
+        self.0
+            .set_global_property(global, name, value::to_value(value, &ty)?)
+            .map_err(|e| JsValue::from(e.to_string()))
+    }
+    /// Sets the handler for a callback of an exported global.
+    #[wasm_bindgen]
+    pub fn set_global_callback(
+        &self,
+        global: &str,
+        name: &str,
+        callback: js_sys::Function,
+    ) -> Result<(), JsValue> {
+        let ty = find_type(global_properties_and_callbacks(&self.0, global)?, name)?;
+        self.0
+            .set_global_callback(global, name, js_callback(name, &ty, callback)?)
+            .map_err(|e| JsValue::from(e.to_string()))
+    }
+    /// Invokes a callback or function of an exported global.
+    #[wasm_bindgen]
+    pub fn invoke_global(
+        &self,
+        global: &str,
+        name: &str,
+        args: js_sys::Array,
+    ) -> Result<JsValue, JsValue> {
+        let ty = find_type(global_properties_and_callbacks(&self.0, global)?, name)?;
+        let result = self
+            .0
+            .invoke_global(global, name, &arguments(name, &ty, args)?)
+            .map_err(|e| JsValue::from(e.to_string()))?;
+        value::to_js_value(&result)
+    }
+
     fn invoke_from_event_loop_wrapped_in_promise(
         &self,
         callback: impl FnOnce(
@@ -306,6 +390,72 @@ fn invoke_from_event_loop_wrapped_in_promise(
     }
 }
 
+fn find_type(
+    mut properties: impl Iterator<Item = (String, Type)>,
+    name: &str,
+) -> Result<Type, JsValue> {
+    properties
+        .find_map(|(prop_name, ty)| (prop_name == name).then_some(ty))
+        .ok_or_else(|| JsValue::from(format!("{name} not found in the component")))
+}
+
+fn global_properties_and_callbacks(
+    instance: &slint_interpreter::ComponentInstance,
+    global: &str,
+) -> Result<impl Iterator<Item = (String, Type)>, JsValue> {
+    // Collected because the iterator borrows the definition
+    instance
+        .definition()
+        .global_properties_and_callbacks(global)
+        .map(|properties| properties.collect::<Vec<_>>().into_iter())
+        .ok_or_else(|| JsValue::from(format!("Global {global} not found")))
+}
+
+/// Converts the arguments of a call to the callback or function `name` of type `ty`
+fn arguments(name: &str, ty: &Type, args: js_sys::Array) -> Result<Vec<Value>, JsValue> {
+    let (Type::Callback { args: arg_types, .. } | Type::Function { args: arg_types, .. }) = ty
+    else {
+        return Err(JsValue::from(format!("{name} is not a callback or a function")));
+    };
+    if args.length() as usize != arg_types.len() {
+        return Err(JsValue::from(format!(
+            "{name} expects {} arguments, but {} were provided",
+            arg_types.len(),
+            args.length()
+        )));
+    }
+    args.iter().zip(arg_types).map(|(arg, ty)| value::to_value(arg, ty)).collect()
+}
+
+/// Wraps a JavaScript function as the handler of the callback `name` of type `ty`.
+/// Errors thrown by the function are logged, and the callback returns the default value.
+fn js_callback(
+    name: &str,
+    ty: &Type,
+    callback: js_sys::Function,
+) -> Result<impl Fn(&[Value]) -> Value + 'static, JsValue> {
+    let Type::Callback { return_type, .. } = ty else {
+        return Err(JsValue::from(format!("{name} is not a callback")));
+    };
+    let return_type = return_type.as_deref().cloned().unwrap_or(Type::Void);
+    let name = name.to_string();
+    Ok(move |args: &[Value]| {
+        let result = args
+            .iter()
+            .map(value::to_js_value)
+            .collect::<Result<js_sys::Array, _>>()
+            .and_then(|args| callback.apply(&JsValue::UNDEFINED, &args))
+            .and_then(|result| match return_type {
+                Type::Void => Ok(Value::Void),
+                _ => value::to_value(result, &return_type),
+            });
+        result.unwrap_or_else(|e| {
+            i_slint_core::debug_log!("Error in the handler of callback {name}: {e:?}");
+            slint_interpreter::default_value_for_type(&return_type)
+        })
+    })
+}
+
 /// Register DOM event handlers on all instance and set up the event loop for that.
 /// You can call this function only once. It will throw an exception but that is safe
 /// to ignore.
@@ -0,0 +1,292 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Conversion between the interpreter values and JavaScript values.
+//!
+//! Colors are CSS color strings. Gradients are objects with a `type` (`"linear"`, `"radial"`, or
+//! `"conic"`), the parameters of the gradient, a `spread`, and the `stops` as an array of
+//! `{ color, position }` objects. Images are `ImageData` (or `undefined` for the images that have
+//! no pixel data, such as the empty image), structs are objects whose field names use `_` instead
+//! of `-`, and enumeration values are strings.
+
+use i_slint_compiler::langtype::Type;
+use i_slint_core::graphics::{
+    ConicGradientBrush, GradientSpread, GradientStop, LinearGradientBrush, RadialGradientBrush,
+};
+use i_slint_core::model::{Model, ModelRc, SharedVectorModel};
+use slint_interpreter::{Brush, Color, Image, Rgba8Pixel, SharedPixelBuffer, Value};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{Clamped, JsCast};
+
+pub fn to_js_value(value: &Value) -> Result<JsValue, JsValue> {
+    Ok(match value {
+        Value::Void => JsValue::UNDEFINED,
+        Value::Number(number) => JsValue::from_f64(*number),
+        Value::String(string) => JsValue::from_str(string),
+        Value::Bool(value) => JsValue::from_bool(*value),
+        Value::Brush(brush) => brush_to_js_value(brush)?,
+        Value::Image(image) => match image.to_rgba8() {
+            Some(buffer) => web_sys::ImageData::new_with_u8_clamped_array_and_sh(
+                Clamped(buffer.as_bytes()),
+                buffer.width(),
+                buffer.height(),
+            )?
+            .into(),
+            None => JsValue::UNDEFINED,
+        },
+        Value::Model(model) => {
+            let array = js_sys::Array::new();
+            for value in model.iter() {
+                array.push(&to_js_value(&value)?);
+            }
+            array.into()
+        }
+        Value::Struct(struct_value) => {
+            let object = js_sys::Object::new();
+            for (field_name, field_value) in struct_value.iter() {
+                js_sys::Reflect::set(
+                    &object,
+                    &JsValue::from_str(&field_name.replace('-', "_")),
+                    &to_js_value(field_value)?,
+                )?;
+            }
+            object.into()
+        }
+        Value::EnumerationValue(_, value) => JsValue::from_str(value),
+        _ => JsValue::UNDEFINED,
+    })
+}
+
+pub fn to_value(value: JsValue, ty: &Type) -> Result<Value, JsValue> {
+    match ty {
+        Type::Float32
+        | Type::Int32
+        | Type::Duration
+        | Type::Angle
+        | Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Rem
+        | Type::Percent
+        | Type::UnitProduct(_) => value
+            .as_f64()
+            .map(Value::Number)
+            .ok_or_else(|| JsValue::from_str("Cannot convert value to number")),
+        Type::String => value
+            .as_string()
+            .map(|string| Value::String(string.into()))
+            .ok_or_else(|| JsValue::from_str("Cannot convert value to string")),
+        Type::Bool => value
+            .as_bool()
+            .map(Value::Bool)
+            .ok_or_else(|| JsValue::from_str("Cannot convert value to bool")),
+        Type::Color => Ok(Value::Brush(Brush::from(color_from_js_value(value)?))),
+        Type::Brush if value.is_string() => {
+            Ok(Value::Brush(Brush::from(color_from_js_value(value)?)))
+        }
+        Type::Brush => brush_from_js_value(value).map(Value::Brush),
+        Type::Image => {
+            if value.is_undefined() {
+                return Ok(Value::Image(Image::default()));
+            }
+            let image_data = value.dyn_into::<web_sys::ImageData>().map_err(|_| {
+                JsValue::from_str("Cannot convert value to image, because it is not an ImageData")
+            })?;
+            let buffer = SharedPixelBuffer::<Rgba8Pixel>::clone_from_slice(
+                image_data.data().0.as_slice(),
+                image_data.width(),
+                image_data.height(),
+            );
+            Ok(Value::Image(Image::from_rgba8(buffer)))
+        }
+        Type::Struct { fields, .. } => {
+            if !value.is_object() {
+                return Err(JsValue::from_str("Cannot convert value to struct"));
+            }
+            Ok(Value::Struct(
+                fields
+                    .iter()
+                    .map(|(field_name, field_ty)| {
+                        let field = js_sys::Reflect::get(
+                            &value,
+                            &JsValue::from_str(&field_name.replace('-', "_")),
+                        )?;
+                        let field_value = if field.is_undefined() {
+                            slint_interpreter::default_value_for_type(field_ty)
+                        } else {
+                            to_value(field, field_ty)?
+                        };
+                        Ok((field_name.to_string(), field_value))
+                    })
+                    .collect::<Result<_, JsValue>>()?,
+            ))
+        }
+        Type::Array(element_ty) => {
+            if !js_sys::Array::is_array(&value) {
+                return Err(JsValue::from_str(
+                    "Cannot convert value to model, because it is not an array",
+                ));
+            }
+            let values = js_sys::Array::from(&value)
+                .iter()
+                .map(|element| to_value(element, element_ty))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::Model(ModelRc::new(SharedVectorModel::from(
+                values.into_iter().collect::<slint_interpreter::SharedVector<_>>(),
+            ))))
+        }
+        Type::Enumeration(e) => {
+            let value = value
+                .as_string()
+                .ok_or_else(|| JsValue::from_str("Cannot convert value to enumeration value"))?;
+            if !e.values.iter().any(|v| *v == value) {
+                return Err(JsValue::from_str(&format!(
+                    "{value} is not a value of enum {}",
+                    e.name
+                )));
+            }
+            Ok(Value::EnumerationValue(e.name.to_string(), value))
+        }
+        _ => Err(JsValue::from_str(&format!("Cannot convert value to {ty}"))),
+    }
+}
+
+fn color_to_js_value(color: Color) -> JsValue {
+    let color = color.to_argb_u8();
+    JsValue::from_str(&format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        color.red, color.green, color.blue, color.alpha
+    ))
+}
+
+fn color_from_js_value(value: JsValue) -> Result<Color, JsValue> {
+    let string = value.as_string().ok_or_else(|| {
+        JsValue::from_str("Cannot convert value to color, because it is not a string")
+    })?;
+    let c = string
+        .parse::<css_color_parser2::Color>()
+        .map_err(|_| JsValue::from_str(&format!("Could not convert {string} to Color.")))?;
+    Ok(Color::from_argb_u8((c.a * 255.) as u8, c.r, c.g, c.b))
+}
+
+fn brush_to_js_value(brush: &Brush) -> Result<JsValue, JsValue> {
+    let object = js_sys::Object::new();
+    let set = |name: &str, value: &JsValue| js_sys::Reflect::set(&object, &name.into(), value);
+    let set_center = |(x, y): (f32, f32)| {
+        let center = js_sys::Object::new();
+        js_sys::Reflect::set(&center, &"x".into(), &JsValue::from_f64(x as f64))?;
+        js_sys::Reflect::set(&center, &"y".into(), &JsValue::from_f64(y as f64))?;
+        set("center", &center)
+    };
+    let (kind, spread, stops) = match brush {
+        Brush::SolidColor(color) => return Ok(color_to_js_value(*color)),
+        Brush::LinearGradient(g) => {
+            set("angle", &JsValue::from_f64(g.angle() as f64))?;
+            ("linear", g.spread(), g.stops().collect::<Vec<_>>())
+        }
+        Brush::RadialGradient(g) => {
+            set_center(g.center())?;
+            set("radius", &JsValue::from_f64(g.radius() as f64))?;
+            ("radial", g.spread(), g.stops().collect())
+        }
+        Brush::ConicGradient(g) => {
+            set("from_angle", &JsValue::from_f64(g.from_angle() as f64))?;
+            set_center(g.center())?;
+            ("conic", g.spread(), g.stops().collect())
+        }
+        _ => return Err(JsValue::from_str("Cannot convert this brush to a JavaScript value")),
+    };
+    set("type", &kind.into())?;
+    let spread = match spread {
+        GradientSpread::Repeat => "repeat",
+        GradientSpread::Reflect => "reflect",
+        _ => "pad",
+    };
+    set("spread", &spread.into())?;
+    let js_stops = js_sys::Array::new();
+    for stop in stops {
+        let js_stop = js_sys::Object::new();
+        js_sys::Reflect::set(&js_stop, &"color".into(), &color_to_js_value(stop.color))?;
+        js_sys::Reflect::set(
+            &js_stop,
+            &"position".into(),
+            &JsValue::from_f64(stop.position as f64),
+        )?;
+        js_stops.push(&js_stop);
+    }
+    set("stops", &js_stops)?;
+    Ok(object.into())
+}
+
+fn brush_from_js_value(value: JsValue) -> Result<Brush, JsValue> {
+    if !value.is_object() {
+        return Err(JsValue::from_str(
+            "Cannot convert value to brush, because it is neither a string nor an object",
+        ));
+    }
+    let get = |object: &JsValue, name: &str| js_sys::Reflect::get(object, &name.into());
+    // Missing numbers take the default value of the gradient
+    let number = |object: &JsValue, name: &str, default: f32| -> Result<f32, JsValue> {
+        let field = get(object, name)?;
+        if field.is_undefined() {
+            return Ok(default);
+        }
+        field.as_f64().map(|x| x as f32).ok_or_else(|| {
+            JsValue::from_str(&format!("The {name} of the gradient is not a number"))
+        })
+    };
+    let center = || -> Result<(f32, f32), JsValue> {
+        let center = get(&value, "center")?;
+        if center.is_undefined() {
+            return Ok((0.5, 0.5));
+        }
+        Ok((number(&center, "x", 0.5)?, number(&center, "y", 0.5)?))
+    };
+    let spread = match get(&value, "spread")?.as_string().as_deref() {
+        None | Some("pad") => GradientSpread::Pad,
+        Some("repeat") => GradientSpread::Repeat,
+        Some("reflect") => GradientSpread::Reflect,
+        Some(spread) => {
+            return Err(JsValue::from_str(&format!("{spread} is not a spread of gradient")))
+        }
+    };
+    let js_stops = get(&value, "stops")?;
+    if !js_sys::Array::is_array(&js_stops) {
+        return Err(JsValue::from_str("The stops of the gradient are not an array"));
+    }
+    let stops = js_sys::Array::from(&js_stops)
+        .iter()
+        .map(|stop| {
+            Ok(GradientStop {
+                color: color_from_js_value(get(&stop, "color")?)?,
+                position: number(&stop, "position", 0.)?,
+            })
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+    Ok(match get(&value, "type")?.as_string().as_deref() {
+        Some("linear") => Brush::LinearGradient(
+            LinearGradientBrush::new(number(&value, "angle", 0.)?, stops).with_spread(spread),
+        ),
+        Some("radial") => {
+            let (x, y) = center()?;
+            Brush::RadialGradient(
+                RadialGradientBrush::new_circle(stops)
+                    .with_center(x, y)
+                    .with_radius(number(&value, "radius", 0.)?)
+                    .with_spread(spread),
+            )
+        }
+        Some("conic") => {
+            let (x, y) = center()?;
+            Brush::ConicGradient(
+                ConicGradientBrush::new(number(&value, "from_angle", 0.)?, stops)
+                    .with_center(x, y)
+                    .with_spread(spread),
+            )
+        }
+        _ => {
+            return Err(JsValue::from_str(
+                "Cannot convert value to brush, because its type is not linear, radial, or conic",
+            ))
+        }
+    })
+}
@@ -73,15 +73,6 @@ fn update_window_properties(&self, properties: WindowProperties<'_>) {
         }
     }
 
-    fn internal(
-        &self,
-        _: i_slint_core::InternalToken,
-    ) -> Option<&dyn i_slint_core::window::WindowAdapterInternal> {
-        Some(self)
-    }
-}
-
-impl i_slint_core::window::WindowAdapterInternal for AndroidWindowAdapter {
     #[cfg(feature = "native-activity")]
     fn input_method_request(&self, request: InputMethodRequest) {
         match request {
@@ -161,6 +152,15 @@ fn input_method_request(&self, request: InputMethodRequest) {
         });
     }
 
+    fn internal(
+        &self,
+        _: i_slint_core::InternalToken,
+    ) -> Option<&dyn i_slint_core::window::WindowAdapterInternal> {
+        Some(self)
+    }
+}
+
+impl i_slint_core::window::WindowAdapterInternal for AndroidWindowAdapter {
     fn color_scheme(&self) -> ColorScheme {
         self.color_scheme.as_ref().get()
     }
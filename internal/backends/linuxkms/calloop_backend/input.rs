@@ -4,7 +4,6 @@
 //! This module contains the code to receive input events from libinput
 
 use std::cell::RefCell;
-#[cfg(feature = "libseat")]
 use std::collections::HashMap;
 #[cfg(not(feature = "libseat"))]
 use std::fs::{File, OpenOptions};
@@ -24,7 +23,7 @@
 use input::LibinputInterface;
 
 use input::event::keyboard::{KeyState, KeyboardEventTrait};
-use input::event::touch::TouchEventPosition;
+use input::event::touch::{TouchEventPosition, TouchEventSlot};
 use xkbcommon::*;
 
 use crate::fullscreenwindowadapter::FullscreenWindowAdapter;
@@ -115,7 +114,8 @@ pub struct LibInputHandler<'a> {
     libinput: input::Libinput,
     token: Option<calloop::Token>,
     mouse_pos: Pin<Rc<Property<Option<LogicalPosition>>>>,
-    last_touch_pos: LogicalPosition,
+    /// The last position of the touch points that are pressed, by seat slot
+    touch_positions: HashMap<u32, LogicalPosition>,
     window: &'a RefCell<Option<Rc<FullscreenWindowAdapter>>>,
     keystate: Option<xkb::State>,
 }
@@ -137,7 +137,7 @@ pub fn init<T>(
             libinput,
             token: Default::default(),
             mouse_pos: mouse_pos_property.clone(),
-            last_touch_pos: Default::default(),
+            touch_positions: Default::default(),
             window,
             keystate: Default::default(),
         };
@@ -235,31 +235,50 @@ fn process_events<F>(
                     }
                 }
                 input::Event::Touch(touch_event) => {
-                    if let Some(event) = match touch_event {
+                    match touch_event {
                         input::event::TouchEvent::Down(touch_down_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let touch_id = touch_down_event.seat_slot();
+                            let position = LogicalPosition::new(
                                 touch_down_event.x_transformed(screen_size.width as u32) as _,
                                 touch_down_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerPressed {
-                                position: self.last_touch_pos,
-                                button: PointerEventButton::Left,
-                            })
+                            self.touch_positions.insert(touch_id, position);
+                            window.dispatch_event(WindowEvent::TouchPressed {
+                                touch_id: touch_id as _,
+                                position,
+                            });
+                        }
+                        input::event::TouchEvent::Up(touch_up_event) => {
+                            let touch_id = touch_up_event.seat_slot();
+                            if let Some(position) = self.touch_positions.remove(&touch_id) {
+                                window.dispatch_event(WindowEvent::TouchReleased {
+                                    touch_id: touch_id as _,
+                                    position,
+                                });
+                            }
                         }
-                        input::event::TouchEvent::Up(..) => Some(WindowEvent::PointerReleased {
-                            position: self.last_touch_pos,
-                            button: PointerEventButton::Left,
-                        }),
                         input::event::TouchEvent::Motion(touch_motion_event) => {
-                            self.last_touch_pos = LogicalPosition::new(
+                            let touch_id = touch_motion_event.seat_slot();
+                            let position = LogicalPosition::new(
                                 touch_motion_event.x_transformed(screen_size.width as u32) as _,
                                 touch_motion_event.y_transformed(screen_size.height as u32) as _,
                             );
-                            Some(WindowEvent::PointerMoved { position: self.last_touch_pos })
+                            self.touch_positions.insert(touch_id, position);
+                            window.dispatch_event(WindowEvent::TouchMoved {
+                                touch_id: touch_id as _,
+                                position,
+                            });
                         }
-                        _ => None,
-                    } {
-                        window.dispatch_event(event);
+                        // The touch sequence was interrupted, cancel all the touch points
+                        input::event::TouchEvent::Cancel(..) => {
+                            for (touch_id, position) in self.touch_positions.drain() {
+                                window.dispatch_event(WindowEvent::TouchCancelled {
+                                    touch_id: touch_id as _,
+                                    position,
+                                });
+                            }
+                        }
+                        _ => {}
                     }
                 }
                 input::Event::Keyboard(input::event::KeyboardEvent::Key(key_event)) => {
@@ -25,11 +25,14 @@
 const NAME: u32 = QAccessible_Text_Name;
 const DESCRIPTION: u32 = QAccessible_Text_Description;
 const VALUE: u32 = QAccessible_Text_Value;
+const ACCELERATOR: u32 = QAccessible_Text_Accelerator;
 const CHECKED: u32 = QAccessible_Text_UserText;
 const VALUE_MINIMUM: u32 = CHECKED + 1;
 const VALUE_MAXIMUM: u32 = VALUE_MINIMUM + 1;
 const VALUE_STEP: u32 = VALUE_MAXIMUM + 1;
 const CHECKABLE: u32 = VALUE_STEP + 1;
+const EXPANDABLE: u32 = CHECKABLE + 1;
+const EXPANDED: u32 = EXPANDABLE + 1;
 
 pub struct AccessibleItemPropertiesTracker {
     obj: *mut c_void,
@@ -208,6 +211,8 @@ fn arm_state_tracker(self: Pin<&Self>) {
             if let Some(item_rc) = item.upgrade() {
                 item_rc.accessible_string_property(AccessibleStringProperty::Checkable);
                 item_rc.accessible_string_property(AccessibleStringProperty::Checked);
+                item_rc.accessible_string_property(AccessibleStringProperty::Expandable);
+                item_rc.accessible_string_property(AccessibleStringProperty::Expanded);
             }
         });
     }
@@ -267,6 +272,8 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
     const uint32_t VALUE_MAXIMUM { VALUE_MINIMUM + 1 };
     const uint32_t VALUE_STEP { VALUE_MAXIMUM + 1 };
     const uint32_t CHECKABLE { VALUE_STEP + 1 };
+    const uint32_t EXPANDABLE { CHECKABLE + 1 };
+    const uint32_t EXPANDED { EXPANDABLE + 1 };
 
     // ------------------------------------------------------------------------------
     // Helper:
@@ -321,6 +328,7 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
                     i_slint_core::items::AccessibleRole::TextInput => QAccessible_Role_EditableText,
                     i_slint_core::items::AccessibleRole::Switch => QAccessible_Role_CheckBox,
                     i_slint_core::items::AccessibleRole::ListItem => QAccessible_Role_ListItem,
+                    i_slint_core::items::AccessibleRole::TreeItem => QAccessible_Role_TreeItem,
                     _ => QAccessible_Role_NoRole,
                 }
             });
@@ -355,11 +363,14 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
                     NAME => item.accessible_string_property(AccessibleStringProperty::Label),
                     DESCRIPTION => item.accessible_string_property(AccessibleStringProperty::Description),
                     VALUE => item.accessible_string_property(AccessibleStringProperty::Value),
+                    ACCELERATOR => item.accessible_string_property(AccessibleStringProperty::KeyboardShortcut),
                     CHECKED => item.accessible_string_property(AccessibleStringProperty::Checked),
                     VALUE_MINIMUM => item.accessible_string_property(AccessibleStringProperty::ValueMinimum),
                     VALUE_MAXIMUM => item.accessible_string_property(AccessibleStringProperty::ValueMaximum),
                     VALUE_STEP => item.accessible_string_property(AccessibleStringProperty::ValueStep),
                     CHECKABLE => item.accessible_string_property(AccessibleStringProperty::Checkable),
+                    EXPANDABLE => item.accessible_string_property(AccessibleStringProperty::Expandable),
+                    EXPANDED => item.accessible_string_property(AccessibleStringProperty::Expanded),
                     _ => None,
                 };
                 if let Some(string) = string {
@@ -619,6 +630,9 @@ fn arm_focus_delegation_tracker(self: Pin<&Self>) {
             state.focused = has_focus_delegation;
             state.checked = (checked == "true") ? 1 : 0;
             state.checkable = (item_string_property(m_data, CHECKABLE) == "true") ? 1 : 0;
+            state.expandable = (item_string_property(m_data, EXPANDABLE) == "true") ? 1 : 0;
+            state.expanded = (state.expandable && item_string_property(m_data, EXPANDED) == "true") ? 1 : 0;
+            state.collapsed = (state.expandable && !state.expanded) ? 1 : 0;
             return state; /* FIXME */
         }
 
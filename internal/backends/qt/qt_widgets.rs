@@ -23,7 +23,8 @@
 use cpp::{cpp, cpp_class};
 use i_slint_core::graphics::Color;
 use i_slint_core::input::{
-    FocusEvent, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult, MouseEvent,
+    FocusEvent, FocusReason, InputEventFilterResult, InputEventResult, KeyEvent, KeyEventResult,
+    MouseEvent,
 };
 use i_slint_core::item_rendering::{CachedRenderingData, ItemRenderer};
 use i_slint_core::items::{Item, ItemConsts, ItemRc, ItemVTable, RenderingResult, VoidArg};
@@ -270,7 +270,10 @@ fn input_event(
                     InputEventResult::EventAccepted
                 }
             }
-            MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. } => return InputEventResult::EventIgnored,
         });
         if let MouseEvent::Released { position, .. } = event {
             let geo = self_rc.geometry();
@@ -317,7 +320,7 @@ fn focus_event(
             Self::FIELD_OFFSETS
                 .has_focus
                 .apply_pin(self)
-                .set(event == &FocusEvent::FocusIn || event == &FocusEvent::WindowReceivedFocus);
+                .set(matches!(event, FocusEvent::FocusIn(_) | FocusEvent::WindowReceivedFocus));
             FocusEventResult::FocusAccepted
         } else {
             FocusEventResult::FocusIgnored
@@ -128,7 +128,7 @@ fn focus_event(
             Self::FIELD_OFFSETS
                 .has_focus
                 .apply_pin(self)
-                .set(event == &FocusEvent::FocusIn || event == &FocusEvent::WindowReceivedFocus);
+                .set(matches!(event, FocusEvent::FocusIn(_) | FocusEvent::WindowReceivedFocus));
             FocusEventResult::FocusAccepted
         } else {
             FocusEventResult::FocusIgnored
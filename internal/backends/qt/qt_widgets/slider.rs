@@ -209,7 +209,11 @@ fn input_event(
                 click_count: _,
             } => {
                 if !self.has_focus() {
-                    WindowInner::from_pub(window_adapter.window()).set_focus_item(self_rc, true);
+                    WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                        self_rc,
+                        true,
+                        FocusReason::PointerClick,
+                    );
                 }
                 data.pressed_x = if vertical { pos.y as f32 } else { pos.x as f32 };
                 data.pressed = 1;
@@ -246,6 +250,9 @@ fn input_event(
                 debug_assert_ne!(button, PointerEventButton::Left);
                 InputEventResult::EventIgnored
             }
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. } => InputEventResult::EventIgnored,
         };
         data.active_controls = new_control;
 
@@ -299,7 +306,7 @@ fn focus_event(
             Self::FIELD_OFFSETS
                 .has_focus
                 .apply_pin(self)
-                .set(event == &FocusEvent::FocusIn || event == &FocusEvent::WindowReceivedFocus);
+                .set(matches!(event, FocusEvent::FocusIn(_) | FocusEvent::WindowReceivedFocus));
             FocusEventResult::FocusAccepted
         } else {
             FocusEventResult::FocusIgnored
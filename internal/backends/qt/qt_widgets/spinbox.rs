@@ -200,7 +200,10 @@ fn input_event(
                     }
                     true
                 }
-                MouseEvent::Moved { .. } => false,
+                MouseEvent::Moved { .. }
+                | MouseEvent::DragMove { .. }
+                | MouseEvent::Drop { .. }
+                | MouseEvent::TouchGesture { .. } => false,
                 MouseEvent::Wheel { delta_y, .. } => {
                     if delta_y > 0. {
                         let v = self.value();
@@ -228,7 +231,11 @@ fn input_event(
 
         if let MouseEvent::Pressed { .. } = event {
             if !self.has_focus() {
-                WindowInner::from_pub(window_adapter.window()).set_focus_item(self_rc, true);
+                WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                    self_rc,
+                    true,
+                    FocusReason::PointerClick,
+                );
             }
         }
         InputEventResult::EventAccepted
@@ -269,7 +276,7 @@ fn focus_event(
         _self_rc: &ItemRc,
     ) -> FocusEventResult {
         match event {
-            FocusEvent::FocusIn => {
+            FocusEvent::FocusIn(_) => {
                 if self.enabled() {
                     self.has_focus.set(true);
                 }
@@ -432,7 +432,10 @@ fn input_event(
                     InputEventResult::EventIgnored
                 }
             }
-            MouseEvent::Wheel { .. } => return InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. } => return InputEventResult::EventIgnored,
         });
         let click_on_press = cpp!(unsafe [] -> bool as "bool" {
             return qApp->style()->styleHint(QStyle::SH_TabBar_SelectMouseType, nullptr, nullptr) == QEvent::MouseButtonPress;
@@ -440,7 +443,11 @@ fn input_event(
         if matches!(event, MouseEvent::Released { button, .. } if !click_on_press && button == PointerEventButton::Left)
             || matches!(event, MouseEvent::Pressed { button, .. } if click_on_press && button == PointerEventButton::Left)
         {
-            WindowInner::from_pub(window_adapter.window()).set_focus_item(self_rc, true);
+            WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                self_rc,
+                true,
+                FocusReason::PointerClick,
+            );
             self.current.set(self.tab_index());
             InputEventResult::EventAccepted
         } else {
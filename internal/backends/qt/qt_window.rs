@@ -8,8 +8,8 @@
     RenderingMetrics, RenderingMetricsCollector,
 };
 use i_slint_core::graphics::{
-    euclid, Brush, Color, FontRequest, IntRect, Point, Rgba8Pixel, SharedImageBuffer,
-    SharedPixelBuffer,
+    euclid, normalize_stops, Brush, Color, FontRequest, GradientSpread, IntRect, Point, Rgba8Pixel,
+    SharedImageBuffer, SharedPixelBuffer,
 };
 use i_slint_core::input::{KeyEvent, KeyEventType, MouseEvent};
 use i_slint_core::item_rendering::{
@@ -544,17 +544,21 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 g.angle(),
                 [width as f32, height as f32].into(),
             );
+            let (from, to, stops) = normalize_stops(g.stops(), g.spread());
+            let (start, end) = (start.lerp(end, from), start.lerp(end, to));
             let p1 = qttypes::QPointF { x: start.x as _, y: start.y as _ };
             let p2 = qttypes::QPointF { x: end.x as _, y: end.y as _ };
+            let spread = to_qt_spread(g.spread());
             cpp_class!(unsafe struct QLinearGradient as "QLinearGradient");
             let mut qlg = cpp! {
-                unsafe [p1 as "QPointF", p2 as "QPointF"] -> QLinearGradient as "QLinearGradient" {
+                unsafe [p1 as "QPointF", p2 as "QPointF", spread as "int"] -> QLinearGradient as "QLinearGradient" {
                     QLinearGradient qlg(p1, p2);
+                    qlg.setSpread(QGradient::Spread(spread));
                     return qlg;
                 }
             };
-            let count = g.stops().count();
-            for (idx, s) in g.stops().enumerate() {
+            let count = stops.len();
+            for (idx, s) in stops.iter().enumerate() {
                 let pos: f32 = mangle_position(s.position, idx, count);
                 let color: u32 = s.color.as_argb_encoded();
                 cpp! {unsafe [mut qlg as "QLinearGradient", pos as "float", color as "QRgb"] {
@@ -566,15 +570,26 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
             }}
         }
         i_slint_core::Brush::RadialGradient(g) => {
+            let (from, to, stops) = normalize_stops(g.stops(), g.spread());
+            let (cx, cy) = g.center();
+            let center = qttypes::QPointF {
+                x: cx as qttypes::qreal * width,
+                y: cy as qttypes::qreal * height,
+            };
+            let radius = g.radius_for_size([width as f32, height as f32].into()) as qttypes::qreal;
+            let (focal_radius, radius) =
+                (radius * from as qttypes::qreal, radius * to as qttypes::qreal);
+            let spread = to_qt_spread(g.spread());
             cpp_class!(unsafe struct QRadialGradient as "QRadialGradient");
             let mut qrg = cpp! {
-                unsafe [width as "qreal", height as "qreal"] -> QRadialGradient as "QRadialGradient" {
-                    QRadialGradient qrg(width / 2, height / 2, (width + height) / 4);
+                unsafe [center as "QPointF", radius as "qreal", focal_radius as "qreal", spread as "int"] -> QRadialGradient as "QRadialGradient" {
+                    QRadialGradient qrg(center, radius, center, focal_radius);
+                    qrg.setSpread(QGradient::Spread(spread));
                     return qrg;
                 }
             };
-            let count = g.stops().count();
-            for (idx, s) in g.stops().enumerate() {
+            let count = stops.len();
+            for (idx, s) in stops.iter().enumerate() {
                 let pos: f32 = mangle_position(s.position, idx, count);
                 let color: u32 = s.color.as_argb_encoded();
                 cpp! {unsafe [mut qrg as "QRadialGradient", pos as "float", color as "QRgb"] {
@@ -585,10 +600,46 @@ fn mangle_position(position: f32, idx: usize, count: usize) -> f32 {
                 return QBrush(qrg);
             }}
         }
+        i_slint_core::Brush::ConicGradient(g) => {
+            let (cx, cy) = g.center();
+            let center = qttypes::QPointF {
+                x: cx as qttypes::qreal * width,
+                y: cy as qttypes::qreal * height,
+            };
+            // Qt's conical gradients go counter-clockwise from 3 o'clock, ours go clockwise
+            // from the top, so mirror the positions.
+            let angle = (90. - g.from_angle()) as qttypes::qreal;
+            cpp_class!(unsafe struct QConicalGradient as "QConicalGradient");
+            let mut qcg = cpp! {
+                unsafe [center as "QPointF", angle as "qreal"] -> QConicalGradient as "QConicalGradient" {
+                    return QConicalGradient(center, angle);
+                }
+            };
+            let count = g.stops().count();
+            for (idx, s) in g.stops().rev().enumerate() {
+                let pos: f32 = mangle_position(1. - s.position.clamp(0., 1.), idx, count);
+                let color: u32 = s.color.as_argb_encoded();
+                cpp! {unsafe [mut qcg as "QConicalGradient", pos as "float", color as "QRgb"] {
+                    qcg.setColorAt(pos, QColor::fromRgba(color));
+                }};
+            }
+            cpp! {unsafe [qcg as "QConicalGradient"] -> qttypes::QBrush as "QBrush" {
+                return QBrush(qcg);
+            }}
+        }
         _ => qttypes::QBrush::default(),
     }
 }
 
+fn to_qt_spread(spread: GradientSpread) -> i32 {
+    // https://doc.qt.io/qt-6/qgradient.html#Spread-enum
+    match spread {
+        GradientSpread::Reflect => 1,
+        GradientSpread::Repeat => 2,
+        _ => 0,
+    }
+}
+
 fn from_qt_button(qt_button: u32) -> PointerEventButton {
     match qt_button {
         // https://doc.qt.io/qt-6/qt.html#MouseButton-enum
@@ -1744,7 +1795,8 @@ fn key_event(&self, key: i32, text: qttypes::QString, released: bool, repeat: bo
     }
 
     fn close_popup(&self) {
-        WindowInner::from_pub(&self.window).close_popup();
+        // Qt delivers the mouse events to the top-most popup
+        WindowInner::from_pub(&self.window).close_top_popup();
     }
 
     fn close_policy(&self) -> PopupClosePolicy {
@@ -1996,6 +2048,47 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         }};
     }
 
+    fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
+        let widget_ptr = self.widget_ptr();
+        let props = match request {
+            i_slint_core::window::InputMethodRequest::Enable(props) => {
+                cpp! {unsafe [widget_ptr as "QWidget*"] {
+                    widget_ptr->setAttribute(Qt::WA_InputMethodEnabled, true);
+                }};
+                props
+            }
+            i_slint_core::window::InputMethodRequest::Disable => {
+                cpp! {unsafe [widget_ptr as "SlintWidget*"] {
+                    widget_ptr->ime_text = "";
+                    widget_ptr->ime_cursor = 0;
+                    widget_ptr->ime_anchor = 0;
+                    widget_ptr->setAttribute(Qt::WA_InputMethodEnabled, false);
+                }};
+                return;
+            }
+            i_slint_core::window::InputMethodRequest::Update(props) => props,
+            _ => return,
+        };
+
+        let rect = qttypes::QRectF {
+            x: props.cursor_rect_origin.x as _,
+            y: props.cursor_rect_origin.y as _,
+            width: props.cursor_rect_size.width as _,
+            height: props.cursor_rect_size.height as _,
+        };
+        let cursor: i32 = props.text[..props.cursor_position].encode_utf16().count() as _;
+        let anchor: i32 =
+            props.anchor_position.map_or(cursor, |a| props.text[..a].encode_utf16().count() as _);
+        let text: qttypes::QString = props.text.as_str().into();
+        cpp! {unsafe [widget_ptr as "SlintWidget*", rect as "QRectF", cursor as "int", anchor as "int", text as "QString"]  {
+            widget_ptr->ime_position = rect.toRect();
+            widget_ptr->ime_text = text;
+            widget_ptr->ime_cursor = cursor;
+            widget_ptr->ime_anchor = anchor;
+            QGuiApplication::inputMethod()->update(Qt::ImQueryInput);
+        }};
+    }
+
     fn internal(&self, _: i_slint_core::InternalToken) -> Option<&dyn WindowAdapterInternal> {
         Some(self)
     }
@@ -2037,6 +2130,69 @@ fn create_popup(&self, geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>> {
         Some(popup_window as _)
     }
 
+    fn show_native_popup_menu(
+        &self,
+        context_menu: Pin<&items::ContextMenu>,
+        position: i_slint_core::api::LogicalPosition,
+    ) -> bool {
+        fn fill_menu(
+            menu_ptr: *mut std::ffi::c_void,
+            context_menu: Pin<&items::ContextMenu>,
+            parent_id: &SharedString,
+            all_entries: &mut Vec<items::MenuEntry>,
+        ) {
+            for entry in context_menu.entries(parent_id) {
+                if entry.is_separator {
+                    cpp! {unsafe [menu_ptr as "QMenu*"] { menu_ptr->addSeparator(); }};
+                    continue;
+                }
+                let title: qttypes::QString = entry.title.as_str().into();
+                let enabled = entry.enabled;
+                if entry.has_sub_menu {
+                    let sub_menu = cpp! {unsafe [menu_ptr as "QMenu*", title as "QString", enabled as "bool"] -> *mut std::ffi::c_void as "QMenu*" {
+                        auto sub_menu = menu_ptr->addMenu(title);
+                        sub_menu->setEnabled(enabled);
+                        return sub_menu;
+                    }};
+                    fill_menu(sub_menu, context_menu, &entry.id, all_entries);
+                    continue;
+                }
+                let checkable = entry.checkable;
+                let checked = entry.checked;
+                let shortcut: qttypes::QString = entry.shortcut.as_str().into();
+                let index = all_entries.len() as i32;
+                cpp! {unsafe [menu_ptr as "QMenu*", title as "QString", enabled as "bool", checkable as "bool",
+                              checked as "bool", shortcut as "QString", index as "int"] {
+                    auto action = menu_ptr->addAction(title);
+                    action->setEnabled(enabled);
+                    action->setCheckable(checkable);
+                    action->setChecked(checked);
+                    action->setShortcut(QKeySequence(shortcut));
+                    action->setData(index);
+                }};
+                all_entries.push(entry);
+            }
+        }
+
+        let widget_ptr = self.widget_ptr();
+        let menu_ptr = cpp! {unsafe [widget_ptr as "QWidget*"] -> *mut std::ffi::c_void as "QMenu*" {
+            return new QMenu(widget_ptr);
+        }};
+        let mut all_entries = Vec::new();
+        fill_menu(menu_ptr, context_menu, &SharedString::default(), &mut all_entries);
+
+        let pos = qttypes::QPoint { x: position.x as _, y: position.y as _ };
+        let index = cpp! {unsafe [widget_ptr as "QWidget*", menu_ptr as "QMenu*", pos as "QPoint"] -> i32 as "int" {
+            std::unique_ptr<QMenu> menu(menu_ptr);
+            auto action = menu->exec(widget_ptr->mapToGlobal(pos));
+            return action ? action->data().toInt() : -1;
+        }};
+        if let Some(entry) = usize::try_from(index).ok().and_then(|i| all_entries.get(i)) {
+            context_menu.activate(entry);
+        }
+        true
+    }
+
     fn set_mouse_cursor(&self, cursor: MouseCursor) {
         let widget_ptr = self.widget_ptr();
         //unidirectional resize cursors are replaced with bidirectional ones
@@ -2076,47 +2232,6 @@ fn set_mouse_cursor(&self, cursor: MouseCursor) {
         }};
     }
 
-    fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
-        let widget_ptr = self.widget_ptr();
-        let props = match request {
-            i_slint_core::window::InputMethodRequest::Enable(props) => {
-                cpp! {unsafe [widget_ptr as "QWidget*"] {
-                    widget_ptr->setAttribute(Qt::WA_InputMethodEnabled, true);
-                }};
-                props
-            }
-            i_slint_core::window::InputMethodRequest::Disable => {
-                cpp! {unsafe [widget_ptr as "SlintWidget*"] {
-                    widget_ptr->ime_text = "";
-                    widget_ptr->ime_cursor = 0;
-                    widget_ptr->ime_anchor = 0;
-                    widget_ptr->setAttribute(Qt::WA_InputMethodEnabled, false);
-                }};
-                return;
-            }
-            i_slint_core::window::InputMethodRequest::Update(props) => props,
-            _ => return,
-        };
-
-        let rect = qttypes::QRectF {
-            x: props.cursor_rect_origin.x as _,
-            y: props.cursor_rect_origin.y as _,
-            width: props.cursor_rect_size.width as _,
-            height: props.cursor_rect_size.height as _,
-        };
-        let cursor: i32 = props.text[..props.cursor_position].encode_utf16().count() as _;
-        let anchor: i32 =
-            props.anchor_position.map_or(cursor, |a| props.text[..a].encode_utf16().count() as _);
-        let text: qttypes::QString = props.text.as_str().into();
-        cpp! {unsafe [widget_ptr as "SlintWidget*", rect as "QRectF", cursor as "int", anchor as "int", text as "QString"]  {
-            widget_ptr->ime_position = rect.toRect();
-            widget_ptr->ime_text = text;
-            widget_ptr->ime_cursor = cursor;
-            widget_ptr->ime_anchor = anchor;
-            QGuiApplication::inputMethod()->update(Qt::ImQueryInput);
-        }};
-    }
-
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -6,6 +6,12 @@
 
 mod search_api;
 pub use search_api::*;
+mod recording;
+pub use recording::*;
+#[cfg(feature = "snapshot-testing")]
+mod snapshot;
+#[cfg(feature = "snapshot-testing")]
+pub use snapshot::*;
 #[cfg(feature = "internal")]
 mod internal_tests;
 #[cfg(feature = "internal")]
@@ -0,0 +1,447 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Recording and replaying of the input events dispatched to a window.
+
+use crate::testing_backend::TestingWindow;
+use i_slint_core::api::{LogicalPosition, LogicalSize, Window};
+use i_slint_core::platform::WindowEvent;
+use i_slint_core::tests::{slint_get_mocked_time, slint_mock_elapsed_time};
+use i_slint_core::window::WindowInner;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+const HEADER: &str = "# Slint input recording";
+
+/// An entry of an [`InputRecording`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RecordedInput {
+    /// An event that was dispatched to the window.
+    Event(WindowEvent),
+    /// A checkpoint that was added with [`InputRecorder::checkpoint()`].
+    Checkpoint(String),
+}
+
+/// A sequence of input events, with the (mock) time at which they were dispatched.
+///
+/// Create a recording with [`InputRecorder`], and play it back with [`replay_input_recording()`].
+///
+/// The recording can be saved to a file with [`Self::save()`]. The file format is a text file, with one
+/// event per line, that can also be edited by hand:
+///
+/// ```text
+/// # Slint input recording
+/// 0 pointer-moved 20 30
+/// 0 pointer-pressed 20 30 left
+/// 50 pointer-released 20 30 left
+/// 50 key-pressed "a"
+/// 60 key-released "a"
+/// 100 checkpoint after-typing
+/// ```
+///
+/// The first column is the time in milliseconds since the start of the recording.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputRecording {
+    entries: Vec<(Duration, RecordedInput)>,
+}
+
+impl InputRecording {
+    /// Returns the recorded entries, with the time since the start of the recording.
+    pub fn entries(&self) -> &[(Duration, RecordedInput)] {
+        &self.entries
+    }
+
+    /// Writes the recording into the file at the given path.
+    ///
+    /// Returns an error of kind [`InvalidData`](std::io::ErrorKind::InvalidData) if the
+    /// recording contains an event that the file format doesn't support.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        if let Some((time, event)) = self.entries.iter().find_map(|(time, entry)| match entry {
+            RecordedInput::Event(event) if event_to_string(event).is_none() => Some((time, event)),
+            _ => None,
+        }) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("the event {event:?} at {}ms can't be saved", time.as_millis()),
+            ));
+        }
+        std::fs::write(path, self.to_string())
+    }
+
+    /// Reads a recording from the file at the given path.
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        std::fs::read_to_string(path)?
+            .parse()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Events that the file format doesn't support are written as comments, so they are skipped
+/// when parsing the recording. [`InputRecording::save()`] returns an error for them instead.
+impl std::fmt::Display for InputRecording {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for (time, entry) in &self.entries {
+            let entry = match entry {
+                RecordedInput::Checkpoint(name) => format!("checkpoint {name}"),
+                RecordedInput::Event(event) => match event_to_string(event) {
+                    Some(event) => event,
+                    None => {
+                        writeln!(f, "# unsupported event: {event:?}")?;
+                        continue;
+                    }
+                },
+            };
+            writeln!(f, "{} {entry}", time.as_millis())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the line of the recording for the event, without the time, or None if the file format
+/// doesn't support that event.
+fn event_to_string(event: &WindowEvent) -> Option<String> {
+    Some(match event {
+        WindowEvent::PointerPressed { position, button } => {
+            format!("pointer-pressed {} {} {button}", position.x, position.y)
+        }
+        WindowEvent::PointerReleased { position, button } => {
+            format!("pointer-released {} {} {button}", position.x, position.y)
+        }
+        WindowEvent::PointerMoved { position } => {
+            format!("pointer-moved {} {}", position.x, position.y)
+        }
+        WindowEvent::PointerScrolled { position, delta_x, delta_y } => {
+            format!("pointer-scrolled {} {} {delta_x} {delta_y}", position.x, position.y)
+        }
+        WindowEvent::PointerExited => "pointer-exited".into(),
+        WindowEvent::TouchPressed { touch_id, position } => {
+            format!("touch-pressed {touch_id} {} {}", position.x, position.y)
+        }
+        WindowEvent::TouchMoved { touch_id, position } => {
+            format!("touch-moved {touch_id} {} {}", position.x, position.y)
+        }
+        WindowEvent::TouchReleased { touch_id, position } => {
+            format!("touch-released {touch_id} {} {}", position.x, position.y)
+        }
+        WindowEvent::TouchCancelled { touch_id, position } => {
+            format!("touch-cancelled {touch_id} {} {}", position.x, position.y)
+        }
+        WindowEvent::KeyPressed { text } => format!("key-pressed {}", quote(text)),
+        WindowEvent::KeyPressRepeated { text } => {
+            format!("key-press-repeated {}", quote(text))
+        }
+        WindowEvent::KeyReleased { text } => format!("key-released {}", quote(text)),
+        WindowEvent::ScaleFactorChanged { scale_factor } => {
+            format!("scale-factor-changed {scale_factor}")
+        }
+        WindowEvent::Resized { size } => {
+            format!("resized {} {}", size.width, size.height)
+        }
+        WindowEvent::CloseRequested => "close-requested".into(),
+        WindowEvent::WindowActiveChanged(active) => {
+            format!("window-active-changed {active}")
+        }
+        WindowEvent::ImePreedit { text, selection_start, selection_end } => {
+            format!("ime-preedit {selection_start} {selection_end} {}", quote(text))
+        }
+        WindowEvent::ImeCommit { text } => format!("ime-commit {}", quote(text)),
+        WindowEvent::ImeDeleteSurrounding { before_length, after_length } => {
+            format!("ime-delete-surrounding {before_length} {after_length}")
+        }
+        _ => return None,
+    })
+}
+
+impl std::str::FromStr for InputRecording {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = Vec::new();
+        for (line_number, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = parse_line(line).map_err(|e| format!("line {}: {e}", line_number + 1))?;
+            entries.push(entry);
+        }
+        Ok(Self { entries })
+    }
+}
+
+fn parse_line(line: &str) -> Result<(Duration, RecordedInput), String> {
+    let (time, rest) = line.split_once(' ').ok_or("missing event")?;
+    let time = Duration::from_millis(time.parse().map_err(|_| format!("invalid time '{time}'"))?);
+    let (kind, args) = rest.split_once(' ').unwrap_or((rest, ""));
+    let args = args.trim();
+
+    let numbers = |count: usize| -> Result<Vec<f32>, String> {
+        let numbers = args
+            .split_whitespace()
+            .take(count)
+            .map(|n| n.parse::<f32>().map_err(|_| format!("invalid number '{n}'")))
+            .collect::<Result<Vec<_>, _>>()?;
+        if numbers.len() != count {
+            return Err(format!("'{kind}' expects {count} numbers"));
+        }
+        Ok(numbers)
+    };
+    let position = || numbers(2).map(|n| LogicalPosition::new(n[0], n[1]));
+    let touch = || -> Result<(u64, LogicalPosition), String> {
+        let n = numbers(3)?;
+        let touch_id = args.split_whitespace().next().unwrap_or_default();
+        let touch_id = touch_id.parse().map_err(|_| format!("invalid touch id '{touch_id}'"))?;
+        Ok((touch_id, LogicalPosition::new(n[1], n[2])))
+    };
+    let offsets = || -> Result<(usize, usize, &str), String> {
+        let mut split = args.splitn(3, ' ');
+        let mut offset = || {
+            let n = split.next().unwrap_or_default();
+            n.parse::<usize>().map_err(|_| format!("invalid offset '{n}'"))
+        };
+        Ok((offset()?, offset()?, split.next().unwrap_or_default()))
+    };
+    let button = || {
+        let button = args.split_whitespace().nth(2).ok_or("missing pointer button")?;
+        button.parse().map_err(|_| format!("invalid pointer button '{button}'"))
+    };
+
+    let event = match kind {
+        "checkpoint" => return Ok((time, RecordedInput::Checkpoint(args.into()))),
+        "pointer-pressed" => {
+            WindowEvent::PointerPressed { position: position()?, button: button()? }
+        }
+        "pointer-released" => {
+            WindowEvent::PointerReleased { position: position()?, button: button()? }
+        }
+        "pointer-moved" => WindowEvent::PointerMoved { position: position()? },
+        "pointer-scrolled" => {
+            let n = numbers(4)?;
+            WindowEvent::PointerScrolled {
+                position: LogicalPosition::new(n[0], n[1]),
+                delta_x: n[2],
+                delta_y: n[3],
+            }
+        }
+        "pointer-exited" => WindowEvent::PointerExited,
+        "touch-pressed" => {
+            let (touch_id, position) = touch()?;
+            WindowEvent::TouchPressed { touch_id, position }
+        }
+        "touch-moved" => {
+            let (touch_id, position) = touch()?;
+            WindowEvent::TouchMoved { touch_id, position }
+        }
+        "touch-released" => {
+            let (touch_id, position) = touch()?;
+            WindowEvent::TouchReleased { touch_id, position }
+        }
+        "touch-cancelled" => {
+            let (touch_id, position) = touch()?;
+            WindowEvent::TouchCancelled { touch_id, position }
+        }
+        "key-pressed" => WindowEvent::KeyPressed { text: unquote(args)?.into() },
+        "key-press-repeated" => WindowEvent::KeyPressRepeated { text: unquote(args)?.into() },
+        "key-released" => WindowEvent::KeyReleased { text: unquote(args)?.into() },
+        "scale-factor-changed" => WindowEvent::ScaleFactorChanged { scale_factor: numbers(1)?[0] },
+        "resized" => {
+            let n = numbers(2)?;
+            WindowEvent::Resized { size: LogicalSize::new(n[0], n[1]) }
+        }
+        "close-requested" => WindowEvent::CloseRequested,
+        "window-active-changed" => WindowEvent::WindowActiveChanged(
+            args.parse().map_err(|_| format!("invalid boolean '{args}'"))?,
+        ),
+        "ime-preedit" => {
+            let (selection_start, selection_end, text) = offsets()?;
+            WindowEvent::ImePreedit { text: unquote(text)?.into(), selection_start, selection_end }
+        }
+        "ime-commit" => WindowEvent::ImeCommit { text: unquote(args)?.into() },
+        "ime-delete-surrounding" => {
+            let (before_length, after_length, _) = offsets()?;
+            WindowEvent::ImeDeleteSurrounding { before_length, after_length }
+        }
+        _ => return Err(format!("unknown event '{kind}'")),
+    };
+    Ok((time, RecordedInput::Event(event)))
+}
+
+/// Quote the text of a key event. The special keys are control characters or in the unicode
+/// private use area, so they are escaped to keep the file readable.
+fn quote(text: &str) -> String {
+    let mut result = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            c if c.is_control() || ('\u{e000}'..='\u{f8ff}').contains(&c) => {
+                result.push_str(&format!("\\u{{{:x}}}", c as u32))
+            }
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn unquote(text: &str) -> Result<String, String> {
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .ok_or_else(|| format!("expected a quoted string, got '{text}'"))?;
+    let mut result = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('u') => {
+                let code: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                let c = code
+                    .strip_prefix('{')
+                    .and_then(|code| u32::from_str_radix(code, 16).ok())
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid escape sequence in '{text}'"))?;
+                result.push(c);
+            }
+            Some(c @ ('"' | '\\')) => result.push(c),
+            _ => return Err(format!("invalid escape sequence in '{text}'")),
+        }
+    }
+    Ok(result)
+}
+
+pub(crate) struct RecorderState {
+    start: u64,
+    recording: RefCell<InputRecording>,
+}
+
+impl RecorderState {
+    fn push(&self, entry: RecordedInput) {
+        let time = Duration::from_millis(slint_get_mocked_time().saturating_sub(self.start));
+        self.recording.borrow_mut().entries.push((time, entry));
+    }
+
+    pub(crate) fn record_event(&self, event: &WindowEvent) {
+        if event_to_string(event).is_none() {
+            i_slint_core::debug_log!("The input recording can't be saved with the event {event:?}");
+        }
+        self.push(RecordedInput::Event(event.clone()))
+    }
+}
+
+fn testing_window(window: &Window) -> Rc<dyn i_slint_core::window::WindowAdapter> {
+    let adapter = WindowInner::from_pub(window).window_adapter();
+    assert!(
+        adapter
+            .internal(i_slint_core::InternalToken)
+            .is_some_and(|wa| wa.as_any().is::<TestingWindow>()),
+        "input recording requires the testing backend"
+    );
+    adapter
+}
+
+fn with_testing_window<R>(
+    adapter: &Rc<dyn i_slint_core::window::WindowAdapter>,
+    f: impl FnOnce(&TestingWindow) -> R,
+) -> R {
+    f(adapter
+        .internal(i_slint_core::InternalToken)
+        .and_then(|wa| wa.as_any().downcast_ref::<TestingWindow>())
+        .unwrap())
+}
+
+/// Records all the events dispatched with [`Window::dispatch_event()`] to a window, until
+/// [`Self::finish()`] is called.
+///
+/// The time of the events is the simulated time that is advanced with
+/// [`mock_elapsed_time()`](crate::mock_elapsed_time).
+///
+/// ```rust
+/// # i_slint_backend_testing::init_no_event_loop();
+/// use slint::platform::WindowEvent;
+/// use slint::ComponentHandle;
+/// slint::slint!{ export component App inherits Window { } }
+/// let app = App::new().unwrap();
+/// let recorder = i_slint_backend_testing::InputRecorder::start(app.window());
+/// app.window().dispatch_event(WindowEvent::KeyPressed { text: "a".into() });
+/// recorder.checkpoint("after-a");
+/// let recording = recorder.finish();
+/// assert_eq!(recording.entries().len(), 2);
+/// ```
+pub struct InputRecorder {
+    window_adapter: Rc<dyn i_slint_core::window::WindowAdapter>,
+    state: Rc<RecorderState>,
+}
+
+impl InputRecorder {
+    /// Starts recording the events of the given window. Any previous recorder of that window
+    /// stops recording.
+    ///
+    /// Panics if the window is not a window of the testing backend.
+    pub fn start(window: &Window) -> Self {
+        let window_adapter = testing_window(window);
+        let state = Rc::new(RecorderState {
+            start: slint_get_mocked_time(),
+            recording: Default::default(),
+        });
+        with_testing_window(&window_adapter, |w| *w.recorder.borrow_mut() = Some(state.clone()));
+        Self { window_adapter, state }
+    }
+
+    /// Adds a named checkpoint to the recording. [`replay_input_recording()`] calls its callback
+    /// when reaching this checkpoint, so that the state of the window can be verified, for
+    /// example with [`Window::take_snapshot()`].
+    pub fn checkpoint(&self, name: &str) {
+        self.state.push(RecordedInput::Checkpoint(name.into()))
+    }
+
+    /// Stops recording and returns the recorded events.
+    pub fn finish(self) -> InputRecording {
+        self.state.recording.borrow().clone()
+    }
+}
+
+impl Drop for InputRecorder {
+    fn drop(&mut self) {
+        with_testing_window(&self.window_adapter, |w| {
+            let mut recorder = w.recorder.borrow_mut();
+            if recorder.as_ref().is_some_and(|r| Rc::ptr_eq(r, &self.state)) {
+                *recorder = None;
+            }
+        });
+    }
+}
+
+/// Dispatches the events of the recording to the window, advancing the simulated time between
+/// the events the same way as during the recording.
+///
+/// `on_checkpoint` is called with the name of each checkpoint of the recording, at the time it
+/// was recorded.
+///
+/// The testing backend must be initialized with mock time, for example with
+/// [`init_no_event_loop()`](crate::init_no_event_loop) or
+/// [`init_integration_test_with_mock_time()`](crate::init_integration_test_with_mock_time).
+pub fn replay_input_recording(
+    window: &Window,
+    recording: &InputRecording,
+    mut on_checkpoint: impl FnMut(&str, &Window),
+) {
+    let start = slint_get_mocked_time();
+    for (time, entry) in &recording.entries {
+        let target = start + time.as_millis() as u64;
+        let now = slint_get_mocked_time();
+        if target > now {
+            slint_mock_elapsed_time(target - now);
+        }
+        match entry {
+            RecordedInput::Event(event) => window.dispatch_event(event.clone()),
+            RecordedInput::Checkpoint(name) => on_checkpoint(name, window),
+        }
+    }
+}
@@ -502,6 +502,16 @@ pub fn accessible_placeholder_text(&self) -> Option<SharedString> {
         })
     }
 
+    /// Returns the value of the element's `accessible-keyboard-shortcut` property, if present.
+    pub fn accessible_keyboard_shortcut(&self) -> Option<SharedString> {
+        if self.element_index != 0 {
+            return None;
+        }
+        self.item.upgrade().and_then(|item| {
+            item.accessible_string_property(AccessibleStringProperty::KeyboardShortcut)
+        })
+    }
+
     /// Sets the value of the element's `accessible-value` property. Note that you can only set this
     /// property if it is declared in your Slint code.
     pub fn set_accessible_value(&self, value: impl Into<SharedString>) {
@@ -599,6 +609,28 @@ pub fn accessible_checkable(&self) -> Option<bool> {
             .and_then(|item| item.parse().ok())
     }
 
+    /// Returns the value of the `accessible-expandable` property, if present
+    pub fn accessible_expandable(&self) -> Option<bool> {
+        if self.element_index != 0 {
+            return None;
+        }
+        self.item
+            .upgrade()
+            .and_then(|item| item.accessible_string_property(AccessibleStringProperty::Expandable))
+            .and_then(|item| item.parse().ok())
+    }
+
+    /// Returns the value of the `accessible-expanded` property, if present
+    pub fn accessible_expanded(&self) -> Option<bool> {
+        if self.element_index != 0 {
+            return None;
+        }
+        self.item
+            .upgrade()
+            .and_then(|item| item.accessible_string_property(AccessibleStringProperty::Expanded))
+            .and_then(|item| item.parse().ok())
+    }
+
     /// Returns the value of the `accessible-selected` property, if present
     pub fn accessible_selected(&self) -> Option<bool> {
         if self.element_index != 0 {
@@ -768,6 +800,64 @@ pub async fn double_click(&self, button: i_slint_core::platform::PointerEventBut
             i_slint_core::platform::WindowEvent::PointerReleased { position, button },
         );
     }
+
+    /// Simulates dragging this element with the left mouse button and dropping it at the center
+    /// point of the `target` element. The drag starts at the center point of this element.
+    pub async fn drag_to(&self, target: &ElementHandle) {
+        let Some(item) = self.item.upgrade() else { return };
+        let Some(window_adapter) = item.window_adapter() else { return };
+        let window = window_adapter.window();
+
+        let start = self.center();
+        let end = target.center();
+        let button = i_slint_core::platform::PointerEventButton::Left;
+
+        window
+            .dispatch_event(i_slint_core::platform::WindowEvent::PointerMoved { position: start });
+        window.dispatch_event(i_slint_core::platform::WindowEvent::PointerPressed {
+            position: start,
+            button,
+        });
+
+        const STEPS: u8 = 5;
+        for step in 1..=STEPS {
+            wait_for(std::time::Duration::from_millis(10)).await;
+            let factor = step as f32 / STEPS as f32;
+            let position = LogicalPosition::new(
+                start.x + (end.x - start.x) * factor,
+                start.y + (end.y - start.y) * factor,
+            );
+            window.dispatch_event(i_slint_core::platform::WindowEvent::PointerMoved { position });
+        }
+
+        window.dispatch_event(i_slint_core::platform::WindowEvent::PointerReleased {
+            position: end,
+            button,
+        });
+    }
+
+    /// Simulates data of the given mime type being dragged from another application and dropped
+    /// at the center point of the element.
+    pub fn drop_data(&self, mime_type: impl Into<SharedString>, data: impl Into<SharedString>) {
+        let Some(item) = self.item.upgrade() else { return };
+        let Some(window_adapter) = item.window_adapter() else { return };
+        let window = window_adapter.window();
+
+        let position = self.center();
+        i_slint_core::window::WindowInner::from_pub(window)
+            .start_drag(mime_type.into(), data.into());
+        window.dispatch_event(i_slint_core::platform::WindowEvent::PointerMoved { position });
+        window.dispatch_event(i_slint_core::platform::WindowEvent::PointerReleased {
+            position,
+            button: i_slint_core::platform::PointerEventButton::Left,
+        });
+    }
+
+    fn center(&self) -> LogicalPosition {
+        let item_pos = self.absolute_position();
+        let item_size = self.size();
+        LogicalPosition::new(item_pos.x + item_size.width / 2., item_pos.y + item_size.height / 2.)
+    }
 }
 
 async fn wait_for(duration: std::time::Duration) {
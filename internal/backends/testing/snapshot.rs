@@ -0,0 +1,166 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Comparison of window snapshots with reference images
+
+use i_slint_core::api::Window;
+use i_slint_core::graphics::{Rgba8Pixel, SharedPixelBuffer};
+use std::path::{Path, PathBuf};
+
+/// The environment variable that, when set to `1`, makes [`assert_snapshot_matches()`] write the
+/// reference images instead of comparing them.
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &str = "SLINT_UPDATE_SNAPSHOTS";
+
+/// How much a snapshot may differ from its reference image.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SnapshotTolerance {
+    /// The maximum difference of each color channel (red, green, blue, alpha) of a pixel
+    /// for the pixel to be considered equal.
+    pub channel: u8,
+    /// The percentage of the pixels that may exceed the `channel` tolerance.
+    pub different_pixels_percentage: f32,
+}
+
+/// Renders the window with the software renderer and compares the result to the reference image
+/// `snapshots/<name>.png`, in the directory of the source file calling this function.
+///
+/// Panics if the images differ, and writes the rendered image as `<name>.actual.png` and an image
+/// highlighting the differences as `<name>.diff.png` next to the reference image.
+///
+/// Set the `SLINT_UPDATE_SNAPSHOTS` environment variable to `1` to create or update the reference images.
+///
+/// This requires the `snapshot-testing` feature. Note that text is rendered with the fonts
+/// installed on the system, so snapshots containing text may differ between systems.
+#[track_caller]
+pub fn assert_snapshot_matches(window: &Window, name: &str) {
+    assert_snapshot_matches_with_tolerance(window, name, SnapshotTolerance::default())
+}
+
+/// Same as [`assert_snapshot_matches()`], but allows the snapshot to differ from the reference
+/// image within the given tolerance.
+#[track_caller]
+pub fn assert_snapshot_matches_with_tolerance(
+    window: &Window,
+    name: &str,
+    tolerance: SnapshotTolerance,
+) {
+    let directory = snapshot_directory(std::panic::Location::caller().file());
+    let snapshot = window.take_snapshot().unwrap_or_else(|e| panic!("Cannot take snapshot: {e}"));
+    if let Err(e) = compare_snapshot(&directory, name, &snapshot, tolerance) {
+        panic!("Snapshot '{name}' does not match: {e}");
+    }
+}
+
+/// Returns the `snapshots` directory next to the given source file.
+///
+/// The file name from the caller location is relative to either the package or the workspace,
+/// so try the ancestors of the manifest directory.
+fn snapshot_directory(source_file: &str) -> PathBuf {
+    let source_file = Path::new(source_file);
+    let source_file = if source_file.is_absolute() {
+        source_file.to_path_buf()
+    } else {
+        let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+        manifest_dir
+            .ancestors()
+            .map(|dir| dir.join(source_file))
+            .find(|path| path.exists())
+            .unwrap_or_else(|| manifest_dir.join(source_file))
+    };
+    source_file.parent().unwrap_or(Path::new(".")).join("snapshots")
+}
+
+fn compare_snapshot(
+    directory: &Path,
+    name: &str,
+    snapshot: &SharedPixelBuffer<Rgba8Pixel>,
+    tolerance: SnapshotTolerance,
+) -> Result<(), String> {
+    let reference_path = directory.join(format!("{name}.png"));
+    let actual_path = directory.join(format!("{name}.actual.png"));
+    let diff_path = directory.join(format!("{name}.diff.png"));
+
+    if std::env::var(UPDATE_SNAPSHOTS_ENV_VAR).is_ok_and(|var| var == "1") {
+        std::fs::create_dir_all(directory)
+            .map_err(|e| format!("cannot create {}: {e}", directory.display()))?;
+        save_image(&reference_path, snapshot)?;
+        // Remove the leftovers of a previous failure
+        let _ = std::fs::remove_file(&actual_path);
+        let _ = std::fs::remove_file(&diff_path);
+        return Ok(());
+    }
+
+    let reference = match image::open(&reference_path) {
+        Ok(image) => image.into_rgba8(),
+        Err(e) => {
+            return Err(format!(
+                "cannot load the reference image {}: {e}. Run the test with {UPDATE_SNAPSHOTS_ENV_VAR}=1 to create it",
+                reference_path.display()
+            ))
+        }
+    };
+
+    let result = if (reference.width(), reference.height()) != (snapshot.width(), snapshot.height())
+    {
+        Err(format!(
+            "the size of the snapshot is {}x{}, but the size of the reference image is {}x{}",
+            snapshot.width(),
+            snapshot.height(),
+            reference.width(),
+            reference.height()
+        ))
+    } else {
+        let mut diff = SharedPixelBuffer::<Rgba8Pixel>::new(snapshot.width(), snapshot.height());
+        let mut different_pixels = 0usize;
+        let mut max_difference = 0u8;
+        for ((expected, actual), diff) in
+            reference.pixels().zip(snapshot.as_slice().iter()).zip(diff.make_mut_slice().iter_mut())
+        {
+            let actual = [actual.r, actual.g, actual.b, actual.a];
+            let difference =
+                expected.0.iter().zip(actual.iter()).map(|(e, a)| e.abs_diff(*a)).max().unwrap();
+            max_difference = max_difference.max(difference);
+            *diff = if difference > tolerance.channel {
+                different_pixels += 1;
+                Rgba8Pixel::new(255, 0, 0, 255)
+            } else {
+                // Show the unchanged pixels in a faded gray
+                let gray =
+                    ((expected[0] as u16 + expected[1] as u16 + expected[2] as u16) / 3) as u8 / 4
+                        + 192;
+                Rgba8Pixel::new(gray, gray, gray, 255)
+            };
+        }
+        let percentage = different_pixels as f32 * 100. / (snapshot.as_slice().len().max(1)) as f32;
+        if different_pixels == 0 || percentage <= tolerance.different_pixels_percentage {
+            Ok(())
+        } else {
+            save_image(&diff_path, &diff)?;
+            Err(format!(
+                "{percentage:.2}% of the pixels are different (maximum channel difference: {max_difference}). See {}",
+                diff_path.display()
+            ))
+        }
+    };
+
+    if result.is_err() {
+        save_image(&actual_path, snapshot)?;
+    } else {
+        let _ = std::fs::remove_file(&actual_path);
+        let _ = std::fs::remove_file(&diff_path);
+    }
+    result
+}
+
+fn save_image(path: &Path, buffer: &SharedPixelBuffer<Rgba8Pixel>) -> Result<(), String> {
+    image::save_buffer(
+        path,
+        buffer.as_bytes(),
+        buffer.width(),
+        buffer.height(),
+        image::ColorType::Rgba8,
+    )
+    .map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
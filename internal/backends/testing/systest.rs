@@ -528,6 +528,7 @@ fn convert_to_proto_accessible_role(
         i_slint_core::items::AccessibleRole::TextInput => proto::AccessibleRole::TextInput,
         i_slint_core::items::AccessibleRole::Switch => proto::AccessibleRole::Switch,
         i_slint_core::items::AccessibleRole::ListItem => proto::AccessibleRole::ListItem,
+        i_slint_core::items::AccessibleRole::TreeItem => proto::AccessibleRole::TreeItem,
         _ => return None,
     })
 }
@@ -554,6 +555,7 @@ fn convert_from_proto_accessible_role(
         proto::AccessibleRole::TextInput => i_slint_core::items::AccessibleRole::TextInput,
         proto::AccessibleRole::Switch => i_slint_core::items::AccessibleRole::Switch,
         proto::AccessibleRole::ListItem => i_slint_core::items::AccessibleRole::ListItem,
+        proto::AccessibleRole::TreeItem => i_slint_core::items::AccessibleRole::TreeItem,
     })
 }
 
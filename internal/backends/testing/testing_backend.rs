@@ -46,6 +46,9 @@ fn create_window_adapter(
             size: Default::default(),
             ime_requests: Default::default(),
             mouse_cursor: Default::default(),
+            recorder: Default::default(),
+            #[cfg(feature = "snapshot-testing")]
+            software_renderer: Default::default(),
         }))
     }
 
@@ -109,6 +112,10 @@ pub struct TestingWindow {
     size: Cell<PhysicalSize>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
+    pub(crate) recorder: RefCell<Option<Rc<crate::recording::RecorderState>>>,
+    /// Used to render the window in `take_snapshot()`
+    #[cfg(feature = "snapshot-testing")]
+    software_renderer: i_slint_core::software_renderer::SoftwareRenderer,
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -116,13 +123,15 @@ fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 
-    fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
-        self.ime_requests.borrow_mut().push(request)
-    }
-
     fn set_mouse_cursor(&self, cursor: i_slint_core::items::MouseCursor) {
         self.mouse_cursor.set(cursor);
     }
+
+    fn event_dispatched(&self, event: &i_slint_core::platform::WindowEvent) {
+        if let Some(recorder) = self.recorder.borrow().as_ref() {
+            recorder.record_event(event);
+        }
+    }
 }
 
 impl WindowAdapter for TestingWindow {
@@ -156,6 +165,10 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         }
     }
 
+    fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
+        self.ime_requests.borrow_mut().push(request)
+    }
+
     fn internal(&self, _: i_slint_core::InternalToken) -> Option<&dyn WindowAdapterInternal> {
         Some(self)
     }
@@ -205,6 +218,21 @@ fn text_input_cursor_rect_for_byte_offset(
         LogicalRect::new(Point2D::new(byte_offset as f32 * 10., 0.), Size2D::new(1., 10.))
     }
 
+    // this works only for single line text
+    fn styled_text_span_at_position(
+        &self,
+        _styled_text: Pin<&i_slint_core::items::StyledText>,
+        styled_string: &i_slint_core::styled_text::StyledString,
+        pos: LogicalPoint,
+        _font_request: FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        if pos.x < 0. || pos.y < 0. || pos.y >= 10. {
+            return None;
+        }
+        styled_string.span_at_byte_offset((pos.x / 10.) as usize)
+    }
+
     fn register_font_from_memory(
         &self,
         _data: &'static [u8],
@@ -225,6 +253,27 @@ fn default_font_size(&self) -> LogicalLength {
 
     fn set_window_adapter(&self, _window_adapter: &Rc<dyn WindowAdapter>) {
         // No-op since TestingWindow is also the WindowAdapter
+        #[cfg(feature = "snapshot-testing")]
+        self.software_renderer.set_window_adapter(_window_adapter);
+    }
+
+    #[cfg(feature = "snapshot-testing")]
+    fn free_graphics_resources(
+        &self,
+        component: i_slint_core::item_tree::ItemTreeRef,
+        items: &mut dyn Iterator<Item = Pin<i_slint_core::items::ItemRef<'_>>>,
+    ) -> Result<(), PlatformError> {
+        self.software_renderer.free_graphics_resources(component, items)
+    }
+
+    #[cfg(feature = "snapshot-testing")]
+    fn take_snapshot(
+        &self,
+    ) -> Result<
+        i_slint_core::graphics::SharedPixelBuffer<i_slint_core::graphics::Rgba8Pixel>,
+        PlatformError,
+    > {
+        self.software_renderer.take_snapshot()
     }
 }
 
@@ -0,0 +1,112 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use i_slint_backend_testing::{
+    replay_input_recording, InputRecorder, InputRecording, RecordedInput,
+};
+// The public `mock_elapsed_time` takes a `Duration`, but the one of the `internal` feature takes
+// milliseconds: use the function they both call, so that the test builds with all the features.
+use i_slint_core::tests::slint_mock_elapsed_time as mock_elapsed_time;
+use slint::platform::{Key, PointerEventButton, WindowEvent};
+use slint::{ComponentHandle, LogicalPosition, SharedString};
+
+slint::slint! {
+    export component App inherits Window {
+        width: 100px;
+        height: 100px;
+        out property <int> click-count: 0;
+        out property <string> text <=> input.text;
+        TouchArea {
+            height: 50px;
+            y: 0;
+            clicked => { root.click-count += 1; }
+        }
+        input := TextInput {
+            height: 50px;
+            y: 50px;
+        }
+    }
+}
+
+fn click(app: &App, x: f32, y: f32) {
+    let position = LogicalPosition::new(x, y);
+    let button = PointerEventButton::Left;
+    app.window().dispatch_event(WindowEvent::PointerMoved { position });
+    app.window().dispatch_event(WindowEvent::PointerPressed { position, button });
+    mock_elapsed_time(50);
+    app.window().dispatch_event(WindowEvent::PointerReleased { position, button });
+}
+
+#[test]
+fn test_record_and_replay() {
+    i_slint_backend_testing::init_no_event_loop();
+
+    let app = App::new().unwrap();
+    let recorder = InputRecorder::start(app.window());
+    click(&app, 10., 10.);
+    recorder.checkpoint("clicked");
+    mock_elapsed_time(100);
+    click(&app, 10., 70.);
+    for text in [SharedString::from("H"), "é".into(), Key::Shift.into(), "\"".into()] {
+        app.window().dispatch_event(WindowEvent::KeyPressed { text: text.clone() });
+        app.window().dispatch_event(WindowEvent::KeyReleased { text });
+    }
+    app.window()
+        .dispatch_event(WindowEvent::ImeDeleteSurrounding { before_length: 1, after_length: 0 });
+    app.window().dispatch_event(WindowEvent::ImePreedit {
+        text: "ni".into(),
+        selection_start: 2,
+        selection_end: 2,
+    });
+    app.window().dispatch_event(WindowEvent::ImeCommit { text: "你".into() });
+    recorder.checkpoint("typed");
+    let position = LogicalPosition::new(20., 20.);
+    app.window().dispatch_event(WindowEvent::TouchPressed { touch_id: 3, position });
+    app.window().dispatch_event(WindowEvent::TouchReleased { touch_id: 3, position });
+    app.window().dispatch_event(WindowEvent::TouchPressed { touch_id: 4, position });
+    app.window().dispatch_event(WindowEvent::TouchCancelled { touch_id: 4, position });
+    recorder.checkpoint("touched");
+    let recording = recorder.finish();
+    assert_eq!(app.get_click_count(), 2);
+    assert_eq!(app.get_text(), "Hé你");
+
+    // Events dispatched after the recorder is finished are not recorded
+    click(&app, 10., 10.);
+    assert_eq!(app.get_click_count(), 3);
+
+    let text = recording.to_string();
+    assert!(text.contains("\n0 pointer-pressed 10 10 left\n50 pointer-released 10 10 left\n"));
+    assert!(text.contains("\n50 checkpoint clicked\n"));
+    assert!(text.contains("\n150 pointer-pressed 10 70 left\n"));
+    assert!(text.contains("key-pressed \"\\u{10}\"\n"));
+    assert!(text.contains(" ime-delete-surrounding 1 0\n"));
+    assert!(text.contains(" ime-preedit 2 2 \"ni\"\n"));
+    assert!(text.contains(" ime-commit \"你\"\n"));
+    assert!(text.contains(" touch-pressed 3 20 20\n"));
+    assert!(text.contains(" touch-released 3 20 20\n"));
+    assert!(text.contains(" touch-cancelled 4 20 20\n"));
+    let parsed: InputRecording = text.parse().unwrap();
+    assert_eq!(parsed, recording);
+    assert_eq!(
+        parsed.entries()[3],
+        (std::time::Duration::from_millis(50), RecordedInput::Checkpoint("clicked".into()))
+    );
+
+    let app = App::new().unwrap();
+    let mut checkpoints = Vec::new();
+    replay_input_recording(app.window(), &parsed, |name, window| {
+        assert!(window.is_visible() == app.window().is_visible());
+        checkpoints.push((name.to_string(), app.get_click_count(), app.get_text()));
+    });
+    assert_eq!(
+        checkpoints,
+        vec![
+            ("clicked".to_string(), 1, "".into()),
+            ("typed".to_string(), 1, "Hé你".into()),
+            ("touched".to_string(), 2, "Hé你".into())
+        ]
+    );
+
+    assert!("10 key-pressed a".parse::<InputRecording>().unwrap_err().starts_with("line 1:"));
+    assert!("# comment\n10 teleport 1 2".parse::<InputRecording>().is_err());
+}
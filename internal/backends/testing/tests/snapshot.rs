@@ -0,0 +1,61 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use i_slint_backend_testing::{
+    assert_snapshot_matches, assert_snapshot_matches_with_tolerance, SnapshotTolerance,
+};
+use slint::ComponentHandle;
+
+slint::slint! {
+    export component App inherits Window {
+        width: 40px;
+        height: 30px;
+        in property <color> inner-color: blue;
+        background: white;
+        Rectangle {
+            x: 5px;
+            y: 5px;
+            width: 20px;
+            height: 10px;
+            background: inner-color;
+        }
+    }
+}
+
+#[test]
+fn test_snapshot() {
+    i_slint_backend_testing::init_no_event_loop();
+
+    let app = App::new().unwrap();
+    app.show().unwrap();
+    assert_snapshot_matches(app.window(), "rectangle");
+    if std::env::var(i_slint_backend_testing::UPDATE_SNAPSHOTS_ENV_VAR).is_ok() {
+        return;
+    }
+
+    // A slightly different color is within the tolerance
+    app.set_inner_color(slint::Color::from_rgb_u8(0, 0, 250));
+    assert_snapshot_matches_with_tolerance(
+        app.window(),
+        "rectangle",
+        SnapshotTolerance { channel: 5, different_pixels_percentage: 0. },
+    );
+
+    app.set_inner_color(slint::Color::from_rgb_u8(255, 0, 0));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        assert_snapshot_matches(app.window(), "rectangle")
+    }));
+    let snapshots = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    assert!(result.is_err());
+    assert!(snapshots.join("rectangle.diff.png").exists());
+    assert!(snapshots.join("rectangle.actual.png").exists());
+    std::fs::remove_file(snapshots.join("rectangle.diff.png")).unwrap();
+    std::fs::remove_file(snapshots.join("rectangle.actual.png")).unwrap();
+
+    // 200 pixels out of 1200 are different
+    assert_snapshot_matches_with_tolerance(
+        app.window(),
+        "rectangle",
+        SnapshotTolerance { channel: 0, different_pixels_percentage: 20. },
+    );
+}
@@ -13,6 +13,7 @@
 use i_slint_core::accessibility::{
     AccessibilityAction, AccessibleStringProperty, SupportedAccessibilityAction,
 };
+use i_slint_core::input::FocusReason;
 use i_slint_core::item_tree::{ItemTreeRc, ItemTreeRef, ItemTreeWeak};
 use i_slint_core::items::{ItemRc, WindowItem};
 use i_slint_core::lengths::ScaleFactor;
@@ -118,7 +119,11 @@ fn handle_request(&self, request: ActionRequest) {
             Action::Default => AccessibilityAction::Default,
             Action::Focus => {
                 if let Some(item) = self.nodes.item_rc_for_node_id(request.target) {
-                    WindowInner::from_pub(window_adapter.window()).set_focus_item(&item, true);
+                    WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                        &item,
+                        true,
+                        FocusReason::AccessibilityAction,
+                    );
                 }
                 return;
             }
@@ -385,6 +390,7 @@ fn build_node_without_children(&self, item: &ItemRc, scale_factor: ScaleFactor)
                     }
                     i_slint_core::items::AccessibleRole::Switch => Role::Switch,
                     i_slint_core::items::AccessibleRole::ListItem => Role::ListBoxOption,
+                    i_slint_core::items::AccessibleRole::TreeItem => Role::TreeItem,
                     _ => Role::Unknown,
                 },
                 item.accessible_string_property(
@@ -426,6 +432,16 @@ fn build_node_without_children(&self, item: &ItemRc, scale_factor: ScaleFactor)
             builder.set_toggled(if is_checked { Toggled::True } else { Toggled::False });
         }
 
+        if item
+            .accessible_string_property(AccessibleStringProperty::Expandable)
+            .is_some_and(|x| x == "true")
+        {
+            builder.set_expanded(
+                item.accessible_string_property(AccessibleStringProperty::Expanded)
+                    .is_some_and(|x| x == "true"),
+            );
+        }
+
         if let Some(description) =
             item.accessible_string_property(AccessibleStringProperty::Description)
         {
@@ -479,6 +495,13 @@ fn build_node_without_children(&self, item: &ItemRc, scale_factor: ScaleFactor)
             builder.set_placeholder(placeholder.to_string());
         }
 
+        if let Some(shortcut) = item
+            .accessible_string_property(AccessibleStringProperty::KeyboardShortcut)
+            .filter(|x| !x.is_empty())
+        {
+            builder.set_keyboard_shortcut(shortcut.to_string());
+        }
+
         if item
             .accessible_string_property(AccessibleStringProperty::Selectable)
             .is_some_and(|x| x == "true")
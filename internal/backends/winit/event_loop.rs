@@ -237,6 +237,19 @@ pub fn window_by_id(id: winit::window::WindowId) -> Option<Rc<WinitWindowAdapter
     ALL_WINDOWS.with(|windows| windows.borrow().get(&id).and_then(|weakref| weakref.upgrade()))
 }
 
+/// Returns the `text/uri-list` drag data for the paths, with the `file://` URI of each path on its own line.
+fn uri_list(paths: &[std::path::PathBuf]) -> corelib::SharedString {
+    let uris = paths.iter().map(|path| {
+        let path = path.to_string_lossy().replace('\\', "/");
+        if path.starts_with('/') {
+            format!("file://{path}")
+        } else {
+            format!("file:///{path}")
+        }
+    });
+    uris.collect::<Vec<_>>().join("\r\n").into()
+}
+
 /// This enum captures run-time specific events that can be dispatched to the event loop in
 /// addition to the winit events.
 pub enum CustomEvent {
@@ -269,10 +282,14 @@ pub struct EventLoopState {
     // last seen cursor position
     cursor_pos: LogicalPoint,
     pressed: bool,
-    current_touch_id: Option<u64>,
 
     loop_error: Option<PlatformError>,
     current_resize_direction: Option<ResizeDirection>,
+
+    /// The files dragged over a window. winit sends an event for each of them.
+    hovered_files: Vec<std::path::PathBuf>,
+    /// The files dropped on a window, which are dropped together once winit sent all their events.
+    dropped_files: Option<(winit::window::WindowId, Vec<std::path::PathBuf>)>,
 }
 
 impl winit::application::ApplicationHandler<SlintUserEvent> for EventLoopState {
@@ -481,35 +498,43 @@ macro_rules! winit_key_to_char {
                 };
                 runtime_window.process_mouse_input(ev);
             }
+            // winit doesn't report the cursor position while files are dragged over the window
+            // on all platforms, so the last known position is used.
+            WindowEvent::HoveredFile(path) => {
+                self.hovered_files.push(path);
+                runtime_window.start_drag("text/uri-list".into(), uri_list(&self.hovered_files));
+                runtime_window.process_mouse_input(MouseEvent::Moved { position: self.cursor_pos });
+            }
+            WindowEvent::HoveredFileCancelled => {
+                self.hovered_files.clear();
+                runtime_window.cancel_drag();
+            }
+            WindowEvent::DroppedFile(path) => {
+                self.hovered_files.clear();
+                self.dropped_files.get_or_insert_with(|| (window_id, Vec::new())).1.push(path);
+            }
             WindowEvent::Touch(touch) => {
-                if Some(touch.id) == self.current_touch_id || self.current_touch_id.is_none() {
-                    let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
-                    let position = euclid::point2(location.x, location.y);
-                    let ev = match touch.phase {
-                        winit::event::TouchPhase::Started => {
-                            self.pressed = true;
-                            if self.current_touch_id.is_none() {
-                                self.current_touch_id = Some(touch.id);
-                            }
-                            MouseEvent::Pressed {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
-                            self.pressed = false;
-                            self.current_touch_id = None;
-                            MouseEvent::Released {
-                                position,
-                                button: PointerEventButton::Left,
-                                click_count: 0,
-                            }
-                        }
-                        winit::event::TouchPhase::Moved => MouseEvent::Moved { position },
-                    };
-                    runtime_window.process_mouse_input(ev);
-                }
+                let location = touch.location.to_logical(runtime_window.scale_factor() as f64);
+                let position = corelib::api::LogicalPosition::new(location.x, location.y);
+                let touch_id = touch.id;
+                let ev = match touch.phase {
+                    winit::event::TouchPhase::Started => {
+                        self.pressed = true;
+                        corelib::platform::WindowEvent::TouchPressed { touch_id, position }
+                    }
+                    winit::event::TouchPhase::Ended => {
+                        self.pressed = false;
+                        corelib::platform::WindowEvent::TouchReleased { touch_id, position }
+                    }
+                    winit::event::TouchPhase::Cancelled => {
+                        self.pressed = false;
+                        corelib::platform::WindowEvent::TouchCancelled { touch_id, position }
+                    }
+                    winit::event::TouchPhase::Moved => {
+                        corelib::platform::WindowEvent::TouchMoved { touch_id, position }
+                    }
+                };
+                window.window().dispatch_event(ev);
             }
             WindowEvent::ScaleFactorChanged { scale_factor, inner_size_writer: _ } => {
                 if std::env::var("SLINT_SCALE_FACTOR").is_err() {
@@ -564,6 +589,18 @@ fn new_events(&mut self, event_loop: &ActiveEventLoop, _cause: winit::event::Sta
     }
 
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        if let Some((window_id, paths)) = self.dropped_files.take() {
+            if let Some(window) = window_by_id(window_id) {
+                let runtime_window = WindowInner::from_pub(window.window());
+                runtime_window.start_drag("text/uri-list".into(), uri_list(&paths));
+                runtime_window.process_mouse_input(MouseEvent::Released {
+                    position: self.cursor_pos,
+                    button: PointerEventButton::Left,
+                    click_count: 0,
+                });
+            }
+        }
+
         if !event_loop.exiting() {
             ALL_WINDOWS.with(|windows| {
                 for w in windows.borrow().iter().filter_map(|(_, w)| w.upgrade()) {
@@ -544,6 +544,16 @@ fn on_winit_window_event(
         callback: impl FnMut(&i_slint_core::api::Window, &winit::event::WindowEvent) -> WinitWindowEventResult
             + 'static,
     );
+    /// Registers a callback that is invoked when the pointer leaves this Slint window while dragging
+    /// the data of a `DragArea`. The callback receives the window, and the MIME type and data of the
+    /// `DragArea`.
+    ///
+    /// winit has no API to start a drag and drop operation of the windowing system, so Slint can't drop
+    /// the data in other applications by itself. The callback can continue the drag with the API of the
+    /// platform, using the window handle that [`Self::with_winit_window()`] provides.
+    ///
+    /// If this window [is not backed by winit](WinitWindowAccessor::has_winit_window), this function is a no-op.
+    fn on_drag_out(&self, callback: impl FnMut(&i_slint_core::api::Window, &str, &str) + 'static);
 }
 
 impl WinitWindowAccessor for i_slint_core::api::Window {
@@ -581,6 +591,16 @@ fn on_winit_window_event(
                     .set(Some(Box::new(move |window, event| callback(window, event))));
             });
     }
+
+    fn on_drag_out(&self, callback: impl FnMut(&i_slint_core::api::Window, &str, &str) + 'static) {
+        if let Some(adapter) = i_slint_core::window::WindowInner::from_pub(self)
+            .window_adapter()
+            .internal(i_slint_core::InternalToken)
+            .and_then(|wa| wa.as_any().downcast_ref::<WinitWindowAdapter>())
+        {
+            adapter.drag_out_handler.set(Some(Box::new(callback)));
+        }
+    }
 }
 
 impl private::WinitWindowAccessorSealed for i_slint_core::api::Window {}
@@ -275,6 +275,9 @@ pub struct WinitWindowAdapter {
         >,
     >,
 
+    /// Set with [`crate::WinitWindowAccessor::on_drag_out`]
+    pub(crate) drag_out_handler: Cell<Option<Box<dyn FnMut(&corelib::api::Window, &str, &str)>>>,
+
     winit_window_or_none: RefCell<WinitWindowOrNone>,
 }
 
@@ -315,6 +318,7 @@ pub(crate) fn new(
             )
             .into(),
             window_event_filter: Cell::new(None),
+            drag_out_handler: Cell::new(None),
         });
 
         debug_assert!(!self_rc.renderer.is_suspended());
@@ -881,50 +885,6 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
         }
     }
 
-    fn internal(&self, _: corelib::InternalToken) -> Option<&dyn WindowAdapterInternal> {
-        Some(self)
-    }
-}
-
-impl WindowAdapterInternal for WinitWindowAdapter {
-    fn set_mouse_cursor(&self, cursor: MouseCursor) {
-        let winit_cursor = match cursor {
-            MouseCursor::Default => winit::window::CursorIcon::Default,
-            MouseCursor::None => winit::window::CursorIcon::Default,
-            MouseCursor::Help => winit::window::CursorIcon::Help,
-            MouseCursor::Pointer => winit::window::CursorIcon::Pointer,
-            MouseCursor::Progress => winit::window::CursorIcon::Progress,
-            MouseCursor::Wait => winit::window::CursorIcon::Wait,
-            MouseCursor::Crosshair => winit::window::CursorIcon::Crosshair,
-            MouseCursor::Text => winit::window::CursorIcon::Text,
-            MouseCursor::Alias => winit::window::CursorIcon::Alias,
-            MouseCursor::Copy => winit::window::CursorIcon::Copy,
-            MouseCursor::Move => winit::window::CursorIcon::Move,
-            MouseCursor::NoDrop => winit::window::CursorIcon::NoDrop,
-            MouseCursor::NotAllowed => winit::window::CursorIcon::NotAllowed,
-            MouseCursor::Grab => winit::window::CursorIcon::Grab,
-            MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
-            MouseCursor::ColResize => winit::window::CursorIcon::ColResize,
-            MouseCursor::RowResize => winit::window::CursorIcon::RowResize,
-            MouseCursor::NResize => winit::window::CursorIcon::NResize,
-            MouseCursor::EResize => winit::window::CursorIcon::EResize,
-            MouseCursor::SResize => winit::window::CursorIcon::SResize,
-            MouseCursor::WResize => winit::window::CursorIcon::WResize,
-            MouseCursor::NeResize => winit::window::CursorIcon::NeResize,
-            MouseCursor::NwResize => winit::window::CursorIcon::NwResize,
-            MouseCursor::SeResize => winit::window::CursorIcon::SeResize,
-            MouseCursor::SwResize => winit::window::CursorIcon::SwResize,
-            MouseCursor::EwResize => winit::window::CursorIcon::EwResize,
-            MouseCursor::NsResize => winit::window::CursorIcon::NsResize,
-            MouseCursor::NeswResize => winit::window::CursorIcon::NeswResize,
-            MouseCursor::NwseResize => winit::window::CursorIcon::NwseResize,
-        };
-        if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
-            winit_window.set_cursor_visible(cursor != MouseCursor::None);
-            winit_window.set_cursor(winit_cursor);
-        }
-    }
-
     fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
         #[cfg(not(target_arch = "wasm32"))]
         if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
@@ -972,6 +932,50 @@ fn input_method_request(&self, request: corelib::window::InputMethodRequest) {
         };
     }
 
+    fn internal(&self, _: corelib::InternalToken) -> Option<&dyn WindowAdapterInternal> {
+        Some(self)
+    }
+}
+
+impl WindowAdapterInternal for WinitWindowAdapter {
+    fn set_mouse_cursor(&self, cursor: MouseCursor) {
+        let winit_cursor = match cursor {
+            MouseCursor::Default => winit::window::CursorIcon::Default,
+            MouseCursor::None => winit::window::CursorIcon::Default,
+            MouseCursor::Help => winit::window::CursorIcon::Help,
+            MouseCursor::Pointer => winit::window::CursorIcon::Pointer,
+            MouseCursor::Progress => winit::window::CursorIcon::Progress,
+            MouseCursor::Wait => winit::window::CursorIcon::Wait,
+            MouseCursor::Crosshair => winit::window::CursorIcon::Crosshair,
+            MouseCursor::Text => winit::window::CursorIcon::Text,
+            MouseCursor::Alias => winit::window::CursorIcon::Alias,
+            MouseCursor::Copy => winit::window::CursorIcon::Copy,
+            MouseCursor::Move => winit::window::CursorIcon::Move,
+            MouseCursor::NoDrop => winit::window::CursorIcon::NoDrop,
+            MouseCursor::NotAllowed => winit::window::CursorIcon::NotAllowed,
+            MouseCursor::Grab => winit::window::CursorIcon::Grab,
+            MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
+            MouseCursor::ColResize => winit::window::CursorIcon::ColResize,
+            MouseCursor::RowResize => winit::window::CursorIcon::RowResize,
+            MouseCursor::NResize => winit::window::CursorIcon::NResize,
+            MouseCursor::EResize => winit::window::CursorIcon::EResize,
+            MouseCursor::SResize => winit::window::CursorIcon::SResize,
+            MouseCursor::WResize => winit::window::CursorIcon::WResize,
+            MouseCursor::NeResize => winit::window::CursorIcon::NeResize,
+            MouseCursor::NwResize => winit::window::CursorIcon::NwResize,
+            MouseCursor::SeResize => winit::window::CursorIcon::SeResize,
+            MouseCursor::SwResize => winit::window::CursorIcon::SwResize,
+            MouseCursor::EwResize => winit::window::CursorIcon::EwResize,
+            MouseCursor::NsResize => winit::window::CursorIcon::NsResize,
+            MouseCursor::NeswResize => winit::window::CursorIcon::NeswResize,
+            MouseCursor::NwseResize => winit::window::CursorIcon::NwseResize,
+        };
+        if let Some(winit_window) = self.winit_window_or_none.borrow().as_window() {
+            winit_window.set_cursor_visible(cursor != MouseCursor::None);
+            winit_window.set_cursor(winit_cursor);
+        }
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -994,6 +998,13 @@ fn color_scheme(&self) -> ColorScheme {
             .get()
     }
 
+    fn drag_left_window(&self, data: &corelib::items::DropEvent) {
+        if let Some(mut handler) = self.drag_out_handler.take() {
+            handler(self.window(), &data.mime_type, &data.data);
+            self.drag_out_handler.set(Some(handler));
+        }
+    }
+
     #[cfg(enable_accesskit)]
     fn handle_focus_change(&self, _old: Option<ItemRc>, _new: Option<ItemRc>) {
         self.accesskit_adapter.borrow_mut().handle_focus_item_change();
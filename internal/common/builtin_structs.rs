@@ -86,6 +86,22 @@ struct PointerScrollEvent {
                 }
             }
 
+            /// This structure is passed to the callbacks of the `DropArea` element.
+            /// It describes the data of a drag and drop operation.
+            struct DropEvent {
+                @name = "slint::private_api::DropEvent"
+                export {
+                    /// The mime type of the data, for example `text/plain` or `text/uri-list`
+                    mime_type: SharedString,
+                    /// The data being dragged
+                    data: SharedString,
+                    /// The position of the pointer, relative to the `DropArea`
+                    position: LogicalPosition,
+                }
+                private {
+                }
+            }
+
             /// This structure is generated and passed to the key press and release callbacks of the `FocusScope` element.
             struct KeyEvent {
                 @name = "slint::private_api::KeyEvent"
@@ -127,6 +143,24 @@ struct StandardListViewItem {
                 }
             }
 
+            /// Represents an item in a StandardTreeView.
+            #[non_exhaustive]
+            struct StandardTreeViewItem {
+                @name = "slint::StandardTreeViewItem"
+                export {
+                    /// The text content of the item
+                    text: SharedString,
+                    /// The depth of the item in the tree. Top level items have a level of 0.
+                    level: i32,
+                    /// Whether the item has children that can be shown by expanding it
+                    has_children: bool,
+                    /// Whether the children of the item are shown
+                    expanded: bool,
+                }
+                private {
+                }
+            }
+
             /// This is used to define the column and the column header of a TableView
             #[non_exhaustive]
             struct TableColumn {
@@ -147,6 +181,32 @@ struct TableColumn {
                 }
             }
 
+            /// An entry of a menu of a `MenuBar` or a `ContextMenuArea`, as passed to the
+            /// implementation of the menus in the widget styles and in the backends.
+            struct MenuEntry {
+                @name = "slint::private_api::MenuEntry"
+                export {
+                    /// The title of the entry
+                    title: SharedString,
+                    /// The id that identifies the entry within its menu tree
+                    id: SharedString,
+                    /// Whether the entry can be activated
+                    enabled: bool,
+                    /// Whether the entry can be checked
+                    checkable: bool,
+                    /// Whether the entry is checked
+                    checked: bool,
+                    /// The key sequence of the shortcut, such as `Ctrl+S`
+                    shortcut: SharedString,
+                    /// Whether the entry opens a sub-menu
+                    has_sub_menu: bool,
+                    /// Whether the entry is a separator
+                    is_separator: bool,
+                }
+                private {
+                }
+            }
+
             /// Value of the state property
             /// A state is just the current state, but also has information about the previous state and the moment it changed
             struct StateInfo {
@@ -321,6 +321,35 @@ enum LayoutAlignment {
                 SpaceAround,
             }
 
+            /// This enum describes the direction in which the items of a [`FlexboxLayout`](elements.md#flexboxlayout) are placed.
+            enum FlexboxLayoutDirection {
+                /// The items are placed horizontally from left to right, lines are stacked from top to bottom.
+                Row,
+                /// The items are placed vertically from top to bottom, lines are stacked from left to right.
+                Column,
+            }
+
+            /// This enum describes whether the items of a [`FlexboxLayout`](elements.md#flexboxlayout) can be placed on several lines.
+            enum FlexboxLayoutWrap {
+                /// Items that don't fit on the current line are moved to the next line.
+                Wrap,
+                /// All the items are placed on a single line.
+                NoWrap,
+            }
+
+            /// This enum describes how the items of a [`FlexboxLayout`](elements.md#flexboxlayout) are aligned
+            /// within their line, perpendicular to the direction of the layout.
+            enum FlexboxLayoutAlignItems {
+                /// The items are stretched to fill the size of the line, within their constraints.
+                Stretch,
+                /// The items use their preferred size and are placed at the start of the line.
+                Start,
+                /// The items use their preferred size and are centered in the line.
+                Center,
+                /// The items use their preferred size and are placed at the end of the line.
+                End,
+            }
+
             /// PathEvent is a low-level data structure describing the composition of a path. Typically it is
             /// generated at compile time from a higher-level description, such as SVG commands.
             enum PathEvent {
@@ -364,7 +393,7 @@ enum AccessibleRole {
                 Text,
                 /// The role for a [`TableView`](slint-reference:src/language/widgets/standardtableview ) or behaves like one.
                 Table,
-                /// The role for a TreeView or behaves like one. (Not provided yet)
+                /// The role for a [`StandardTreeView`](slint-reference:src/language/widgets/standardtreeview) or behaves like one.
                 Tree,
                 /// The element is a [`ProgressIndicator`](slint-reference:src/language/widgets/progressindicator) or behaves like one.
                 ProgressIndicator,
@@ -375,6 +404,8 @@ enum AccessibleRole {
                 Switch,
                 /// The element is an item in a [`ListView`](slint-reference:src/language/widgets/listview).
                 ListItem,
+                /// The element is an item in a [`StandardTreeView`](slint-reference:src/language/widgets/standardtreeview).
+                TreeItem,
             }
 
             /// This enum represents the different values of the `sort-order` property.
@@ -432,6 +463,19 @@ enum ScrollBarPolicy {
                 AlwaysOn,
             }
 
+            /// This enum describes how an element can receive the keyboard focus.
+            /// The focus can always be set programmatically with `focus()`, regardless of the policy.
+            enum FocusPolicy {
+                /// The element receives the focus when navigating with the <kbd>Tab</kbd> key and when clicked.
+                TabAndClick,
+                /// The element only receives the focus when navigating with the <kbd>Tab</kbd> key.
+                TabOnly,
+                /// The element only receives the focus when clicked.
+                ClickOnly,
+                /// The element doesn't receive the focus from user interaction.
+                NoFocus,
+            }
+
             // This enum describes the close behaviour of [`PopupWindow`](elements.md#popupwindow)
             enum PopupClosePolicy {
                 /// Closes the `PopupWindow` when user clicks.
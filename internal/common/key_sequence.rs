@@ -0,0 +1,167 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Parsing of the key sequences of the `Shortcut` element, such as `"Ctrl+Shift+S"`.
+//!
+//! This is used by the compiler to diagnose invalid sequences, and by the runtime to
+//! match the key events.
+
+/// A key combined with modifiers.
+///
+/// The modifiers follow the `KeyboardModifiers` of the key events: on macOS, `control` is the
+/// Command key (⌘) and `meta` is the Control key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct KeySequence {
+    pub alt: bool,
+    pub control: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// The character of the key, as in the text of the key event. Letters are lowercase, and the
+    /// special keys use the codes of the `Key` namespace.
+    pub key: char,
+}
+
+/// The reason why a key sequence could not be parsed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySequenceError {
+    Empty,
+    MissingKey,
+    UnknownModifier,
+    DuplicateModifier,
+    UnknownKey,
+}
+
+impl core::fmt::Display for KeySequenceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "the key sequence is empty",
+            Self::MissingKey => "the key sequence has no key after the modifiers",
+            Self::UnknownModifier => {
+                "unknown modifier, expected one of Ctrl, Cmd, Alt, Option, Shift, or Meta"
+            }
+            Self::DuplicateModifier => "a modifier is used twice",
+            Self::UnknownKey => "unknown key, expected a single character or the name of a key",
+        })
+    }
+}
+
+macro_rules! declare_special_key_from_name {
+    ($($char:literal # $name:ident # $($_qt:ident)|* # $($_winit:ident $(($_pos:ident))?)|* # $($_xkb:ident)|* ;)*) => {
+        fn special_key_from_name(name: &str) -> Option<char> {
+            $(if name.eq_ignore_ascii_case(stringify!($name)) {
+                return Some($char);
+            })*
+            None
+        }
+    };
+}
+crate::for_each_special_keys!(declare_special_key_from_name);
+
+/// Shorter names accepted for some keys, in addition to the names of the `Key` namespace
+const KEY_ALIASES: [(&str, &str); 6] = [
+    ("Enter", "Return"),
+    ("Esc", "Escape"),
+    ("Up", "UpArrow"),
+    ("Down", "DownArrow"),
+    ("Left", "LeftArrow"),
+    ("Right", "RightArrow"),
+];
+
+impl KeySequence {
+    /// Parses a key sequence made of modifiers and a key separated by `+`, such as `Ctrl+S`,
+    /// `Alt+F4` or `Ctrl++`. The names are case insensitive.
+    ///
+    /// `Ctrl` and `Cmd` are the same modifier: the Control key, or the Command key on macOS.
+    pub fn parse(sequence: &str) -> Result<Self, KeySequenceError> {
+        let sequence = sequence.trim();
+        if sequence.is_empty() {
+            return Err(KeySequenceError::Empty);
+        }
+        let (modifiers, key) = match sequence.strip_suffix("++") {
+            Some(modifiers) => (Some(modifiers), "+"),
+            None if sequence == "+" => (None, sequence),
+            None => match sequence.rsplit_once('+') {
+                Some((modifiers, key)) => (Some(modifiers), key.trim()),
+                None => (None, sequence),
+            },
+        };
+
+        let mut result = Self::default();
+        for modifier in modifiers.into_iter().flat_map(|m| m.split('+')) {
+            let modifier = modifier.trim();
+            let is = |names: &[&str]| names.iter().any(|n| n.eq_ignore_ascii_case(modifier));
+            let flag = if is(&["Ctrl", "Control", "Cmd", "Command"]) {
+                &mut result.control
+            } else if is(&["Alt", "Option"]) {
+                &mut result.alt
+            } else if is(&["Shift"]) {
+                &mut result.shift
+            } else if is(&["Meta", "Super", "Win"]) {
+                &mut result.meta
+            } else {
+                return Err(KeySequenceError::UnknownModifier);
+            };
+            if core::mem::replace(flag, true) {
+                return Err(KeySequenceError::DuplicateModifier);
+            }
+        }
+
+        let mut chars = key.chars();
+        result.key = match (chars.next(), chars.next()) {
+            (None, _) => return Err(KeySequenceError::MissingKey),
+            (Some(c), None) => to_lowercase(c),
+            _ => {
+                let name = KEY_ALIASES
+                    .iter()
+                    .find(|(alias, _)| alias.eq_ignore_ascii_case(key))
+                    .map_or(key, |(_, name)| name);
+                special_key_from_name(name).ok_or(KeySequenceError::UnknownKey)?
+            }
+        };
+        Ok(result)
+    }
+
+    /// Returns true if the key is a printable character that isn't a letter or a digit.
+    /// Such characters may require Shift to be typed, depending on the keyboard layout.
+    pub fn is_symbol(&self) -> bool {
+        !self.key.is_alphanumeric()
+            && !self.key.is_control()
+            && !self.key.is_whitespace()
+            && !('\u{F700}'..='\u{F8FF}').contains(&self.key)
+    }
+}
+
+/// Returns the lowercase version of a character, if it is a single character
+pub fn to_lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+#[test]
+fn parse_key_sequence() {
+    let parse = KeySequence::parse;
+    assert_eq!(parse("Ctrl+S"), Ok(KeySequence { control: true, key: 's', ..Default::default() }));
+    assert_eq!(
+        parse(" cmd + shift + z "),
+        Ok(KeySequence { control: true, shift: true, key: 'z', ..Default::default() })
+    );
+    assert_eq!(
+        parse("Alt+F4"),
+        Ok(KeySequence { alt: true, key: '\u{F707}', ..Default::default() })
+    );
+    assert_eq!(parse("Ctrl++"), Ok(KeySequence { control: true, key: '+', ..Default::default() }));
+    assert_eq!(parse("+"), Ok(KeySequence { key: '+', ..Default::default() }));
+    assert_eq!(parse("esc"), Ok(KeySequence { key: '\u{001b}', ..Default::default() }));
+    assert_eq!(parse("Meta+PageDown").map(|s| (s.meta, s.key)), Ok((true, '\u{F72D}')));
+    assert_eq!(parse(""), Err(KeySequenceError::Empty));
+    assert_eq!(parse("Ctrl+"), Err(KeySequenceError::MissingKey));
+    assert_eq!(parse("Hyper+S"), Err(KeySequenceError::UnknownModifier));
+    assert_eq!(parse("Ctrl+Control+S"), Err(KeySequenceError::DuplicateModifier));
+    assert_eq!(parse("Ctrl+Foo"), Err(KeySequenceError::UnknownKey));
+    assert!(parse("Ctrl+-").unwrap().is_symbol());
+    assert!(!parse("Ctrl+A").unwrap().is_symbol());
+    assert!(!parse("Ctrl+Up").unwrap().is_symbol());
+}
@@ -8,6 +8,7 @@
 pub mod builtin_structs;
 pub mod enums;
 pub mod key_codes;
+pub mod key_sequence;
 
 #[cfg(feature = "shared-fontdb")]
 pub mod sharedfontdb;
@@ -72,6 +72,7 @@ pub enum BuiltinFunction {
     ParseDate,
     TextInputFocused,
     SetTextInputFocused,
+    TextInputType,
     ImplicitLayoutInfo(Orientation),
     ItemAbsolutePosition,
     RegisterCustomFontByPath,
@@ -293,6 +294,12 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::SetTextInputFocused => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::Bool] }
             }
+            BuiltinFunction::TextInputType => Type::Function {
+                return_type: Box::new(Type::Enumeration(
+                    crate::typeregister::BUILTIN_ENUMS.with(|e| e.InputType.clone()),
+                )),
+                args: vec![],
+            },
             BuiltinFunction::ItemAbsolutePosition => Type::Function {
                 return_type: Box::new(crate::typeregister::logical_point_type()),
                 args: vec![Type::ElementReference],
@@ -381,6 +388,7 @@ fn is_const(&self) -> bool {
             BuiltinFunction::Hsv => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => false,
+            BuiltinFunction::TextInputType => false,
             BuiltinFunction::ImplicitLayoutInfo(_) => false,
             BuiltinFunction::ItemAbsolutePosition => true,
             BuiltinFunction::RegisterCustomFontByPath
@@ -443,6 +451,7 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::ItemAbsolutePosition => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => true,
+            BuiltinFunction::TextInputType => true,
             BuiltinFunction::RegisterCustomFontByPath
             | BuiltinFunction::RegisterCustomFontByMemory
             | BuiltinFunction::RegisterBitmapFont => false,
@@ -671,6 +680,22 @@ pub enum Expression {
     /// a code block with different expression
     CodeBlock(Vec<Expression>),
 
+    /// A `for` loop within a code block. The `body` is evaluated for each row of the `model`,
+    /// with the model data stored in the local variable `model_data`, and the row index stored
+    /// in the local variable `index` if any.
+    ForLoop {
+        model_data: SmolStr,
+        index: Option<SmolStr>,
+        model: Box<Expression>,
+        body: Box<Expression>,
+    },
+
+    /// A `while` loop within a code block
+    WhileLoop {
+        condition: Box<Expression>,
+        body: Box<Expression>,
+    },
+
     /// A function call
     FunctionCall {
         function: Box<Expression>,
@@ -729,11 +754,29 @@ pub enum Expression {
         angle: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: GradientSpread,
     },
 
     RadialGradient {
+        /// The center, as a fraction of the size of the shape
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        /// The radius as a length, or zero for half of the largest side of the shape
+        radius: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: GradientSpread,
+    },
+
+    ConicGradient {
+        from_angle: Box<Expression>,
+        /// The center, as a fraction of the size of the shape
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        /// as a fraction of a turn
+        stops: Vec<(Expression, Expression)>,
+        spread: GradientSpread,
     },
 
     EnumerationValue(EnumerationValue),
@@ -799,6 +842,8 @@ pub fn ty(&self) -> Type {
             },
             Expression::Cast { to, .. } => to.clone(),
             Expression::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty()),
+            Expression::ForLoop { .. } => Type::Void,
+            Expression::WhileLoop { .. } => Type::Void,
             Expression::FunctionCall { function, .. } => match function.ty() {
                 Type::Function { return_type, .. } => *return_type,
                 Type::Callback { return_type, .. } => return_type.map_or(Type::Void, |x| *x),
@@ -880,6 +925,7 @@ pub fn ty(&self) -> Type {
             Expression::EasingCurve(_) => Type::Easing,
             Expression::LinearGradient { .. } => Type::Brush,
             Expression::RadialGradient { .. } => Type::Brush,
+            Expression::ConicGradient { .. } => Type::Brush,
             Expression::EnumerationValue(value) => Type::Enumeration(value.enumeration.clone()),
             // invalid because the expression is unreachable
             Expression::ReturnStatement(_) => Type::Invalid,
@@ -921,6 +967,14 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::CodeBlock(sub) => {
                 sub.iter().for_each(visitor);
             }
+            Expression::ForLoop { model, body, .. } => {
+                visitor(model);
+                visitor(body);
+            }
+            Expression::WhileLoop { condition, body } => {
+                visitor(condition);
+                visitor(body);
+            }
             Expression::FunctionCall { function, arguments, source_location: _ } => {
                 visitor(function);
                 arguments.iter().for_each(visitor);
@@ -964,14 +1018,26 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
             Expression::StoreLocalVariable { value, .. } => visitor(value),
             Expression::ReadLocalVariable { .. } => {}
             Expression::EasingCurve(_) => {}
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 visitor(angle);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { center_x, center_y, radius, stops, .. } => {
+                visitor(center_x);
+                visitor(center_y);
+                visitor(radius);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops, .. } => {
+                visitor(from_angle);
+                visitor(center_x);
+                visitor(center_y);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
@@ -1023,6 +1089,14 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::CodeBlock(sub) => {
                 sub.iter_mut().for_each(visitor);
             }
+            Expression::ForLoop { model, body, .. } => {
+                visitor(model);
+                visitor(body);
+            }
+            Expression::WhileLoop { condition, body } => {
+                visitor(condition);
+                visitor(body);
+            }
             Expression::FunctionCall { function, arguments, source_location: _ } => {
                 visitor(function);
                 arguments.iter_mut().for_each(visitor);
@@ -1069,14 +1143,26 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
             Expression::StoreLocalVariable { value, .. } => visitor(value),
             Expression::ReadLocalVariable { .. } => {}
             Expression::EasingCurve(_) => {}
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 visitor(angle);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { center_x, center_y, radius, stops, .. } => {
+                visitor(center_x);
+                visitor(center_y);
+                visitor(radius);
+                for (c, s) in stops {
+                    visitor(c);
+                    visitor(s);
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops, .. } => {
+                visitor(from_angle);
+                visitor(center_x);
+                visitor(center_y);
                 for (c, s) in stops {
                     visitor(c);
                     visitor(s);
@@ -1132,6 +1218,8 @@ pub fn is_constant(&self) -> bool {
             Expression::ArrayIndex { array, index } => array.is_constant() && index.is_constant(),
             Expression::Cast { from, .. } => from.is_constant(),
             Expression::CodeBlock(sub) => sub.len() == 1 && sub.first().unwrap().is_constant(),
+            Expression::ForLoop { .. } => false,
+            Expression::WhileLoop { .. } => false,
             Expression::FunctionCall { function, arguments, .. } => {
                 // Assume that constant function are, in fact, pure
                 function.is_constant() && arguments.iter().all(|a| a.is_constant())
@@ -1159,11 +1247,20 @@ pub fn is_constant(&self) -> bool {
             // we should somehow find out if this is constant or not
             Expression::ReadLocalVariable { .. } => false,
             Expression::EasingCurve(_) => true,
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 angle.is_constant() && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
-            Expression::RadialGradient { stops } => {
-                stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            Expression::RadialGradient { center_x, center_y, radius, stops, .. } => {
+                center_x.is_constant()
+                    && center_y.is_constant()
+                    && radius.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops, .. } => {
+                from_angle.is_constant()
+                    && center_x.is_constant()
+                    && center_y.is_constant()
+                    && stops.iter().all(|(c, s)| c.is_constant() && s.is_constant())
             }
             Expression::EnumerationValue(_) => true,
             Expression::ReturnStatement(expr) => {
@@ -1636,6 +1733,42 @@ pub enum EasingCurve {
     // Custom(Box<dyn Fn(f32)->f32>),
 }
 
+/// How a gradient fills the area outside of the range of its stops
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GradientSpread {
+    #[default]
+    Pad,
+    Repeat,
+    Reflect,
+}
+
+impl GradientSpread {
+    /// Splits the name of a gradient into its spread and the name without the prefix.
+    /// For example `repeating-linear-gradient` returns `(Repeat, "linear-gradient")`.
+    pub fn from_gradient_name(name: &str) -> (Self, &str) {
+        for (spread, prefix) in [
+            (Self::Repeat, "repeating-"),
+            (Self::Repeat, "repeating_"),
+            (Self::Reflect, "reflecting-"),
+            (Self::Reflect, "reflecting_"),
+        ] {
+            if let Some(name) = name.strip_prefix(prefix) {
+                return (spread, name);
+            }
+        }
+        (Self::Pad, name)
+    }
+
+    /// The prefix of the `@` keyword for this spread, eg `repeating-`.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Pad => "",
+            Self::Repeat => "repeating-",
+            Self::Reflect => "reflecting-",
+        }
+    }
+}
+
 // The compiler generates ResourceReference::AbsolutePath for all references like @image-url("foo.png")
 // and the resource lowering path may change this to EmbeddedData if configured.
 #[derive(Clone, Debug)]
@@ -1701,6 +1834,22 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, "}}")
         }
+        Expression::ForLoop { model_data, index, model, body } => {
+            write!(f, "for {}", model_data)?;
+            if let Some(index) = index {
+                write!(f, "[{}]", index)?;
+            }
+            write!(f, " in ")?;
+            pretty_print(f, model)?;
+            write!(f, " ")?;
+            pretty_print(f, body)
+        }
+        Expression::WhileLoop { condition, body } => {
+            write!(f, "while ")?;
+            pretty_print(f, condition)?;
+            write!(f, " ")?;
+            pretty_print(f, body)
+        }
         Expression::FunctionCall { function, arguments, source_location: _ } => {
             pretty_print(f, function)?;
             write!(f, "(")?;
@@ -1758,8 +1907,8 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
         }
         Expression::PathData(data) => write!(f, "{:?}", data),
         Expression::EasingCurve(e) => write!(f, "{:?}", e),
-        Expression::LinearGradient { angle, stops } => {
-            write!(f, "@linear-gradient(")?;
+        Expression::LinearGradient { angle, stops, spread } => {
+            write!(f, "@{}linear-gradient(", spread.prefix())?;
             pretty_print(f, angle)?;
             for (c, s) in stops {
                 write!(f, ", ")?;
@@ -1769,8 +1918,28 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             }
             write!(f, ")")
         }
-        Expression::RadialGradient { stops } => {
-            write!(f, "@radial-gradient(circle")?;
+        Expression::RadialGradient { center_x, center_y, radius, stops, spread } => {
+            write!(f, "@{}radial-gradient(circle ", spread.prefix())?;
+            pretty_print(f, radius)?;
+            write!(f, " at ")?;
+            pretty_print(f, center_x)?;
+            write!(f, " ")?;
+            pretty_print(f, center_y)?;
+            for (c, s) in stops {
+                write!(f, ", ")?;
+                pretty_print(f, c)?;
+                write!(f, "  ")?;
+                pretty_print(f, s)?;
+            }
+            write!(f, ")")
+        }
+        Expression::ConicGradient { from_angle, center_x, center_y, stops, spread } => {
+            write!(f, "@{}conic-gradient(from ", spread.prefix())?;
+            pretty_print(f, from_angle)?;
+            write!(f, " at ")?;
+            pretty_print(f, center_x)?;
+            write!(f, " ")?;
+            pretty_print(f, center_y)?;
             for (c, s) in stops {
                 write!(f, ", ")?;
                 pretty_print(f, c)?;
@@ -1,8 +1,134 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-use super::writer::TokenWriter;
-use i_slint_compiler::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
+/*!
+ The formatter for `.slint` files.
+
+ It works on the [`SyntaxNode`] of a parsed document: comments are preserved, while the whitespace
+ and the indentation of the elements and bindings is normalized.
+ The tokens go through a [`writer::TokenWriter`], so that the LSP can compute edits instead of
+ re-writing the whole file.
+*/
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
+use std::io::Write;
+use std::path::Path;
+use writer::TokenWriter;
+
+pub mod writer;
+
+/// Format the source of a file and write the result in `file`.
+///
+/// `.slint` files are formatted, while `.rs` and `.md` files have their `slint!` macros and
+/// ```` ```slint ```` code blocks formatted. Other files are written unchanged.
+/// When some code can't be parsed, its unparsed part is written unchanged and the errors are
+/// reported in `diag`.
+pub fn format_file(
+    source: String,
+    path: &Path,
+    file: &mut impl Write,
+    diag: &mut BuildDiagnostics,
+) -> std::io::Result<()> {
+    match path.extension() {
+        Some(ext) if ext == "rs" => format_rust_file(source, file, diag),
+        Some(ext) if ext == "md" => format_markdown_file(source, file, diag),
+        // Formatting .60 files because of backwards compatibility (project was recently renamed)
+        Some(ext) if ext == "slint" || ext == ".60" => format_slint_file(source, path, file, diag),
+        _ => {
+            // This allows usage like `cat x.slint | slint-lsp format /dev/stdin`
+            if path == Path::new("/dev/stdin") || path == Path::new("-") {
+                return format_slint_file(source, path, file, diag);
+            }
+            // With other file types, we just output them in their original form.
+            file.write_all(source.as_bytes())
+        }
+    }
+}
+
+/// Format the source of a file (see [`format_file`]) and return whether it was already formatted
+pub fn is_formatted(source: &str, path: &Path, diag: &mut BuildDiagnostics) -> bool {
+    let mut formatted = Vec::new();
+    format_file(source.into(), path, &mut formatted, diag).is_ok() && formatted == source.as_bytes()
+}
+
+/// FIXME! this is duplicated with the updater
+fn format_rust_file(
+    source: String,
+    file: &mut impl Write,
+    diag: &mut BuildDiagnostics,
+) -> std::io::Result<()> {
+    let mut last = 0;
+    for range in crate::lexer::locate_slint_macro(&source) {
+        file.write_all(source[last..=range.start].as_bytes())?;
+        last = range.end;
+        let code = &source[range];
+
+        let syntax_node = crate::parser::parse(code.to_owned(), None, diag);
+        let len = syntax_node.text_range().end().into();
+        format_syntax_node(syntax_node, file)?;
+        if diag.has_errors() {
+            file.write_all(&code.as_bytes()[len..])?;
+        }
+    }
+    file.write_all(source[last..].as_bytes())
+}
+
+/// FIXME! this is duplicated with the updater
+fn format_markdown_file(
+    source: String,
+    file: &mut impl Write,
+    diag: &mut BuildDiagnostics,
+) -> std::io::Result<()> {
+    let mut source_slice = &source[..];
+    const CODE_FENCE_START: &str = "```slint\n";
+    const CODE_FENCE_END: &str = "```\n";
+    'l: while let Some(code_start) =
+        source_slice.find(CODE_FENCE_START).map(|idx| idx + CODE_FENCE_START.len())
+    {
+        let code_end = if let Some(code_end) = source_slice[code_start..].find(CODE_FENCE_END) {
+            code_end
+        } else {
+            break 'l;
+        };
+        file.write_all(source_slice[..=code_start - 1].as_bytes())?;
+        source_slice = &source_slice[code_start..];
+        let code = &source_slice[..code_end];
+        source_slice = &source_slice[code_end..];
+
+        let syntax_node = crate::parser::parse(code.to_owned(), None, diag);
+        let len = syntax_node.text_range().end().into();
+        format_syntax_node(syntax_node, file)?;
+        if diag.has_errors() {
+            file.write_all(&code.as_bytes()[len..])?;
+        }
+    }
+    file.write_all(source_slice.as_bytes())
+}
+
+fn format_slint_file(
+    source: String,
+    path: &Path,
+    file: &mut impl Write,
+    diag: &mut BuildDiagnostics,
+) -> std::io::Result<()> {
+    let syntax_node = crate::parser::parse(source.clone(), Some(path), diag);
+    let len = syntax_node.node.text_range().end().into();
+    format_syntax_node(syntax_node, file)?;
+    if diag.has_errors() {
+        file.write_all(&source.as_bytes()[len..])?;
+    }
+    Ok(())
+}
+
+fn format_syntax_node(node: SyntaxNode, file: &mut impl Write) -> std::io::Result<()> {
+    if let Some(doc) = syntax_nodes::Document::new(node) {
+        let mut writer = writer::FileWriter { file };
+        format_document(doc, &mut writer)
+    } else {
+        Err(std::io::Error::new(std::io::ErrorKind::Other, "Not a Document"))
+    }
+}
 
 pub fn format_document(
     doc: syntax_nodes::Document,
@@ -120,6 +246,15 @@ fn format_node(
         SyntaxKind::ReturnStatement => {
             return format_return_statement(node, writer, state);
         }
+        SyntaxKind::LetStatement => {
+            return format_let_statement(node, writer, state);
+        }
+        SyntaxKind::ForStatement => {
+            return format_for_statement(node, writer, state);
+        }
+        SyntaxKind::WhileStatement => {
+            return format_while_statement(node, writer, state);
+        }
         SyntaxKind::AtGradient => {
             return format_at_gradient(node, writer, state);
         }
@@ -803,6 +938,63 @@ fn format_return_statement(
     Ok(())
 }
 
+fn format_let_statement(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    let mut ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, " ")?;
+    if ok && node.child_node(SyntaxKind::Type).is_some() {
+        ok = whitespace_to(&mut sub, SyntaxKind::Colon, writer, state, "")?
+            && whitespace_to(&mut sub, SyntaxKind::Type, writer, state, " ")?;
+    }
+    let _ok = ok
+        && whitespace_to(&mut sub, SyntaxKind::Equal, writer, state, " ")?
+        && whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, " ")?
+        && whitespace_to(&mut sub, SyntaxKind::Semicolon, writer, state, "")?;
+    state.new_line();
+    finish_node(sub, writer, state)?;
+    Ok(())
+}
+
+fn format_for_statement(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    let mut ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, " ")?;
+    if ok && node.child_node(SyntaxKind::RepeatedIndex).is_some() {
+        ok = whitespace_to(&mut sub, SyntaxKind::RepeatedIndex, writer, state, "")?;
+    }
+    let _ok = ok
+        && whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, " ")?
+        && whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, " ")?
+        && whitespace_to(&mut sub, SyntaxKind::CodeBlock, writer, state, " ")?;
+    finish_node(sub, writer, state)?;
+    state.whitespace_to_add = None;
+    state.new_line();
+    Ok(())
+}
+
+fn format_while_statement(
+    node: &SyntaxNode,
+    writer: &mut impl TokenWriter,
+    state: &mut FormatState,
+) -> Result<(), std::io::Error> {
+    let mut sub = node.children_with_tokens();
+    let _ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
+        && whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, " ")?
+        && whitespace_to(&mut sub, SyntaxKind::CodeBlock, writer, state, " ")?;
+    finish_node(sub, writer, state)?;
+    state.whitespace_to_add = None;
+    state.new_line();
+    Ok(())
+}
+
 fn format_at_gradient(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -1297,23 +1489,26 @@ fn format_member_access(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diagnostics::BuildDiagnostics;
     use crate::fmt::writer::FileWriter;
-    use i_slint_compiler::diagnostics::BuildDiagnostics;
 
-    // FIXME more descriptive errors when an assertion fails
-    #[track_caller]
-    fn assert_formatting(unformatted: &str, formatted: &str) {
+    fn format(source: &str) -> String {
         // Parse the unformatted string
-        let syntax_node = i_slint_compiler::parser::parse(
-            String::from(unformatted),
-            None,
-            &mut BuildDiagnostics::default(),
-        );
+        let syntax_node =
+            crate::parser::parse(String::from(source), None, &mut BuildDiagnostics::default());
         // Turn the syntax node into a document
         let doc = syntax_nodes::Document::new(syntax_node).unwrap();
         let mut file = Vec::new();
         format_document(doc, &mut FileWriter { file: &mut file }).unwrap();
-        assert_eq!(String::from_utf8(file).unwrap(), formatted);
+        String::from_utf8(file).unwrap()
+    }
+
+    // FIXME more descriptive errors when an assertion fails
+    #[track_caller]
+    fn assert_formatting(unformatted: &str, formatted: &str) {
+        assert_eq!(format(unformatted), formatted);
+        // Formatting must be idempotent
+        assert_eq!(format(formatted), formatted, "formatting the formatted output changed it");
     }
 
     #[test]
@@ -1321,6 +1516,26 @@ fn basic_formatting() {
         assert_formatting("A:=Text{}", "A := Text { }\n");
     }
 
+    #[test]
+    fn format_files() {
+        let mut diag = BuildDiagnostics::default();
+        let mut format_file_source = |source: &str, path: &str| {
+            let mut file = Vec::new();
+            format_file(source.into(), Path::new(path), &mut file, &mut diag).unwrap();
+            String::from_utf8(file).unwrap()
+        };
+        assert_eq!(format_file_source("A:=Text{}", "a.slint"), "A := Text { }\n");
+        assert_eq!(
+            format_file_source("Text\n```slint\nA:=Text{}\n```\nA:=Text{}\n", "a.md"),
+            "Text\n```slint\nA := Text { }\n```\nA:=Text{}\n"
+        );
+        assert_eq!(format_file_source("A:=Text{}", "a.txt"), "A:=Text{}");
+        assert!(!diag.has_errors());
+
+        assert!(is_formatted("A := Text { }\n", Path::new("a.slint"), &mut diag));
+        assert!(!is_formatted("A := Text {}\n", Path::new("a.slint"), &mut diag));
+    }
+
     #[test]
     fn components() {
         assert_formatting(
@@ -1873,6 +2088,37 @@ fn code_block() {
         );
     }
 
+    #[test]
+    fn local_variables_and_loops() {
+        assert_formatting(
+            r#"
+component ABC {
+    function foo(model: [int]) -> int {
+        let   a:int=42 ;  let b = a*2;
+        for x [ i ]   in model{ a+= x*i; }
+        while(b>0){b -= 1;}
+        b
+    }
+}
+"#,
+            r#"
+component ABC {
+    function foo(model: [int]) -> int {
+        let a: int = 42;
+        let b = a * 2;
+        for x[i] in model {
+            a += x * i;
+        }
+        while (b > 0) {
+            b -= 1;
+        }
+        b
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn trailing_comma_array() {
         assert_formatting(
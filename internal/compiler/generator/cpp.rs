@@ -464,7 +464,7 @@ pub fn escape_string(str: &str) -> String {
     }
 }
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve, MinMaxOp};
+use crate::expression_tree::{BuiltinFunction, EasingCurve, GradientSpread, MinMaxOp};
 use crate::langtype::{Enumeration, EnumerationValue, NativeClass, Type};
 use crate::layout::Orientation;
 use crate::llr::{
@@ -705,6 +705,10 @@ pub fn generate(
 
     let llr = llr::lower_to_item_tree::lower_to_item_tree(&doc, compiler_config);
 
+    if let Some(translations) = &llr.translations {
+        generate_translations(translations, &mut file.resources);
+    }
+
     // Forward-declare the root so that sub-components can access singletons, the window, etc.
     file.declarations.extend(
         llr.public_components
@@ -846,6 +850,99 @@ pub fn generate(
     Ok(file)
 }
 
+/// The number of forms per language in the `slint_translated_plurals` array
+fn translations_plural_forms_count(translations: &crate::translations::Translations) -> usize {
+    translations.plurals.iter().flatten().flatten().map(|forms| forms.len()).max().unwrap_or(0)
+}
+
+fn generate_translations(
+    translations: &crate::translations::Translations,
+    declarations: &mut Vec<Declaration>,
+) {
+    let to_cpp_string = |s: Option<&SmolStr>| match s {
+        Some(s) => format!("u8\"{}\"", escape_string(s.as_str())),
+        None => "nullptr".into(),
+    };
+
+    let strings = translations
+        .strings
+        .iter()
+        .flat_map(|strings| strings.iter().map(|s| to_cpp_string(s.as_ref())))
+        .collect::<Vec<_>>();
+    if !strings.is_empty() {
+        declarations.push(Declaration::Var(Var {
+            ty: "const char8_t *const".into(),
+            name: "slint_translated_strings".into(),
+            array_size: Some(strings.len()),
+            init: Some(format!("{{ {} }}", strings.join(", "))),
+            ..Default::default()
+        }));
+    }
+
+    let forms_count = translations_plural_forms_count(translations);
+    let plurals = translations
+        .plurals
+        .iter()
+        .flat_map(|plurals| plurals.iter())
+        .flat_map(|forms| {
+            let forms = forms.as_deref().unwrap_or_default();
+            (0..forms_count).map(|i| to_cpp_string(forms.get(i)))
+        })
+        .collect::<Vec<_>>();
+    if !plurals.is_empty() {
+        declarations.push(Declaration::Var(Var {
+            ty: "const char8_t *const".into(),
+            name: "slint_translated_plurals".into(),
+            array_size: Some(plurals.len()),
+            init: Some(format!("{{ {} }}", plurals.join(", "))),
+            ..Default::default()
+        }));
+
+        let rules = translations.plural_rules.iter().map(|rule| match rule {
+            Some(rule) => format!(
+                "[](int32_t n) -> uintptr_t {{ return {}; }}",
+                plural_rule_to_cpp(rule)
+            ),
+            None => "nullptr".into(),
+        });
+        declarations.push(Declaration::Var(Var {
+            ty: "const slint::private_api::PluralRule".into(),
+            name: "slint_translated_plural_rules".into(),
+            array_size: Some(translations.plural_rules.len()),
+            init: Some(format!("{{ {} }}", rules.collect::<Vec<_>>().join(", "))),
+            ..Default::default()
+        }));
+    }
+
+    let languages = translations.languages.iter().map(|l| to_cpp_string(Some(l)));
+    declarations.push(Declaration::Var(Var {
+        ty: "const char8_t *const".into(),
+        name: "slint_translation_bundle_languages".into(),
+        array_size: Some(translations.languages.len()),
+        init: Some(format!("{{ {} }}", languages.collect::<Vec<_>>().join(", "))),
+        ..Default::default()
+    }));
+}
+
+/// Generate the C++ code of a plural rule. The rule uses the C semantic, so it can be written as is.
+fn plural_rule_to_cpp(rule: &crate::translations::PluralRule) -> String {
+    use crate::translations::PluralRule;
+    match rule {
+        PluralRule::N => "n".into(),
+        PluralRule::Number(x) => x.to_string(),
+        PluralRule::UnaryOp { sub, op } => format!("({op}{})", plural_rule_to_cpp(sub)),
+        PluralRule::BinaryOp { lhs, rhs, op } => {
+            format!("({} {op} {})", plural_rule_to_cpp(lhs), plural_rule_to_cpp(rhs))
+        }
+        PluralRule::Condition { condition, true_expr, false_expr } => format!(
+            "({} ? {} : {})",
+            plural_rule_to_cpp(condition),
+            plural_rule_to_cpp(true_expr),
+            plural_rule_to_cpp(false_expr)
+        ),
+    }
+}
+
 fn embed_resource(
     resource: &crate::embedded_resources::EmbeddedResources,
     path: &SmolStr,
@@ -1702,6 +1799,9 @@ fn generate_item_tree(
         create_code.push("self->globals = &self->m_globals;".into());
         create_code.push("self->m_globals.root_weak = self->self_weak;".into());
         create_code.push("slint::cbindgen_private::slint_ensure_backend();".into());
+        if root.translations.is_some() {
+            create_code.push("slint::private_api::set_bundled_languages(slint_translation_bundle_languages);".into());
+        }
     }
 
     let global_access = if parent_ctx.is_some() { "parent->globals" } else { "self->globals" };
@@ -1866,6 +1966,15 @@ fn generate_sub_component(
             format_smolstr!("slint::private_api::Property<{}>", property.ty.cpp_type().unwrap())
         };
 
+        if let Some(name) = &property.debug_name {
+            if !matches!(property.ty, Type::Callback { .. }) {
+                init.push(format!(
+                    "self->{cpp_name}.set_profiler_name(\"{}\");",
+                    escape_string(name)
+                ));
+            }
+        }
+
         target_struct.members.push((
             field_access,
             Declaration::Var(Var { ty, name: cpp_name, ..Default::default() }),
@@ -1985,6 +2094,23 @@ fn generate_sub_component(
                 ..Default::default()
             }),
         ));
+        if root.has_debug_info {
+            let qualified_id = component.qualified_item_id(item);
+            let mut it = Some(&item.ty);
+            while let Some(ty) = it {
+                for (prop, info) in &ty.properties {
+                    if info.ty.is_property_type() && prop != "commands" {
+                        init.push(format!(
+                            "self->{}.{}.set_profiler_name(\"{}\");",
+                            ident(&item.name),
+                            ident(prop),
+                            escape_string(&format!("{qualified_id}.{prop}"))
+                        ));
+                    }
+                }
+                it = ty.parent.as_ref();
+            }
+        }
     }
 
     for (idx, repeated) in component.repeated.iter().enumerate() {
@@ -2124,6 +2250,17 @@ fn generate_sub_component(
         ));
     }
 
+    for (i, _) in component.popup_windows.iter().enumerate() {
+        target_struct.members.push((
+            field_access,
+            Declaration::Var(Var {
+                ty: "mutable std::optional<uint32_t>".into(),
+                name: format_smolstr!("popup_id_{}", i),
+                ..Default::default()
+            }),
+        ));
+    }
+
     target_struct
         .members
         .extend(generate_functions(&component.functions, &ctx).map(|x| (Access::Public, x)));
@@ -2515,6 +2652,17 @@ fn generate_global(
     }
 
     let mut init = vec!["(void)this->globals;".into()];
+    if root.has_debug_info {
+        for property in global.properties.iter().filter(|p| p.use_count.get() > 0) {
+            if !matches!(property.ty, Type::Callback { .. }) {
+                init.push(format!(
+                    "this->{}.set_profiler_name(\"{}\");",
+                    ident(&property.name),
+                    escape_string(&format!("{}::{}", global.name, property.name))
+                ));
+            }
+        }
+    }
     let ctx = EvaluationContext::new_global(
         root,
         global,
@@ -2942,6 +3090,14 @@ fn native_item<'a>(
     }
 }
 
+fn compile_gradient_spread(spread: GradientSpread) -> &'static str {
+    match spread {
+        GradientSpread::Pad => "slint::private_api::GradientSpread::Pad",
+        GradientSpread::Repeat => "slint::private_api::GradientSpread::Repeat",
+        GradientSpread::Reflect => "slint::private_api::GradientSpread::Reflect",
+    }
+}
+
 fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String {
     use llr::Expression;
     match expr {
@@ -3102,7 +3258,9 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::CodeBlock(sub) => {
             match sub.len() {
                 0 => String::new(),
-                1 => compile_expression(&sub[0], ctx),
+                1 if !matches!(sub[0], Expression::StoreLocalVariable { .. }) => {
+                    compile_expression(&sub[0], ctx)
+                }
                 len => {
                     let mut x = sub.iter().enumerate().map(|(i, e)| {
                         if i == len - 1 {
@@ -3116,6 +3274,36 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 }
             }
         }
+        Expression::ForLoop { model_data, index, model, body } => {
+            let store_index = index.as_ref().map_or(String::new(), |index| {
+                format!("[[maybe_unused]] int {} = loop_index;", ident(index))
+            });
+            let body = compile_expression(body, ctx);
+            let model_ty = model.ty(ctx);
+            let model = compile_expression(model, ctx);
+            if matches!(model_ty, Type::Array(_)) {
+                format!(
+                    "[&]{{ auto loop_model = {model}; const auto loop_count = slint::private_api::model_length(loop_model); \
+                    for (long int loop_index = 0; loop_index < loop_count; ++loop_index) {{ \
+                    [[maybe_unused]] auto {model_data} = slint::private_api::access_array_index(loop_model, loop_index); {store_index} {body}; }} }}()",
+                    model_data = ident(model_data),
+                )
+            } else {
+                format!(
+                    "[&]{{ const int loop_count = {model}; \
+                    for (int loop_index = 0; loop_index < loop_count; ++loop_index) {{ \
+                    [[maybe_unused]] int {model_data} = loop_index; {store_index} {body}; }} }}()",
+                    model_data = ident(model_data),
+                )
+            }
+        }
+        Expression::WhileLoop { condition, body } => {
+            format!(
+                "[&]{{ while ({}) {{ {}; }} }}()",
+                compile_expression(condition, ctx),
+                compile_expression(body, ctx)
+            )
+        }
         Expression::PropertyAssignment { property, value} => {
             let value = compile_expression(value, ctx);
             property_set_value_code(property, &value, ctx)
@@ -3273,7 +3461,7 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInBounce".into(),
         Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
-        Expression::LinearGradient{angle, stops} => {
+        Expression::LinearGradient{angle, stops, spread} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
@@ -3281,19 +3469,36 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
             });
             format!(
-                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::LinearGradientBrush({}, stops, {})); }}()",
-                stops_it.join(", "), angle, stops.len()
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::LinearGradientBrush({}, stops, {}, {})); }}()",
+                stops_it.join(", "), angle, stops.len(), compile_gradient_spread(*spread)
+            )
+        }
+        Expression::RadialGradient{ center_x, center_y, radius, stops, spread } => {
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let radius = compile_expression(radius, ctx);
+            let mut stops_it = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
+            });
+            format!(
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::RadialGradientBrush({}, {}, {}, stops, {}, {})); }}()",
+                stops_it.join(", "), center_x, center_y, radius, stops.len(), compile_gradient_spread(*spread)
             )
         }
-        Expression::RadialGradient{ stops} => {
+        Expression::ConicGradient{ from_angle, center_x, center_y, stops, spread } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 format!("slint::private_api::GradientStop{{ {}, {}, }}", color, position)
             });
             format!(
-                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::RadialGradientBrush(stops, {})); }}()",
-                stops_it.join(", "), stops.len()
+                "[&] {{ const slint::private_api::GradientStop stops[] = {{ {} }}; return slint::Brush(slint::private_api::ConicGradientBrush({}, {}, {}, stops, {}, {})); }}()",
+                stops_it.join(", "), from_angle, center_x, center_y, stops.len(), compile_gradient_spread(*spread)
             )
         }
         Expression::EnumerationValue(value) => {
@@ -3359,6 +3564,25 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
             )
         }
         Expression::EmptyComponentFactory => panic!("component-factory not yet supported in C++"),
+        Expression::TranslationReference { format_args, string_index, plural } => {
+            let args = compile_expression(format_args, ctx);
+            let languages_count = ctx.compilation_unit.translations.as_ref().unwrap().languages.len();
+            match plural {
+                Some(plural) => {
+                    let forms_count = translations_plural_forms_count(ctx.compilation_unit.translations.as_ref().unwrap());
+                    let plural = compile_expression(plural, ctx);
+                    format!(
+                        "slint::private_api::translate_from_bundle_with_plural(std::span(slint_translated_plurals + {}, {}), slint_translated_plural_rules, {args}, {plural})",
+                        string_index * languages_count * forms_count,
+                        languages_count * forms_count,
+                    )
+                }
+                None => format!(
+                    "slint::private_api::translate_from_bundle(std::span(slint_translated_strings + {}, {languages_count}), {args})",
+                    string_index * languages_count,
+                ),
+            }
+        }
     }
 }
 
@@ -3559,6 +3783,9 @@ fn compile_builtin_function_call(
         BuiltinFunction::TextInputFocused => {
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
+        BuiltinFunction::TextInputType => {
+            format!("{}.text_input_type()", access_window_field(ctx))
+        }
         BuiltinFunction::ShowPopupWindow => {
             if let [llr::Expression::NumberLiteral(popup_index), close_policy, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
@@ -3588,15 +3815,33 @@ fn compile_builtin_function_call(
                 let position = compile_expression(&popup.position.borrow(), &popup_ctx);
                 let close_policy = compile_expression(close_policy, ctx);
                 format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, [=](auto self) {{ return {position}; }}, {close_policy}, {{ {parent_component} }})"
+                    "{component_access}->popup_id_{popup_index} = {window}.show_popup<{popup_window_id}>({component_access}, [=](auto self) {{ return {position}; }}, {close_policy}, {{ {parent_component} }})",
+                    popup_index = *popup_index as usize
                 )
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
         BuiltinFunction::ClosePopupWindow => {
-            let window = access_window_field(ctx);
-            format!("{window}.close_popup()")
+            if let [llr::Expression::NumberLiteral(popup_index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut component_access = "self".into();
+
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access = format!("{}->parent", component_access);
+                    }
+                };
+
+                let window = access_window_field(ctx);
+                format!(
+                    "{window}.close_popup({component_access}->popup_id_{popup_index})",
+                    popup_index = *popup_index as usize
+                )
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
         }
         BuiltinFunction::SetSelectionOffsets => {
             if let [llr::Expression::PropertyReference(pr), from, to] = arguments {
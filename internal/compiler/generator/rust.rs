@@ -12,7 +12,9 @@
     this is usually a local variable to the init code that shouldn't rbe relied upon by the binding code.
 */
 
-use crate::expression_tree::{BuiltinFunction, EasingCurve, MinMaxOp, OperatorClass};
+use crate::expression_tree::{
+    BuiltinFunction, EasingCurve, GradientSpread, MinMaxOp, OperatorClass,
+};
 use crate::langtype::{Enumeration, EnumerationValue, Type};
 use crate::layout::Orientation;
 use crate::llr::{
@@ -195,6 +197,7 @@ pub fn generate(doc: &Document, compiler_config: &CompilerConfiguration) -> Toke
     let compo_ids = llr.public_components.iter().map(|c| ident(&c.name));
 
     let resource_symbols = generate_resources(doc);
+    let translations = llr.translations.as_ref().map(generate_translations);
     let named_exports = generate_named_exports(doc);
     // The inner module was meant to be internal private, but projects have been reaching into it
     // so we can't change the name of this module
@@ -217,6 +220,7 @@ mod #generated_mod {
             #(#public_components)*
             #shared_globals
             #(#resource_symbols)*
+            #translations
             const _THE_SAME_VERSION_MUST_BE_USED_FOR_THE_COMPILER_AND_THE_RUNTIME : slint::#version_check = slint::#version_check;
         }
         #[allow(unused_imports)]
@@ -246,6 +250,11 @@ fn generate_public_component(
         argument_types: &[],
     };
 
+    let set_bundled_languages = unit
+        .translations
+        .as_ref()
+        .map(|_| quote!(sp::set_bundled_languages(&_SLINT_BUNDLED_LANGUAGES);));
+
     let property_and_callback_accessors = public_api(
         &llr.public_properties,
         &llr.private_properties,
@@ -259,6 +268,7 @@ fn generate_public_component(
 
         impl #public_component_id {
             pub fn new() -> core::result::Result<Self, slint::PlatformError> {
+                #set_bundled_languages
                 let inner = #inner_component_id::new()?;
                 inner.globals.get().unwrap().init();
                 #inner_component_id::user_init(sp::VRc::map(inner.clone(), |x| x));
@@ -703,9 +713,33 @@ fn generate_sub_component(
             concat!(stringify!(#inner_component_id), ".", stringify!(#declared_property_vars)).into());)*
     ));
 
+    for property in component.properties.iter().filter(|p| p.use_count.get() > 0) {
+        if let Some(name) = property.debug_name.as_ref().map(|n| n.as_str()) {
+            if !matches!(property.ty, Type::Callback { .. }) {
+                let prop = ident(&property.name);
+                init.push(quote!(self_rc.#prop.set_profiler_name(#name);));
+            }
+        }
+    }
+
     for item in &component.items {
         item_names.push(ident(&item.name));
         item_types.push(ident(&item.ty.class_name));
+        if root.has_debug_info {
+            let qualified_id = component.qualified_item_id(item);
+            let mut it = Some(&item.ty);
+            let elem_name = ident(&item.name);
+            while let Some(ty) = it {
+                for (prop, info) in &ty.properties {
+                    if info.ty.is_property_type() && prop != "commands" {
+                        let name = format!("{qualified_id}.{prop}");
+                        let prop = ident(prop);
+                        init.push(quote!(self_rc.#elem_name.#prop.set_profiler_name(#name);));
+                    }
+                }
+                it = ty.parent.as_ref();
+            }
+        }
         #[cfg(slint_debug_property)]
         {
             let mut it = Some(&item.ty);
@@ -1060,6 +1094,8 @@ fn generate_sub_component(
 
     let timer_names =
         component.timers.iter().enumerate().map(|(idx, _)| format_ident!("timer{idx}"));
+    let popup_id_names =
+        component.popup_windows.iter().enumerate().map(|(idx, _)| format_ident!("popup_id_{idx}"));
     let update_timers = (!component.timers.is_empty()).then(|| {
         let updt = component.timers.iter().enumerate().map(|(idx, tmr)| {
             let ident = format_ident!("timer{idx}");
@@ -1108,6 +1144,7 @@ struct #inner_component_id {
             #(#repeated_element_names : sp::Repeater<#repeated_element_components>,)*
             #(#change_tracker_names : sp::ChangeTracker,)*
             #(#timer_names : sp::Timer,)*
+            #(#popup_id_names : ::core::cell::Cell<::core::option::Option<::core::num::NonZeroU32>>,)*
             self_weak : sp::OnceCell<sp::VWeakMapped<sp::ItemTreeVTable, #inner_component_id>>,
             #(parent : #parent_component_type,)*
             globals: sp::OnceCell<sp::Rc<SharedGlobals>>,
@@ -1320,6 +1357,16 @@ fn generate_global(global: &llr::GlobalComponent, root: &llr::CompilationUnit) -
             concat!(stringify!(#inner_component_id), ".", stringify!(#declared_property_vars)).into());)*
     ));
 
+    if root.has_debug_info {
+        for property in global.properties.iter().filter(|p| p.use_count.get() > 0) {
+            if !matches!(property.ty, Type::Callback { .. }) {
+                let name = format!("{}::{}", global.name, property.name);
+                let prop = ident(&property.name);
+                init.push(quote!(self_rc.#prop.set_profiler_name(#name);));
+            }
+        }
+    }
+
     let ctx = EvaluationContext::new_global(
         root,
         global,
@@ -2108,6 +2155,15 @@ fn access_item_rc(pr: &llr::PropertyReference, ctx: &EvaluationContext) -> Token
     }
 }
 
+/// Returns the builder call that sets the spread of a gradient, if it isn't the default one
+fn compile_gradient_spread(spread: GradientSpread) -> TokenStream {
+    match spread {
+        GradientSpread::Pad => quote!(),
+        GradientSpread::Repeat => quote!(.with_spread(sp::GradientSpread::Repeat)),
+        GradientSpread::Reflect => quote!(.with_spread(sp::GradientSpread::Reflect)),
+    }
+}
+
 fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream {
     match expr {
         Expression::StringLiteral(s) => {
@@ -2266,6 +2322,40 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             let map = sub.iter().map(|e| compile_expression(e, ctx));
             quote!({ #(#map);* })
         }
+        Expression::ForLoop { model_data, index, model, body } => {
+            let model_data = ident(model_data);
+            let store_index = index.as_ref().map(|index| {
+                let index = ident(index);
+                quote!(let #index = loop_index as i32;)
+            });
+            let body = compile_expression(body, ctx);
+            let model_ty = model.ty(ctx);
+            let model = compile_expression(model, ctx);
+            if matches!(model_ty, Type::Array(_)) {
+                quote!({
+                    let loop_model = #model;
+                    loop_model.model_tracker().track_row_count_changes();
+                    for loop_index in 0..loop_model.row_count() {
+                        let #model_data = loop_model.row_data_tracked(loop_index).unwrap_or_default();
+                        #store_index
+                        #body;
+                    }
+                })
+            } else {
+                quote!({
+                    for loop_index in 0..(#model) {
+                        let #model_data = loop_index;
+                        #store_index
+                        #body;
+                    }
+                })
+            }
+        }
+        Expression::WhileLoop { condition, body } => {
+            let condition = compile_expression(condition, ctx);
+            let body = compile_expression(body, ctx);
+            quote!(while #condition { #body; })
+        }
         Expression::PropertyAssignment { property, value } => {
             let value = compile_expression(value, ctx);
             property_set_value_tokens(property, value, ctx)
@@ -2482,25 +2572,47 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
             quote!(sp::EasingCurve::EaseInOutBounce)
         }
-        Expression::LinearGradient { angle, stops } => {
+        Expression::LinearGradient { angle, stops, spread } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 quote!(sp::GradientStop{ color: #color, position: #position as _ })
             });
+            let spread = compile_gradient_spread(*spread);
             quote!(slint::Brush::LinearGradient(
-                sp::LinearGradientBrush::new(#angle as _, [#(#stops),*])
+                sp::LinearGradientBrush::new(#angle as _, [#(#stops),*])#spread
             ))
         }
-        Expression::RadialGradient { stops } => {
+        Expression::RadialGradient { center_x, center_y, radius, stops, spread } => {
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let radius = compile_expression(radius, ctx);
             let stops = stops.iter().map(|(color, stop)| {
                 let color = compile_expression(color, ctx);
                 let position = compile_expression(stop, ctx);
                 quote!(sp::GradientStop{ color: #color, position: #position as _ })
             });
+            let spread = compile_gradient_spread(*spread);
             quote!(slint::Brush::RadialGradient(
                 sp::RadialGradientBrush::new_circle([#(#stops),*])
+                    .with_center(#center_x as _, #center_y as _)
+                    .with_radius(#radius as _)#spread
+            ))
+        }
+        Expression::ConicGradient { from_angle, center_x, center_y, stops, spread } => {
+            let from_angle = compile_expression(from_angle, ctx);
+            let center_x = compile_expression(center_x, ctx);
+            let center_y = compile_expression(center_y, ctx);
+            let stops = stops.iter().map(|(color, stop)| {
+                let color = compile_expression(color, ctx);
+                let position = compile_expression(stop, ctx);
+                quote!(sp::GradientStop{ color: #color, position: #position as _ })
+            });
+            let spread = compile_gradient_spread(*spread);
+            quote!(slint::Brush::ConicGradient(
+                sp::ConicGradientBrush::new(#from_angle as _, [#(#stops),*])
+                    .with_center(#center_x as _, #center_y as _)#spread
             ))
         }
         Expression::EnumerationValue(value) => {
@@ -2572,6 +2684,26 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
             }
         }
         Expression::EmptyComponentFactory => quote!(slint::ComponentFactory::default()),
+        Expression::TranslationReference { format_args, string_index, plural } => {
+            let args = compile_expression(format_args, ctx);
+            // The type of an empty array cannot be inferred from the generic parameter
+            let args = quote!(((#args) as sp::Slice<'_, sp::SharedString>));
+            match plural {
+                Some(plural) => {
+                    let plural = compile_expression(plural, ctx);
+                    quote!(sp::translate_from_bundle_with_plural(
+                        &self::_SLINT_TRANSLATED_STRINGS_PLURALS[#string_index],
+                        &self::_SLINT_TRANSLATED_PLURAL_RULES,
+                        #args.as_slice(),
+                        #plural as _
+                    ))
+                }
+                None => quote!(sp::translate_from_bundle(
+                    &self::_SLINT_TRANSLATED_STRINGS[#string_index],
+                    #args.as_slice()
+                )),
+            }
+        }
     }
 }
 
@@ -2587,7 +2719,7 @@ fn compile_builtin_function_call(
                 let window_tokens = access_window_adapter_field(ctx);
                 let focus_item = access_item_rc(pr, ctx);
                 quote!(
-                    sp::WindowInner::from_pub(#window_tokens.window()).set_focus_item(#focus_item, true)
+                    sp::WindowInner::from_pub(#window_tokens.window()).set_focus_item(#focus_item, true, sp::FocusReason::Programmatic)
                 )
             } else {
                 panic!("internal error: invalid args to SetFocusItem {:?}", arguments)
@@ -2598,7 +2730,7 @@ fn compile_builtin_function_call(
                 let window_tokens = access_window_adapter_field(ctx);
                 let focus_item = access_item_rc(pr, ctx);
                 quote!(
-                    sp::WindowInner::from_pub(#window_tokens.window()).set_focus_item(#focus_item, false)
+                    sp::WindowInner::from_pub(#window_tokens.window()).set_focus_item(#focus_item, false, sp::FocusReason::Programmatic)
                 )
             } else {
                 panic!("internal error: invalid args to ClearFocusItem {:?}", arguments)
@@ -2632,27 +2764,46 @@ fn compile_builtin_function_call(
 
                 let close_policy = compile_expression(close_policy, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
+                let popup_id_name = format_ident!("popup_id_{}", *popup_index as usize);
                 quote!({
                     let popup_instance = #popup_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone()).unwrap();
                     let popup_instance_vrc = sp::VRc::map(popup_instance.clone(), |x| x);
                     #popup_window_id::user_init(popup_instance_vrc.clone());
                     let position = { let _self = popup_instance_vrc.as_pin_ref(); #position };
-                    sp::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
-                        &sp::VRc::into_dyn(popup_instance.into()),
-                        position,
-                        #close_policy,
-                        #parent_component
-                    )
+                    #component_access_tokens.#popup_id_name.set(Some(
+                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
+                            &sp::VRc::into_dyn(popup_instance.into()),
+                            position,
+                            #close_policy,
+                            #parent_component
+                        )
+                    ));
                 })
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
             }
         }
         BuiltinFunction::ClosePopupWindow => {
-            let window_adapter_tokens = access_window_adapter_field(ctx);
-            quote!(
-                sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup()
-            )
+            if let [Expression::NumberLiteral(popup_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut component_access_tokens = quote!(_self);
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access_tokens =
+                            quote!(#component_access_tokens.parent.upgrade().unwrap().as_pin_ref());
+                    }
+                }
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                let popup_id_name = format_ident!("popup_id_{}", *popup_index as usize);
+                quote!(
+                    if let Some(current_id) = #component_access_tokens.#popup_id_name.take() {
+                        sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup(current_id);
+                    }
+                )
+            } else {
+                panic!("internal error: invalid args to ClosePopupWindow {:?}", arguments)
+            }
         }
         BuiltinFunction::SetSelectionOffsets => {
             if let [llr::Expression::PropertyReference(pr), from, to] = arguments {
@@ -2876,6 +3027,10 @@ fn compile_builtin_function_call(
             let window_adapter_tokens = access_window_adapter_field(ctx);
             quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).set_text_input_focused(#(#a)*))
         }
+        BuiltinFunction::TextInputType => {
+            let window_adapter_tokens = access_window_adapter_field(ctx);
+            quote!(sp::WindowInner::from_pub(#window_adapter_tokens.window()).text_input_type())
+        }
         BuiltinFunction::Translate => {
             quote!(slint::private_unstable_api::translate(#((#a) as _),*))
         }
@@ -2898,6 +3053,81 @@ fn compile_builtin_function_call(
     }
 }
 
+fn generate_translations(translations: &crate::translations::Translations) -> TokenStream {
+    let strings = translations.strings.iter().map(|strings| {
+        let array = strings.iter().map(|s| match s.as_ref().map(SmolStr::as_str) {
+            Some(s) => quote!(Some(#s)),
+            None => quote!(None),
+        });
+        quote!(&[#(#array),*])
+    });
+    let plurals = translations.plurals.iter().map(|plurals| {
+        let array = plurals.iter().map(|p| match p {
+            Some(p) => {
+                let p = p.iter().map(SmolStr::as_str);
+                quote!(Some(&[#(#p),*]))
+            }
+            None => quote!(None),
+        });
+        quote!(&[#(#array),*])
+    });
+    let rules = translations.plural_rules.iter().map(|rule| match rule {
+        Some(rule) => {
+            let rule = plural_rule_to_tokens(rule);
+            quote!(Some((|n: i32| (#rule) as usize) as fn(i32) -> usize))
+        }
+        None => quote!(None),
+    });
+    let languages = translations.languages.iter().map(SmolStr::as_str);
+    let count = translations.languages.len();
+    quote!(
+        #[allow(dead_code)]
+        const _SLINT_TRANSLATED_STRINGS: &[&[sp::Option<&str>]] = &[#(#strings),*];
+        #[allow(dead_code)]
+        const _SLINT_TRANSLATED_STRINGS_PLURALS: &[&[sp::Option<&[&str]>]] = &[#(#plurals),*];
+        #[allow(dead_code)]
+        const _SLINT_TRANSLATED_PLURAL_RULES: [sp::Option<fn(i32) -> usize>; #count] = [#(#rules),*];
+        const _SLINT_BUNDLED_LANGUAGES: [&str; #count] = [#(#languages),*];
+    )
+}
+
+/// Generate the code of a plural rule, with C semantic: the expression is an `i32` in the variable `n`
+fn plural_rule_to_tokens(rule: &crate::translations::PluralRule) -> TokenStream {
+    use crate::translations::PluralRule;
+    match rule {
+        PluralRule::N => quote!(n),
+        PluralRule::Number(x) => {
+            let x = proc_macro2::Literal::i32_unsuffixed(*x);
+            quote!(#x)
+        }
+        PluralRule::UnaryOp { sub, op } => {
+            let sub = plural_rule_to_tokens(sub);
+            if *op == '!' {
+                quote!((((#sub) == 0) as i32))
+            } else {
+                quote!((-(#sub)))
+            }
+        }
+        PluralRule::BinaryOp { lhs, rhs, op } => {
+            let (lhs, rhs) = (plural_rule_to_tokens(lhs), plural_rule_to_tokens(rhs));
+            let op_tokens = TokenStream::from_str(op).unwrap();
+            match *op {
+                "&&" | "||" => quote!(((((#lhs) != 0) #op_tokens ((#rhs) != 0)) as i32)),
+                "==" | "!=" | "<" | ">" | "<=" | ">=" => {
+                    quote!((((#lhs) #op_tokens (#rhs)) as i32))
+                }
+                _ => quote!(((#lhs) #op_tokens (#rhs))),
+            }
+        }
+        PluralRule::Condition { condition, true_expr, false_expr } => {
+            let condition = plural_rule_to_tokens(condition);
+            let true_expr = plural_rule_to_tokens(true_expr);
+            let false_expr = plural_rule_to_tokens(false_expr);
+            quote!((if (#condition) != 0 { #true_expr } else { #false_expr }))
+        }
+    }
+}
+
 /// Return a TokenStream for a name (as in [`Type::Struct::name`])
 fn struct_name_to_tokens(name: &str) -> TokenStream {
     // the name match the C++ signature so we need to change that to the rust namespace
@@ -23,6 +23,7 @@ pub enum Orientation {
 pub enum Layout {
     GridLayout(GridLayout),
     BoxLayout(BoxLayout),
+    FlexboxLayout(FlexboxLayout),
 }
 
 impl Layout {
@@ -30,18 +31,21 @@ pub fn rect(&self) -> &LayoutRect {
         match self {
             Layout::GridLayout(g) => &g.geometry.rect,
             Layout::BoxLayout(g) => &g.geometry.rect,
+            Layout::FlexboxLayout(g) => &g.geometry.rect,
         }
     }
     pub fn rect_mut(&mut self) -> &mut LayoutRect {
         match self {
             Layout::GridLayout(g) => &mut g.geometry.rect,
             Layout::BoxLayout(g) => &mut g.geometry.rect,
+            Layout::FlexboxLayout(g) => &mut g.geometry.rect,
         }
     }
     pub fn geometry(&self) -> &LayoutGeometry {
         match self {
             Layout::GridLayout(l) => &l.geometry,
             Layout::BoxLayout(l) => &l.geometry,
+            Layout::FlexboxLayout(l) => &l.geometry,
         }
     }
 }
@@ -52,6 +56,7 @@ pub fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedRefe
         match self {
             Layout::GridLayout(grid) => grid.visit_named_references(visitor),
             Layout::BoxLayout(l) => l.visit_named_references(visitor),
+            Layout::FlexboxLayout(l) => l.visit_named_references(visitor),
         }
     }
 }
@@ -420,7 +425,7 @@ fn find_binding<R>(
 }
 
 /// Return a named reference to a property if a binding is set on that property
-fn binding_reference(element: &ElementRc, name: &str) -> Option<NamedReference> {
+pub(crate) fn binding_reference(element: &ElementRc, name: &str) -> Option<NamedReference> {
     find_binding(element, name, |_, _, _| NamedReference::new(element, name))
 }
 
@@ -485,6 +490,36 @@ fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReferenc
     }
 }
 
+/// Internal representation of a FlexboxLayout
+#[derive(Debug, Clone)]
+pub struct FlexboxLayout {
+    /// The main axis of the layout: Horizontal if the direction is `row`, Vertical if it is `column`
+    pub orientation: Orientation,
+    pub elems: Vec<LayoutItem>,
+    pub geometry: LayoutGeometry,
+    pub wrap: Option<NamedReference>,
+    pub justify_content: Option<NamedReference>,
+    pub align_items: Option<NamedReference>,
+}
+
+impl FlexboxLayout {
+    fn visit_named_references(&mut self, visitor: &mut impl FnMut(&mut NamedReference)) {
+        for cell in &mut self.elems {
+            cell.constraints.visit_named_references(visitor);
+        }
+        self.geometry.visit_named_references(visitor);
+        if let Some(e) = self.wrap.as_mut() {
+            visitor(&mut *e);
+        }
+        if let Some(e) = self.justify_content.as_mut() {
+            visitor(&mut *e);
+        }
+        if let Some(e) = self.align_items.as_mut() {
+            visitor(&mut *e);
+        }
+    }
+}
+
 /// The [`Type`] for a runtime LayoutInfo structure
 pub fn layout_info_type() -> Type {
     Type::Struct {
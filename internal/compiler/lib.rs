@@ -22,6 +22,7 @@
 pub mod embedded_resources;
 pub mod expression_tree;
 pub mod fileaccess;
+pub mod fmt;
 pub mod generator;
 pub mod langtype;
 pub mod layout;
@@ -38,6 +39,7 @@
 pub mod typeregister;
 
 pub mod passes;
+pub mod translations;
 
 use crate::generator::OutputFormat;
 use std::path::Path;
@@ -132,6 +134,9 @@ pub struct CompilerConfiguration {
 
     /// The domain used as one of the parameter to the translate function
     pub translation_domain: Option<String>,
+    /// When set, the translations are read from the `<lang>/LC_MESSAGES/<domain>.po` files
+    /// in this directory and bundled in the generated code, instead of using gettext at runtime.
+    pub translation_path_bundle: Option<std::path::PathBuf>,
 
     /// C++ namespace
     pub cpp_namespace: Option<String>,
@@ -215,6 +220,7 @@ pub fn new(output_format: OutputFormat) -> Self {
             accessibility: true,
             enable_experimental,
             translation_domain: None,
+            translation_path_bundle: None,
             cpp_namespace,
             debug_info,
             components_to_generate: ComponentSelection::ExportedWindows,
@@ -313,6 +319,30 @@ pub async fn compile_syntax_node(
 
     diagnostics.all_loaded_files = loader.all_files().cloned().collect();
 
+    if let Some(path) = loader.compiler_config.translation_path_bundle.as_ref() {
+        if !diagnostics.has_errors() {
+            let location = diagnostics::SourceLocation {
+                source_file: Some(diagnostics::SourceFileInner::from_path_only(path.clone())),
+                span: Default::default(),
+            };
+            #[cfg(feature = "bundle-translations")]
+            match translations::TranslationsBuilder::load_translations(
+                path,
+                loader.compiler_config.translation_domain.as_deref(),
+                &mut diagnostics.all_loaded_files,
+            ) {
+                Ok(builder) => doc.translation_builder = Some(builder),
+                Err(err) => diagnostics.push_error_with_span(err, location),
+            }
+            #[cfg(not(feature = "bundle-translations"))]
+            diagnostics.push_error_with_span(
+                "Bundling translations requires the `bundle-translations` feature of the compiler"
+                    .into(),
+                location,
+            );
+        }
+    }
+
     (doc, diagnostics, loader)
 }
 
@@ -64,6 +64,21 @@ pub enum Expression {
     /// a code block with different expression
     CodeBlock(Vec<Expression>),
 
+    /// Evaluate the body for each row of the model, with the model data stored in the local
+    /// variable `model_data`, and the row index in the local variable `index` if any
+    ForLoop {
+        model_data: SmolStr,
+        index: Option<SmolStr>,
+        model: Box<Expression>,
+        body: Box<Expression>,
+    },
+
+    /// Evaluate the body as long as the condition is true
+    WhileLoop {
+        condition: Box<Expression>,
+        body: Box<Expression>,
+    },
+
     /// A function call
     BuiltinFunctionCall {
         function: BuiltinFunction,
@@ -145,11 +160,25 @@ pub enum Expression {
         angle: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: crate::expression_tree::GradientSpread,
     },
 
     RadialGradient {
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        radius: Box<Expression>,
         /// First expression in the tuple is a color, second expression is the stop position
         stops: Vec<(Expression, Expression)>,
+        spread: crate::expression_tree::GradientSpread,
+    },
+
+    ConicGradient {
+        from_angle: Box<Expression>,
+        center_x: Box<Expression>,
+        center_y: Box<Expression>,
+        /// First expression in the tuple is a color, second expression is the stop position
+        stops: Vec<(Expression, Expression)>,
+        spread: crate::expression_tree::GradientSpread,
     },
 
     EnumerationValue(crate::langtype::EnumerationValue),
@@ -190,6 +219,17 @@ pub enum Expression {
     },
 
     EmptyComponentFactory,
+
+    /// A reference to bundled translated string
+    TranslationReference {
+        /// An expression of type array of strings
+        format_args: Box<Expression>,
+        /// The index in [`crate::translations::Translations::strings`], or
+        /// [`crate::translations::Translations::plurals`] if there is a plural
+        string_index: usize,
+        /// The `n` value to use for the plural form if it is a plural form
+        plural: Option<Box<Expression>>,
+    },
 }
 
 impl Expression {
@@ -266,6 +306,8 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             },
             Self::Cast { to, .. } => to.clone(),
             Self::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty(ctx)),
+            Self::ForLoop { .. } => Type::Void,
+            Self::WhileLoop { .. } => Type::Void,
             Self::BuiltinFunctionCall { function, .. } => match function.ty() {
                 Type::Function { return_type, .. } => *return_type,
                 _ => unreachable!(),
@@ -297,6 +339,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             Self::EasingCurve(_) => Type::Easing,
             Self::LinearGradient { .. } => Type::Brush,
             Self::RadialGradient { .. } => Type::Brush,
+            Self::ConicGradient { .. } => Type::Brush,
             Self::EnumerationValue(e) => Type::Enumeration(e.enumeration.clone()),
             Self::LayoutCacheAccess { .. } => Type::LogicalLength,
             Self::BoxLayoutFunction { sub_expression, .. } => sub_expression.ty(ctx),
@@ -305,6 +348,7 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             }
             Self::MinMax { ty, .. } => ty.clone(),
             Self::EmptyComponentFactory => Type::ComponentFactory,
+            Self::TranslationReference { .. } => Type::String,
         }
     }
 }
@@ -326,6 +370,14 @@ macro_rules! visit_impl {
             }
             Expression::Cast { from, .. } => $visitor(from),
             Expression::CodeBlock(b) => b.$iter().for_each($visitor),
+            Expression::ForLoop { model, body, .. } => {
+                $visitor(model);
+                $visitor(body);
+            }
+            Expression::WhileLoop { condition, body } => {
+                $visitor(condition);
+                $visitor(body);
+            }
             Expression::BuiltinFunctionCall { arguments, .. }
             | Expression::CallBackCall { arguments, .. }
             | Expression::FunctionCall { arguments, .. } => arguments.$iter().for_each($visitor),
@@ -355,14 +407,26 @@ macro_rules! visit_impl {
             Expression::Array { values, .. } => values.$iter().for_each($visitor),
             Expression::Struct { values, .. } => values.$values().for_each($visitor),
             Expression::EasingCurve(_) => {}
-            Expression::LinearGradient { angle, stops } => {
+            Expression::LinearGradient { angle, stops, .. } => {
                 $visitor(angle);
                 for (a, b) in stops {
                     $visitor(a);
                     $visitor(b);
                 }
             }
-            Expression::RadialGradient { stops } => {
+            Expression::RadialGradient { center_x, center_y, radius, stops, .. } => {
+                $visitor(center_x);
+                $visitor(center_y);
+                $visitor(radius);
+                for (a, b) in stops {
+                    $visitor(a);
+                    $visitor(b);
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops, .. } => {
+                $visitor(from_angle);
+                $visitor(center_x);
+                $visitor(center_y);
                 for (a, b) in stops {
                     $visitor(a);
                     $visitor(b);
@@ -387,6 +451,12 @@ macro_rules! visit_impl {
                 $visitor(rhs);
             }
             Expression::EmptyComponentFactory => {}
+            Expression::TranslationReference { format_args, plural, string_index: _ } => {
+                $visitor(format_args);
+                if let Some(plural) = plural {
+                    $visitor(plural);
+                }
+            }
         }
     };
 }
@@ -104,6 +104,9 @@ pub enum PropertyReference {
 pub struct Property {
     pub name: SmolStr,
     pub ty: Type,
+    /// The name of the property qualified with its component and element id, as in
+    /// `Component::id.property`. Only set when the debug info are enabled.
+    pub debug_name: Option<SmolStr>,
     /// The amount of time this property is used of another property
     /// This property is only valid after the [`count_property_use`](super::optim_passes::count_property_use) pass
     pub use_count: Cell<usize>,
@@ -308,6 +311,17 @@ pub fn child_item_count(&self) -> u32 {
         count
     }
 
+    /// The id of the item qualified with the name of the component where it was declared
+    /// (`Component::id`), as found in the debug info. Falls back to the name of the item.
+    pub fn qualified_item_id(&self, item: &Item) -> String {
+        self.element_infos
+            .get(&item.index_in_tree)
+            .and_then(|infos| infos.split([';', '/']).next()?.split_once(','))
+            .map(|(_, id)| id)
+            .filter(|id| !id.is_empty())
+            .map_or_else(|| format!("{}::{}", self.name, item.name), |id| id.to_string())
+    }
+
     /// Return if a local property is used. (unused property shouldn't be generated)
     pub fn prop_used(&self, prop: &PropertyReference) -> bool {
         if let PropertyReference::Local { property_index, sub_component_path } = prop {
@@ -368,6 +382,8 @@ pub struct CompilationUnit {
     pub sub_components: Vec<Rc<SubComponent>>,
     pub globals: Vec<GlobalComponent>,
     pub has_debug_info: bool,
+    /// The translations bundled with the compilation unit, when translations are bundled
+    pub translations: Option<crate::translations::Translations>,
 }
 
 impl CompilationUnit {
@@ -117,16 +117,22 @@ pub fn lower_expression(
         tree_Expression::CodeBlock(expr) => {
             llr_Expression::CodeBlock(expr.iter().map(|e| lower_expression(e, ctx)).collect::<_>())
         }
+        tree_Expression::ForLoop { model_data, index, model, body } => llr_Expression::ForLoop {
+            model_data: model_data.clone(),
+            index: index.clone(),
+            model: Box::new(lower_expression(model, ctx)),
+            body: Box::new(lower_expression(body, ctx)),
+        },
+        tree_Expression::WhileLoop { condition, body } => llr_Expression::WhileLoop {
+            condition: Box::new(lower_expression(condition, ctx)),
+            body: Box::new(lower_expression(body, ctx)),
+        },
         tree_Expression::FunctionCall { function, arguments, .. } => match &**function {
             tree_Expression::BuiltinFunctionReference(BuiltinFunction::ShowPopupWindow, _) => {
                 lower_show_popup(arguments, ctx)
             }
             tree_Expression::BuiltinFunctionReference(BuiltinFunction::ClosePopupWindow, _) => {
-                // FIXME: right now, `popup.close()` will close any visible popup, as the popup argument is ignored
-                llr_Expression::BuiltinFunctionCall {
-                    function: BuiltinFunction::ClosePopupWindow,
-                    arguments: vec![],
-                }
+                lower_close_popup(arguments, ctx)
             }
             tree_Expression::BuiltinFunctionReference(f, _) => {
                 let mut arguments =
@@ -135,6 +141,12 @@ pub fn lower_expression(
                     if let llr_Expression::Array { as_model, .. } = &mut arguments[3] {
                         *as_model = false;
                     }
+                    if let Some(translation_builder) = ctx.state.translation_builder.as_ref() {
+                        match translation_builder.borrow_mut().lower_translate_call(arguments) {
+                            Ok(e) => return e,
+                            Err(args) => arguments = args,
+                        }
+                    }
                 }
                 llr_Expression::BuiltinFunctionCall { function: f.clone(), arguments }
             }
@@ -186,19 +198,40 @@ pub fn lower_expression(
         },
         tree_Expression::PathData(data) => compile_path(data, ctx),
         tree_Expression::EasingCurve(x) => llr_Expression::EasingCurve(x.clone()),
-        tree_Expression::LinearGradient { angle, stops } => llr_Expression::LinearGradient {
-            angle: Box::new(lower_expression(angle, ctx)),
-            stops: stops
-                .iter()
-                .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
-                .collect::<_>(),
-        },
-        tree_Expression::RadialGradient { stops } => llr_Expression::RadialGradient {
-            stops: stops
-                .iter()
-                .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
-                .collect::<_>(),
-        },
+        tree_Expression::LinearGradient { angle, stops, spread } => {
+            llr_Expression::LinearGradient {
+                angle: Box::new(lower_expression(angle, ctx)),
+                stops: stops
+                    .iter()
+                    .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                    .collect::<_>(),
+                spread: *spread,
+            }
+        }
+        tree_Expression::RadialGradient { center_x, center_y, radius, stops, spread } => {
+            llr_Expression::RadialGradient {
+                center_x: Box::new(lower_expression(center_x, ctx)),
+                center_y: Box::new(lower_expression(center_y, ctx)),
+                radius: Box::new(lower_expression(radius, ctx)),
+                stops: stops
+                    .iter()
+                    .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                    .collect::<_>(),
+                spread: *spread,
+            }
+        }
+        tree_Expression::ConicGradient { from_angle, center_x, center_y, stops, spread } => {
+            llr_Expression::ConicGradient {
+                from_angle: Box::new(lower_expression(from_angle, ctx)),
+                center_x: Box::new(lower_expression(center_x, ctx)),
+                center_y: Box::new(lower_expression(center_y, ctx)),
+                stops: stops
+                    .iter()
+                    .map(|(a, b)| (lower_expression(a, ctx), lower_expression(b, ctx)))
+                    .collect::<_>(),
+                spread: *spread,
+            }
+        }
         tree_Expression::EnumerationValue(e) => llr_Expression::EnumerationValue(e.clone()),
         tree_Expression::ReturnStatement(..) => {
             panic!("The remove return pass should have removed all return")
@@ -357,34 +390,35 @@ fn repeater_special_property(
     llr_Expression::PropertyReference(r)
 }
 
+/// Returns the index of the popup in the list of popups of the component it's declared in,
+/// together with its close policy and a reference to the element it's declared in.
+fn lower_popup_reference(
+    popup_window: &ElementRc,
+    ctx: &ExpressionContext,
+) -> (usize, EnumerationValue, llr_Expression) {
+    let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+    let parent_component =
+        pop_comp.parent_element.upgrade().unwrap().borrow().enclosing_component.upgrade().unwrap();
+    let popup_list = parent_component.popup_windows.borrow();
+    let (popup_index, popup) =
+        popup_list.iter().enumerate().find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp)).unwrap();
+    let item_ref = lower_expression(
+        &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
+        ctx,
+    );
+    (popup_index, popup.close_policy.clone(), item_ref)
+}
+
 fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
     if let [tree_Expression::ElementReference(e)] = args {
-        let popup_window = e.upgrade().unwrap();
-        let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
-        let parent_component = pop_comp
-            .parent_element
-            .upgrade()
-            .unwrap()
-            .borrow()
-            .enclosing_component
-            .upgrade()
-            .unwrap();
-        let popup_list = parent_component.popup_windows.borrow();
-        let (popup_index, popup) = popup_list
-            .iter()
-            .enumerate()
-            .find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp))
-            .unwrap();
-        let item_ref = lower_expression(
-            &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
-            ctx,
-        );
+        let (popup_index, close_policy, item_ref) =
+            lower_popup_reference(&e.upgrade().unwrap(), ctx);
 
         llr_Expression::BuiltinFunctionCall {
             function: BuiltinFunction::ShowPopupWindow,
             arguments: vec![
                 llr_Expression::NumberLiteral(popup_index as _),
-                llr_Expression::EnumerationValue(popup.close_policy.clone()),
+                llr_Expression::EnumerationValue(close_policy),
                 item_ref,
             ],
         }
@@ -393,6 +427,19 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
     }
 }
 
+fn lower_close_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Expression {
+    if let [tree_Expression::ElementReference(e)] = args {
+        let (popup_index, _, item_ref) = lower_popup_reference(&e.upgrade().unwrap(), ctx);
+
+        llr_Expression::BuiltinFunctionCall {
+            function: BuiltinFunction::ClosePopupWindow,
+            arguments: vec![llr_Expression::NumberLiteral(popup_index as _), item_ref],
+        }
+    } else {
+        panic!("invalid arguments to ClosePopupWindow");
+    }
+}
+
 pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> Animation {
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
@@ -505,7 +552,8 @@ fn compute_layout_info(
         }
         crate::layout::Layout::BoxLayout(layout) => {
             let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
-            let bld = box_layout_data(layout, o, ctx);
+            let bld =
+                box_layout_data(&layout.elems, layout.geometry.alignment.as_ref(), o, "cells", ctx);
             let sub_expression = if o == layout.orientation {
                 llr_Expression::ExtraBuiltinFunctionCall {
                     function: "box_layout_info".into(),
@@ -530,6 +578,39 @@ fn compute_layout_info(
                 None => sub_expression,
             }
         }
+        crate::layout::Layout::FlexboxLayout(layout) => {
+            let mut main = flexbox_main_axis_data(layout, ctx);
+            if o == layout.orientation {
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "flexbox_layout_info".into(),
+                    arguments: vec![
+                        main.cells.cells,
+                        main.spacing,
+                        main.padding,
+                        main.cells.alignment,
+                        main.wrap,
+                    ],
+                    return_ty: crate::layout::layout_info_type(),
+                };
+                wrap_box_layout_function(main.cells.compute_cells, None, o, sub_expression)
+            } else {
+                let (padding, spacing) =
+                    generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+                let cross = box_layout_data(&layout.elems, None, o, "cells", ctx);
+                let main_compute_cells = main.cells.compute_cells.take();
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "flexbox_layout_info_cross".into(),
+                    arguments: vec![main.into_struct(ctx), cross.cells, spacing, padding],
+                    return_ty: crate::layout::layout_info_type(),
+                };
+                wrap_box_layout_function(
+                    main_compute_cells,
+                    None,
+                    layout.orientation,
+                    wrap_box_layout_function(cross.compute_cells, None, o, sub_expression),
+                )
+            }
+        }
     }
 }
 
@@ -606,7 +687,8 @@ fn solve_layout(
         }
         crate::layout::Layout::BoxLayout(layout) => {
             let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
-            let bld = box_layout_data(layout, o, ctx);
+            let bld =
+                box_layout_data(&layout.elems, layout.geometry.alignment.as_ref(), o, "cells", ctx);
             let size = layout_geometry_size(&layout.geometry.rect, o, ctx);
             let data = make_struct(
                 "BoxLayoutData",
@@ -655,6 +737,129 @@ fn solve_layout(
                 },
             }
         }
+        crate::layout::Layout::FlexboxLayout(layout) => {
+            let mut main = flexbox_main_axis_data(layout, ctx);
+            let has_repeater = main.cells.compute_cells.is_some();
+            let repeater_indices = if has_repeater {
+                llr_Expression::ReadLocalVariable {
+                    name: "repeated_indices".into(),
+                    ty: Type::Array(Type::Int32.into()),
+                }
+            } else {
+                llr_Expression::Array { element_ty: Type::Int32, values: vec![], as_model: false }
+            };
+            let repeater_indices_variable = has_repeater.then(|| "repeated_indices".into());
+            if o == layout.orientation {
+                let main_compute_cells = main.cells.compute_cells.take();
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "solve_flexbox_layout".into(),
+                    arguments: vec![main.into_struct(ctx), repeater_indices],
+                    return_ty: Type::LayoutCache,
+                };
+                wrap_box_layout_function(
+                    main_compute_cells,
+                    repeater_indices_variable,
+                    o,
+                    sub_expression,
+                )
+            } else {
+                let (padding, spacing) =
+                    generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+                let cross = box_layout_data(&layout.elems, None, o, "cells", ctx);
+                let size = layout_geometry_size(&layout.geometry.rect, o, ctx);
+                let align_items = enum_property_or_default(
+                    layout.align_items.as_ref(),
+                    crate::typeregister::BUILTIN_ENUMS.with(|e| e.FlexboxLayoutAlignItems.clone()),
+                    ctx,
+                );
+                let main_compute_cells = main.cells.compute_cells.take();
+                let sub_expression = llr_Expression::ExtraBuiltinFunctionCall {
+                    function: "solve_flexbox_layout_cross".into(),
+                    arguments: vec![
+                        main.into_struct(ctx),
+                        cross.cells,
+                        size,
+                        spacing,
+                        padding,
+                        align_items,
+                        repeater_indices,
+                    ],
+                    return_ty: Type::LayoutCache,
+                };
+                wrap_box_layout_function(
+                    main_compute_cells,
+                    None,
+                    layout.orientation,
+                    wrap_box_layout_function(
+                        cross.compute_cells,
+                        repeater_indices_variable,
+                        o,
+                        sub_expression,
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// The data of a FlexboxLayout along its main axis
+struct FlexboxMainAxisData {
+    size: llr_Expression,
+    spacing: llr_Expression,
+    padding: llr_Expression,
+    wrap: llr_Expression,
+    cells: BoxLayoutDataResult,
+}
+
+impl FlexboxMainAxisData {
+    /// Create the FlexboxLayoutData struct
+    fn into_struct(self, ctx: &ExpressionContext) -> llr_Expression {
+        make_struct(
+            "FlexboxLayoutData",
+            [
+                ("size", Type::Float32, self.size),
+                ("spacing", Type::Float32, self.spacing),
+                ("padding", self.padding.ty(ctx), self.padding),
+                (
+                    "alignment",
+                    crate::typeregister::BUILTIN_ENUMS
+                        .with(|e| Type::Enumeration(e.LayoutAlignment.clone())),
+                    self.cells.alignment,
+                ),
+                (
+                    "wrap",
+                    crate::typeregister::BUILTIN_ENUMS
+                        .with(|e| Type::Enumeration(e.FlexboxLayoutWrap.clone())),
+                    self.wrap,
+                ),
+                ("cells", self.cells.cells.ty(ctx), self.cells.cells),
+            ],
+        )
+    }
+}
+
+fn flexbox_main_axis_data(
+    layout: &crate::layout::FlexboxLayout,
+    ctx: &ExpressionContext,
+) -> FlexboxMainAxisData {
+    let o = layout.orientation;
+    let (padding, spacing) = generate_layout_padding_and_spacing(&layout.geometry, o, ctx);
+    FlexboxMainAxisData {
+        size: layout_geometry_size(&layout.geometry.rect, o, ctx),
+        spacing,
+        padding,
+        wrap: enum_property_or_default(
+            layout.wrap.as_ref(),
+            crate::typeregister::BUILTIN_ENUMS.with(|e| e.FlexboxLayoutWrap.clone()),
+            ctx,
+        ),
+        cells: box_layout_data(
+            &layout.elems,
+            layout.justify_content.as_ref(),
+            o,
+            "main_cells",
+            ctx,
+        ),
     }
 }
 
@@ -667,22 +872,19 @@ struct BoxLayoutDataResult {
 }
 
 fn box_layout_data(
-    layout: &crate::layout::BoxLayout,
+    elems: &[crate::layout::LayoutItem],
+    alignment: Option<&NamedReference>,
     orientation: Orientation,
+    cells_variable: &str,
     ctx: &ExpressionContext,
 ) -> BoxLayoutDataResult {
-    let alignment = if let Some(expr) = &layout.geometry.alignment {
-        llr_Expression::PropertyReference(ctx.map_property_reference(expr))
-    } else {
-        let e = crate::typeregister::BUILTIN_ENUMS.with(|e| e.LayoutAlignment.clone());
-        llr_Expression::EnumerationValue(EnumerationValue {
-            value: e.default_value,
-            enumeration: e,
-        })
-    };
+    let alignment = enum_property_or_default(
+        alignment,
+        crate::typeregister::BUILTIN_ENUMS.with(|e| e.LayoutAlignment.clone()),
+        ctx,
+    );
 
-    let repeater_count =
-        layout.elems.iter().filter(|i| i.element.borrow().repeated.is_some()).count();
+    let repeater_count = elems.iter().filter(|i| i.element.borrow().repeated.is_some()).count();
 
     let element_ty = Type::Struct {
         fields: IntoIterator::into_iter([("constraint".into(), crate::layout::layout_info_type())])
@@ -694,8 +896,7 @@ fn box_layout_data(
 
     if repeater_count == 0 {
         let cells = llr_Expression::Array {
-            values: layout
-                .elems
+            values: elems
                 .iter()
                 .map(|li| {
                     let layout_info =
@@ -712,7 +913,7 @@ fn box_layout_data(
         BoxLayoutDataResult { alignment, cells, compute_cells: None }
     } else {
         let mut elements = vec![];
-        for item in &layout.elems {
+        for item in elems {
             if item.element.borrow().repeated.is_some() {
                 let repeater_index =
                     match ctx.mapping.element_mapping.get(&item.element.clone().into()).unwrap() {
@@ -730,10 +931,49 @@ fn box_layout_data(
             }
         }
         let cells = llr_Expression::ReadLocalVariable {
-            name: "cells".into(),
+            name: cells_variable.into(),
             ty: Type::Array(Box::new(crate::layout::layout_info_type())),
         };
-        BoxLayoutDataResult { alignment, cells, compute_cells: Some(("cells".into(), elements)) }
+        BoxLayoutDataResult {
+            alignment,
+            cells,
+            compute_cells: Some((cells_variable.into(), elements)),
+        }
+    }
+}
+
+/// Wrap the sub_expression in a BoxLayoutFunction if the cells need to be computed
+fn wrap_box_layout_function(
+    compute_cells: Option<(String, Vec<Either<llr_Expression, u32>>)>,
+    repeater_indices: Option<SmolStr>,
+    orientation: Orientation,
+    sub_expression: llr_Expression,
+) -> llr_Expression {
+    match compute_cells {
+        Some((cells_variable, elements)) => llr_Expression::BoxLayoutFunction {
+            cells_variable,
+            repeater_indices,
+            elements,
+            orientation,
+            sub_expression: Box::new(sub_expression),
+        },
+        None => sub_expression,
+    }
+}
+
+/// Return the value of the property of enum type, or the default value of the enum if not set
+fn enum_property_or_default(
+    nr: Option<&NamedReference>,
+    enumeration: Rc<crate::langtype::Enumeration>,
+    ctx: &ExpressionContext,
+) -> llr_Expression {
+    if let Some(nr) = nr {
+        llr_Expression::PropertyReference(ctx.map_property_reference(nr))
+    } else {
+        llr_Expression::EnumerationValue(EnumerationValue {
+            value: enumeration.default_value,
+            enumeration,
+        })
     }
 }
 
@@ -11,6 +11,7 @@
 use crate::object_tree::{self, Component, ElementRc, PropertyAnalysis, PropertyVisibility};
 use crate::CompilerConfiguration;
 use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
@@ -18,7 +19,10 @@ pub fn lower_to_item_tree(
     document: &crate::object_tree::Document,
     compiler_config: &CompilerConfiguration,
 ) -> CompilationUnit {
-    let mut state = LoweringState::default();
+    let mut state = LoweringState {
+        translation_builder: document.translation_builder.clone().map(RefCell::new),
+        ..Default::default()
+    };
 
     let mut globals = Vec::new();
     for g in &document.used_types.borrow().globals {
@@ -64,6 +68,7 @@ pub fn lower_to_item_tree(
             })
             .collect(),
         has_debug_info: compiler_config.debug_info,
+        translations: state.translation_builder.take().map(|b| b.into_inner().result()),
     };
     super::optim_passes::run_passes(&root);
     root
@@ -73,6 +78,7 @@ pub fn lower_to_item_tree(
 pub struct LoweringState {
     global_properties: HashMap<NamedReference, PropertyReference>,
     sub_components: HashMap<ByAddress<Rc<Component>>, LoweredSubComponent>,
+    pub translation_builder: Option<RefCell<crate::translations::TranslationsBuilder>>,
 }
 
 #[derive(Debug, Clone)]
@@ -247,6 +253,8 @@ fn lower_sub_component(
         }
     };
 
+    let debug_component_name =
+        if component.id.is_empty() { sub_component.name.clone() } else { component.id.clone() };
     let s: Option<ElementRc> = None;
     let mut repeater_offset = 0;
     crate::object_tree::recurse_elem(&component.root_element, &s, &mut |element, parent| {
@@ -275,9 +283,19 @@ fn lower_sub_component(
                 NamedReference::new(element, p),
                 PropertyReference::Local { sub_component_path: vec![], property_index },
             );
+            let debug_name = compiler_config.debug_info.then(|| {
+                if Rc::ptr_eq(element, &component.root_element) {
+                    format_smolstr!("{debug_component_name}::{p}")
+                } else if let Some(id) = elem.debug.first().and_then(|d| d.qualified_id.as_ref()) {
+                    format_smolstr!("{id}.{p}")
+                } else {
+                    format_smolstr!("{debug_component_name}::{}.{p}", elem.id)
+                }
+            });
             sub_component.properties.push(Property {
                 name: format_smolstr!("{}_{}", elem.id, p),
                 ty: x.property_type.clone(),
+                debug_name,
                 ..Property::default()
             });
         }
@@ -264,6 +264,15 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
             Expression::CodeBlock(v) => {
                 write!(f, "{{ {} }}", v.iter().map(e).join("; "))
             }
+            Expression::ForLoop { model_data, index, model, body } => match index {
+                Some(index) => {
+                    write!(f, "for {}[{}] in {} {}", model_data, index, e(model), e(body))
+                }
+                None => write!(f, "for {} in {} {}", model_data, e(model), e(body)),
+            },
+            Expression::WhileLoop { condition, body } => {
+                write!(f, "while {} {}", e(condition), e(body))
+            }
             Expression::BuiltinFunctionCall { function, arguments } => {
                 write!(f, "{:?}({})", function, arguments.iter().map(e).join(", "))
             }
@@ -318,15 +327,29 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 values.iter().map(|(k, v)| format!("{}: {}", k, e(v))).join(", ")
             ),
             Expression::EasingCurve(x) => write!(f, "{:?}", x),
-            Expression::LinearGradient { angle, stops } => write!(
+            Expression::LinearGradient { angle, stops, spread } => write!(
                 f,
-                "@linear-gradient({}, {})",
+                "@{}linear-gradient({}, {})",
+                spread.prefix(),
                 e(angle),
                 stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
             ),
-            Expression::RadialGradient { stops } => write!(
+            Expression::RadialGradient { center_x, center_y, radius, stops, spread } => write!(
+                f,
+                "@{}radial-gradient(circle {} at {} {}, {})",
+                spread.prefix(),
+                e(radius),
+                e(center_x),
+                e(center_y),
+                stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
+            ),
+            Expression::ConicGradient { from_angle, center_x, center_y, stops, spread } => write!(
                 f,
-                "@radial-gradient(circle, {})",
+                "@{}conic-gradient(from {} at {} {}, {})",
+                spread.prefix(),
+                e(from_angle),
+                e(center_x),
+                e(center_y),
                 stops.iter().map(|(e1, e2)| format!("{} {}", e(e1), e(e2))).join(", ")
             ),
             Expression::EnumerationValue(x) => write!(f, "{}", x),
@@ -349,6 +372,18 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 MinMaxOp::Max => write!(f, "max({}, {})", e(lhs), e(rhs)),
             },
             Expression::EmptyComponentFactory => write!(f, "<empty-component-factory>",),
+            Expression::TranslationReference { format_args, string_index, plural } => {
+                match plural {
+                    Some(plural) => write!(
+                        f,
+                        "@tr({:?} % {}, {})",
+                        string_index,
+                        e(plural),
+                        e(format_args)
+                    ),
+                    None => write!(f, "@tr({:?}, {})", string_index, e(format_args)),
+                }
+            }
         }
     }
 }
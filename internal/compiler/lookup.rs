@@ -14,8 +14,8 @@
 use crate::object_tree::{ElementRc, PropertyVisibility};
 use crate::parser::NodeOrToken;
 use crate::typeregister::TypeRegister;
-use smol_str::SmolStr;
-use std::cell::RefCell;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::{Cell, RefCell};
 
 mod named_colors;
 
@@ -37,6 +37,10 @@ pub struct LookupCtx<'a> {
     /// The name of the arguments of the callback or function
     pub arguments: Vec<SmolStr>,
 
+    /// The local variables declared with `let` (or by a `for` loop) in the code blocks
+    /// currently being resolved. There is one entry per nested code block.
+    pub local_variables: Vec<Vec<LocalVariable>>,
+
     /// The type register in which to look for Globals
     pub type_register: &'a TypeRegister,
 
@@ -57,6 +61,7 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             component_scope: Default::default(),
             diag,
             arguments: Default::default(),
+            local_variables: Default::default(),
             type_register,
             type_loader: None,
             current_token: None,
@@ -188,6 +193,55 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
     }
 }
 
+/// A local variable declared in a code block, see [`LookupCtx::local_variables`]
+#[derive(Debug)]
+pub struct LocalVariable {
+    pub name: SmolStr,
+    pub ty: Type,
+    /// Set when an expression refers to the variable, to report the unused ones
+    pub used: Cell<bool>,
+}
+
+/// The name under which a local variable declared in .slint is stored in
+/// [`Expression::StoreLocalVariable`], so that it doesn't clash with compiler generated variables
+pub fn local_variable_name(name: &str) -> SmolStr {
+    format_smolstr!("local_{}", name)
+}
+
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for var in ctx.local_variables.iter().rev().flat_map(|scope| scope.iter().rev()) {
+            if let Some(r) = f(&var.name, Self::read(var)) {
+                return Some(r);
+            }
+        }
+        None
+    }
+
+    fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
+        let var = ctx
+            .local_variables
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|var| var.name == name)?;
+        var.used.set(true);
+        Some(Self::read(var))
+    }
+}
+
+impl LocalVariableLookup {
+    fn read(var: &LocalVariable) -> LookupResult {
+        Expression::ReadLocalVariable { name: local_variable_name(&var.name), ty: var.ty.clone() }
+            .into()
+    }
+}
+
 struct ArgumentsLookup;
 impl LookupObject for ArgumentsLookup {
     fn for_each_entry<R>(
@@ -929,16 +983,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, BuiltinFunctionLookup),
+                            ),
+                        ),
                     ),
                 ),
             ),
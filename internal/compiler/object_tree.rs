@@ -59,6 +59,9 @@ pub struct Document {
 
     /// The list of used extra types used recursively.
     pub used_types: RefCell<UsedSubTypes>,
+
+    /// The translations loaded from the `.po` files, if the translations are bundled
+    pub translation_builder: Option<crate::translations::TranslationsBuilder>,
 }
 
 impl Document {
@@ -235,6 +238,7 @@ pub fn from_node(
             exports,
             embedded_file_resources: Default::default(),
             used_types: Default::default(),
+            translation_builder: None,
         }
     }
 
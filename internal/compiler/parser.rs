@@ -367,8 +367,14 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// the right-hand-side of a binding
         // Fixme: the test should be a or
         BindingExpression-> [ ?CodeBlock, ?Expression ],
-        CodeBlock-> [ *Expression, *ReturnStatement ],
+        CodeBlock-> [ *Expression, *ReturnStatement, *LetStatement, *ForStatement, *WhileStatement ],
         ReturnStatement -> [ ?Expression ],
+        /// `let foo: type = expression;`  (the type is optional)
+        LetStatement -> [ DeclaredIdentifier, ?Type, Expression ],
+        /// `for foo[index] in model { ... }`
+        ForStatement -> [ DeclaredIdentifier, ?RepeatedIndex, Expression, CodeBlock ],
+        /// `while condition { ... }`
+        WhileStatement -> [ Expression, CodeBlock ],
         // FIXME: the test should test that as alternative rather than several of them (but it can also be a literal)
         Expression-> [ ?Expression, ?FunctionCallExpression, ?IndexExpression, ?SelfAssignment,
                        ?ConditionalExpression, ?QualifiedName, ?BinaryExpression, ?Array, ?ObjectLiteral,
@@ -226,10 +226,7 @@ fn parse_at_keyword(p: &mut impl Parser) {
         "image-url" | "image_url" => {
             parse_image_url(p);
         }
-        "linear-gradient" | "linear_gradient" => {
-            parse_gradient(p);
-        }
-        "radial-gradient" | "radial_gradient" => {
+        name if is_gradient_name(name) => {
             parse_gradient(p);
         }
         "tr" => {
@@ -238,11 +235,29 @@ fn parse_at_keyword(p: &mut impl Parser) {
         _ => {
             p.consume();
             p.test(SyntaxKind::Identifier); // consume the identifier, so that autocomplete works
-            p.error("Expected 'image-url', 'tr', 'linear-gradient' or 'radial-gradient' after '@'");
+            p.error("Expected 'image-url', 'tr', 'linear-gradient', 'radial-gradient' or 'conic-gradient' after '@'");
         }
     }
 }
 
+/// Returns true for `linear-gradient`, `radial-gradient` and `conic-gradient`, optionally
+/// prefixed by `repeating-` or `reflecting-`
+fn is_gradient_name(name: &str) -> bool {
+    let name = ["repeating-", "repeating_", "reflecting-", "reflecting_"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .unwrap_or(name);
+    matches!(
+        name,
+        "linear-gradient"
+            | "linear_gradient"
+            | "radial-gradient"
+            | "radial_gradient"
+            | "conic-gradient"
+            | "conic_gradient"
+    )
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,Array
 /// [ a, b, c , d]
@@ -340,6 +355,10 @@ fn parse_template_string(p: &mut impl Parser) {
 /// @linear-gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @linear_gradient(217deg, rgba(255,0,0,0.8), rgba(255,0,0,0) 70.71%)
 /// @radial-gradient(circle, #e66465, blue 50%, #9198e5)
+/// @radial-gradient(circle 50px at 25% 75%, #e66465, blue 50%, #9198e5)
+/// @conic-gradient(from 90deg at 50% 50%, red, blue 0.25turn, red)
+/// @repeating-linear-gradient(90deg, red 0%, blue 20%)
+/// @reflecting-radial-gradient(circle, red, blue 20%)
 /// ```
 fn parse_gradient(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::AtGradient);
@@ -4,6 +4,7 @@
 use super::element::parse_code_block;
 use super::expressions::parse_expression;
 use super::prelude::*;
+use super::r#type::parse_type;
 
 #[cfg_attr(test, parser_test)]
 /// ```test
@@ -14,6 +15,9 @@
 /// if (true) { foo = bar; } else { bar = foo;  }
 /// return;
 /// if (true) { return 42; }
+/// let foo = 42;
+/// for x in model { foo(x); }
+/// while (true) { }
 /// ```
 pub fn parse_statement(p: &mut impl Parser) -> bool {
     if p.nth(0).kind() == SyntaxKind::RBrace {
@@ -40,6 +44,36 @@ pub fn parse_statement(p: &mut impl Parser) -> bool {
         return true;
     }
 
+    if p.peek().as_str() == "let" && p.nth(1).kind() == SyntaxKind::Identifier {
+        parse_let_statement(p);
+        return true;
+    }
+
+    if p.peek().as_str() == "for" && p.nth(1).kind() == SyntaxKind::Identifier {
+        parse_for_statement(p);
+        return true;
+    }
+
+    if p.peek().as_str() == "while"
+        && !matches!(
+            p.nth(1).kind(),
+            SyntaxKind::Dot
+                | SyntaxKind::Comma
+                | SyntaxKind::Semicolon
+                | SyntaxKind::RBrace
+                | SyntaxKind::RBracket
+                | SyntaxKind::RParent
+                | SyntaxKind::Equal
+                | SyntaxKind::PlusEqual
+                | SyntaxKind::MinusEqual
+                | SyntaxKind::StarEqual
+                | SyntaxKind::DivEqual
+        )
+    {
+        parse_while_statement(p);
+        return true;
+    }
+
     if p.peek().as_str() == "return" {
         let mut p = p.start_node_at(checkpoint, SyntaxKind::ReturnStatement);
         p.expect(SyntaxKind::Identifier); // "return"
@@ -98,3 +132,71 @@ fn parse_if_statement(p: &mut impl Parser) {
         let _ = p.start_node(SyntaxKind::CodeBlock);
     }
 }
+
+#[cfg_attr(test, parser_test)]
+/// ```test,LetStatement
+/// let foo = 42;
+/// let foo: int = 42;
+/// let foo-bar: [string] = ["hello", "world"];
+/// let foo: { a: int } = { a: 45 };
+/// ```
+fn parse_let_statement(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::LetStatement);
+    debug_assert_eq!(p.peek().as_str(), "let");
+    p.expect(SyntaxKind::Identifier); // "let"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.test(SyntaxKind::Colon) {
+        parse_type(&mut *p);
+    }
+    p.expect(SyntaxKind::Equal);
+    parse_expression(&mut *p);
+    p.expect(SyntaxKind::Semicolon);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,ForStatement
+/// for x in model { }
+/// for x[idx] in model { foo(x, idx); }
+/// for x in [1, 2, 3] { if (x > 2) { foo = x; } }
+/// for x in 42 { }
+/// ```
+fn parse_for_statement(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::ForStatement);
+    debug_assert_eq!(p.peek().as_str(), "for");
+    p.expect(SyntaxKind::Identifier); // "for"
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    if p.nth(0).kind() == SyntaxKind::LBracket {
+        let mut p = p.start_node(SyntaxKind::RepeatedIndex);
+        p.expect(SyntaxKind::LBracket);
+        p.expect(SyntaxKind::Identifier);
+        p.expect(SyntaxKind::RBracket);
+    }
+    if p.peek().as_str() != "in" {
+        p.error("Invalid 'for' syntax: there should be a 'in' token");
+        drop(p.start_node(SyntaxKind::Expression));
+        drop(p.start_node(SyntaxKind::CodeBlock));
+        return;
+    }
+    p.consume(); // "in"
+    parse_expression(&mut *p);
+    parse_code_block(&mut *p);
+}
+
+#[cfg_attr(test, parser_test)]
+/// ```test,WhileStatement
+/// while (true) { }
+/// while foo < 10 { foo += 1; }
+/// ```
+fn parse_while_statement(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::WhileStatement);
+    debug_assert_eq!(p.peek().as_str(), "while");
+    p.expect(SyntaxKind::Identifier); // "while"
+    parse_expression(&mut *p);
+    parse_code_block(&mut *p);
+}
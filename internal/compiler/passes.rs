@@ -7,6 +7,7 @@
 mod check_expressions;
 mod check_public_api;
 mod check_rotation;
+mod check_shortcuts;
 mod clip;
 mod collect_custom_fonts;
 mod collect_globals;
@@ -30,6 +31,7 @@
 mod lower_accessibility;
 mod lower_component_container;
 mod lower_layout;
+mod lower_menus;
 mod lower_popups;
 mod lower_property_to_element;
 mod lower_shadows;
@@ -95,6 +97,7 @@ pub async fn run_passes(
         );
     });
     lower_tabwidget::lower_tabwidget(doc, type_loader, diag).await;
+    lower_menus::lower_menus(doc, type_loader, diag).await;
     collect_subcomponents::collect_subcomponents(doc);
 
     doc.visit_all_used_components(|component| {
@@ -110,6 +113,7 @@ pub async fn run_passes(
         lower_popups::lower_popups(component, &doc.local_registry, diag);
         collect_init_code::collect_init_code(component);
         lower_timers::lower_timers(component, diag);
+        lower_menus::remove_menu_elements(component);
     });
 
     inlining::inline(doc, inlining::InlineSelection::InlineOnlyRequiredComponents, diag);
@@ -290,5 +294,6 @@ pub fn run_import_passes(
     check_expressions::check_expressions(doc, diag);
     purity_check::purity_check(doc, diag);
     check_rotation::check_rotation(doc, diag);
+    check_shortcuts::check_shortcuts(doc, diag);
     unique_id::check_unique_id(doc, diag);
 }
@@ -69,6 +69,24 @@ pub fn apply_default_properties_from_style(
                         to: Type::Brush,
                     });
                 }
+                "StyledText" => {
+                    elem.set_binding_if_not_set("color".into(), || Expression::Cast {
+                        from: Expression::PropertyReference(NamedReference::new(
+                            &palette.root_element,
+                            "foreground",
+                        ))
+                        .into(),
+                        to: Type::Brush,
+                    });
+                    elem.set_binding_if_not_set("link-color".into(), || Expression::Cast {
+                        from: Expression::PropertyReference(NamedReference::new(
+                            &palette.root_element,
+                            "accent-background",
+                        ))
+                        .into(),
+                        to: Type::Brush,
+                    });
+                }
                 "Dialog" | "Window" => {
                     elem.set_binding_if_not_set("background".into(), || Expression::Cast {
                         from: Expression::PropertyReference(NamedReference::new(
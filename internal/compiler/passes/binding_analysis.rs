@@ -405,6 +405,19 @@ fn recurse_expression(expr: &Expression, vis: &mut impl FnMut(&PropertyPath, Rea
                 crate::layout::Layout::BoxLayout(l) => {
                     visit_layout_items_dependencies(l.elems.iter(), *o, vis)
                 }
+                crate::layout::Layout::FlexboxLayout(l) => {
+                    visit_layout_items_dependencies(l.elems.iter(), *o, vis);
+                    // The cross axis depends on how the items are split in lines along the main axis
+                    if *o != l.orientation {
+                        if let Some(nr) = l.geometry.rect.size_reference(l.orientation) {
+                            vis(&nr.clone().into(), P);
+                        }
+                        visit_layout_items_dependencies(l.elems.iter(), l.orientation, vis);
+                    }
+                    for nr in [&l.wrap, &l.justify_content, &l.align_items].into_iter().flatten() {
+                        vis(&nr.clone().into(), P);
+                    }
+                }
             }
 
             let mut g = l.geometry().clone();
@@ -489,12 +502,14 @@ fn visit_implicit_layout_info_dependencies(
                 vis(&NamedReference::new(item, "width").into(), N);
             }
         }
-        "Text" | "TextInput" => {
+        "Text" | "TextInput" | "StyledText" => {
             vis(&NamedReference::new(item, "text").into(), N);
             vis(&NamedReference::new(item, "font-family").into(), N);
             vis(&NamedReference::new(item, "font-size").into(), N);
             vis(&NamedReference::new(item, "font-weight").into(), N);
-            vis(&NamedReference::new(item, "letter-spacing").into(), N);
+            if base_type.as_str() != "StyledText" {
+                vis(&NamedReference::new(item, "letter-spacing").into(), N);
+            }
             vis(&NamedReference::new(item, "wrap").into(), N);
             let wrap_set = item.borrow().is_binding_set("wrap", false)
                 || item
@@ -506,10 +521,10 @@ fn visit_implicit_layout_info_dependencies(
             if wrap_set && orientation == Orientation::Vertical {
                 vis(&NamedReference::new(item, "width").into(), N);
             }
-            if base_type.as_str() == "TextInput" {
-                vis(&NamedReference::new(item, "single-line").into(), N);
-            } else {
-                vis(&NamedReference::new(item, "overflow").into(), N);
+            match base_type.as_str() {
+                "TextInput" => vis(&NamedReference::new(item, "single-line").into(), N),
+                "Text" => vis(&NamedReference::new(item, "overflow").into(), N),
+                _ => (),
             }
         }
 
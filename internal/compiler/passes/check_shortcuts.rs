@@ -0,0 +1,77 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Check the key sequences of the `Shortcut` elements, and warn about shortcuts that conflict
+
+use crate::diagnostics::{BuildDiagnostics, Spanned};
+use crate::expression_tree::Expression;
+use crate::object_tree::Component;
+use i_slint_common::key_sequence::KeySequence;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+pub fn check_shortcuts(doc: &crate::object_tree::Document, diag: &mut BuildDiagnostics) {
+    // The shortcuts that are always enabled, by component and popup, to detect the conflicts
+    let mut seen = HashMap::<(*const Component, *const ()), Vec<KeySequence>>::new();
+    for cmp in &doc.inner_components {
+        crate::object_tree::recurse_elem_including_sub_components(
+            cmp,
+            &std::ptr::null::<()>(),
+            &mut |elem, popup| {
+                let e = elem.borrow();
+                let Some(builtin) = e.builtin_type() else { return *popup };
+                if builtin.name == "PopupWindow" {
+                    // The shortcuts of a popup take precedence over the ones of the window
+                    return Rc::as_ptr(elem) as *const ();
+                }
+                // The element of a repeater is checked in its own component
+                if builtin.name != "Shortcut" || e.repeated.is_some() {
+                    return *popup;
+                }
+                let Some(binding) = e.bindings.get("keys") else { return *popup };
+                let binding = binding.borrow();
+                let Some(keys) = string_literal(&binding.expression) else { return *popup };
+                let span = binding.span.clone().unwrap_or_else(|| e.to_source_location());
+                match KeySequence::parse(keys) {
+                    Err(err) => {
+                        diag.push_error_with_span(
+                            format!("Invalid key sequence '{keys}': {err}"),
+                            span,
+                        );
+                    }
+                    // The default value of `enabled` from builtins.slint has the lowest priority
+                    Ok(sequence)
+                        if !e
+                            .bindings
+                            .get("enabled")
+                            .is_some_and(|b| b.borrow().priority != i32::MAX) =>
+                    {
+                        let component = e
+                            .enclosing_component
+                            .upgrade()
+                            .map_or(std::ptr::null(), |c| Rc::as_ptr(&c));
+                        let sequences = seen.entry((component, *popup)).or_default();
+                        if sequences.contains(&sequence) {
+                            diag.push_warning_with_span(
+                                format!("The key sequence '{keys}' is already used by another Shortcut. Only the first one will be activated"),
+                                span,
+                            );
+                        } else {
+                            sequences.push(sequence);
+                        }
+                    }
+                    Ok(_) => {}
+                }
+                *popup
+            },
+        );
+    }
+}
+
+fn string_literal(expression: &Expression) -> Option<&str> {
+    match expression {
+        Expression::StringLiteral(s) => Some(s.as_str()),
+        Expression::CodeBlock(block) if block.len() == 1 => string_literal(&block[0]),
+        _ => None,
+    }
+}
@@ -26,8 +26,6 @@ struct PropertyReadCounts {
     counts: HashMap<NamedReference, usize>,
     /// If at least one element of the map has duplicates
     has_duplicate: bool,
-    /// if there is an assignment of a property we currently disable this optimization
-    has_set: bool,
 }
 
 #[derive(Default)]
@@ -85,16 +83,16 @@ fn get_mapping(&self, nr: &NamedReference) -> Option<SmolStr> {
 }
 
 fn process_expression(expr: &mut Expression, old_state: &DedupPropState) {
-    if old_state.counts.borrow().has_set {
+    // A property may change after an assignment, so the reads can't be deduplicated across it.
+    // This must be known before processing the branches, as their replacements refer to the
+    // local variables of this scope. The branches that don't assign are processed on their own.
+    if has_assignment(expr) {
+        process_conditional_expressions(expr, &DedupPropState::default());
         return;
     }
     let new_state = DedupPropState { parent_state: Some(old_state), ..DedupPropState::default() };
     collect_unconditional_read_count(expr, &new_state);
     process_conditional_expressions(expr, &new_state);
-    if new_state.counts.borrow().has_set {
-        old_state.counts.borrow_mut().has_set = true;
-        return;
-    }
     do_replacements(expr, &new_state);
     if new_state.counts.borrow().has_duplicate {
         let mut stores = vec![];
@@ -126,17 +124,14 @@ fn collect_unconditional_read_count(expr: &Expression, result: &DedupPropState)
         Expression::Condition { condition, .. } => {
             condition.visit(|sub| collect_unconditional_read_count(sub, result))
         }
-        Expression::SelfAssignment { .. } => {
-            result.counts.borrow_mut().has_set = true;
-        }
+        // The body of a loop may be evaluated several times, so the reads within it can't be deduplicated
+        Expression::ForLoop { model, .. } => collect_unconditional_read_count(model, result),
+        Expression::WhileLoop { .. } => {}
         _ => expr.visit(|sub| collect_unconditional_read_count(sub, result)),
     }
 }
 
 fn process_conditional_expressions(expr: &mut Expression, state: &DedupPropState) {
-    if state.counts.borrow().has_set {
-        return;
-    }
     match expr {
         Expression::BinaryExpression { lhs, rhs, op: '|' | '&' } => {
             lhs.visit_mut(|sub| process_conditional_expressions(sub, state));
@@ -147,13 +142,25 @@ fn process_conditional_expressions(expr: &mut Expression, state: &DedupPropState
             process_expression(true_expr, state);
             process_expression(false_expr, state);
         }
-        Expression::SelfAssignment { .. } => {
-            state.counts.borrow_mut().has_set = true;
+        // Each evaluation of the loop body and condition is a scope of its own
+        Expression::ForLoop { model, body, .. } => {
+            process_conditional_expressions(model, state);
+            process_expression(body, &DedupPropState::default());
+        }
+        Expression::WhileLoop { condition, body } => {
+            process_expression(condition, &DedupPropState::default());
+            process_expression(body, &DedupPropState::default());
         }
         _ => expr.visit_mut(|sub| process_conditional_expressions(sub, state)),
     }
 }
 
+fn has_assignment(expr: &Expression) -> bool {
+    let mut result = false;
+    expr.visit_recursive(&mut |e| result |= matches!(e, Expression::SelfAssignment { .. }));
+    result
+}
+
 fn do_replacements(expr: &mut Expression, state: &DedupPropState) {
     match expr {
         Expression::PropertyReference(nr) => {
@@ -168,6 +175,8 @@ fn do_replacements(expr: &mut Expression, state: &DedupPropState) {
         Expression::Condition { condition, .. } => {
             condition.visit_mut(|sub| do_replacements(sub, state));
         }
+        Expression::ForLoop { model, .. } => do_replacements(model, state),
+        Expression::WhileLoop { .. } => {}
         _ => expr.visit_mut(|sub| do_replacements(sub, state)),
     }
 }
@@ -539,6 +539,11 @@ fn fixup_element_references(expr: &mut Expression, mapping: &Mapping) {
                     fxe(&mut e.element);
                 }
             }
+            crate::layout::Layout::FlexboxLayout(l) => {
+                for e in &mut l.elems {
+                    fxe(&mut e.element);
+                }
+            }
         },
         Expression::RepeaterModelReference { element }
         | Expression::RepeaterIndexReference { element } => fx(element),
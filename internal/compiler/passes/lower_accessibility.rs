@@ -38,6 +38,9 @@ pub fn lower_accessibility_properties(component: &Rc<Component>, diag: &mut Buil
                 // maybe it was set on the parent
                 None => elem.borrow().is_binding_set("accessible-role", false),
             };
+            if accessible_role_set {
+                apply_shortcut(elem);
+            }
 
             for prop_name in crate::typeregister::reserved_accessibility_properties()
                 .map(|x| x.0)
@@ -75,3 +78,22 @@ fn apply_builtin(e: &ElementRc) {
         });
     }
 }
+
+/// An accessible element reports the key sequence of its `Shortcut` child
+fn apply_shortcut(e: &ElementRc) {
+    let shortcut = e
+        .borrow()
+        .children
+        .iter()
+        .find(|c| {
+            c.borrow().repeated.is_none()
+                && c.borrow().builtin_type().is_some_and(|b| b.name == "Shortcut")
+        })
+        .cloned();
+    if let Some(shortcut) = shortcut {
+        let keys = NamedReference::new(&shortcut, "keys");
+        e.borrow_mut().set_binding_if_not_set("accessible-keyboard-shortcut".into(), || {
+            Expression::PropertyReference(keys)
+        });
+    }
+}
@@ -111,6 +111,7 @@ fn lower_element_layout(
         "GridLayout" => lower_grid_layout(component, elem, diag, type_register),
         "HorizontalLayout" => lower_box_layout(elem, diag, Orientation::Horizontal),
         "VerticalLayout" => lower_box_layout(elem, diag, Orientation::Vertical),
+        "FlexboxLayout" => lower_flexbox_layout(elem, diag),
         "Dialog" => {
             lower_dialog_layout(elem, style_metrics, diag);
             return; // the Dialog stays in the tree as a Dialog
@@ -440,6 +441,106 @@ fn lower_box_layout(
     }
 }
 
+fn lower_flexbox_layout(layout_element: &ElementRc, diag: &mut BuildDiagnostics) {
+    let orientation = match layout_element.borrow().bindings.get("direction") {
+        None => Orientation::Horizontal,
+        Some(binding) => match &binding.borrow().expression {
+            Expression::EnumerationValue(val) => {
+                debug_assert_eq!(val.enumeration.name, "FlexboxLayoutDirection");
+                if val.enumeration.values[val.value] == "column" {
+                    Orientation::Vertical
+                } else {
+                    Orientation::Horizontal
+                }
+            }
+            _ => {
+                diag.push_error(
+                    "The `direction` property of a FlexboxLayout must be known at compile-time"
+                        .into(),
+                    &*binding.borrow(),
+                );
+                Orientation::Horizontal
+            }
+        },
+    };
+
+    let mut layout = FlexboxLayout {
+        orientation,
+        elems: Default::default(),
+        geometry: LayoutGeometry::new(layout_element),
+        wrap: binding_reference(layout_element, "wrap"),
+        justify_content: binding_reference(layout_element, "justify-content"),
+        align_items: binding_reference(layout_element, "align-items"),
+    };
+
+    let layout_cache_prop_h = create_new_prop(layout_element, "layout-cache-h", Type::LayoutCache);
+    let layout_cache_prop_v = create_new_prop(layout_element, "layout-cache-v", Type::LayoutCache);
+    let layout_info_prop_h = create_new_prop(layout_element, "layoutinfo-h", layout_info_type());
+    let layout_info_prop_v = create_new_prop(layout_element, "layoutinfo-v", layout_info_type());
+
+    let layout_children = std::mem::take(&mut layout_element.borrow_mut().children);
+    for layout_child in &layout_children {
+        if let Some(item) = create_layout_item(layout_child, diag) {
+            let index = layout.elems.len() * 2;
+            let rep_idx = &item.repeater_index;
+            let e = &item.elem;
+            set_prop_from_cache(e, "x", &layout_cache_prop_h, index, rep_idx, diag);
+            if !item.item.constraints.fixed_width {
+                set_prop_from_cache(e, "width", &layout_cache_prop_h, index + 1, rep_idx, diag);
+            }
+            set_prop_from_cache(e, "y", &layout_cache_prop_v, index, rep_idx, diag);
+            if !item.item.constraints.fixed_height {
+                set_prop_from_cache(e, "height", &layout_cache_prop_v, index + 1, rep_idx, diag);
+            }
+            layout.elems.push(item.item);
+        }
+    }
+    layout_element.borrow_mut().children = layout_children;
+    let span = layout_element.borrow().to_source_location();
+    layout_cache_prop_h.element().borrow_mut().bindings.insert(
+        layout_cache_prop_h.name().into(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlexboxLayout(layout.clone()), Orientation::Horizontal),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_cache_prop_v.element().borrow_mut().bindings.insert(
+        layout_cache_prop_v.name().into(),
+        BindingExpression::new_with_span(
+            Expression::SolveLayout(Layout::FlexboxLayout(layout.clone()), Orientation::Vertical),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_h.element().borrow_mut().bindings.insert(
+        layout_info_prop_h.name().into(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(
+                Layout::FlexboxLayout(layout.clone()),
+                Orientation::Horizontal,
+            ),
+            span.clone(),
+        )
+        .into(),
+    );
+    layout_info_prop_v.element().borrow_mut().bindings.insert(
+        layout_info_prop_v.name().into(),
+        BindingExpression::new_with_span(
+            Expression::ComputeLayoutInfo(
+                Layout::FlexboxLayout(layout.clone()),
+                Orientation::Vertical,
+            ),
+            span,
+        )
+        .into(),
+    );
+    layout_element.borrow_mut().layout_info_prop = Some((layout_info_prop_h, layout_info_prop_v));
+    for d in layout_element.borrow_mut().debug.iter_mut() {
+        d.layout = Some(Layout::FlexboxLayout(layout.clone()));
+    }
+}
+
 fn lower_dialog_layout(
     dialog_element: &ElementRc,
     style_metrics: &Rc<Component>,
@@ -657,6 +758,11 @@ fn create_layout_item(
     item_element: &ElementRc,
     diag: &mut BuildDiagnostics,
 ) -> Option<CreateLayoutItemResult> {
+    // Shortcuts have no visual representation and don't take space in layouts
+    if matches!(&item_element.borrow().base_type, ElementType::Builtin(b) if b.name == "Shortcut") {
+        return None;
+    }
+
     let fix_explicit_percent = |prop: &str, item: &ElementRc| {
         if !item.borrow().bindings.get(prop).map_or(false, |b| b.borrow().ty() == Type::Percent) {
             return;
@@ -0,0 +1,515 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Passes that lower the `MenuBar` and the `ContextMenuArea`.
+//!
+//! The `Menu`, `MenuItem` and `MenuSeparator` elements are turned into a tree of entries
+//! identified by their path (`"0"`, `"0/2"`, ...). The `MenuBar` and the `ContextMenuArea` are
+//! replaced by their implementation in the style, which queries the entries with the
+//! `entry-count` and `entry` callbacks, and reports the activated entry with `activated`.
+//!
+//! Like the TabWidget, this must be done before inlining because the implementation needs to be
+//! inlined. The menu elements stay in the tree until [`remove_menu_elements`] so that their
+//! properties end up in the right component once the repeaters are processed.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{Expression, NamedReference, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use smol_str::{format_smolstr, SmolStr};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Sub-menus are shown in panels cascading from the menu, and the style only has so many of them
+const MAX_SUB_MENU_DEPTH: usize = 4;
+
+struct MenuTypes {
+    menubar_impl: ElementType,
+    context_menu_area_impl: ElementType,
+    shortcut: ElementType,
+    vertical_layout: ElementType,
+    empty: ElementType,
+    menu_entry: Type,
+}
+
+pub async fn lower_menus(
+    doc: &Document,
+    type_loader: &mut crate::typeloader::TypeLoader,
+    diag: &mut BuildDiagnostics,
+) {
+    // Ignore import errors
+    let mut build_diags_to_ignore = BuildDiagnostics::default();
+    let menubar_impl = type_loader
+        .import_component("std-widgets.slint", "MenuBarImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load MenuBarImpl from std-widgets.slint");
+    let context_menu_area_impl = type_loader
+        .import_component("std-widgets.slint", "ContextMenuAreaImpl", &mut build_diags_to_ignore)
+        .await
+        .expect("can't load ContextMenuAreaImpl from std-widgets.slint");
+    let types = {
+        let tr = type_loader.global_type_registry.borrow();
+        MenuTypes {
+            menubar_impl: ElementType::Component(menubar_impl),
+            context_menu_area_impl: ElementType::Component(context_menu_area_impl),
+            shortcut: tr.lookup_builtin_element("Shortcut").unwrap(),
+            vertical_layout: tr.lookup_builtin_element("VerticalLayout").unwrap(),
+            empty: tr.empty_type(),
+            menu_entry: tr.lookup("MenuEntry"),
+        }
+    };
+
+    // The menus are taken out of the tree while visiting it so they are not reported as
+    // misplaced, and put back afterwards.
+    let mut lowered_menus = Vec::new();
+
+    doc.visit_all_used_components(|component| {
+        recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+            let builtin_name = match &elem.borrow().base_type {
+                ElementType::Builtin(b) => b.name.clone(),
+                _ => SmolStr::default(),
+            };
+            match builtin_name.as_str() {
+                "ContextMenuArea" => {
+                    process_context_menu_area(elem, &types, &mut lowered_menus, diag)
+                }
+                "MenuBar" => diag.push_error(
+                    "MenuBar can only be placed directly within a Window".into(),
+                    &*elem.borrow(),
+                ),
+                "Menu" | "MenuItem" | "MenuSeparator" => diag.push_error(
+                    format!("{builtin_name} can only be placed within a MenuBar, a ContextMenuArea or another Menu"),
+                    &*elem.borrow(),
+                ),
+                _ => {
+                    let is_window_root = elem
+                        .borrow()
+                        .enclosing_component
+                        .upgrade()
+                        .is_some_and(|c| Rc::ptr_eq(&c.root_element, elem))
+                        && elem
+                            .borrow()
+                            .builtin_type()
+                            .is_some_and(|b| matches!(b.name.as_str(), "Window" | "Dialog"));
+                    if is_window_root {
+                        process_window(elem, &types, &mut lowered_menus, diag);
+                    }
+                }
+            }
+        })
+    });
+
+    for (parent, menus) in lowered_menus {
+        parent.borrow_mut().children.extend(menus);
+    }
+}
+
+/// Remove the `Menu`, `MenuItem` and `MenuSeparator` elements from the tree. They don't render
+/// anything, but their properties are still used by the lowered menus.
+pub fn remove_menu_elements(component: &Rc<Component>) {
+    recurse_elem_including_sub_components_no_borrow(component, &(), &mut |elem, _| {
+        let mut removed = Vec::new();
+        elem.borrow_mut().children.retain(|child| {
+            let is_menu = matches!(&child.borrow().base_type, ElementType::Builtin(b) if matches!(b.name.as_str(), "Menu" | "MenuItem" | "MenuSeparator"));
+            if is_menu {
+                removed.push(child.clone());
+            }
+            !is_menu
+        });
+        // The entries of the sub-menus are optimized out too, each on their own
+        while let Some(child) = removed.pop() {
+            removed.extend(std::mem::take(&mut child.borrow_mut().children));
+            let enclosing = child.borrow().enclosing_component.upgrade().unwrap();
+            enclosing.optimized_elements.borrow_mut().push(child);
+        }
+    })
+}
+
+fn process_context_menu_area(
+    elem: &ElementRc,
+    types: &MenuTypes,
+    lowered_menus: &mut Vec<(ElementRc, Vec<ElementRc>)>,
+    diag: &mut BuildDiagnostics,
+) {
+    let (menus, children): (Vec<_>, Vec<_>) =
+        std::mem::take(&mut elem.borrow_mut().children).into_iter().partition(|c| {
+            matches!(&c.borrow().base_type, ElementType::Builtin(b) if matches!(b.name.as_str(), "Menu" | "MenuItem" | "MenuSeparator"))
+        });
+    elem.borrow_mut().children = children;
+
+    let mut menus = menus
+        .into_iter()
+        .filter(|menu| {
+            let menu = menu.borrow();
+            if menu.repeated.is_some() {
+                diag.push_error(
+                    "dynamic menus ('if' or 'for') are currently not supported".into(),
+                    &*menu,
+                );
+                false
+            } else if menu.base_type.to_string() != "Menu" {
+                diag.push_error(format!("{} must be placed within a Menu", menu.base_type), &*menu);
+                false
+            } else {
+                true
+            }
+        })
+        .collect::<Vec<_>>()
+        .into_iter();
+
+    let Some(menu) = menus.next() else {
+        diag.push_error("ContextMenuArea must contain a Menu".into(), &*elem.borrow());
+        return;
+    };
+    for extra in menus {
+        diag.push_error("Only one Menu is allowed in a ContextMenuArea".into(), &*extra.borrow());
+    }
+
+    let mut entries = MenuEntries::default();
+    entries.collect(&menu, SmolStr::default(), 0, &mut Vec::new(), diag);
+
+    {
+        let mut elem = elem.borrow_mut();
+        elem.base_type = types.context_menu_area_impl.clone();
+        elem.default_fill_parent = (true, true);
+    }
+    entries.set_callbacks(elem, &types.menu_entry);
+    lowered_menus.push((elem.clone(), vec![menu]));
+}
+
+fn process_window(
+    window: &ElementRc,
+    types: &MenuTypes,
+    lowered_menus: &mut Vec<(ElementRc, Vec<ElementRc>)>,
+    diag: &mut BuildDiagnostics,
+) {
+    let (menubars, children): (Vec<_>, Vec<_>) =
+        std::mem::take(&mut window.borrow_mut().children).into_iter().partition(
+            |c| matches!(&c.borrow().base_type, ElementType::Builtin(b) if b.name == "MenuBar"),
+        );
+    let mut menubars = menubars.into_iter();
+    let Some(menubar) = menubars.next() else {
+        window.borrow_mut().children = children;
+        return;
+    };
+    for extra in menubars {
+        diag.push_error("Only one MenuBar is allowed in a Window".into(), &*extra.borrow());
+    }
+    if menubar.borrow().repeated.is_some() {
+        diag.push_error(
+            "MenuBar cannot be directly repeated or conditional".into(),
+            &*menubar.borrow(),
+        );
+    }
+
+    let mut entries = MenuEntries::default();
+    let mut menus = Vec::new();
+    for child in std::mem::take(&mut menubar.borrow_mut().children) {
+        if child.borrow().repeated.is_some() {
+            diag.push_error(
+                "dynamic menus ('if' or 'for') are currently not supported".into(),
+                &*child.borrow(),
+            );
+        } else if child.borrow().base_type.to_string() != "Menu" {
+            diag.push_error(
+                format!(
+                    "{} is not allowed within MenuBar. Only Menu are valid children",
+                    child.borrow().base_type
+                ),
+                &*child.borrow(),
+            );
+        } else {
+            menus.push(child);
+        }
+    }
+    entries.counts.push((SmolStr::default(), menus.len()));
+    for (index, menu) in menus.iter().enumerate() {
+        let id = format_smolstr!("{index}");
+        entries.entries.push(Entry {
+            id: id.clone(),
+            element: menu.clone(),
+            kind: EntryKind::Menu,
+            parents: Vec::new(),
+        });
+        entries.collect(menu, id, 0, &mut vec![menu.clone()], diag);
+    }
+
+    menubar.borrow_mut().base_type = types.menubar_impl.clone();
+    entries.set_callbacks(&menubar, &types.menu_entry);
+    lowered_menus.push((menubar.clone(), menus));
+
+    let enclosing_component = window.borrow().enclosing_component.clone();
+    let content = Element {
+        id: format_smolstr!("{}-content", window.borrow().id),
+        base_type: types.empty.clone(),
+        enclosing_component: enclosing_component.clone(),
+        children,
+        ..Default::default()
+    }
+    .make_rc();
+    let layout = Element {
+        id: format_smolstr!("{}-menubar-layout", window.borrow().id),
+        base_type: types.vertical_layout.clone(),
+        enclosing_component: enclosing_component.clone(),
+        children: vec![menubar, content],
+        ..Default::default()
+    }
+    .make_rc();
+
+    // The shortcuts of the menu items are active as long as the window is
+    let shortcuts = entries.entries.iter().filter(|e| {
+        e.kind == EntryKind::Item && e.element.borrow().is_binding_set("shortcut", true)
+    });
+    let shortcuts = shortcuts
+        .map(|entry| {
+            let mut shortcut = Element {
+                base_type: types.shortcut.clone(),
+                enclosing_component: enclosing_component.clone(),
+                ..Default::default()
+            };
+            shortcut.bindings.insert(
+                "keys".into(),
+                RefCell::new(
+                    Expression::PropertyReference(NamedReference::new(&entry.element, "shortcut"))
+                        .into(),
+                ),
+            );
+            let enabled = entry
+                .parents
+                .iter()
+                .chain(std::iter::once(&entry.element))
+                .map(|e| Expression::PropertyReference(NamedReference::new(e, "enabled")))
+                .reduce(|lhs, rhs| Expression::BinaryExpression {
+                    lhs: lhs.into(),
+                    rhs: rhs.into(),
+                    op: '&',
+                })
+                .unwrap();
+            shortcut.bindings.insert("enabled".into(), RefCell::new(enabled.into()));
+            shortcut
+                .bindings
+                .insert("activated".into(), RefCell::new(entry.activation_code().into()));
+            shortcut.make_rc()
+        })
+        .collect::<Vec<_>>();
+
+    window.borrow_mut().children = std::iter::once(layout).chain(shortcuts).collect();
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum EntryKind {
+    Menu,
+    Item,
+    Separator,
+}
+
+struct Entry {
+    id: SmolStr,
+    element: ElementRc,
+    kind: EntryKind,
+    /// The `Menu` elements that contain this entry, from the outermost in the MenuBar
+    parents: Vec<ElementRc>,
+}
+
+impl Entry {
+    /// The code run when the entry is activated, either from the menu or from its shortcut
+    fn activation_code(&self) -> Expression {
+        let mut code = Vec::new();
+        if self.element.borrow().is_binding_set("checkable", true) {
+            let checked =
+                Expression::PropertyReference(NamedReference::new(&self.element, "checked"));
+            code.push(Expression::Condition {
+                condition: Expression::PropertyReference(NamedReference::new(
+                    &self.element,
+                    "checkable",
+                ))
+                .into(),
+                true_expr: Expression::SelfAssignment {
+                    lhs: checked.clone().into(),
+                    rhs: Expression::UnaryOp { sub: checked.into(), op: '!' }.into(),
+                    op: '=',
+                    node: None,
+                }
+                .into(),
+                false_expr: Expression::CodeBlock(vec![]).into(),
+            });
+        }
+        code.push(Expression::FunctionCall {
+            function: Expression::CallbackReference(
+                NamedReference::new(&self.element, "activated"),
+                None,
+            )
+            .into(),
+            arguments: vec![],
+            source_location: None,
+        });
+        Expression::CodeBlock(code)
+    }
+}
+
+#[derive(Default)]
+struct MenuEntries {
+    /// The number of entries of each menu, by id of the menu
+    counts: Vec<(SmolStr, usize)>,
+    entries: Vec<Entry>,
+}
+
+impl MenuEntries {
+    /// Collect the entries of the `menu` element, whose id is `menu_id` and which is shown at the given `depth`
+    fn collect(
+        &mut self,
+        menu: &ElementRc,
+        menu_id: SmolStr,
+        depth: usize,
+        parents: &mut Vec<ElementRc>,
+        diag: &mut BuildDiagnostics,
+    ) {
+        let mut children = std::mem::take(&mut menu.borrow_mut().children);
+        children.retain(|child| {
+            let child = child.borrow();
+            if child.repeated.is_some() {
+                diag.push_error(
+                    "dynamic menu entries ('if' or 'for') are currently not supported".into(),
+                    &*child,
+                );
+                false
+            } else if !matches!(child.base_type.to_string().as_str(), "Menu" | "MenuItem" | "MenuSeparator") {
+                diag.push_error(
+                    format!(
+                        "{} is not allowed within Menu. Only Menu MenuItem MenuSeparator are valid children",
+                        child.base_type
+                    ),
+                    &*child,
+                );
+                false
+            } else {
+                true
+            }
+        });
+
+        self.counts.push((menu_id.clone(), children.len()));
+        for (index, child) in children.iter().enumerate() {
+            let id = if menu_id.is_empty() {
+                format_smolstr!("{index}")
+            } else {
+                format_smolstr!("{menu_id}/{index}")
+            };
+            let kind = match child.borrow().base_type.to_string().as_str() {
+                "Menu" => EntryKind::Menu,
+                "MenuItem" => EntryKind::Item,
+                _ => EntryKind::Separator,
+            };
+            self.entries.push(Entry {
+                id: id.clone(),
+                element: child.clone(),
+                kind,
+                parents: parents.clone(),
+            });
+            if kind == EntryKind::Menu {
+                if depth >= MAX_SUB_MENU_DEPTH {
+                    diag.push_error(
+                        format!(
+                            "Menus cannot be nested more than {MAX_SUB_MENU_DEPTH} levels deep"
+                        ),
+                        &*child.borrow(),
+                    );
+                    child.borrow_mut().children.clear();
+                    continue;
+                }
+                parents.push(child.clone());
+                self.collect(child, id, depth + 1, parents, diag);
+                parents.pop();
+            }
+        }
+        menu.borrow_mut().children = children;
+    }
+
+    /// Set the `entry-count`, `entry` and `activated` callbacks of the lowered element
+    fn set_callbacks(&self, elem: &ElementRc, menu_entry_ty: &Type) {
+        let id_param = || Expression::FunctionParameterReference { index: 0, ty: Type::String };
+        let is_id = |param: Expression, id: &SmolStr| Expression::BinaryExpression {
+            lhs: param.into(),
+            rhs: Expression::StringLiteral(id.clone()).into(),
+            op: '=',
+        };
+        let chain = |branches: Vec<(Expression, Expression)>, default: Expression| {
+            branches.into_iter().rev().fold(default, |false_expr, (condition, true_expr)| {
+                Expression::Condition {
+                    condition: condition.into(),
+                    true_expr: true_expr.into(),
+                    false_expr: false_expr.into(),
+                }
+            })
+        };
+
+        let entry_count = chain(
+            self.counts
+                .iter()
+                .filter(|(_, count)| *count > 0)
+                .map(|(id, count)| {
+                    (is_id(id_param(), id), Expression::NumberLiteral(*count as _, Unit::None))
+                })
+                .collect(),
+            Expression::NumberLiteral(0., Unit::None),
+        );
+
+        let entry = chain(
+            self.entries
+                .iter()
+                .map(|e| (is_id(id_param(), &e.id), entry_struct(e, menu_entry_ty)))
+                .collect(),
+            Expression::default_value_for_type(menu_entry_ty),
+        );
+
+        let entry_id = Expression::StructFieldAccess {
+            base: Expression::FunctionParameterReference { index: 0, ty: menu_entry_ty.clone() }
+                .into(),
+            name: "id".into(),
+        };
+        let activated = chain(
+            self.entries
+                .iter()
+                .filter(|e| e.kind == EntryKind::Item)
+                .map(|e| (is_id(entry_id.clone(), &e.id), e.activation_code()))
+                .collect(),
+            Expression::CodeBlock(vec![]),
+        );
+
+        let mut elem = elem.borrow_mut();
+        elem.bindings.insert("entry-count".into(), RefCell::new(entry_count.into()));
+        elem.bindings.insert("entry".into(), RefCell::new(entry.into()));
+        elem.bindings.insert("activated".into(), RefCell::new(activated.into()));
+    }
+}
+
+fn entry_struct(entry: &Entry, menu_entry_ty: &Type) -> Expression {
+    let prop =
+        |name: &str| Expression::PropertyReference(NamedReference::new(&entry.element, name));
+    let string = |s: &'static str| Expression::StringLiteral(SmolStr::new_static(s));
+    let (title, enabled) = match entry.kind {
+        EntryKind::Separator => (string(""), Expression::BoolLiteral(false)),
+        _ => (prop("title"), prop("enabled")),
+    };
+    let (checkable, checked, shortcut) = match entry.kind {
+        EntryKind::Item => (prop("checkable"), prop("checked"), prop("shortcut")),
+        _ => (Expression::BoolLiteral(false), Expression::BoolLiteral(false), string("")),
+    };
+    let values = HashMap::from([
+        (SmolStr::new_static("title"), title),
+        (SmolStr::new_static("id"), Expression::StringLiteral(entry.id.clone())),
+        (SmolStr::new_static("enabled"), enabled),
+        (SmolStr::new_static("checkable"), checkable),
+        (SmolStr::new_static("checked"), checked),
+        (SmolStr::new_static("shortcut"), shortcut),
+        (
+            SmolStr::new_static("has-sub-menu"),
+            Expression::BoolLiteral(entry.kind == EntryKind::Menu),
+        ),
+        (
+            SmolStr::new_static("is-separator"),
+            Expression::BoolLiteral(entry.kind == EntryKind::Separator),
+        ),
+    ]);
+    Expression::Struct { ty: menu_entry_ty.clone(), values }
+}
@@ -1,7 +1,8 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-//! Passe lower the access to the global TextInputInterface.text-input-focused to getter or setter.
+//! Passe lower the access to the global TextInputInterface.text-input-focused to getter or setter,
+//! and TextInputInterface.input-type to a getter.
 
 use crate::expression_tree::{BuiltinFunction, Expression};
 use crate::namedreference::NamedReference;
@@ -11,6 +12,17 @@
 pub fn lower_text_input_interface(component: &Rc<Component>) {
     visit_all_expressions(component, |e, _| {
         e.visit_recursive_mut(&mut |e| match e {
+            Expression::PropertyReference(nr) if is_text_input_type_prop(nr) => {
+                *e = Expression::FunctionCall {
+                    function: Expression::BuiltinFunctionReference(
+                        BuiltinFunction::TextInputType,
+                        None,
+                    )
+                    .into(),
+                    arguments: vec![],
+                    source_location: None,
+                };
+            }
             Expression::PropertyReference(nr) if is_input_text_focused_prop(nr) => {
                 *e = Expression::FunctionCall {
                     function: Expression::BuiltinFunctionReference(
@@ -43,9 +55,17 @@ pub fn lower_text_input_interface(component: &Rc<Component>) {
 }
 
 fn is_input_text_focused_prop(nr: &NamedReference) -> bool {
-    if !nr.element().borrow().builtin_type().map_or(false, |bt| bt.name == "TextInputInterface") {
+    if !is_text_input_interface(nr) {
         return false;
     }
     assert_eq!(nr.name(), "text-input-focused");
     true
 }
+
+fn is_text_input_type_prop(nr: &NamedReference) -> bool {
+    is_text_input_interface(nr) && nr.name() == "input-type"
+}
+
+fn is_text_input_interface(nr: &NamedReference) -> bool {
+    nr.element().borrow().builtin_type().map_or(false, |bt| bt.name == "TextInputInterface")
+}
@@ -11,7 +11,7 @@
 use crate::diagnostics::{BuildDiagnostics, Spanned};
 use crate::expression_tree::*;
 use crate::langtype::{ElementType, Type};
-use crate::lookup::{LookupCtx, LookupObject, LookupResult};
+use crate::lookup::{LocalVariable, LookupCtx, LookupObject, LookupResult};
 use crate::object_tree::*;
 use crate::parser::{identifier_text, syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
 use crate::typeregister::TypeRegister;
@@ -41,6 +41,7 @@ fn resolve_expression(
             component_scope: &scope.0,
             diag,
             arguments: vec![],
+            local_variables: vec![],
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
@@ -175,14 +176,33 @@ pub fn from_binding_expression_node(node: SyntaxNode, ctx: &mut LookupCtx) -> Se
     fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Expression {
         debug_assert_eq!(node.kind(), SyntaxKind::CodeBlock);
 
-        let mut statements_or_exprs = node
+        ctx.local_variables.push(Vec::new());
+        let (mut statements_or_exprs, nodes): (Vec<_>, Vec<_>) = node
             .children()
-            .filter_map(|n| match n.kind() {
-                SyntaxKind::Expression => Some(Self::from_expression_node(n.into(), ctx)),
-                SyntaxKind::ReturnStatement => Some(Self::from_return_statement(n.into(), ctx)),
-                _ => None,
+            .filter_map(|n| {
+                let e = match n.kind() {
+                    SyntaxKind::Expression => Self::from_expression_node(n.clone().into(), ctx),
+                    SyntaxKind::ReturnStatement => {
+                        Self::from_return_statement(n.clone().into(), ctx)
+                    }
+                    SyntaxKind::LetStatement => Self::from_let_statement(n.clone().into(), ctx),
+                    SyntaxKind::ForStatement => Self::from_for_statement(n.clone().into(), ctx),
+                    SyntaxKind::WhileStatement => Self::from_while_statement(n.clone().into(), ctx),
+                    _ => return None,
+                };
+                Some((e, n))
             })
-            .collect::<Vec<_>>();
+            .unzip();
+        let variables = ctx.local_variables.pop().unwrap_or_default();
+
+        // Each let statement declared one variable of the scope, in order
+        let declarations = nodes
+            .iter()
+            .filter_map(|n| syntax_nodes::LetStatement::new(n.clone()))
+            .map(|n| n.DeclaredIdentifier().into());
+        for (var, declaration) in variables.iter().zip(declarations) {
+            Self::check_local_variable_used(var, &declaration, ctx);
+        }
 
         let exit_points_and_return_types = statements_or_exprs
             .iter()
@@ -211,6 +231,127 @@ fn from_codeblock_node(node: syntax_nodes::CodeBlock, ctx: &mut LookupCtx) -> Ex
         Expression::CodeBlock(statements_or_exprs)
     }
 
+    /// Register a new local variable in the innermost scope, after checking that it doesn't
+    /// shadow another local variable or an argument.
+    fn declare_local_variable(name: SmolStr, ty: Type, node: &SyntaxNode, ctx: &mut LookupCtx) {
+        if ctx.local_variables.iter().flatten().any(|var| var.name == name) {
+            ctx.diag.push_error(format!("Redeclaration of local variable '{name}'"), node);
+        } else if ctx.arguments.contains(&name) {
+            ctx.diag.push_error(
+                format!("Local variable '{name}' shadows an argument with the same name"),
+                node,
+            );
+        }
+        ctx.local_variables
+            .last_mut()
+            .expect("local variable declared outside of a code block")
+            .push(LocalVariable { name, ty, used: Default::default() });
+    }
+
+    /// Warn about a local variable that no expression refers to. Prefixing the name with an
+    /// underscore silences the warning.
+    fn check_local_variable_used(
+        var: &LocalVariable,
+        declaration: &SyntaxNode,
+        ctx: &mut LookupCtx,
+    ) {
+        if !var.used.get() && !var.name.starts_with('-') {
+            ctx.diag.push_warning(format!("Unused local variable '{}'", var.name), declaration);
+        }
+    }
+
+    fn from_let_statement(node: syntax_nodes::LetStatement, ctx: &mut LookupCtx) -> Expression {
+        let name = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        let value = Self::from_expression_node(node.Expression(), ctx);
+        let value = match node.Type() {
+            Some(ty_node) => {
+                let ty = type_from_node(ty_node, ctx.diag, ctx.type_register);
+                value.maybe_convert_to(ty, &node, ctx.diag)
+            }
+            None => value,
+        };
+        let ty = value.ty();
+        if ty != Type::Invalid && (!ty.is_property_type() || ty == Type::ElementReference) {
+            ctx.diag.push_error(
+                format!("Cannot store a value of type '{ty}' in a local variable"),
+                &node.Expression(),
+            );
+        }
+        Self::declare_local_variable(name.clone(), ty, &node.DeclaredIdentifier(), ctx);
+        Expression::StoreLocalVariable {
+            name: crate::lookup::local_variable_name(&name),
+            value: Box::new(value),
+        }
+    }
+
+    fn from_for_statement(node: syntax_nodes::ForStatement, ctx: &mut LookupCtx) -> Expression {
+        let model = Self::from_expression_node(node.Expression(), ctx);
+        let (model, model_data_ty) = match model.ty() {
+            Type::Float32 | Type::Int32 => {
+                (model.maybe_convert_to(Type::Int32, &node.Expression(), ctx.diag), Type::Int32)
+            }
+            Type::Array(ty) => (model, *ty),
+            Type::Invalid => (model, Type::Invalid),
+            ty => {
+                ctx.diag.push_error(
+                    format!("Cannot iterate over a value of type '{ty}'"),
+                    &node.Expression(),
+                );
+                (Expression::Invalid, Type::Invalid)
+            }
+        };
+        Self::check_no_return_in_loop(&node, ctx);
+
+        let model_data = identifier_text(&node.DeclaredIdentifier()).unwrap_or_default();
+        let index = node.RepeatedIndex().map(|n| identifier_text(&n).unwrap_or_default());
+        ctx.local_variables.push(Vec::new());
+        Self::declare_local_variable(
+            model_data.clone(),
+            model_data_ty,
+            &node.DeclaredIdentifier(),
+            ctx,
+        );
+        if let Some(index) = &index {
+            let index_node = node.RepeatedIndex().unwrap();
+            Self::declare_local_variable(index.clone(), Type::Int32, &index_node, ctx);
+        }
+        let body = Self::from_codeblock_node(node.CodeBlock(), ctx);
+        let variables = ctx.local_variables.pop().unwrap_or_default();
+        let declarations = std::iter::once(node.DeclaredIdentifier().into())
+            .chain(node.RepeatedIndex().map(Into::into));
+        for (var, declaration) in variables.iter().zip(declarations) {
+            Self::check_local_variable_used(var, &declaration, ctx);
+        }
+
+        Expression::ForLoop {
+            model_data: crate::lookup::local_variable_name(&model_data),
+            index: index.map(|i| crate::lookup::local_variable_name(&i)),
+            model: Box::new(model),
+            body: Box::new(body),
+        }
+    }
+
+    fn from_while_statement(node: syntax_nodes::WhileStatement, ctx: &mut LookupCtx) -> Expression {
+        let condition = Self::from_expression_node(node.Expression(), ctx).maybe_convert_to(
+            Type::Bool,
+            &node.Expression(),
+            ctx.diag,
+        );
+        Self::check_no_return_in_loop(&node, ctx);
+        let body = Self::from_codeblock_node(node.CodeBlock(), ctx);
+        Expression::WhileLoop { condition: Box::new(condition), body: Box::new(body) }
+    }
+
+    /// The remove_return pass cannot lower a `return` that happens within a loop
+    fn check_no_return_in_loop(node: &SyntaxNode, ctx: &mut LookupCtx) {
+        for ret in node.node.descendants().filter(|n| n.kind() == SyntaxKind::ReturnStatement) {
+            ctx.diag.push_error(
+                "'return' is not supported within a loop".into(),
+                &SyntaxNode { node: ret, source_file: node.source_file.clone() },
+            );
+        }
+    }
+
     fn from_return_statement(
         node: syntax_nodes::ReturnStatement,
         ctx: &mut LookupCtx,
@@ -411,15 +552,43 @@ fn from_at_image_url_node(node: syntax_nodes::AtImageUrl, ctx: &mut LookupCtx) -
     fn from_at_gradient(node: syntax_nodes::AtGradient, ctx: &mut LookupCtx) -> Self {
         enum GradKind {
             Linear { angle: Box<Expression> },
-            Radial,
+            Radial { center: (Box<Expression>, Box<Expression>), radius: Box<Expression> },
+            Conic { from_angle: Box<Expression>, center: (Box<Expression>, Box<Expression>) },
         }
 
         let mut subs = node
             .children_with_tokens()
-            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression));
+            .filter(|n| matches!(n.kind(), SyntaxKind::Comma | SyntaxKind::Expression))
+            .peekable();
 
         let grad_token = node.child_token(SyntaxKind::Identifier).unwrap();
-        let grad_text = grad_token.text();
+        let (spread, grad_text) = GradientSpread::from_gradient_name(grad_token.text());
+
+        let is_keyword = |n: Option<&NodeOrToken>, keyword: &str| matches!(n, Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == keyword);
+        let default_center = || {
+            (
+                Box::new(Expression::NumberLiteral(0.5, Unit::None)),
+                Box::new(Expression::NumberLiteral(0.5, Unit::None)),
+            )
+        };
+        // Parses the two positions of the center that follow `at`
+        let parse_center = |subs: &mut dyn Iterator<Item = NodeOrToken>, ctx: &mut LookupCtx| {
+            let mut coordinate = || match subs.next() {
+                Some(NodeOrToken::Node(n)) if n.kind() == SyntaxKind::Expression => Some(Box::new(
+                    Expression::from_expression_node(n.clone().into(), ctx).maybe_convert_to(
+                        Type::Float32,
+                        &n,
+                        ctx.diag,
+                    ),
+                )),
+                _ => None,
+            };
+            let center = coordinate().zip(coordinate());
+            if center.is_none() {
+                ctx.diag.push_error("Expected two positions after 'at'".into(), &node);
+            }
+            center
+        };
 
         let grad_kind = if grad_text.starts_with("linear") {
             let angle_expr = match subs.next() {
@@ -447,16 +616,31 @@ enum GradKind {
             );
             GradKind::Linear { angle }
         } else if grad_text.starts_with("radial") {
-            if !matches!(subs.next(), Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == "circle")
-            {
+            if !is_keyword(subs.next().as_ref(), "circle") {
                 ctx.diag.push_error("Expected 'circle': currently, only @radial-gradient(circle, ...) are supported".into(), &node);
                 return Expression::Invalid;
             }
-            let comma = subs.next();
-            if matches!(&comma, Some(NodeOrToken::Node(n)) if n.text().to_string().trim() == "at") {
-                ctx.diag.push_error("'at' in @radial-gradient is not yet supported".into(), &comma);
-                return Expression::Invalid;
+            let mut radius = Box::new(Expression::NumberLiteral(0., Unit::Px));
+            if !is_keyword(subs.peek(), "at") {
+                if let Some(NodeOrToken::Node(n)) = subs.next_if(|n| n.kind() != SyntaxKind::Comma)
+                {
+                    radius =
+                        Box::new(
+                            Expression::from_expression_node(n.clone().into(), ctx)
+                                .maybe_convert_to(Type::LogicalLength, &n, ctx.diag),
+                        );
+                }
             }
+            let center = if is_keyword(subs.peek(), "at") {
+                subs.next();
+                match parse_center(&mut subs, ctx) {
+                    Some(center) => center,
+                    None => return Expression::Invalid,
+                }
+            } else {
+                default_center()
+            };
+            let comma = subs.next();
             if comma.as_ref().map_or(false, |s| s.kind() != SyntaxKind::Comma) {
                 ctx.diag.push_error(
                     "'circle' must be followed by a comma".into(),
@@ -464,9 +648,49 @@ enum GradKind {
                 );
                 return Expression::Invalid;
             }
-            GradKind::Radial
+            GradKind::Radial { center, radius }
+        } else if grad_text.starts_with("conic") {
+            let mut has_header = false;
+            let mut from_angle = Box::new(Expression::NumberLiteral(0., Unit::Deg));
+            if is_keyword(subs.peek(), "from") {
+                subs.next();
+                has_header = true;
+                match subs.next() {
+                    Some(NodeOrToken::Node(n)) if n.kind() == SyntaxKind::Expression => {
+                        from_angle = Box::new(
+                            Expression::from_expression_node(n.clone().into(), ctx)
+                                .maybe_convert_to(Type::Angle, &n, ctx.diag),
+                        );
+                    }
+                    _ => {
+                        ctx.diag.push_error("Expected angle expression after 'from'".into(), &node);
+                        return Expression::Invalid;
+                    }
+                }
+            }
+            let center = if is_keyword(subs.peek(), "at") {
+                subs.next();
+                has_header = true;
+                match parse_center(&mut subs, ctx) {
+                    Some(center) => center,
+                    None => return Expression::Invalid,
+                }
+            } else {
+                default_center()
+            };
+            if has_header {
+                let comma = subs.next();
+                if comma.as_ref().is_some_and(|s| s.kind() != SyntaxKind::Comma) {
+                    ctx.diag.push_error(
+                        "'from' and 'at' must be followed by a comma".into(),
+                        comma.as_ref().map_or(&node, |x| x as &dyn Spanned),
+                    );
+                    return Expression::Invalid;
+                }
+            }
+            GradKind::Conic { from_angle, center }
         } else {
-            // Parser should have ensured we have one of the linear or radial gradient
+            // Parser should have ensured we have one of the linear, radial or conic gradient
             panic!("Not a gradient {grad_text:?}");
         };
 
@@ -512,6 +736,18 @@ enum Stop {
                         break;
                     }
                     Stop::Color(col) => {
+                        // The stops of conic gradients can also be angles
+                        let e = if matches!(grad_kind, GradKind::Conic { .. })
+                            && e.ty() == Type::Angle
+                        {
+                            Expression::BinaryExpression {
+                                lhs: Box::new(e),
+                                rhs: Box::new(Expression::NumberLiteral(360., Unit::Deg)),
+                                op: '/',
+                            }
+                        } else {
+                            e
+                        };
                         stops.push((col, e.maybe_convert_to(Type::Float32, &n, ctx.diag)))
                     }
                 }
@@ -570,8 +806,13 @@ enum Stop {
         }
 
         match grad_kind {
-            GradKind::Linear { angle } => Expression::LinearGradient { angle, stops },
-            GradKind::Radial => Expression::RadialGradient { stops },
+            GradKind::Linear { angle } => Expression::LinearGradient { angle, stops, spread },
+            GradKind::Radial { center: (center_x, center_y), radius } => {
+                Expression::RadialGradient { center_x, center_y, radius, stops, spread }
+            }
+            GradKind::Conic { from_angle, center: (center_x, center_y) } => {
+                Expression::ConicGradient { from_angle, center_x, center_y, stops, spread }
+            }
         }
     }
 
@@ -1544,6 +1785,7 @@ fn resolve_two_way_bindings(
                             component_scope: &new_scope.0,
                             diag,
                             arguments: vec![],
+                            local_variables: vec![],
                             type_register,
                             type_loader: None,
                             current_token: Some(node.clone().into()),
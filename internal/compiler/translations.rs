@@ -0,0 +1,400 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Support for bundling the translations from `.po` files directly in the generated code,
+//! so that no gettext runtime is needed to translate the `@tr(...)` strings.
+
+use crate::llr::Expression;
+use smol_str::SmolStr;
+use std::collections::HashMap;
+
+#[cfg(feature = "extract-translations")]
+pub mod extract;
+
+/// The translations that are embedded in the generated code
+#[derive(Clone, Debug, Default)]
+pub struct Translations {
+    /// For each string without plural form (the index is stored in [`Expression::TranslationReference`]),
+    /// the translation in each language. The language at index 0 is the original string.
+    pub strings: Vec<Vec<Option<SmolStr>>>,
+    /// For each string with a plural form (the index is stored in [`Expression::TranslationReference`]),
+    /// all the forms in each language. The language at index 0 is the original singular and plural.
+    pub plurals: Vec<Vec<Option<Vec<SmolStr>>>>,
+    /// The rule that selects the plural form for each language.
+    /// The first one is for the original language, and is `None` (english rule)
+    pub plural_rules: Vec<Option<PluralRule>>,
+    /// The name of each language. The first one is the original and is empty
+    pub languages: Vec<SmolStr>,
+}
+
+/// The expression of a `Plural-Forms` header, which computes the plural form from `n`.
+/// This uses the semantic of the C language: comparisons evaluate to 0 or 1
+/// and any non-zero value is true.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PluralRule {
+    /// The `n` variable
+    N,
+    Number(i32),
+    UnaryOp {
+        sub: Box<PluralRule>,
+        /// '!' or '-'
+        op: char,
+    },
+    BinaryOp {
+        lhs: Box<PluralRule>,
+        rhs: Box<PluralRule>,
+        /// One of `+ - * / % == != < > <= >= && ||`
+        op: &'static str,
+    },
+    Condition {
+        condition: Box<PluralRule>,
+        true_expr: Box<PluralRule>,
+        false_expr: Box<PluralRule>,
+    },
+}
+
+impl PluralRule {
+    /// Parse the expression from a `plural=...` rule
+    pub fn parse(rule: &str) -> Result<Self, String> {
+        let mut parser = PluralRuleParser { input: rule.as_bytes(), pos: 0 };
+        let result = parser.parse_condition()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.input.len() {
+            return Err(format!("Unexpected '{}' in plural rule", &rule[parser.pos..]));
+        }
+        Ok(result)
+    }
+
+    /// Evaluate the rule for the given value of `n`
+    pub fn eval(&self, n: i32) -> i32 {
+        match self {
+            PluralRule::N => n,
+            PluralRule::Number(x) => *x,
+            PluralRule::UnaryOp { sub, op: '-' } => sub.eval(n).wrapping_neg(),
+            PluralRule::UnaryOp { sub, .. } => (sub.eval(n) == 0) as i32,
+            PluralRule::BinaryOp { lhs, rhs, op } => {
+                let (lhs, rhs) = (lhs.eval(n), rhs.eval(n));
+                match *op {
+                    "+" => lhs.wrapping_add(rhs),
+                    "-" => lhs.wrapping_sub(rhs),
+                    "*" => lhs.wrapping_mul(rhs),
+                    "/" => lhs.checked_div(rhs).unwrap_or(0),
+                    "%" => lhs.checked_rem(rhs).unwrap_or(0),
+                    "==" => (lhs == rhs) as i32,
+                    "!=" => (lhs != rhs) as i32,
+                    "<" => (lhs < rhs) as i32,
+                    ">" => (lhs > rhs) as i32,
+                    "<=" => (lhs <= rhs) as i32,
+                    ">=" => (lhs >= rhs) as i32,
+                    "&&" => (lhs != 0 && rhs != 0) as i32,
+                    "||" => (lhs != 0 || rhs != 0) as i32,
+                    _ => unreachable!("invalid operator {op}"),
+                }
+            }
+            PluralRule::Condition { condition, true_expr, false_expr } => {
+                if condition.eval(n) != 0 {
+                    true_expr.eval(n)
+                } else {
+                    false_expr.eval(n)
+                }
+            }
+        }
+    }
+}
+
+struct PluralRuleParser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl PluralRuleParser<'_> {
+    /// The binary operators, from the lowest to the highest precedence
+    const BINARY_OPERATORS: &'static [&'static [&'static str]] =
+        &[&["||"], &["&&"], &["==", "!="], &["<=", ">=", "<", ">"], &["+", "-"], &["*", "/", "%"]];
+
+    fn skip_whitespace(&mut self) {
+        while self.input.get(self.pos).is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.input[self.pos..].starts_with(token.as_bytes()) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_condition(&mut self) -> Result<PluralRule, String> {
+        let condition = self.parse_binary(0)?;
+        if !self.eat("?") {
+            return Ok(condition);
+        }
+        let true_expr = self.parse_condition()?;
+        if !self.eat(":") {
+            return Err("Expected ':' in plural rule".into());
+        }
+        let false_expr = self.parse_condition()?;
+        Ok(PluralRule::Condition {
+            condition: Box::new(condition),
+            true_expr: Box::new(true_expr),
+            false_expr: Box::new(false_expr),
+        })
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Result<PluralRule, String> {
+        let Some(operators) = Self::BINARY_OPERATORS.get(level) else {
+            return self.parse_unary();
+        };
+        let mut lhs = self.parse_binary(level + 1)?;
+        'outer: loop {
+            for op in operators.iter() {
+                // Don't mistake `!=` for `!` or `<=` for `<`, and so on
+                let save = self.pos;
+                if self.eat(op) {
+                    if op.len() == 1 && self.input.get(self.pos) == Some(&b'=') {
+                        self.pos = save;
+                        continue;
+                    }
+                    let rhs = self.parse_binary(level + 1)?;
+                    lhs = PluralRule::BinaryOp { lhs: Box::new(lhs), rhs: Box::new(rhs), op };
+                    continue 'outer;
+                }
+            }
+            return Ok(lhs);
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<PluralRule, String> {
+        self.skip_whitespace();
+        match self.input.get(self.pos) {
+            Some(b'!') | Some(b'-') => {
+                let op = self.input[self.pos] as char;
+                self.pos += 1;
+                Ok(PluralRule::UnaryOp { sub: Box::new(self.parse_unary()?), op })
+            }
+            Some(b'(') => {
+                self.pos += 1;
+                let result = self.parse_condition()?;
+                if !self.eat(")") {
+                    return Err("Expected ')' in plural rule".into());
+                }
+                Ok(result)
+            }
+            Some(b'n') => {
+                self.pos += 1;
+                Ok(PluralRule::N)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.pos;
+                while self.input.get(self.pos).is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                let number = std::str::from_utf8(&self.input[start..self.pos]).unwrap();
+                number
+                    .parse()
+                    .map(PluralRule::Number)
+                    .map_err(|_| format!("Invalid number '{number}' in plural rule"))
+            }
+            Some(c) => Err(format!("Unexpected '{}' in plural rule", *c as char)),
+            None => Err("Unexpected end of plural rule".into()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(not(feature = "bundle-translations"), allow(dead_code))]
+enum TranslatedMessage {
+    Singular(SmolStr),
+    Plural(Vec<SmolStr>),
+}
+
+/// The translations loaded from the `.po` files, used while lowering the `@tr(...)` calls
+#[derive(Clone, Debug, Default)]
+pub struct TranslationsBuilder {
+    result: Translations,
+    /// For each language, the translated messages indexed by (context, msgid)
+    catalogs: Vec<HashMap<(SmolStr, SmolStr), TranslatedMessage>>,
+    /// Maps (context, original) to the index in `result.strings`
+    strings_map: HashMap<(SmolStr, SmolStr), usize>,
+    /// Maps (context, original, plural) to the index in `result.plurals`
+    plurals_map: HashMap<(SmolStr, SmolStr, SmolStr), usize>,
+}
+
+impl TranslationsBuilder {
+    /// Load all the `<lang>/LC_MESSAGES/<domain>.po` files from the given directory.
+    ///
+    /// If there is no domain, any `.po` file found in the `LC_MESSAGES` directory is loaded.
+    /// The files that were loaded are added to the `loaded_files`.
+    #[cfg(feature = "bundle-translations")]
+    pub fn load_translations(
+        path: &std::path::Path,
+        domain: Option<&str>,
+        loaded_files: &mut Vec<std::path::PathBuf>,
+    ) -> Result<Self, String> {
+        let mut dirs = std::fs::read_dir(path)
+            .map_err(|e| format!("Cannot read the translations in {}: {e}", path.display()))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.path())
+            .collect::<Vec<_>>();
+        // The order of the languages must be stable from one build to the other
+        dirs.sort();
+
+        let mut builder = Self::default();
+        builder.result.languages.push(SmolStr::default());
+        builder.result.plural_rules.push(None);
+        for dir in dirs {
+            let Some(language) = dir.file_name().and_then(|x| x.to_str()) else { continue };
+            let messages_dir = dir.join("LC_MESSAGES");
+            let po_file = match domain {
+                Some(domain) => Some(messages_dir.join(format!("{domain}.po"))),
+                None => std::fs::read_dir(&messages_dir)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|p| p.extension().is_some_and(|ext| ext == "po"))
+                    .min(),
+            };
+            let Some(po_file) = po_file.filter(|p| p.is_file()) else { continue };
+
+            let catalog = polib::po_file::parse(&po_file)
+                .map_err(|e| format!("Error parsing {}: {e}", po_file.display()))?;
+            let plural_rule = PluralRule::parse(&catalog.metadata.plural_rules.expr)
+                .map_err(|e| format!("Error parsing {}: {e}", po_file.display()))?;
+
+            let mut messages = HashMap::new();
+            for message in catalog.messages() {
+                if !message.is_translated() || message.is_fuzzy() {
+                    continue;
+                }
+                let translated = if message.is_plural() {
+                    TranslatedMessage::Plural(
+                        message.msgstr_plural().unwrap().iter().map(SmolStr::from).collect(),
+                    )
+                } else {
+                    TranslatedMessage::Singular(message.msgstr().unwrap().into())
+                };
+                messages.insert((message.msgctxt().into(), message.msgid().into()), translated);
+            }
+
+            loaded_files.push(po_file);
+            builder.catalogs.push(messages);
+            builder.result.languages.push(language.into());
+            builder.result.plural_rules.push(Some(plural_rule));
+        }
+        Ok(builder)
+    }
+
+    /// Lower the arguments of a `BuiltinFunction::Translate` call to a [`Expression::TranslationReference`].
+    ///
+    /// Returns the arguments back if they cannot be looked up at compile time.
+    pub fn lower_translate_call(
+        &mut self,
+        args: Vec<Expression>,
+    ) -> Result<Expression, Vec<Expression>> {
+        let [Expression::StringLiteral(_), Expression::StringLiteral(_), _, _, _, Expression::StringLiteral(_)] =
+            args.as_slice()
+        else {
+            return Err(args);
+        };
+        let [Expression::StringLiteral(original), Expression::StringLiteral(context), _domain, format_args, n, Expression::StringLiteral(plural)] =
+            <[Expression; 6]>::try_from(args).unwrap()
+        else {
+            unreachable!()
+        };
+
+        if plural.is_empty() {
+            let key = (context, original);
+            let string_index = match self.strings_map.get(&key) {
+                Some(index) => *index,
+                None => {
+                    let translations = std::iter::once(Some(key.1.clone()))
+                        .chain(self.catalogs.iter().map(|catalog| match catalog.get(&key) {
+                            Some(TranslatedMessage::Singular(s)) => Some(s.clone()),
+                            _ => None,
+                        }))
+                        .collect();
+                    self.result.strings.push(translations);
+                    self.strings_map.insert(key, self.result.strings.len() - 1);
+                    self.result.strings.len() - 1
+                }
+            };
+            Ok(Expression::TranslationReference {
+                format_args: Box::new(format_args),
+                string_index,
+                plural: None,
+            })
+        } else {
+            let key = (context, original, plural);
+            let string_index = match self.plurals_map.get(&key) {
+                Some(index) => *index,
+                None => {
+                    let lookup_key = (key.0.clone(), key.1.clone());
+                    let translations = std::iter::once(Some(vec![key.1.clone(), key.2.clone()]))
+                        .chain(self.catalogs.iter().map(|catalog| match catalog.get(&lookup_key) {
+                            Some(TranslatedMessage::Plural(forms)) => Some(forms.clone()),
+                            _ => None,
+                        }))
+                        .collect();
+                    self.result.plurals.push(translations);
+                    self.plurals_map.insert(key, self.result.plurals.len() - 1);
+                    self.result.plurals.len() - 1
+                }
+            };
+            Ok(Expression::TranslationReference {
+                format_args: Box::new(format_args),
+                string_index,
+                plural: Some(Box::new(n)),
+            })
+        }
+    }
+
+    pub fn result(self) -> Translations {
+        self.result
+    }
+}
+
+#[test]
+fn parse_plural_rules() {
+    let english = PluralRule::parse("(n != 1)").unwrap();
+    assert_eq!(english.eval(0), 1);
+    assert_eq!(english.eval(1), 0);
+    assert_eq!(english.eval(2), 1);
+
+    let french = PluralRule::parse("n > 1").unwrap();
+    assert_eq!(french.eval(0), 0);
+    assert_eq!(french.eval(1), 0);
+    assert_eq!(french.eval(2), 1);
+
+    let polish =
+        PluralRule::parse("(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<10 || n%100>=20) ? 1 : 2)")
+            .unwrap();
+    assert_eq!(polish.eval(1), 0);
+    assert_eq!(polish.eval(3), 1);
+    assert_eq!(polish.eval(5), 2);
+    assert_eq!(polish.eval(13), 2);
+    assert_eq!(polish.eval(22), 1);
+    assert_eq!(polish.eval(112), 2);
+
+    let arabic = PluralRule::parse(
+        "n==0 ? 0 : n==1 ? 1 : n==2 ? 2 : n%100>=3 && n%100<=10 ? 3 : n%100>=11 ? 4 : 5",
+    )
+    .unwrap();
+    assert_eq!(arabic.eval(0), 0);
+    assert_eq!(arabic.eval(2), 2);
+    assert_eq!(arabic.eval(105), 3);
+    assert_eq!(arabic.eval(111), 4);
+    assert_eq!(arabic.eval(100), 5);
+
+    assert_eq!(PluralRule::parse("0").unwrap(), PluralRule::Number(0));
+    assert_eq!(PluralRule::parse("!n").unwrap().eval(0), 1);
+    assert!(PluralRule::parse("n +").is_err());
+    assert!(PluralRule::parse("(n != 1").is_err());
+    assert!(PluralRule::parse("n = 1").is_err());
+    assert!(PluralRule::parse("x").is_err());
+}
@@ -254,6 +254,7 @@ fn snapshot_document(&mut self, document: &object_tree::Document) -> object_tree
             exports,
             embedded_file_resources: document.embedded_file_resources.clone(),
             used_types: RefCell::new(self.snapshot_used_sub_types(&document.used_types.borrow())),
+            translation_builder: document.translation_builder.clone(),
         }
     }
 
@@ -688,6 +689,16 @@ fn snapshot_expression(
             Expression::CodeBlock(exprs) => {
                 Expression::CodeBlock(exprs.iter().map(|e| self.snapshot_expression(e)).collect())
             }
+            Expression::ForLoop { model_data, index, model, body } => Expression::ForLoop {
+                model_data: model_data.clone(),
+                index: index.clone(),
+                model: Box::new(self.snapshot_expression(model)),
+                body: Box::new(self.snapshot_expression(body)),
+            },
+            Expression::WhileLoop { condition, body } => Expression::WhileLoop {
+                condition: Box::new(self.snapshot_expression(condition)),
+                body: Box::new(self.snapshot_expression(body)),
+            },
             Expression::FunctionCall { function, arguments, source_location } => {
                 Expression::FunctionCall {
                     function: Box::new(self.snapshot_expression(function)),
@@ -756,19 +767,42 @@ fn snapshot_expression(
                     expression_tree::Path::Commands(Box::new(self.snapshot_expression(ex)))
                 }
             }),
-            Expression::LinearGradient { angle, stops } => Expression::LinearGradient {
+            Expression::LinearGradient { angle, stops, spread } => Expression::LinearGradient {
                 angle: Box::new(self.snapshot_expression(angle)),
                 stops: stops
                     .iter()
                     .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
                     .collect(),
+                spread: *spread,
             },
-            Expression::RadialGradient { stops } => Expression::RadialGradient {
-                stops: stops
-                    .iter()
-                    .map(|(e1, e2)| (self.snapshot_expression(e1), self.snapshot_expression(e2)))
-                    .collect(),
-            },
+            Expression::RadialGradient { center_x, center_y, radius, stops, spread } => {
+                Expression::RadialGradient {
+                    center_x: Box::new(self.snapshot_expression(center_x)),
+                    center_y: Box::new(self.snapshot_expression(center_y)),
+                    radius: Box::new(self.snapshot_expression(radius)),
+                    stops: stops
+                        .iter()
+                        .map(|(e1, e2)| {
+                            (self.snapshot_expression(e1), self.snapshot_expression(e2))
+                        })
+                        .collect(),
+                    spread: *spread,
+                }
+            }
+            Expression::ConicGradient { from_angle, center_x, center_y, stops, spread } => {
+                Expression::ConicGradient {
+                    from_angle: Box::new(self.snapshot_expression(from_angle)),
+                    center_x: Box::new(self.snapshot_expression(center_x)),
+                    center_y: Box::new(self.snapshot_expression(center_y)),
+                    stops: stops
+                        .iter()
+                        .map(|(e1, e2)| {
+                            (self.snapshot_expression(e1), self.snapshot_expression(e2))
+                        })
+                        .collect(),
+                    spread: *spread,
+                }
+            }
             Expression::ReturnStatement(expr) => Expression::ReturnStatement(
                 expr.as_ref().map(|e| Box::new(self.snapshot_expression(e))),
             ),
@@ -109,6 +109,9 @@ pub fn reserved_accessibility_properties() -> impl Iterator<Item = (&'static str
         ("accessible-delegate-focus", Type::Int32),
         ("accessible-description", Type::String),
         ("accessible-enabled", Type::Bool),
+        ("accessible-expandable", Type::Bool),
+        ("accessible-expanded", Type::Bool),
+        ("accessible-keyboard-shortcut", Type::String),
         ("accessible-label", Type::String),
         ("accessible-value", Type::String),
         ("accessible-value-maximum", Type::Float32),
@@ -309,6 +312,7 @@ macro_rules! map_type {
             ($pub_type:ident, SharedString) => { Type::String };
             ($pub_type:ident, Coord) => { Type::LogicalLength };
             ($pub_type:ident, KeyboardModifiers) => { $pub_type.clone() };
+            ($pub_type:ident, LogicalPosition) => { logical_point_type() };
             ($pub_type:ident, $_:ident) => {
                 BUILTIN_ENUMS.with(|e| Type::Enumeration(e.$pub_type.clone()))
             };
@@ -20,6 +20,9 @@ pub enum AccessibleStringProperty {
     DelegateFocus,
     Description,
     Enabled,
+    Expandable,
+    Expanded,
+    KeyboardShortcut,
     Label,
     PlaceholderText,
     Selectable,
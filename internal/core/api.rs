@@ -12,6 +12,7 @@
 use crate::graphics::{Rgba8Pixel, SharedPixelBuffer};
 use crate::input::{KeyEventType, MouseEvent};
 use crate::item_tree::ItemTreeVTable;
+use crate::items::PointerEventKind;
 use crate::window::{WindowAdapter, WindowInner};
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
@@ -585,6 +586,9 @@ pub fn set_minimized(&self, minimized: bool) {
     /// the top left corner of the window.
     // TODO: Return a Result<(), PlatformError>
     pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
+        if let Some(internal) = self.0.window_adapter().internal(crate::InternalToken) {
+            internal.event_dispatched(&event);
+        }
         match event {
             crate::platform::WindowEvent::PointerPressed { position, button } => {
                 self.0.process_mouse_input(MouseEvent::Pressed {
@@ -615,6 +619,34 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
             crate::platform::WindowEvent::PointerExited => {
                 self.0.process_mouse_input(MouseEvent::Exit)
             }
+            crate::platform::WindowEvent::TouchPressed { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    PointerEventKind::Down,
+                );
+            }
+            crate::platform::WindowEvent::TouchMoved { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    PointerEventKind::Move,
+                );
+            }
+            crate::platform::WindowEvent::TouchReleased { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    PointerEventKind::Up,
+                );
+            }
+            crate::platform::WindowEvent::TouchCancelled { touch_id, position } => {
+                self.0.process_touch_input(
+                    touch_id,
+                    position.to_euclid().cast(),
+                    PointerEventKind::Cancel,
+                );
+            }
 
             crate::platform::WindowEvent::KeyPressed { text } => {
                 self.0.process_key_input(crate::input::KeyEvent {
@@ -656,6 +688,31 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                 }
             }
             crate::platform::WindowEvent::WindowActiveChanged(bool) => self.0.set_active(bool),
+            crate::platform::WindowEvent::ImePreedit { text, selection_start, selection_end } => {
+                self.0.process_key_input(crate::input::KeyEvent {
+                    event_type: KeyEventType::UpdateComposition,
+                    preedit_text: text,
+                    preedit_selection: Some(selection_start as i32..selection_end as i32),
+                    ..Default::default()
+                })
+            }
+            crate::platform::WindowEvent::ImeCommit { text } => {
+                self.0.process_key_input(crate::input::KeyEvent {
+                    text,
+                    event_type: KeyEventType::CommitComposition,
+                    ..Default::default()
+                })
+            }
+            crate::platform::WindowEvent::ImeDeleteSurrounding { before_length, after_length } => {
+                self.0.process_key_input(crate::input::KeyEvent {
+                    event_type: KeyEventType::CommitComposition,
+                    replacement_range: Some(
+                        -i32::try_from(before_length).unwrap_or(i32::MAX)
+                            ..i32::try_from(after_length).unwrap_or(i32::MAX),
+                    ),
+                    ..Default::default()
+                })
+            }
         }
     }
 
@@ -699,6 +756,20 @@ pub fn window_handle(&self) -> WindowHandle {
     pub fn take_snapshot(&self) -> Result<SharedPixelBuffer<Rgba8Pixel>, PlatformError> {
         self.0.window_adapter().renderer().take_snapshot()
     }
+
+    /// Renders the window contents as a SVG document and returns it as a string.
+    ///
+    /// Unlike [`Self::take_snapshot()`], the result is a vector image: texts are kept as text, and paths,
+    /// gradients, and clips are mapped to the corresponding SVG elements. Only the images are embedded
+    /// as bitmaps. The text layout is computed with the font metrics of the window's renderer, which
+    /// makes this function also usable in headless mode, for example with the testing backend.
+    ///
+    /// The popups that are shown within the window are included. Effects that have no
+    /// equivalent in SVG, such as the native widgets of the Qt style, are embedded as bitmaps.
+    #[cfg(feature = "std")]
+    pub fn export_svg(&self) -> String {
+        crate::svg_renderer::render_window_to_svg(&self.0)
+    }
 }
 
 pub use crate::SharedString;
@@ -8,8 +8,10 @@
 use super::Color;
 use crate::properties::InterpolatedPropertyValue;
 use crate::SharedVector;
-use euclid::default::{Point2D, Size2D};
+use euclid::default::{Point2D, Size2D, Vector2D};
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
 use num_traits::float::Float;
 
@@ -26,9 +28,12 @@ pub enum Brush {
     /// The linear gradient variant of a brush describes the gradient stops for a fill
     /// where all color stops are along a line that's rotated by the specified angle.
     LinearGradient(LinearGradientBrush),
-    /// The radial gradient variant of a brush describes a circle variant centered
-    /// in the middle
+    /// The radial gradient variant of a brush describes a circle variant, centered
+    /// in the middle by default
     RadialGradient(RadialGradientBrush),
+    /// The conic gradient variant of a brush describes the gradient stops for a fill
+    /// where the colors sweep around a center point.
+    ConicGradient(ConicGradientBrush),
 }
 
 /// Construct a brush with transparent color
@@ -40,7 +45,7 @@ fn default() -> Self {
 
 impl Brush {
     /// If the brush is SolidColor, the contained color is returned.
-    /// If the brush is a LinearGradient, the color of the first stop is returned.
+    /// If the brush is a gradient, the color of the first stop is returned.
     pub fn color(&self) -> Color {
         match self {
             Brush::SolidColor(col) => *col,
@@ -50,6 +55,9 @@ pub fn color(&self) -> Color {
             Brush::RadialGradient(gradient) => {
                 gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
             }
+            Brush::ConicGradient(gradient) => {
+                gradient.stops().next().map(|stop| stop.color).unwrap_or_default()
+            }
         }
     }
 
@@ -66,6 +74,7 @@ pub fn is_transparent(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 0,
             Brush::LinearGradient(_) => false,
             Brush::RadialGradient(_) => false,
+            Brush::ConicGradient(_) => false,
         }
     }
 
@@ -82,6 +91,7 @@ pub fn is_opaque(&self) -> bool {
             Brush::SolidColor(c) => c.alpha() == 255,
             Brush::LinearGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
             Brush::RadialGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
+            Brush::ConicGradient(g) => g.stops().all(|s| s.color.alpha() == 255),
         }
     }
 
@@ -90,21 +100,7 @@ pub fn is_opaque(&self) -> bool {
     /// all the colors of this brush.
     #[must_use]
     pub fn brighter(&self, factor: f32) -> Self {
-        match self {
-            Brush::SolidColor(c) => Brush::SolidColor(c.brighter(factor)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
-                g.stops().map(|s| GradientStop {
-                    color: s.color.brighter(factor),
-                    position: s.position,
-                }),
-            )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.brighter(factor), position: s.position }
-                })))
-            }
-        }
+        self.map_colors(|c| c.brighter(factor))
     }
 
     /// Returns a new version of this brush that has the brightness decreased
@@ -112,18 +108,7 @@ pub fn brighter(&self, factor: f32) -> Self {
     /// all the color of this brush.
     #[must_use]
     pub fn darker(&self, factor: f32) -> Self {
-        match self {
-            Brush::SolidColor(c) => Brush::SolidColor(c.darker(factor)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
-                g.stops()
-                    .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
-            )),
-            Brush::RadialGradient(g) => Brush::RadialGradient(RadialGradientBrush::new_circle(
-                g.stops()
-                    .map(|s| GradientStop { color: s.color.darker(factor), position: s.position }),
-            )),
-        }
+        self.map_colors(|c| c.darker(factor))
     }
 
     /// Returns a new version of this brush with the opacity decreased by `factor`.
@@ -133,41 +118,75 @@ pub fn darker(&self, factor: f32) -> Self {
     /// See also [`Color::transparentize`]
     #[must_use]
     pub fn transparentize(&self, amount: f32) -> Self {
-        match self {
-            Brush::SolidColor(c) => Brush::SolidColor(c.transparentize(amount)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
-                g.stops().map(|s| GradientStop {
-                    color: s.color.transparentize(amount),
-                    position: s.position,
-                }),
-            )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.transparentize(amount), position: s.position }
-                })))
-            }
-        }
+        self.map_colors(|c| c.transparentize(amount))
     }
 
     /// Returns a new version of this brush with the related color's opacities
     /// set to `alpha`.
     #[must_use]
     pub fn with_alpha(&self, alpha: f32) -> Self {
-        match self {
-            Brush::SolidColor(c) => Brush::SolidColor(c.with_alpha(alpha)),
-            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush::new(
-                g.angle(),
-                g.stops().map(|s| GradientStop {
-                    color: s.color.with_alpha(alpha),
-                    position: s.position,
-                }),
-            )),
-            Brush::RadialGradient(g) => {
-                Brush::RadialGradient(RadialGradientBrush::new_circle(g.stops().map(|s| {
-                    GradientStop { color: s.color.with_alpha(alpha), position: s.position }
-                })))
+        self.map_colors(|c| c.with_alpha(alpha))
+    }
+
+    /// Returns a copy of this brush where `f` is applied to all colors, keeping the other
+    /// parameters of the gradients.
+    fn map_colors(&self, f: impl Fn(&Color) -> Color) -> Self {
+        fn map_stops(
+            encoded: &SharedVector<GradientStop>,
+            header_len: usize,
+            f: impl Fn(&Color) -> Color,
+        ) -> SharedVector<GradientStop> {
+            let mut encoded = encoded.clone();
+            for stop in encoded.make_mut_slice().iter_mut().skip(header_len) {
+                stop.color = f(&stop.color);
             }
+            encoded
+        }
+        match self {
+            Brush::SolidColor(c) => Brush::SolidColor(f(c)),
+            Brush::LinearGradient(g) => Brush::LinearGradient(LinearGradientBrush(map_stops(
+                &g.0,
+                LinearGradientBrush::HEADER_LEN,
+                f,
+            ))),
+            Brush::RadialGradient(g) => Brush::RadialGradient(RadialGradientBrush(map_stops(
+                &g.0,
+                RadialGradientBrush::HEADER_LEN,
+                f,
+            ))),
+            Brush::ConicGradient(g) => Brush::ConicGradient(ConicGradientBrush(map_stops(
+                &g.0,
+                ConicGradientBrush::HEADER_LEN,
+                f,
+            ))),
+        }
+    }
+}
+
+/// This enum describes how a gradient fills the area that is outside of the range of its stops.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum GradientSpread {
+    /// The area is filled with the color of the closest stop.
+    #[default]
+    Pad,
+    /// The gradient is repeated.
+    Repeat,
+    /// The gradient is repeated, and every other repetition is reflected.
+    Reflect,
+}
+
+impl GradientSpread {
+    fn encode(self) -> f32 {
+        self as u8 as f32
+    }
+
+    fn decode(value: f32) -> Self {
+        match value as u8 {
+            1 => Self::Repeat,
+            2 => Self::Reflect,
+            _ => Self::Pad,
         }
     }
 }
@@ -180,27 +199,44 @@ pub fn with_alpha(&self, alpha: f32) -> Self {
 pub struct LinearGradientBrush(SharedVector<GradientStop>);
 
 impl LinearGradientBrush {
+    /// The number of fake stops at the beginning of the vector that store the angle and the spread.
+    const HEADER_LEN: usize = 2;
+
     /// Creates a new linear gradient, described by the specified angle and the provided color stops.
     ///
     /// The angle need to be specified in degrees.
     /// The stops don't need to be sorted as this function will sort them.
     pub fn new(angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
         let stop_iter = stops.into_iter();
-        let mut encoded_angle_and_stops = SharedVector::with_capacity(stop_iter.size_hint().0 + 1);
-        // The gradient's first stop is a fake stop to store the angle
+        let mut encoded_angle_and_stops = SharedVector::with_capacity(stop_iter.size_hint().0 + 2);
+        // The gradient's first stops are fake stops to store the angle and the spread
         encoded_angle_and_stops.push(GradientStop { color: Default::default(), position: angle });
+        encoded_angle_and_stops.push(GradientStop {
+            color: Default::default(),
+            position: GradientSpread::Pad.encode(),
+        });
         encoded_angle_and_stops.extend(stop_iter);
         Self(encoded_angle_and_stops)
     }
+    /// Returns a copy of this gradient with the specified spread.
+    #[must_use]
+    pub fn with_spread(mut self, spread: GradientSpread) -> Self {
+        self.0.make_mut_slice()[1].position = spread.encode();
+        self
+    }
     /// Returns the angle of the linear gradient in degrees.
     pub fn angle(&self) -> f32 {
         self.0[0].position
     }
+    /// Returns how the gradient fills the area outside of the range of its stops.
+    pub fn spread(&self) -> GradientSpread {
+        GradientSpread::decode(self.0[1].position)
+    }
     /// Returns the color stops of the linear gradient.
     /// The stops are sorted by positions.
     pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
-        // skip the first fake stop that just contains the angle
-        self.0.iter().skip(1)
+        // skip the first fake stops that contain the angle and the spread
+        self.0.iter().skip(Self::HEADER_LEN)
     }
 }
 
@@ -210,14 +246,125 @@ pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
 pub struct RadialGradientBrush(SharedVector<GradientStop>);
 
 impl RadialGradientBrush {
+    /// The number of fake stops at the beginning of the vector that store the center,
+    /// the radius, and the spread.
+    const HEADER_LEN: usize = 4;
+
     /// Creates a new circle radial gradient, centered in the middle and described
     /// by the provided color stops.
     pub fn new_circle(stops: impl IntoIterator<Item = GradientStop>) -> Self {
-        Self(stops.into_iter().collect())
+        let stop_iter = stops.into_iter();
+        let mut encoded = SharedVector::with_capacity(stop_iter.size_hint().0 + Self::HEADER_LEN);
+        // The gradient's first stops are fake stops to store the center, the radius, and the spread
+        for position in [0.5, 0.5, 0., GradientSpread::Pad.encode()] {
+            encoded.push(GradientStop { color: Default::default(), position });
+        }
+        encoded.extend(stop_iter);
+        Self(encoded)
     }
-    /// Returns the color stops of the linear gradient.
+    /// Returns a copy of this gradient with the specified center, relative to the size of
+    /// the shape: `(0, 0)` is the top left corner and `(1, 1)` is the bottom right corner.
+    #[must_use]
+    pub fn with_center(mut self, x: f32, y: f32) -> Self {
+        let header = self.0.make_mut_slice();
+        header[0].position = x;
+        header[1].position = y;
+        self
+    }
+    /// Returns a copy of this gradient with the specified radius in logical pixels.
+    /// A radius of zero means that the radius is the half of the largest side of the shape.
+    #[must_use]
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.0.make_mut_slice()[2].position = radius;
+        self
+    }
+    /// Returns a copy of this gradient with the specified spread.
+    #[must_use]
+    pub fn with_spread(mut self, spread: GradientSpread) -> Self {
+        self.0.make_mut_slice()[3].position = spread.encode();
+        self
+    }
+    /// Returns the center of the gradient relative to the size of the shape.
+    pub fn center(&self) -> (f32, f32) {
+        (self.0[0].position, self.0[1].position)
+    }
+    /// Returns the radius of the gradient in logical pixels, or zero if the radius is
+    /// the half of the largest side of the shape.
+    pub fn radius(&self) -> f32 {
+        self.0[2].position
+    }
+    /// Returns the radius of the gradient for a shape of the given size.
+    pub fn radius_for_size(&self, size: Size2D<f32>) -> f32 {
+        match self.radius() {
+            r if r > 0. => r,
+            _ => size.width.max(size.height) / 2.,
+        }
+    }
+    /// Returns how the gradient fills the area outside of the range of its stops.
+    pub fn spread(&self) -> GradientSpread {
+        GradientSpread::decode(self.0[3].position)
+    }
+    /// Returns the color stops of the radial gradient.
     pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
-        self.0.iter()
+        self.0.iter().skip(Self::HEADER_LEN)
+    }
+}
+
+/// The ConicGradientBrush describes a way of filling a shape with colors that sweep
+/// clockwise around a center point, starting at the top.
+#[derive(Clone, PartialEq, Debug)]
+#[repr(transparent)]
+pub struct ConicGradientBrush(SharedVector<GradientStop>);
+
+impl ConicGradientBrush {
+    /// The number of fake stops at the beginning of the vector that store the angle,
+    /// the center, and the spread.
+    const HEADER_LEN: usize = 4;
+
+    /// Creates a new conic gradient that starts at the specified angle (in degrees, clockwise
+    /// from the top), centered in the middle and described by the provided color stops.
+    ///
+    /// The position of the stops is a fraction of the full turn.
+    pub fn new(from_angle: f32, stops: impl IntoIterator<Item = GradientStop>) -> Self {
+        let stop_iter = stops.into_iter();
+        let mut encoded = SharedVector::with_capacity(stop_iter.size_hint().0 + Self::HEADER_LEN);
+        // The gradient's first stops are fake stops to store the angle, the center, and the spread
+        for position in [from_angle, 0.5, 0.5, GradientSpread::Pad.encode()] {
+            encoded.push(GradientStop { color: Default::default(), position });
+        }
+        encoded.extend(stop_iter);
+        Self(encoded)
+    }
+    /// Returns a copy of this gradient with the specified center, relative to the size of
+    /// the shape: `(0, 0)` is the top left corner and `(1, 1)` is the bottom right corner.
+    #[must_use]
+    pub fn with_center(mut self, x: f32, y: f32) -> Self {
+        let header = self.0.make_mut_slice();
+        header[1].position = x;
+        header[2].position = y;
+        self
+    }
+    /// Returns a copy of this gradient with the specified spread.
+    #[must_use]
+    pub fn with_spread(mut self, spread: GradientSpread) -> Self {
+        self.0.make_mut_slice()[3].position = spread.encode();
+        self
+    }
+    /// Returns the angle in degrees at which the gradient starts, clockwise from the top.
+    pub fn from_angle(&self) -> f32 {
+        self.0[0].position
+    }
+    /// Returns the center of the gradient relative to the size of the shape.
+    pub fn center(&self) -> (f32, f32) {
+        (self.0[1].position, self.0[2].position)
+    }
+    /// Returns how the gradient fills the area outside of the range of its stops.
+    pub fn spread(&self) -> GradientSpread {
+        GradientSpread::decode(self.0[3].position)
+    }
+    /// Returns the color stops of the conic gradient.
+    pub fn stops(&self) -> impl Iterator<Item = &GradientStop> {
+        self.0.iter().skip(Self::HEADER_LEN)
     }
 }
 
@@ -263,6 +410,242 @@ pub fn line_for_angle(angle: f32, size: Size2D<f32>) -> (Point2D<f32>, Point2D<f
     }
 }
 
+/// Returns the range of positions that is repeated by the spread, and the stops mapped so
+/// that this range becomes `0..1`.
+///
+/// For [`GradientSpread::Pad`], the range is `0..1` and the stops are unchanged. Otherwise the range
+/// goes from the position of the first stop to the position of the last stop, like the repeating
+/// gradients of CSS. This is meant for renderers that only support repeating the `0..1` range.
+pub fn normalize_stops<'a>(
+    stops: impl Iterator<Item = &'a GradientStop>,
+    spread: GradientSpread,
+) -> (f32, f32, Vec<GradientStop>) {
+    let stops: Vec<GradientStop> = stops.copied().collect();
+    let (start, end) = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) if spread != GradientSpread::Pad => {
+            (first.position, last.position)
+        }
+        _ => return (0., 1., stops),
+    };
+    if end - start <= f32::EPSILON {
+        return (0., 1., stops);
+    }
+    let stops = stops
+        .into_iter()
+        .map(|s| GradientStop { color: s.color, position: (s.position - start) / (end - start) })
+        .collect();
+    (start, end, stops)
+}
+
+/// Returns the color of the gradient described by the sorted `stops` at the position `t`.
+fn color_at_position(stops: &[GradientStop], spread: GradientSpread, t: f32) -> Color {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return Color::default();
+    };
+    let length = last.position - first.position;
+    let t = if length > f32::EPSILON {
+        let relative = (t - first.position) / length;
+        first.position
+            + length
+                * match spread {
+                    GradientSpread::Pad => relative,
+                    GradientSpread::Repeat => relative - relative.floor(),
+                    GradientSpread::Reflect => {
+                        let r = relative.rem_euclid(2.);
+                        if r > 1. {
+                            2. - r
+                        } else {
+                            r
+                        }
+                    }
+                }
+    } else {
+        t
+    };
+    if t <= first.position {
+        return first.color;
+    }
+    for pair in stops.windows(2) {
+        let (s1, s2) = (pair[0], pair[1]);
+        if t <= s2.position {
+            let distance = s2.position - s1.position;
+            if distance <= f32::EPSILON {
+                return s2.color;
+            }
+            return s1.color.interpolate(&s2.color, (t - s1.position) / distance);
+        }
+    }
+    last.color
+}
+
+/// The geometry of a gradient for a shape of a given size, computed once to sample many points.
+enum GradientGeometry {
+    Linear { start: Point2D<f32>, line: Vector2D<f32> },
+    Radial { center: Point2D<f32>, radius: f32 },
+    Conic { center: Point2D<f32>, from_angle: f32 },
+}
+
+impl GradientGeometry {
+    /// Returns the position of the point `p` on the gradient, 0 for the first stop and 1 for the last
+    fn position(&self, p: Point2D<f32>) -> f32 {
+        match *self {
+            GradientGeometry::Linear { start, line } => {
+                let length = line.square_length();
+                if length > 0. {
+                    (p - start).dot(line) / length
+                } else {
+                    0.
+                }
+            }
+            GradientGeometry::Radial { center, radius } => {
+                if radius > 0. {
+                    (p - center).length() / radius
+                } else {
+                    0.
+                }
+            }
+            GradientGeometry::Conic { center, from_angle } => {
+                let d = p - center;
+                // The angle clockwise from the top, in degrees
+                let angle = d.x.atan2(-d.y).to_degrees() - from_angle;
+                (angle / 360.).rem_euclid(1.)
+            }
+        }
+    }
+}
+
+impl Brush {
+    /// Returns the geometry of the gradient for a shape of the specified size, its stops and
+    /// its spread, or None if the brush is a solid color.
+    fn gradient_for_size(
+        &self,
+        size: Size2D<f32>,
+    ) -> Option<(GradientGeometry, &[GradientStop], GradientSpread)> {
+        match self {
+            Brush::SolidColor(_) => None,
+            Brush::LinearGradient(g) => {
+                let (start, end) = line_for_angle(g.angle(), size);
+                let geometry = GradientGeometry::Linear { start, line: end - start };
+                Some((geometry, &g.0[LinearGradientBrush::HEADER_LEN..], g.spread()))
+            }
+            Brush::RadialGradient(g) => {
+                let (cx, cy) = g.center();
+                let center = Point2D::new(cx * size.width, cy * size.height);
+                let geometry = GradientGeometry::Radial { center, radius: g.radius_for_size(size) };
+                Some((geometry, &g.0[RadialGradientBrush::HEADER_LEN..], g.spread()))
+            }
+            Brush::ConicGradient(g) => {
+                let (cx, cy) = g.center();
+                let center = Point2D::new(cx * size.width, cy * size.height);
+                let geometry = GradientGeometry::Conic { center, from_angle: g.from_angle() };
+                Some((geometry, &g.0[ConicGradientBrush::HEADER_LEN..], g.spread()))
+            }
+        }
+    }
+
+    /// Returns the color of the brush at the point `p` of a shape of the specified size,
+    /// both in logical pixels.
+    ///
+    /// This is meant for renderers that don't support a kind of gradient natively and need
+    /// to compute the colors of the pixels. Use [`Self::to_pixel_buffer()`] to compute the
+    /// colors of a whole shape.
+    pub fn color_at(&self, p: Point2D<f32>, size: Size2D<f32>) -> Color {
+        match self.gradient_for_size(size) {
+            Some((geometry, stops, spread)) => {
+                color_at_position(stops, spread, geometry.position(p))
+            }
+            None => self.color(),
+        }
+    }
+
+    /// Renders the brush into a buffer for a shape of the specified logical size, in
+    /// physical pixels.
+    pub fn to_pixel_buffer(
+        &self,
+        size: Size2D<f32>,
+        scale_factor: f32,
+    ) -> super::SharedPixelBuffer<super::Rgba8Pixel> {
+        let width = (size.width * scale_factor).ceil().max(0.) as u32;
+        let height = (size.height * scale_factor).ceil().max(0.) as u32;
+        let mut buffer = super::SharedPixelBuffer::<super::Rgba8Pixel>::new(width, height);
+        let gradient = self.gradient_for_size(size);
+        for (i, pixel) in buffer.make_mut_slice().iter_mut().enumerate() {
+            let x = (i as u32 % width) as f32 + 0.5;
+            let y = (i as u32 / width) as f32 + 0.5;
+            let color = match &gradient {
+                Some((geometry, stops, spread)) => color_at_position(
+                    stops,
+                    *spread,
+                    geometry.position(Point2D::new(x, y) / scale_factor),
+                ),
+                None => self.color(),
+            };
+            *pixel = super::Rgba8Pixel {
+                r: color.red(),
+                g: color.green(),
+                b: color.blue(),
+                a: color.alpha(),
+            };
+        }
+        buffer
+    }
+}
+
+/// Interpolates two encoded gradients of the same kind, with `lhs` having at least as many
+/// stops as `rhs`. The extra stops of `lhs` move to the end. If `fade_extra_stops` is true, their
+/// color also goes to the color of the last stop of `rhs`.
+fn interpolate_gradient(
+    lhs: &SharedVector<GradientStop>,
+    rhs: &SharedVector<GradientStop>,
+    header_len: usize,
+    spread_index: usize,
+    fade_extra_stops: bool,
+    t: f32,
+) -> SharedVector<GradientStop> {
+    debug_assert!(lhs.len() >= rhs.len());
+    let mut new_grad = lhs.clone();
+    let mut iter = new_grad.make_mut_slice().iter_mut();
+    for (i, s2) in rhs.iter().take(header_len).enumerate() {
+        let s1 = iter.next().unwrap();
+        if i == spread_index {
+            // The spread can't be interpolated
+            if t >= 0.5 {
+                s1.position = s2.position;
+            }
+        } else {
+            s1.position = s1.position.interpolate(&s2.position, t);
+        }
+    }
+    let mut last_color = Color::default();
+    for s2 in rhs.iter().skip(header_len) {
+        let s1 = iter.next().unwrap();
+        last_color = s2.color;
+        s1.color = s1.color.interpolate(&s2.color, t);
+        s1.position = s1.position.interpolate(&s2.position, t);
+    }
+    for x in iter {
+        x.position = x.position.interpolate(&1.0, t);
+        if fade_extra_stops {
+            x.color = x.color.interpolate(&last_color, t);
+        }
+    }
+    new_grad
+}
+
+/// Interpolates the colors of the stops of the encoded gradient from `col`.
+fn interpolate_from_color(
+    col: &Color,
+    grad: &SharedVector<GradientStop>,
+    header_len: usize,
+    t: f32,
+) -> SharedVector<GradientStop> {
+    let mut new_grad = grad.clone();
+    for x in new_grad.make_mut_slice().iter_mut().skip(header_len) {
+        x.color = col.interpolate(&x.color, t);
+    }
+    new_grad
+}
+
 impl InterpolatedPropertyValue for Brush {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         match (self, target_value) {
@@ -270,69 +653,77 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
                 Brush::SolidColor(source_col.interpolate(target_col, t))
             }
             (Brush::SolidColor(col), Brush::LinearGradient(grad)) => {
-                let mut new_grad = grad.clone();
-                for x in new_grad.0.make_mut_slice().iter_mut().skip(1) {
-                    x.color = col.interpolate(&x.color, t);
-                }
-                Brush::LinearGradient(new_grad)
+                Brush::LinearGradient(LinearGradientBrush(interpolate_from_color(
+                    col,
+                    &grad.0,
+                    LinearGradientBrush::HEADER_LEN,
+                    t,
+                )))
+            }
+            (Brush::SolidColor(col), Brush::RadialGradient(grad)) => {
+                Brush::RadialGradient(RadialGradientBrush(interpolate_from_color(
+                    col,
+                    &grad.0,
+                    RadialGradientBrush::HEADER_LEN,
+                    t,
+                )))
             }
-            (a @ Brush::LinearGradient(_), b @ Brush::SolidColor(_)) => {
-                Self::interpolate(b, a, 1. - t)
+            (Brush::SolidColor(col), Brush::ConicGradient(grad)) => {
+                Brush::ConicGradient(ConicGradientBrush(interpolate_from_color(
+                    col,
+                    &grad.0,
+                    ConicGradientBrush::HEADER_LEN,
+                    t,
+                )))
             }
+            (
+                a @ (Brush::LinearGradient(_) | Brush::RadialGradient(_) | Brush::ConicGradient(_)),
+                b @ Brush::SolidColor(_),
+            ) => Self::interpolate(b, a, 1. - t),
             (Brush::LinearGradient(lhs), Brush::LinearGradient(rhs)) => {
                 if lhs.0.len() < rhs.0.len() {
                     Self::interpolate(target_value, self, 1. - t)
                 } else {
-                    let mut new_grad = lhs.clone();
-                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
-                    {
-                        let angle = &mut iter.next().unwrap().position;
-                        *angle = angle.interpolate(&rhs.angle(), t);
-                    }
-                    for s2 in rhs.stops() {
-                        let s1 = iter.next().unwrap();
-                        s1.color = s1.color.interpolate(&s2.color, t);
-                        s1.position = s1.position.interpolate(&s2.position, t);
-                    }
-                    for x in iter {
-                        x.position = x.position.interpolate(&1.0, t);
-                    }
-                    Brush::LinearGradient(new_grad)
+                    Brush::LinearGradient(LinearGradientBrush(interpolate_gradient(
+                        &lhs.0,
+                        &rhs.0,
+                        LinearGradientBrush::HEADER_LEN,
+                        1,
+                        false,
+                        t,
+                    )))
                 }
             }
-            (Brush::SolidColor(col), Brush::RadialGradient(grad)) => {
-                let mut new_grad = grad.clone();
-                for x in new_grad.0.make_mut_slice().iter_mut() {
-                    x.color = col.interpolate(&x.color, t);
+            (Brush::RadialGradient(lhs), Brush::RadialGradient(rhs)) => {
+                if lhs.0.len() < rhs.0.len() {
+                    Self::interpolate(target_value, self, 1. - t)
+                } else {
+                    Brush::RadialGradient(RadialGradientBrush(interpolate_gradient(
+                        &lhs.0,
+                        &rhs.0,
+                        RadialGradientBrush::HEADER_LEN,
+                        3,
+                        true,
+                        t,
+                    )))
                 }
-                Brush::RadialGradient(new_grad)
-            }
-            (a @ Brush::RadialGradient(_), b @ Brush::SolidColor(_)) => {
-                Self::interpolate(b, a, 1. - t)
             }
-            (Brush::RadialGradient(lhs), Brush::RadialGradient(rhs)) => {
+            (Brush::ConicGradient(lhs), Brush::ConicGradient(rhs)) => {
                 if lhs.0.len() < rhs.0.len() {
                     Self::interpolate(target_value, self, 1. - t)
                 } else {
-                    let mut new_grad = lhs.clone();
-                    let mut iter = new_grad.0.make_mut_slice().iter_mut();
-                    let mut last_color = Color::default();
-                    for s2 in rhs.stops() {
-                        let s1 = iter.next().unwrap();
-                        last_color = s2.color;
-                        s1.color = s1.color.interpolate(&s2.color, t);
-                        s1.position = s1.position.interpolate(&s2.position, t);
-                    }
-                    for x in iter {
-                        x.position = x.position.interpolate(&1.0, t);
-                        x.color = x.color.interpolate(&last_color, t);
-                    }
-                    Brush::RadialGradient(new_grad)
+                    Brush::ConicGradient(ConicGradientBrush(interpolate_gradient(
+                        &lhs.0,
+                        &rhs.0,
+                        ConicGradientBrush::HEADER_LEN,
+                        3,
+                        true,
+                        t,
+                    )))
                 }
             }
-            (a @ Brush::LinearGradient(_), b @ Brush::RadialGradient(_))
-            | (a @ Brush::RadialGradient(_), b @ Brush::LinearGradient(_)) => {
-                // Just go to an intermediate color.
+            (a, b) => {
+                // Different kind of gradients: just go to an intermediate color.
                 let color = Color::interpolate(&b.color(), &a.color(), t);
                 if t < 0.5 {
                     Self::interpolate(a, &Brush::SolidColor(color), t * 2.)
@@ -357,3 +748,71 @@ fn test_linear_gradient_encoding() {
     assert_eq!(grad.angle(), 256.);
     assert!(grad.stops().eq(stops.iter()));
 }
+
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_gradient_parameters() {
+    let stops = [
+        GradientStop { position: 0.25, color: Color::from_rgb_u8(255, 0, 0) },
+        GradientStop { position: 0.75, color: Color::from_rgb_u8(0, 0, 255) },
+    ];
+    let grad = LinearGradientBrush::new(90., stops).with_spread(GradientSpread::Reflect);
+    assert_eq!(grad.angle(), 90.);
+    assert_eq!(grad.spread(), GradientSpread::Reflect);
+    assert!(grad.stops().eq(stops.iter()));
+
+    let grad = RadialGradientBrush::new_circle(stops);
+    assert_eq!(grad.center(), (0.5, 0.5));
+    assert_eq!(grad.radius_for_size(Size2D::new(100., 50.)), 50.);
+    let grad = grad.with_center(0.25, 1.).with_radius(20.).with_spread(GradientSpread::Repeat);
+    assert_eq!(grad.center(), (0.25, 1.));
+    assert_eq!(grad.radius_for_size(Size2D::new(100., 50.)), 20.);
+    assert_eq!(grad.spread(), GradientSpread::Repeat);
+    assert!(grad.stops().eq(stops.iter()));
+
+    let grad = ConicGradientBrush::new(45., stops).with_center(0., 0.5);
+    assert_eq!(grad.from_angle(), 45.);
+    assert_eq!(grad.center(), (0., 0.5));
+    assert_eq!(grad.spread(), GradientSpread::Pad);
+    assert!(grad.stops().eq(stops.iter()));
+
+    let (start, end, normalized) = normalize_stops(stops.iter(), GradientSpread::Repeat);
+    assert_eq!((start, end), (0.25, 0.75));
+    assert_eq!(normalized[0].position, 0.);
+    assert_eq!(normalized[1].position, 1.);
+}
+
+#[test]
+fn test_gradient_color_at() {
+    let red = Color::from_rgb_u8(255, 0, 0);
+    let blue = Color::from_rgb_u8(0, 0, 255);
+    let stops =
+        [GradientStop { position: 0., color: red }, GradientStop { position: 0.5, color: blue }];
+    let size = Size2D::new(100., 100.);
+
+    // 90deg goes from left to right
+    let grad = Brush::LinearGradient(LinearGradientBrush::new(90., stops));
+    assert_eq!(grad.color_at(Point2D::new(0., 50.), size), red);
+    assert_eq!(grad.color_at(Point2D::new(50., 50.), size), blue);
+    assert_eq!(grad.color_at(Point2D::new(75., 50.), size), blue);
+    let repeat = Brush::LinearGradient(
+        LinearGradientBrush::new(90., stops).with_spread(GradientSpread::Repeat),
+    );
+    assert_eq!(repeat.color_at(Point2D::new(50.01, 50.), size), red);
+    let reflect = Brush::LinearGradient(
+        LinearGradientBrush::new(90., stops).with_spread(GradientSpread::Reflect),
+    );
+    assert_eq!(reflect.color_at(Point2D::new(100., 50.), size), red);
+
+    let radial = Brush::RadialGradient(RadialGradientBrush::new_circle(stops).with_radius(100.));
+    assert_eq!(radial.color_at(Point2D::new(50., 50.), size), red);
+    assert_eq!(radial.color_at(Point2D::new(100., 50.), size), Color::from_rgb_u8(0, 0, 255));
+
+    // The conic gradient starts at the top and goes clockwise
+    let conic = Brush::ConicGradient(ConicGradientBrush::new(0., stops));
+    assert_eq!(conic.color_at(Point2D::new(50., 0.), size), red);
+    assert_eq!(conic.color_at(Point2D::new(50., 100.), size), blue);
+    assert_eq!(conic.color_at(Point2D::new(0., 50.), size), blue);
+    let conic = Brush::ConicGradient(ConicGradientBrush::new(180., stops));
+    assert_eq!(conic.color_at(Point2D::new(50., 99.), size), red);
+}
@@ -8,7 +8,7 @@
 use crate::item_tree::ItemTreeRc;
 use crate::item_tree::{ItemRc, ItemWeak, VisitChildrenResult};
 pub use crate::items::PointerEventButton;
-use crate::items::{ItemRef, TextCursorDirection};
+use crate::items::{ItemRef, PointerEventKind, TextCursorDirection};
 pub use crate::items::{KeyEvent, KeyboardModifiers};
 use crate::lengths::{LogicalPoint, LogicalVector};
 use crate::timers::Timer;
@@ -49,6 +49,20 @@ pub enum MouseEvent {
     Wheel { position: LogicalPoint, delta_x: Coord, delta_y: Coord },
     /// The mouse exited the item or component
     Exit,
+    /// The pointer moved during a drag and drop operation.
+    /// The data being dragged is available with [`WindowInner::drag_data`].
+    DragMove { position: LogicalPoint },
+    /// The pointer was released during a drag and drop operation, to drop the data at `position`.
+    /// The data being dropped is available with [`WindowInner::drag_data`].
+    Drop { position: LogicalPoint },
+    /// A gesture made with two fingers on a touch screen.
+    /// `position` is the center between the two fingers.
+    /// `kind` is `Down` when the gesture starts, `Move` while it is updated, `Up` when it is
+    /// finished, and `Cancel` if it was cancelled.
+    /// `scale` is the ratio between the current distance of the fingers and their distance
+    /// when the gesture started.
+    /// `rotation` is the angle, in degrees, by which the fingers rotated since the gesture started.
+    TouchGesture { position: LogicalPoint, kind: PointerEventKind, scale: f32, rotation: f32 },
 }
 
 impl MouseEvent {
@@ -60,6 +74,9 @@ pub fn position(&self) -> Option<LogicalPoint> {
             MouseEvent::Moved { position } => Some(*position),
             MouseEvent::Wheel { position, .. } => Some(*position),
             MouseEvent::Exit => None,
+            MouseEvent::DragMove { position } => Some(*position),
+            MouseEvent::Drop { position } => Some(*position),
+            MouseEvent::TouchGesture { position, .. } => Some(*position),
         }
     }
 
@@ -71,12 +88,24 @@ pub fn translate(&mut self, vec: LogicalVector) {
             MouseEvent::Moved { position } => Some(position),
             MouseEvent::Wheel { position, .. } => Some(position),
             MouseEvent::Exit => None,
+            MouseEvent::DragMove { position } => Some(position),
+            MouseEvent::Drop { position } => Some(position),
+            MouseEvent::TouchGesture { position, .. } => Some(position),
         };
         if let Some(pos) = pos {
             *pos += vec;
         }
     }
 
+    /// Convert the pointer moves and releases into the events of a drag and drop operation
+    pub(crate) fn into_drag_event(self) -> Self {
+        match self {
+            MouseEvent::Moved { position } => MouseEvent::DragMove { position },
+            MouseEvent::Released { position, .. } => MouseEvent::Drop { position },
+            _ => self,
+        }
+    }
+
     /// Set the click count of the pressed or released event
     fn set_click_count(&mut self, count: u8) {
         match self {
@@ -454,13 +483,28 @@ pub enum FocusEventResult {
     FocusIgnored,
 }
 
+/// The reason why an item receives the keyboard focus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum FocusReason {
+    /// The focus was set from code, for example with `focus()`.
+    Programmatic,
+    /// The focus was moved with the keyboard: <kbd>Tab</kbd> and <kbd>Shift+Tab</kbd>, or the
+    /// arrow keys within a `FocusGroup`.
+    KeyboardNavigation,
+    /// The item was clicked or tapped.
+    PointerClick,
+    /// An assistive technology, such as a screen reader, requested the focus.
+    AccessibilityAction,
+}
+
 /// This event is sent to a component and items when they receive or loose
 /// the keyboard focus.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(u8)]
 pub enum FocusEvent {
     /// This event is sent when an item receives the focus.
-    FocusIn,
+    FocusIn(FocusReason),
     /// This event is sent when an item looses the focus.
     FocusOut,
     /// This event is sent when the window receives the keyboard focus.
@@ -533,6 +577,135 @@ pub fn check_repeat(&self, mouse_event: MouseEvent, click_interval: Duration) ->
     }
 }
 
+/// This state is used to turn the touch points of a touch screen into mouse events.
+///
+/// The first finger acts as the left mouse button. When a second finger touches the screen,
+/// the mouse press is cancelled and the two fingers start a [`MouseEvent::TouchGesture`], which
+/// lasts until one of them is lifted. The fingers remaining after that are ignored until they
+/// are all lifted.
+#[derive(Default)]
+pub struct TouchState {
+    /// The id and position of the fingers that are currently pressed, in the order they were pressed
+    points: Vec<(u64, LogicalPoint)>,
+    gesture: Option<TouchGestureState>,
+    /// True when the gesture is over but some fingers are still pressed
+    ignore_remaining: bool,
+}
+
+#[derive(Clone, Copy)]
+struct TouchGestureState {
+    /// The distance between the two fingers when the gesture started
+    start_distance: f32,
+    /// The angle of the line between the two fingers, at the last event
+    last_angle: f32,
+    /// The accumulated rotation since the gesture started
+    rotation: f32,
+}
+
+impl TouchState {
+    /// Process a touch event, and return the mouse events that must be dispatched for it.
+    ///
+    /// When a gesture starts, the returned events are a [`MouseEvent::Exit`], which cancels the
+    /// press of the first finger, followed by the start of the gesture.
+    pub fn process(
+        &mut self,
+        touch_id: u64,
+        position: LogicalPoint,
+        kind: PointerEventKind,
+    ) -> impl Iterator<Item = MouseEvent> {
+        let mut events = [None, None];
+        let index = self.points.iter().position(|(id, _)| *id == touch_id);
+        match (kind, index) {
+            (PointerEventKind::Down, None) => {
+                self.points.push((touch_id, position));
+                match self.points.len() {
+                    _ if self.ignore_remaining => {}
+                    1 => {
+                        events[0] = Some(MouseEvent::Pressed {
+                            position,
+                            button: PointerEventButton::Left,
+                            click_count: 0,
+                        })
+                    }
+                    2 => {
+                        let (start_distance, angle) = self.distance_and_angle();
+                        self.gesture = Some(TouchGestureState {
+                            start_distance,
+                            last_angle: angle,
+                            rotation: 0.,
+                        });
+                        events[0] = Some(MouseEvent::Exit);
+                        events[1] = Some(self.gesture_event(PointerEventKind::Down));
+                    }
+                    _ => {}
+                }
+            }
+            (PointerEventKind::Move, Some(index)) => {
+                self.points[index].1 = position;
+                if !self.ignore_remaining && index < 2 {
+                    events[0] = Some(if self.gesture.is_some() {
+                        self.gesture_event(PointerEventKind::Move)
+                    } else {
+                        MouseEvent::Moved { position }
+                    });
+                }
+            }
+            (PointerEventKind::Up | PointerEventKind::Cancel, Some(index)) => {
+                self.points[index].1 = position;
+                if !self.ignore_remaining && index < 2 {
+                    events[0] = Some(if self.gesture.is_some() {
+                        let event = self.gesture_event(kind);
+                        self.gesture = None;
+                        self.ignore_remaining = true;
+                        event
+                    } else if kind == PointerEventKind::Up {
+                        MouseEvent::Released {
+                            position,
+                            button: PointerEventButton::Left,
+                            click_count: 0,
+                        }
+                    } else {
+                        MouseEvent::Exit
+                    });
+                }
+                self.points.remove(index);
+                if self.points.is_empty() {
+                    self.ignore_remaining = false;
+                }
+            }
+            // A finger that is already pressed, or one that is not known
+            _ => {}
+        }
+        events.into_iter().flatten()
+    }
+
+    fn distance_and_angle(&self) -> (f32, f32) {
+        #[cfg(not(feature = "std"))]
+        use num_traits::Float;
+        let delta = (self.points[1].1 - self.points[0].1).cast::<f32>();
+        ((delta.x * delta.x + delta.y * delta.y).sqrt(), delta.y.atan2(delta.x).to_degrees())
+    }
+
+    fn gesture_event(&mut self, kind: PointerEventKind) -> MouseEvent {
+        let (distance, angle) = self.distance_and_angle();
+        let gesture = self.gesture.as_mut().unwrap();
+        let scale =
+            if gesture.start_distance > 0. { distance / gesture.start_distance } else { 1. };
+        // Accumulate the smallest change of angle, so that the rotation isn't limited to 180°
+        let mut delta = (angle - gesture.last_angle) % 360.;
+        if delta > 180. {
+            delta -= 360.;
+        } else if delta < -180. {
+            delta += 360.;
+        }
+        gesture.rotation += delta;
+        gesture.last_angle = angle;
+        let (a, b) = (self.points[0].1, self.points[1].1);
+        let position = LogicalPoint::new((a.x + b.x) / 2 as Coord, (a.y + b.y) / 2 as Coord);
+        MouseEvent::TouchGesture { position, kind, scale, rotation: gesture.rotation }
+    }
+}
+
 /// The state which a window should hold for the mouse input
 #[derive(Default)]
 pub struct MouseInputState {
@@ -664,6 +837,19 @@ pub(crate) fn send_exit_events(
     }
 }
 
+/// Send an exit event to all the items of the `mouse_input_state`, as if the mouse had left
+/// the window. This also releases the grab and drops the delayed event.
+pub(crate) fn cancel_mouse_input(
+    mut mouse_input_state: MouseInputState,
+    window_adapter: &Rc<dyn WindowAdapter>,
+) {
+    let mut new_input_state = MouseInputState {
+        delayed_exit_items: core::mem::take(&mut mouse_input_state.delayed_exit_items),
+        ..Default::default()
+    };
+    send_exit_events(&mouse_input_state, &mut new_input_state, None, window_adapter);
+}
+
 /// Process the `mouse_event` on the `component`, the `mouse_grabber_stack` is the previous stack
 /// of mouse grabber.
 /// Returns a new mouse grabber stack.
@@ -7,7 +7,11 @@
 This module contains the code moving the keyboard focus between items
 */
 
-use crate::item_tree::ItemTreeNodeArray;
+use crate::input::FocusReason;
+use crate::item_tree::{ItemRc, ItemTreeNodeArray};
+use crate::items::{FocusGroup, FocusPolicy, FocusScope, ItemRef, TextInput};
+use alloc::vec::Vec;
+use core::pin::Pin;
 
 pub fn step_out_of_node(
     index: u32,
@@ -59,6 +63,76 @@ pub fn default_previous_in_local_focus_chain(
     }
 }
 
+impl FocusPolicy {
+    /// Returns true if an item with this policy takes the focus for the given reason.
+    pub fn accepts(self, reason: FocusReason) -> bool {
+        match reason {
+            FocusReason::Programmatic => true,
+            FocusReason::KeyboardNavigation => {
+                matches!(self, FocusPolicy::TabAndClick | FocusPolicy::TabOnly)
+            }
+            FocusReason::PointerClick => {
+                matches!(self, FocusPolicy::TabAndClick | FocusPolicy::ClickOnly)
+            }
+            FocusReason::AccessibilityAction => self != FocusPolicy::NoFocus,
+        }
+    }
+}
+
+/// Returns the `tab-index` of the item, or 0 for the items that don't have this property.
+fn tab_index(item: &ItemRc) -> i32 {
+    let item_ref = item.borrow();
+    if let Some(focus_scope) = ItemRef::downcast_pin::<FocusScope>(item_ref) {
+        focus_scope.tab_index()
+    } else if let Some(text_input) = ItemRef::downcast_pin::<TextInput>(item_ref) {
+        text_input.tab_index()
+    } else {
+        0
+    }
+}
+
+fn is_descendant_of(item: &ItemRc, ancestor: &ItemRc) -> bool {
+    let mut current = item.parent_item();
+    while let Some(parent) = current {
+        if parent == *ancestor {
+            return true;
+        }
+        current = parent.parent_item();
+    }
+    false
+}
+
+/// Returns the closest enabled `FocusGroup` that contains the item, and for which `predicate` returns true.
+pub fn find_focus_group(
+    item: &ItemRc,
+    predicate: impl Fn(Pin<&FocusGroup>) -> bool,
+) -> Option<ItemRc> {
+    let mut current = item.parent_item();
+    while let Some(parent) = current {
+        if ItemRef::downcast_pin::<FocusGroup>(parent.borrow())
+            .is_some_and(|group| group.enabled() && predicate(group))
+        {
+            return Some(parent);
+        }
+        current = parent.parent_item();
+    }
+    None
+}
+
+/// Returns `root` and all the items within it, in the order of the keyboard navigation:
+/// Sorted by `tab-index`, and in the order of the item tree for the items with the same `tab-index`.
+pub fn focus_chain(root: &ItemRc) -> Vec<ItemRc> {
+    let mut chain = alloc::vec![root.clone()];
+    let mut item = root.next_focus_item();
+    while item != *root && is_descendant_of(&item, root) {
+        chain.push(item.clone());
+        item = item.next_focus_item();
+    }
+    // The sort is stable, so this keeps the item tree order for the same tab-index
+    chain.sort_by_cached_key(tab_index);
+    chain
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +174,21 @@ fn validate_focus_chains(item_tree: ItemTreeNodeArray<'_>) {
         assert_eq!(forward_chain.len(), item_tree.node_count());
     }
 
+    #[test]
+    fn test_focus_policy() {
+        use FocusReason::*;
+        for (policy, expected) in [
+            (FocusPolicy::TabAndClick, [true, true, true, true]),
+            (FocusPolicy::TabOnly, [true, true, false, true]),
+            (FocusPolicy::ClickOnly, [true, false, true, true]),
+            (FocusPolicy::NoFocus, [true, false, false, false]),
+        ] {
+            let accepted = [Programmatic, KeyboardNavigation, PointerClick, AccessibilityAction]
+                .map(|reason| policy.accepts(reason));
+            assert_eq!(accepted, expected, "{policy:?}");
+        }
+    }
+
     #[test]
     fn test_focus_chain_root_only() {
         let nodes = vec![ItemTreeNode::Item {
@@ -353,6 +353,16 @@ fn draw_text_input(
         _self_rc: &ItemRc,
         _size: LogicalSize,
     );
+    /// Draw text with inline styles. The default implementation draws the plain text, without
+    /// any of the styles.
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&StyledText>,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) {
+        self.draw_text(styled_text, self_rc, size, &styled_text.cached_rendering_data);
+    }
     #[cfg(feature = "std")]
     fn draw_path(&mut self, path: Pin<&Path>, _self_rc: &ItemRc, _size: LogicalSize);
     fn draw_box_shadow(
@@ -822,6 +832,7 @@ fn filter_item(&mut self, item_rc: &ItemRc) -> (bool, LogicalRect) {
     forward_rendering_call2!(fn draw_image(dyn RenderImage));
     forward_rendering_call2!(fn draw_text(dyn RenderText));
     forward_rendering_call!(fn draw_text_input(TextInput));
+    forward_rendering_call!(fn draw_styled_text(StyledText));
     #[cfg(feature = "std")]
     forward_rendering_call!(fn draw_path(Path));
     forward_rendering_call!(fn draw_box_shadow(BoxShadow));
@@ -20,6 +20,7 @@
 #![allow(non_upper_case_globals)]
 #![allow(missing_docs)] // because documenting each property of items is redundant
 
+use crate::api::LogicalPosition;
 use crate::graphics::{Brush, Color, Point};
 use crate::input::{
     FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEventResult,
@@ -43,18 +44,26 @@
 
 mod component_container;
 pub use self::component_container::*;
+mod drag_n_drop;
+pub use drag_n_drop::*;
 mod flickable;
 pub use flickable::Flickable;
+mod gestures;
+pub use gestures::*;
 mod text;
 pub use text::*;
 mod input_items;
 pub use input_items::*;
+mod menus;
+pub use menus::*;
 mod image;
 pub use self::image::*;
 #[cfg(feature = "std")]
 mod path;
 #[cfg(feature = "std")]
 pub use path::*;
+mod shortcut;
+pub use shortcut::*;
 
 /// Alias for `&mut dyn ItemRenderer`. Required so cbindgen generates the ItemVTable
 /// despite the presence of trait object
@@ -66,6 +75,9 @@
 type PointerEventArg = (PointerEvent,);
 type PointerScrollEventArg = (PointerScrollEvent,);
 type PointArg = (Point,);
+type DropEventArg = (DropEvent,);
+type StringArg = (SharedString,);
+type MenuEntryArg = (MenuEntry,);
 
 #[cfg(all(feature = "ffi", windows))]
 #[macro_export]
@@ -534,10 +546,46 @@ fn slint_get_TouchAreaVTable() -> TouchAreaVTable for TouchArea
     fn slint_get_FocusScopeVTable() -> FocusScopeVTable for FocusScope
 }
 
+declare_item_vtable! {
+    fn slint_get_FocusGroupVTable() -> FocusGroupVTable for FocusGroup
+}
+
+declare_item_vtable! {
+    fn slint_get_VirtualKeyboardKeyVTable() -> VirtualKeyboardKeyVTable for VirtualKeyboardKey
+}
+
 declare_item_vtable! {
     fn slint_get_SwipeGestureHandlerVTable() -> SwipeGestureHandlerVTable for SwipeGestureHandler
 }
 
+declare_item_vtable! {
+    fn slint_get_PinchGestureHandlerVTable() -> PinchGestureHandlerVTable for PinchGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_RotateGestureHandlerVTable() -> RotateGestureHandlerVTable for RotateGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_TwoFingerPanGestureHandlerVTable() -> TwoFingerPanGestureHandlerVTable for TwoFingerPanGestureHandler
+}
+
+declare_item_vtable! {
+    fn slint_get_DragAreaVTable() -> DragAreaVTable for DragArea
+}
+
+declare_item_vtable! {
+    fn slint_get_DropAreaVTable() -> DropAreaVTable for DropArea
+}
+
+declare_item_vtable! {
+    fn slint_get_ShortcutVTable() -> ShortcutVTable for Shortcut
+}
+
+declare_item_vtable! {
+    fn slint_get_ContextMenuVTable() -> ContextMenuVTable for ContextMenu
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -1148,6 +1196,10 @@ fn slint_get_ComplexTextVTable() -> ComplexTextVTable for ComplexText
     fn slint_get_SimpleTextVTable() -> SimpleTextVTable for SimpleText
 }
 
+declare_item_vtable! {
+    fn slint_get_StyledTextVTable() -> StyledTextVTable for StyledText
+}
+
 declare_item_vtable! {
     fn slint_get_TextInputVTable() -> TextInputVTable for TextInput
 }
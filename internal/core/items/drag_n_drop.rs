@@ -0,0 +1,271 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use super::{
+    DropEvent, DropEventArg, Item, ItemConsts, ItemRc, ItemRendererRef, PointerEventButton,
+    RenderingResult,
+};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalPoint, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Callback, Coord, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The implementation of the `DragArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DragArea {
+    pub enabled: Property<bool>,
+    pub mime_type: Property<SharedString>,
+    pub data: Property<SharedString>,
+    /// true when the left button was pressed in the area and no drag was started yet
+    pressed: Cell<bool>,
+    pressed_position: Cell<LogicalPoint>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DragArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.pressed.set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                self.pressed_position.set(position);
+                self.pressed.set(true);
+                InputEventFilterResult::ForwardAndInterceptGrab
+            }
+            MouseEvent::Exit => {
+                self.pressed.set(false);
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, .. } => {
+                self.pressed.set(false);
+                InputEventFilterResult::ForwardAndIgnore
+            }
+            MouseEvent::Moved { position } => {
+                if !self.pressed.get() {
+                    InputEventFilterResult::ForwardEvent
+                } else if self.exceeds_threshold(position) {
+                    // Take the grab from the children to start the drag
+                    InputEventFilterResult::Intercept
+                } else {
+                    InputEventFilterResult::ForwardAndInterceptGrab
+                }
+            }
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. }
+            // Not the left button
+            | MouseEvent::Pressed { .. }
+            | MouseEvent::Released { .. } => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } if self.pressed.get() => {
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Moved { position } => {
+                if !self.pressed.get() {
+                    return InputEventResult::EventIgnored;
+                }
+                if !self.exceeds_threshold(position) {
+                    return InputEventResult::GrabMouse;
+                }
+                self.pressed.set(false);
+                // Releasing the grab makes the window dispatch this move again as a DragMove event
+                WindowInner::from_pub(window_adapter.window())
+                    .start_drag(self.mime_type(), self.data());
+                InputEventResult::EventIgnored
+            }
+            MouseEvent::Exit | MouseEvent::Released { .. } => {
+                self.pressed.set(false);
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DragArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl DragArea {
+    fn exceeds_threshold(self: Pin<&Self>, position: LogicalPoint) -> bool {
+        let pressed_position = self.pressed_position.get();
+        let threshold: Coord = super::flickable::DISTANCE_THRESHOLD.get();
+        (position.x - pressed_position.x).abs() > threshold
+            || (position.y - pressed_position.y).abs() > threshold
+    }
+}
+
+/// The implementation of the `DropArea` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct DropArea {
+    pub enabled: Property<bool>,
+    pub contains_drag: Property<bool>,
+    pub can_drop: Callback<DropEventArg, bool>,
+    pub dropped: Callback<DropEventArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for DropArea {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            Self::FIELD_OFFSETS.contains_drag.apply_pin(self).set(false);
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let contains_drag = Self::FIELD_OFFSETS.contains_drag.apply_pin(self);
+        let (position, dropping) = match event {
+            MouseEvent::DragMove { position } => (position, false),
+            MouseEvent::Drop { position } => (position, true),
+            MouseEvent::Exit => {
+                contains_drag.set(false);
+                return InputEventResult::EventIgnored;
+            }
+            _ => return InputEventResult::EventIgnored,
+        };
+        let Some(drag_data) = WindowInner::from_pub(window_adapter.window()).drag_data() else {
+            return InputEventResult::EventIgnored;
+        };
+        let event =
+            DropEvent { position: crate::lengths::logical_position_to_api(position), ..drag_data };
+        if !Self::FIELD_OFFSETS.can_drop.apply_pin(self).call(&(event.clone(),)) {
+            contains_drag.set(false);
+            return InputEventResult::EventIgnored;
+        }
+        if dropping {
+            contains_drag.set(false);
+            Self::FIELD_OFFSETS.dropped.apply_pin(self).call(&(event,));
+        } else {
+            contains_drag.set(true);
+        }
+        InputEventResult::EventAccepted
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for DropArea {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
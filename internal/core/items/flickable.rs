@@ -6,8 +6,8 @@
 //! The `Flickable` item
 
 use super::{
-    Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventButton, RenderingResult,
-    VoidArg,
+    Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventResult, PointerEventButton,
+    PointerEventKind, RenderingResult, VoidArg,
 };
 use crate::animations::{EasingCurve, Instant};
 use crate::input::{
@@ -52,6 +52,10 @@ pub struct Flickable {
 
     pub interactive: Property<bool>,
 
+    pub zoom: Property<f32>,
+    pub min_zoom: Property<f32>,
+    pub max_zoom: Property<f32>,
+
     pub flicked: Callback<VoidArg>,
 
     data: FlickableDataBox,
@@ -194,6 +198,9 @@ struct FlickableDataInner {
     pressed_viewport_pos: LogicalPoint,
     /// Set to true if the flickable is flicking and capturing all mouse event, not forwarding back to the children
     capture_events: bool,
+    /// Set while pinching to zoom: the zoom, the viewport position and the center of the
+    /// fingers when the gesture started
+    pinch_start: Option<(f32, LogicalPoint, LogicalPoint)>,
 }
 
 #[derive(Default, Debug)]
@@ -261,6 +268,10 @@ fn handle_mouse_filter(
                 }
             }
             MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardEvent,
+            // The children get the gestures first, the flickable only zooms if they don't handle them
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. } => InputEventFilterResult::ForwardEvent,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -353,6 +364,59 @@ fn handle_mouse(
                 }
                 InputEventResult::EventAccepted
             }
+            MouseEvent::TouchGesture { position, kind, scale, .. } => {
+                let min_zoom = (Flickable::FIELD_OFFSETS.min_zoom).apply_pin(flick).get();
+                let max_zoom = (Flickable::FIELD_OFFSETS.max_zoom).apply_pin(flick).get();
+                if kind == PointerEventKind::Down && max_zoom > min_zoom {
+                    inner.pinch_start = Some((
+                        (Flickable::FIELD_OFFSETS.zoom).apply_pin(flick).get(),
+                        LogicalPoint::from_lengths(
+                            (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get(),
+                            (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get(),
+                        ),
+                        position,
+                    ));
+                }
+                let Some((start_zoom, start_viewport_pos, start_position)) = inner.pinch_start
+                else {
+                    return InputEventResult::EventIgnored;
+                };
+                if kind == PointerEventKind::Cancel {
+                    inner.pinch_start = None;
+                    return InputEventResult::EventIgnored;
+                }
+
+                let zoom = (start_zoom * scale).max(min_zoom).min(max_zoom);
+                let zoom_prop = (Flickable::FIELD_OFFSETS.zoom).apply_pin(flick);
+                let old_zoom = zoom_prop.get();
+                zoom_prop.set(zoom);
+                // Keep the point of the viewport that was under the fingers when the gesture
+                // started under their center
+                let ratio = if start_zoom > 0. { zoom / start_zoom } else { 1. };
+                let new_pos = position.cast::<f32>()
+                    - (start_position - start_viewport_pos).cast::<f32>() * ratio;
+                let new_pos = ensure_in_bound(flick, new_pos.cast(), flick_rc);
+
+                let viewport_x = (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick);
+                let viewport_y = (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick);
+                let old_pos = (viewport_x.get(), viewport_y.get());
+                viewport_x.set(new_pos.x_length());
+                viewport_y.set(new_pos.y_length());
+                if old_zoom != zoom
+                    || old_pos.0 != new_pos.x_length()
+                    || old_pos.1 != new_pos.y_length()
+                {
+                    (Flickable::FIELD_OFFSETS.flicked).apply_pin(flick).call(&());
+                }
+
+                if kind == PointerEventKind::Up {
+                    inner.pinch_start = None;
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::GrabMouse
+                }
+            }
+            MouseEvent::DragMove { .. } | MouseEvent::Drop { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -396,6 +460,7 @@ fn mouse_released(
         }
         inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
         inner.pressed_time = None;
+        inner.pinch_start = None;
     }
 }
 
@@ -0,0 +1,285 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! The elements that recognize the gestures made with two fingers on a touch screen
+
+use super::{
+    Item, ItemConsts, ItemRc, ItemRendererRef, PointerEventKind, RenderingResult, VoidArg,
+};
+use crate::api::LogicalPosition;
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{logical_position_to_api, LogicalPoint, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowAdapter;
+use crate::{Callback, Property};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The properties and callbacks that all the gesture handlers have
+struct GestureState<'a> {
+    enabled: bool,
+    active: Pin<&'a Property<bool>>,
+    center: Pin<&'a Property<LogicalPosition>>,
+    started: Pin<&'a Callback<VoidArg>>,
+    updated: Pin<&'a Callback<VoidArg>>,
+    finished: Pin<&'a Callback<VoidArg>>,
+    cancelled: Pin<&'a Callback<VoidArg>>,
+}
+
+impl GestureState<'_> {
+    /// Track the gesture in the filter, so that the handler also sees the gesture when one of
+    /// its children has the grab. This lets handlers for different gestures be nested.
+    /// `update` is called with the center of the fingers and the scale and rotation of the event.
+    fn filter(
+        &self,
+        event: MouseEvent,
+        update: impl Fn(LogicalPoint, f32, f32),
+    ) -> InputEventFilterResult {
+        if !self.enabled {
+            self.cancel();
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        let MouseEvent::TouchGesture { position, kind, scale, rotation } = event else {
+            return InputEventFilterResult::ForwardAndIgnore;
+        };
+        match kind {
+            PointerEventKind::Down => {
+                self.cancel();
+                self.center.set(logical_position_to_api(position));
+                update(position, scale, rotation);
+                self.active.set(true);
+                self.started.call(&());
+            }
+            PointerEventKind::Move | PointerEventKind::Up if self.active.get() => {
+                self.center.set(logical_position_to_api(position));
+                update(position, scale, rotation);
+                self.updated.call(&());
+                if kind == PointerEventKind::Up {
+                    self.active.set(false);
+                    self.finished.call(&());
+                }
+            }
+            PointerEventKind::Cancel => self.cancel(),
+            _ => return InputEventFilterResult::ForwardAndIgnore,
+        }
+        InputEventFilterResult::ForwardAndInterceptGrab
+    }
+
+    /// Grab the gesture if none of the children did.
+    fn input_event(&self, event: MouseEvent) -> InputEventResult {
+        match event {
+            MouseEvent::TouchGesture { .. } if self.active.get() => InputEventResult::GrabMouse,
+            MouseEvent::Exit => {
+                self.cancel();
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn cancel(&self) {
+        if self.active.get() {
+            self.active.set(false);
+            self.cancelled.call(&());
+        }
+    }
+}
+
+macro_rules! gesture_state {
+    ($self:ident) => {
+        GestureState {
+            enabled: $self.enabled(),
+            active: Self::FIELD_OFFSETS.active.apply_pin($self),
+            center: Self::FIELD_OFFSETS.center.apply_pin($self),
+            started: Self::FIELD_OFFSETS.started.apply_pin($self),
+            updated: Self::FIELD_OFFSETS.updated.apply_pin($self),
+            finished: Self::FIELD_OFFSETS.finished.apply_pin($self),
+            cancelled: Self::FIELD_OFFSETS.cancelled.apply_pin($self),
+        }
+    };
+}
+
+/// Implements the `Item` trait functions that are the same for all the gesture handlers
+macro_rules! gesture_handler_item_impl {
+    () => {
+        fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+        fn layout_info(
+            self: Pin<&Self>,
+            _orientation: Orientation,
+            _window_adapter: &Rc<dyn WindowAdapter>,
+        ) -> LayoutInfo {
+            LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+        }
+
+        fn input_event(
+            self: Pin<&Self>,
+            event: MouseEvent,
+            _window_adapter: &Rc<dyn WindowAdapter>,
+            _self_rc: &ItemRc,
+        ) -> InputEventResult {
+            gesture_state!(self).input_event(event)
+        }
+
+        fn key_event(
+            self: Pin<&Self>,
+            _: &KeyEvent,
+            _window_adapter: &Rc<dyn WindowAdapter>,
+            _self_rc: &ItemRc,
+        ) -> KeyEventResult {
+            KeyEventResult::EventIgnored
+        }
+
+        fn focus_event(
+            self: Pin<&Self>,
+            _: &FocusEvent,
+            _window_adapter: &Rc<dyn WindowAdapter>,
+            _self_rc: &ItemRc,
+        ) -> FocusEventResult {
+            FocusEventResult::FocusIgnored
+        }
+
+        fn render(
+            self: Pin<&Self>,
+            _backend: &mut ItemRendererRef,
+            _self_rc: &ItemRc,
+            _size: LogicalSize,
+        ) -> RenderingResult {
+            RenderingResult::ContinueRenderingChildren
+        }
+    };
+}
+
+/// The implementation of the `PinchGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct PinchGestureHandler {
+    pub enabled: Property<bool>,
+    pub active: Property<bool>,
+    pub center: Property<LogicalPosition>,
+    /// The ratio between the current distance of the fingers and the one when the gesture started
+    pub scale: Property<f32>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub finished: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for PinchGestureHandler {
+    gesture_handler_item_impl!();
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        gesture_state!(self)
+            .filter(event, |_, scale, _| Self::FIELD_OFFSETS.scale.apply_pin(self).set(scale))
+    }
+}
+
+impl ItemConsts for PinchGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The implementation of the `RotateGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct RotateGestureHandler {
+    pub enabled: Property<bool>,
+    pub active: Property<bool>,
+    pub center: Property<LogicalPosition>,
+    /// The angle, in degrees, by which the fingers rotated since the gesture started
+    pub rotation: Property<f32>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub finished: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for RotateGestureHandler {
+    gesture_handler_item_impl!();
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        gesture_state!(self).filter(event, |_, _, rotation| {
+            Self::FIELD_OFFSETS.rotation.apply_pin(self).set(rotation)
+        })
+    }
+}
+
+impl ItemConsts for RotateGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The implementation of the `TwoFingerPanGestureHandler` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct TwoFingerPanGestureHandler {
+    pub enabled: Property<bool>,
+    pub active: Property<bool>,
+    pub center: Property<LogicalPosition>,
+    /// How much the center of the fingers moved since the gesture started
+    pub translation: Property<LogicalPosition>,
+    pub started: Callback<VoidArg>,
+    pub updated: Callback<VoidArg>,
+    pub finished: Callback<VoidArg>,
+    pub cancelled: Callback<VoidArg>,
+    /// The center of the fingers when the gesture started, in window coordinates, so that
+    /// moving the handler during the gesture doesn't affect the translation
+    start_position: Cell<LogicalPoint>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for TwoFingerPanGestureHandler {
+    gesture_handler_item_impl!();
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        let is_start =
+            matches!(event, MouseEvent::TouchGesture { kind: PointerEventKind::Down, .. });
+        gesture_state!(self).filter(event, |position, _, _| {
+            let position = self_rc.map_to_window(self_rc.geometry().origin + position.to_vector());
+            if is_start {
+                self.start_position.set(position);
+            }
+            Self::FIELD_OFFSETS
+                .translation
+                .apply_pin(self)
+                .set(logical_position_to_api((position - self.start_position.get()).to_point()));
+        })
+    }
+}
+
+impl ItemConsts for TwoFingerPanGestureHandler {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
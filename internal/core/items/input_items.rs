@@ -2,13 +2,13 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use super::{
-    EventResult, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventArg, MouseCursor, PointerEvent,
-    PointerEventArg, PointerEventButton, PointerEventKind, PointerScrollEvent,
+    EventResult, FocusPolicy, Item, ItemConsts, ItemRc, ItemRendererRef, KeyEventArg, MouseCursor,
+    PointerEvent, PointerEventArg, PointerEventButton, PointerEventKind, PointerScrollEvent,
     PointerScrollEventArg, RenderingResult, VoidArg,
 };
 use crate::api::LogicalPosition;
 use crate::input::{
-    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    FocusEvent, FocusEventResult, FocusReason, InputEventFilterResult, InputEventResult, KeyEvent,
     KeyEventResult, KeyEventType, MouseEvent,
 };
 use crate::item_rendering::CachedRenderingData;
@@ -17,7 +17,7 @@
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::{WindowAdapter, WindowInner};
-use crate::{Callback, Coord, Property};
+use crate::{Callback, Coord, Property, SharedString};
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
 use core::cell::Cell;
@@ -199,6 +199,9 @@ fn input_event(
                     }
                 }
             }
+            MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -243,6 +246,8 @@ impl ItemConsts for TouchArea {
 #[pin]
 pub struct FocusScope {
     pub enabled: Property<bool>,
+    pub focus_policy: Property<FocusPolicy>,
+    pub tab_index: Property<i32>,
     pub has_focus: Property<bool>,
     pub key_pressed: Callback<KeyEventArg, EventResult>,
     pub key_released: Callback<KeyEventArg, EventResult>,
@@ -277,8 +282,16 @@ fn input_event(
         window_adapter: &Rc<dyn WindowAdapter>,
         self_rc: &ItemRc,
     ) -> InputEventResult {
-        if self.enabled() && matches!(event, MouseEvent::Pressed { .. }) && !self.has_focus() {
-            WindowInner::from_pub(window_adapter.window()).set_focus_item(self_rc, true);
+        if self.enabled()
+            && matches!(event, MouseEvent::Pressed { .. })
+            && !self.has_focus()
+            && self.focus_policy().accepts(FocusReason::PointerClick)
+        {
+            WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                self_rc,
+                true,
+                FocusReason::PointerClick,
+            );
             InputEventResult::EventAccepted
         } else {
             InputEventResult::EventIgnored
@@ -319,7 +332,10 @@ fn focus_event(
         }
 
         match event {
-            FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus => {
+            FocusEvent::FocusIn(reason) if !self.focus_policy().accepts(*reason) => {
+                return FocusEventResult::FocusIgnored;
+            }
+            FocusEvent::FocusIn(_) | FocusEvent::WindowReceivedFocus => {
                 self.has_focus.set(true);
                 Self::FIELD_OFFSETS.focus_changed_event.apply_pin(self).call(&());
             }
@@ -348,6 +364,216 @@ impl ItemConsts for FocusScope {
     > = FocusScope::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
 }
 
+/// A runtime item that groups the focusable items within it, for the arrow key navigation and to
+/// keep the <kbd>Tab</kbd> navigation inside. See [`crate::item_focus`].
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct FocusGroup {
+    pub enabled: Property<bool>,
+    pub arrow_key_navigation: Property<bool>,
+    pub trap_focus: Property<bool>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for FocusGroup {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for FocusGroup {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        FocusGroup,
+        CachedRenderingData,
+    > = FocusGroup::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// The item used by the styles to implement the keys of the `VirtualKeyboard`. Releasing the
+/// pointer inside it sends a key press and a key release event with its text to the focused item,
+/// without taking the focus from the text input. Like a button, moving the pointer out of it
+/// before releasing cancels the key.
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct VirtualKeyboardKey {
+    pub text: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub pressed: Property<bool>,
+    pub clicked: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl VirtualKeyboardKey {
+    fn send_key(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) {
+        let text = self.text();
+        // Keys without text, such as shift or the layout switch, only report clicks
+        if text.is_empty() {
+            return;
+        }
+        let window = WindowInner::from_pub(window_adapter.window());
+        for event_type in [KeyEventType::KeyPressed, KeyEventType::KeyReleased] {
+            window.process_key_input(KeyEvent {
+                text: text.clone(),
+                event_type,
+                ..Default::default()
+            });
+        }
+    }
+
+    fn release(self: Pin<&Self>) -> bool {
+        let was_pressed = self.pressed();
+        Self::FIELD_OFFSETS.pressed.apply_pin(self).set(false);
+        was_pressed
+    }
+}
+
+impl Item for VirtualKeyboardKey {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        if !self.enabled() {
+            self.release();
+            return InputEventFilterResult::ForwardAndIgnore;
+        }
+        InputEventFilterResult::ForwardAndInterceptGrab
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        if !self.enabled() {
+            return InputEventResult::EventIgnored;
+        }
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Left, .. } => {
+                Self::FIELD_OFFSETS.pressed.apply_pin(self).set(true);
+                InputEventResult::GrabMouse
+            }
+            MouseEvent::Released { button: PointerEventButton::Left, position, .. } => {
+                let geometry = self_rc.geometry();
+                if self.release()
+                    && LogicalRect::new(LogicalPoint::default(), geometry.size).contains(position)
+                {
+                    self.send_key(window_adapter);
+                    Self::FIELD_OFFSETS.clicked.apply_pin(self).call(&());
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Exit => {
+                self.release();
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { .. } if self.pressed() => InputEventResult::GrabMouse,
+            MouseEvent::Moved { .. } => InputEventResult::EventAccepted,
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for VirtualKeyboardKey {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        VirtualKeyboardKey,
+        CachedRenderingData,
+    > = VirtualKeyboardKey::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
 #[repr(C)]
 #[derive(FieldOffsets, Default, SlintElement)]
 #[pin]
@@ -441,7 +667,10 @@ fn input_event_filter_before_children(
                     }
                 }
             }
-            MouseEvent::Wheel { .. } => InputEventFilterResult::ForwardAndIgnore,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. } => InputEventFilterResult::ForwardAndIgnore,
             // Not the left button
             MouseEvent::Pressed { .. } | MouseEvent::Released { .. } => {
                 InputEventFilterResult::ForwardAndIgnore
@@ -499,7 +728,10 @@ fn input_event(
                 Self::FIELD_OFFSETS.moved.apply_pin(self).call(&());
                 InputEventResult::EventAccepted
             }
-            MouseEvent::Wheel { .. } => InputEventResult::EventIgnored,
+            MouseEvent::Wheel { .. }
+            | MouseEvent::DragMove { .. }
+            | MouseEvent::Drop { .. }
+            | MouseEvent::TouchGesture { .. } => InputEventResult::EventIgnored,
         }
     }
 
@@ -0,0 +1,190 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use super::{
+    Item, ItemConsts, ItemRc, ItemRendererRef, MenuEntry, MenuEntryArg, PointArg,
+    PointerEventButton, RenderingResult, StringArg,
+};
+use crate::api::LogicalPosition;
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::{LogicalLength, LogicalPoint, LogicalSize};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowAdapter;
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+
+/// The implementation of the internal `ContextMenu` element, used by the `ContextMenuArea`
+/// and the `MenuBar` of the widget styles.
+///
+/// The entries of the menu are queried with the `entry-count` and `entry` callbacks: the entries
+/// of a menu whose id is `parent` have the ids `parent/0`, `parent/1`, and so on, or `0`, `1`, ...
+/// for the top level menu which has an empty id.
+///
+/// When the backend can show native menus, the menu is shown by the backend. Otherwise, the
+/// `show` callback is invoked so that the style can show the menu in a popup.
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct ContextMenu {
+    /// Whether a click with the right button in the area shows the menu
+    pub enabled: Property<bool>,
+    /// The id of the menu that is shown
+    pub menu_id: Property<SharedString>,
+    /// The size of the window when the menu was shown, so that the popup can cover the window
+    pub window_width: Property<LogicalLength>,
+    pub window_height: Property<LogicalLength>,
+    pub entry_count: Callback<StringArg, i32>,
+    pub entry: Callback<StringArg, MenuEntry>,
+    pub activated: Callback<MenuEntryArg>,
+    pub show: Callback<PointArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for ContextMenu {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo { stretch: 1., ..LayoutInfo::default() }
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        match event {
+            MouseEvent::Pressed { button: PointerEventButton::Right, .. } if self.enabled() => {
+                InputEventFilterResult::Intercept
+            }
+            _ => InputEventFilterResult::ForwardAndIgnore,
+        }
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) -> InputEventResult {
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Right, .. }
+                if self.enabled() =>
+            {
+                self.show_menu(position, window_adapter, self_rc);
+                InputEventResult::EventAccepted
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for ContextMenu {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl ContextMenu {
+    /// Shows the menu below the item. This is the `open-menu()` function in .slint
+    pub fn open_menu(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc: &ItemRc) {
+        let position = LogicalPoint::new(0 as _, self_rc.geometry().height());
+        self.show_menu(position, window_adapter, self_rc);
+    }
+
+    /// Returns the entries of the menu with the given id
+    pub fn entries(self: Pin<&Self>, parent_id: &SharedString) -> Vec<MenuEntry> {
+        let count = self.entry_count.call(&(parent_id.clone(),));
+        (0..count)
+            .map(|index| {
+                let id = if parent_id.is_empty() {
+                    crate::format!("{index}")
+                } else {
+                    crate::format!("{parent_id}/{index}")
+                };
+                self.entry.call(&(id,))
+            })
+            .collect()
+    }
+
+    /// Activates the entry, which toggles it if it's checkable and calls its `activated` callback
+    pub fn activate(self: Pin<&Self>, entry: &MenuEntry) {
+        self.activated.call(&(entry.clone(),));
+    }
+
+    /// `position` is relative to the item
+    fn show_menu(
+        self: Pin<&Self>,
+        position: LogicalPoint,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        self_rc: &ItemRc,
+    ) {
+        let window_position =
+            self_rc.map_to_window(self_rc.geometry().origin + position.to_vector());
+        let shown_natively = window_adapter.internal(crate::InternalToken).is_some_and(|x| {
+            x.show_native_popup_menu(self, LogicalPosition::from_euclid(window_position))
+        });
+        if shown_natively {
+            return;
+        }
+        let window = window_adapter.window();
+        let size = window.size().to_logical(window.scale_factor());
+        Self::FIELD_OFFSETS.window_width.apply_pin(self).set(LogicalLength::new(size.width as _));
+        Self::FIELD_OFFSETS.window_height.apply_pin(self).set(LogicalLength::new(size.height as _));
+        Self::FIELD_OFFSETS.show.apply_pin(self).call(&(position.to_untyped(),));
+    }
+}
+
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_contextmenu_open_menu(
+    s: Pin<&ContextMenu>,
+    window_adapter: *const crate::window::ffi::WindowAdapterRcOpaque,
+    self_component: &vtable::VRc<crate::item_tree::ItemTreeVTable>,
+    self_index: u32,
+) {
+    let window_adapter = &*(window_adapter as *const Rc<dyn WindowAdapter>);
+    let self_rc = ItemRc::new(self_component.clone(), self_index);
+    s.open_menu(window_adapter, &self_rc);
+}
@@ -0,0 +1,117 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+use super::{Item, ItemConsts, ItemRc, ItemRendererRef, RenderingResult, VoidArg};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, KeyEventType, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::LogicalSize;
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::window::WindowAdapter;
+use crate::{Callback, Property, SharedString};
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::pin::Pin;
+use i_slint_common::key_sequence::{to_lowercase, KeySequence};
+use i_slint_core_macros::*;
+
+/// The implementation of the `Shortcut` element
+///
+/// The window looks for a matching shortcut before delivering a key press to the focused item.
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct Shortcut {
+    pub keys: Property<SharedString>,
+    pub enabled: Property<bool>,
+    pub activated: Callback<VoidArg>,
+    /// FIXME: remove this
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl Item for Shortcut {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for Shortcut {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<Self, CachedRenderingData> =
+        Self::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl Shortcut {
+    /// Returns true if the shortcut is enabled and the key press event matches its key sequence,
+    /// which the caller parsed from the `keys` property.
+    pub(crate) fn matches(self: Pin<&Self>, event: &KeyEvent, sequence: &KeySequence) -> bool {
+        if event.event_type != KeyEventType::KeyPressed || !self.enabled() {
+            return false;
+        }
+        let mut chars = event.text.chars();
+        let (Some(key), None) = (chars.next(), chars.next()) else { return false };
+        let modifiers = &event.modifiers;
+        // Symbols may need Shift to be typed, so Shift only matters if the sequence requires it
+        let shift_matches =
+            modifiers.shift == sequence.shift || (sequence.is_symbol() && !sequence.shift);
+        to_lowercase(key) == sequence.key
+            && modifiers.control == sequence.control
+            && modifiers.alt == sequence.alt
+            && modifiers.meta == sequence.meta
+            && shift_matches
+    }
+}
@@ -8,14 +8,15 @@
 Lookup the [`crate::items`] module documentation.
 */
 use super::{
-    FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventResult, KeyEventType,
-    PointArg, PointerEventButton, RenderingResult, TextHorizontalAlignment, TextOverflow,
-    TextStrokeStyle, TextVerticalAlignment, TextWrap, VoidArg,
+    FocusPolicy, FontMetrics, InputType, Item, ItemConsts, ItemRc, ItemRef, KeyEventResult,
+    KeyEventType, MouseCursor, PointArg, PointerEventButton, RenderingResult, StringArg,
+    TextHorizontalAlignment, TextOverflow, TextStrokeStyle, TextVerticalAlignment, TextWrap,
+    VoidArg,
 };
 use crate::graphics::{Brush, Color, FontRequest};
 use crate::input::{
-    key_codes, FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
-    KeyboardModifiers, MouseEvent, StandardShortcut, TextShortcut,
+    key_codes, FocusEvent, FocusEventResult, FocusReason, InputEventFilterResult, InputEventResult,
+    KeyEvent, KeyboardModifiers, MouseEvent, StandardShortcut, TextShortcut,
 };
 use crate::item_rendering::{CachedRenderingData, ItemRenderer, RenderText};
 use crate::layout::{LayoutInfo, Orientation};
@@ -23,6 +24,7 @@
 use crate::platform::Clipboard;
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
+use crate::styled_text::{SpanStyle, StyledString};
 use crate::window::{InputMethodProperties, InputMethodRequest, WindowAdapter, WindowInner};
 use crate::{Callback, Coord, Property, SharedString, SharedVector};
 use alloc::rc::Rc;
@@ -367,6 +369,270 @@ pub fn font_metrics(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>) ->
     }
 }
 
+/// The implementation of the `StyledText` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct StyledText {
+    pub width: Property<LogicalLength>,
+    pub height: Property<LogicalLength>,
+    pub text: Property<SharedString>,
+    pub font_size: Property<LogicalLength>,
+    pub font_weight: Property<i32>,
+    pub color: Property<Brush>,
+    pub horizontal_alignment: Property<TextHorizontalAlignment>,
+    pub vertical_alignment: Property<TextVerticalAlignment>,
+
+    pub font_family: Property<SharedString>,
+    pub font_italic: Property<bool>,
+    pub wrap: Property<TextWrap>,
+    pub link_color: Property<Brush>,
+    pub link_clicked: Callback<StringArg>,
+    pub cached_rendering_data: CachedRenderingData,
+    /// The target of the link on which the pointer was pressed
+    pressed_link: Cell<SharedString>,
+    /// true when the mouse cursor was changed because the pointer hovers a link
+    hovering_link: Cell<bool>,
+}
+
+impl Item for StyledText {
+    fn init(self: Pin<&Self>, _self_rc: &ItemRc) {}
+
+    fn layout_info(
+        self: Pin<&Self>,
+        orientation: Orientation,
+        window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        let styled_string = self.styled_string();
+        let font_request = self.font_request(window_inner);
+        let scale_factor = ScaleFactor::new(window_inner.scale_factor());
+        text_layout_info_impl(
+            self,
+            window_adapter,
+            orientation,
+            Self::FIELD_OFFSETS.width.apply_pin(self),
+            |max_width, text_wrap| {
+                window_adapter.renderer().styled_text_size(
+                    font_request.clone(),
+                    &styled_string,
+                    max_width,
+                    scale_factor,
+                    text_wrap,
+                )
+            },
+        )
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardEvent
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        event: MouseEvent,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        let set_hovering_link = |hovering: bool| {
+            if self.hovering_link.replace(hovering) != hovering {
+                if let Some(x) = window_adapter.internal(crate::InternalToken) {
+                    x.set_mouse_cursor(if hovering {
+                        MouseCursor::Pointer
+                    } else {
+                        MouseCursor::Default
+                    });
+                }
+            }
+        };
+        match event {
+            MouseEvent::Pressed { position, button: PointerEventButton::Left, .. } => {
+                match self.link_at_position(window_adapter, position) {
+                    Some(link) => {
+                        self.pressed_link.set(link);
+                        InputEventResult::GrabMouse
+                    }
+                    None => InputEventResult::EventIgnored,
+                }
+            }
+            MouseEvent::Released { position, button: PointerEventButton::Left, .. } => {
+                let pressed_link = self.pressed_link.take();
+                if pressed_link.is_empty() {
+                    return InputEventResult::EventIgnored;
+                }
+                if self.link_at_position(window_adapter, position).as_ref() == Some(&pressed_link) {
+                    Self::FIELD_OFFSETS.link_clicked.apply_pin(self).call(&(pressed_link,));
+                }
+                InputEventResult::EventAccepted
+            }
+            MouseEvent::Moved { position } => {
+                let link = self.link_at_position(window_adapter, position);
+                set_hovering_link(link.is_some());
+                let pressed_link = self.pressed_link.take();
+                let grabbed = !pressed_link.is_empty();
+                self.pressed_link.set(pressed_link);
+                if link.is_some() || grabbed {
+                    InputEventResult::EventAccepted
+                } else {
+                    InputEventResult::EventIgnored
+                }
+            }
+            MouseEvent::Exit => {
+                set_hovering_link(false);
+                self.pressed_link.take();
+                InputEventResult::EventIgnored
+            }
+            _ => InputEventResult::EventIgnored,
+        }
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        backend: &mut &mut dyn ItemRenderer,
+        self_rc: &ItemRc,
+        size: LogicalSize,
+    ) -> RenderingResult {
+        (*backend).draw_styled_text(self, self_rc, size);
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for StyledText {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        StyledText,
+        CachedRenderingData,
+    > = StyledText::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+impl RenderText for StyledText {
+    fn target_size(self: Pin<&Self>) -> LogicalSize {
+        LogicalSize::from_lengths(self.width(), self.height())
+    }
+
+    fn text(self: Pin<&Self>) -> SharedString {
+        self.styled_string().text.clone()
+    }
+
+    fn font_request(self: Pin<&Self>, window: &WindowInner) -> FontRequest {
+        let window_item = window.window_item();
+
+        FontRequest {
+            family: {
+                let maybe_family = self.font_family();
+                if !maybe_family.is_empty() {
+                    Some(maybe_family)
+                } else {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_family())
+                }
+            },
+            weight: {
+                let weight = self.font_weight();
+                if weight == 0 {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_weight())
+                } else {
+                    Some(weight)
+                }
+            },
+            pixel_size: {
+                let font_size = self.font_size();
+                if font_size.get() == 0 as Coord {
+                    window_item.as_ref().and_then(|item| item.as_pin_ref().font_size())
+                } else {
+                    Some(font_size)
+                }
+            },
+            letter_spacing: None,
+            italic: self.font_italic(),
+        }
+    }
+
+    fn color(self: Pin<&Self>) -> Brush {
+        self.color()
+    }
+
+    fn alignment(
+        self: Pin<&Self>,
+    ) -> (super::TextHorizontalAlignment, super::TextVerticalAlignment) {
+        (self.horizontal_alignment(), self.vertical_alignment())
+    }
+
+    fn wrap(self: Pin<&Self>) -> TextWrap {
+        self.wrap()
+    }
+
+    fn overflow(self: Pin<&Self>) -> TextOverflow {
+        TextOverflow::Clip
+    }
+
+    fn letter_spacing(self: Pin<&Self>) -> LogicalLength {
+        LogicalLength::default()
+    }
+
+    fn stroke(self: Pin<&Self>) -> (Brush, LogicalLength, TextStrokeStyle) {
+        Default::default()
+    }
+}
+
+impl StyledText {
+    /// Returns the parsed `text` property
+    pub fn styled_string(self: Pin<&Self>) -> Rc<StyledString> {
+        StyledString::parse_markdown_cached(&self.text())
+    }
+
+    /// Returns the brush to use for the glyphs of a span with the given style
+    pub fn span_color(self: Pin<&Self>, style: &SpanStyle) -> Brush {
+        match (&style.color, &style.link) {
+            (Some(color), _) => (*color).into(),
+            (None, Some(_)) => self.link_color(),
+            (None, None) => self.color(),
+        }
+    }
+
+    /// Returns the target of the link at the given position, if any
+    pub fn link_at_position(
+        self: Pin<&Self>,
+        window_adapter: &Rc<dyn WindowAdapter>,
+        position: LogicalPoint,
+    ) -> Option<SharedString> {
+        let window_inner = WindowInner::from_pub(window_adapter.window());
+        let styled_string = self.styled_string();
+        if styled_string.spans.iter().all(|span| span.style.link.is_none()) {
+            return None;
+        }
+        let span = window_adapter.renderer().styled_text_span_at_position(
+            self,
+            &styled_string,
+            position,
+            self.font_request(window_inner),
+            ScaleFactor::new(window_inner.scale_factor()),
+        )?;
+        styled_string.spans.get(span)?.style.link.clone()
+    }
+}
+
 fn text_layout_info(
     text: Pin<&dyn RenderText>,
     window_adapter: &Rc<dyn WindowAdapter>,
@@ -377,7 +643,7 @@ fn text_layout_info(
     let text_string = text.text();
     let font_request = text.font_request(window_inner);
     let scale_factor = ScaleFactor::new(window_inner.scale_factor());
-    let implicit_size = |max_width, text_wrap| {
+    text_layout_info_impl(text, window_adapter, orientation, width, |max_width, text_wrap| {
         window_adapter.renderer().text_size(
             font_request.clone(),
             text_string.as_str(),
@@ -385,8 +651,16 @@ fn text_layout_info(
             scale_factor,
             text_wrap,
         )
-    };
+    })
+}
 
+fn text_layout_info_impl(
+    text: Pin<&dyn RenderText>,
+    window_adapter: &Rc<dyn WindowAdapter>,
+    orientation: Orientation,
+    width: Pin<&Property<LogicalLength>>,
+    implicit_size: impl Fn(Option<LogicalLength>, TextWrap) -> LogicalSize,
+) -> LayoutInfo {
     // Stretch uses `round_layout` to explicitly align the top left and bottom right of layout nodes
     // to pixel boundaries. To avoid rounding down causing the minimum width to become so little that
     // letters will be cut off, apply the ceiling here.
@@ -394,12 +668,21 @@ fn text_layout_info(
         Orientation::Horizontal => {
             let implicit_size = implicit_size(None, TextWrap::NoWrap);
             let min = match text.overflow() {
-                TextOverflow::Elide => implicit_size.width.min(
-                    window_adapter
-                        .renderer()
-                        .text_size(font_request, "…", None, scale_factor, TextWrap::NoWrap)
-                        .width,
-                ),
+                TextOverflow::Elide => {
+                    let window_inner = WindowInner::from_pub(window_adapter.window());
+                    implicit_size.width.min(
+                        window_adapter
+                            .renderer()
+                            .text_size(
+                                text.font_request(window_inner),
+                                "…",
+                                None,
+                                ScaleFactor::new(window_inner.scale_factor()),
+                                TextWrap::NoWrap,
+                            )
+                            .width,
+                    )
+                }
                 TextOverflow::Clip => match text.wrap() {
                     TextWrap::NoWrap => implicit_size.width,
                     TextWrap::WordWrap | TextWrap::CharWrap => 0 as Coord,
@@ -489,6 +772,8 @@ pub struct TextInput {
     pub cursor_visible: Property<bool>,
     pub has_focus: Property<bool>,
     pub enabled: Property<bool>,
+    pub focus_policy: Property<FocusPolicy>,
+    pub tab_index: Property<i32>,
     pub accepted: Callback<VoidArg>,
     pub rejected: Callback<VoidArg>,
     pub cursor_position_changed: Callback<PointArg>,
@@ -900,17 +1185,20 @@ fn focus_event(
         self_rc: &ItemRc,
     ) -> FocusEventResult {
         match event {
-            FocusEvent::FocusIn | FocusEvent::WindowReceivedFocus => {
+            FocusEvent::FocusIn(reason) if !self.focus_policy().accepts(*reason) => {
+                return FocusEventResult::FocusIgnored;
+            }
+            FocusEvent::FocusIn(_) | FocusEvent::WindowReceivedFocus => {
                 self.has_focus.set(true);
                 self.show_cursor(window_adapter);
-                WindowInner::from_pub(window_adapter.window()).set_text_input_focused(true);
+                let window_inner = WindowInner::from_pub(window_adapter.window());
+                window_inner.set_text_input_type(self.input_type());
+                window_inner.set_text_input_focused(true);
                 // FIXME: This should be tracked by a PropertyTracker in window and toggled when read_only() toggles.
                 if !self.read_only() {
-                    if let Some(w) = window_adapter.internal(crate::InternalToken) {
-                        w.input_method_request(InputMethodRequest::Enable(
-                            self.ime_properties(window_adapter, self_rc),
-                        ));
-                    }
+                    window_adapter.input_method_request(InputMethodRequest::Enable(
+                        self.ime_properties(window_adapter, self_rc),
+                    ));
                 }
             }
             FocusEvent::FocusOut | FocusEvent::WindowLostFocus => {
@@ -923,10 +1211,8 @@ fn focus_event(
                 }
                 WindowInner::from_pub(window_adapter.window()).set_text_input_focused(false);
                 if !self.read_only() {
-                    if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
-                        window_adapter.input_method_request(InputMethodRequest::Disable);
-                        self.preedit_text.set(Default::default());
-                    }
+                    window_adapter.input_method_request(InputMethodRequest::Disable);
+                    self.preedit_text.set(Default::default());
                 }
             }
         }
@@ -942,11 +1228,9 @@ fn render(
         crate::properties::evaluate_no_tracking(|| {
             if self.has_focus() && self.text() != *backend.window().last_ime_text.borrow() {
                 let window_adapter = &backend.window().window_adapter();
-                if let Some(w) = window_adapter.internal(crate::InternalToken) {
-                    w.input_method_request(InputMethodRequest::Update(
-                        self.ime_properties(window_adapter, self_rc),
-                    ));
-                }
+                window_adapter.input_method_request(InputMethodRequest::Update(
+                    self.ime_properties(window_adapter, self_rc),
+                ));
             }
         });
         (*backend).draw_text_input(self, self_rc, size);
@@ -1268,11 +1552,9 @@ fn update_ime(self: Pin<&Self>, window_adapter: &Rc<dyn WindowAdapter>, self_rc:
         if self.read_only() || !self.has_focus() {
             return;
         }
-        if let Some(w) = window_adapter.internal(crate::InternalToken) {
-            w.input_method_request(InputMethodRequest::Update(
-                self.ime_properties(window_adapter, self_rc),
-            ));
-        }
+        window_adapter.input_method_request(InputMethodRequest::Update(
+            self.ime_properties(window_adapter, self_rc),
+        ));
     }
 
     fn select_and_delete(
@@ -1710,13 +1992,17 @@ fn ensure_focus_and_ime(
         self_rc: &ItemRc,
     ) {
         if !self.has_focus() {
-            WindowInner::from_pub(window_adapter.window()).set_focus_item(self_rc, true);
-        } else if !self.read_only() {
-            if let Some(w) = window_adapter.internal(crate::InternalToken) {
-                w.input_method_request(InputMethodRequest::Enable(
-                    self.ime_properties(window_adapter, self_rc),
-                ));
+            if self.focus_policy().accepts(FocusReason::PointerClick) {
+                WindowInner::from_pub(window_adapter.window()).set_focus_item(
+                    self_rc,
+                    true,
+                    FocusReason::PointerClick,
+                );
             }
+        } else if !self.read_only() {
+            window_adapter.input_method_request(InputMethodRequest::Enable(
+                self.ime_properties(window_adapter, self_rc),
+            ));
         }
     }
 
@@ -5,7 +5,7 @@
 
 // cspell:ignore coord
 
-use crate::items::{DialogButtonRole, LayoutAlignment};
+use crate::items::{DialogButtonRole, FlexboxLayoutAlignItems, FlexboxLayoutWrap, LayoutAlignment};
 use crate::{slice::Slice, Coord, SharedVector};
 use alloc::vec::Vec;
 
@@ -505,21 +505,13 @@ pub struct BoxLayoutCellData {
     pub constraint: LayoutInfo,
 }
 
-/// Solve a BoxLayout
-pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> SharedVector<Coord> {
-    let mut result = SharedVector::<Coord>::default();
-    result.resize(data.cells.len() * 2 + repeater_indexes.len(), 0 as _);
-
-    if data.cells.is_empty() {
-        return result;
-    }
-
-    let mut layout_data: Vec<_> = data
-        .cells
+/// Convert the cells of a box layout to the data used by the solver
+fn box_layout_items(cells: &[BoxLayoutCellData], size: Coord) -> Vec<grid_internal::LayoutData> {
+    cells
         .iter()
         .map(|c| {
-            let min = c.constraint.min.max(c.constraint.min_percent * data.size / 100 as Coord);
-            let max = c.constraint.max.min(c.constraint.max_percent * data.size / 100 as Coord);
+            let min = c.constraint.min.max(c.constraint.min_percent * size / 100 as Coord);
+            let max = c.constraint.max.min(c.constraint.max_percent * size / 100 as Coord);
             grid_internal::LayoutData {
                 min,
                 max,
@@ -528,56 +520,72 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
                 ..Default::default()
             }
         })
-        .collect();
+        .collect()
+}
 
-    let size_without_padding = data.size - data.padding.begin - data.padding.end;
+/// Set the position and size of the items placed one after the other within `size`,
+/// distributing the remaining space according to the alignment
+fn align_box_items(
+    layout_data: &mut [grid_internal::LayoutData],
+    size: Coord,
+    spacing: Coord,
+    padding: &Padding,
+    alignment: LayoutAlignment,
+) {
+    if layout_data.is_empty() {
+        return;
+    }
+
+    let size_without_padding = size - padding.begin - padding.end;
     let pref_size: Coord = layout_data.iter().map(|it| it.pref).sum();
     let num_spacings = (layout_data.len() - 1) as Coord;
-    let spacings = data.spacing * num_spacings;
+    let spacings = spacing * num_spacings;
 
-    let align = match data.alignment {
+    let align = match alignment {
         LayoutAlignment::Stretch => {
-            grid_internal::layout_items(
-                &mut layout_data,
-                data.padding.begin,
-                size_without_padding,
-                data.spacing,
-            );
+            grid_internal::layout_items(layout_data, padding.begin, size_without_padding, spacing);
             None
         }
         _ if size_without_padding <= pref_size + spacings => {
-            grid_internal::layout_items(
-                &mut layout_data,
-                data.padding.begin,
-                size_without_padding,
-                data.spacing,
-            );
+            grid_internal::layout_items(layout_data, padding.begin, size_without_padding, spacing);
             None
         }
         LayoutAlignment::Center => Some((
-            data.padding.begin + (size_without_padding - pref_size - spacings) / 2 as Coord,
-            data.spacing,
+            padding.begin + (size_without_padding - pref_size - spacings) / 2 as Coord,
+            spacing,
         )),
-        LayoutAlignment::Start => Some((data.padding.begin, data.spacing)),
+        LayoutAlignment::Start => Some((padding.begin, spacing)),
         LayoutAlignment::End => {
-            Some((data.padding.begin + (size_without_padding - pref_size - spacings), data.spacing))
+            Some((padding.begin + (size_without_padding - pref_size - spacings), spacing))
         }
         LayoutAlignment::SpaceBetween => {
-            Some((data.padding.begin, (size_without_padding - pref_size) / num_spacings))
+            Some((padding.begin, (size_without_padding - pref_size) / num_spacings))
         }
         LayoutAlignment::SpaceAround => {
             let spacing = (size_without_padding - pref_size) / (num_spacings + 1 as Coord);
-            Some((data.padding.begin + spacing / 2 as Coord, spacing))
+            Some((padding.begin + spacing / 2 as Coord, spacing))
         }
     };
     if let Some((mut pos, spacing)) = align {
-        for it in &mut layout_data {
+        for it in layout_data {
             it.pos = pos;
             it.size = it.pref;
             pos += spacing + it.size;
         }
     }
+}
 
+/// Build the layout cache containing the position and size of each item.
+///
+/// The `repeater_indexes` contains, for each repeater, the index of its first item and the number
+/// of items. The cache entries of these items are moved at the end, and the entries of the repeater
+/// instead contain the offset at which they are.
+fn layout_cache_from_items(
+    layout_data: &[grid_internal::LayoutData],
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let mut result = SharedVector::<Coord>::default();
+    result.resize(layout_data.len() * 2 + repeater_indexes.len(), 0 as _);
     let res = result.make_mut_slice();
 
     // The index/2 in result in which we should add the next repeated item
@@ -615,6 +623,13 @@ pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> S
     result
 }
 
+/// Solve a BoxLayout
+pub fn solve_box_layout(data: &BoxLayoutData, repeater_indexes: Slice<u32>) -> SharedVector<Coord> {
+    let mut layout_data = box_layout_items(data.cells.as_slice(), data.size);
+    align_box_items(&mut layout_data, data.size, data.spacing, &data.padding, data.alignment);
+    layout_cache_from_items(&layout_data, repeater_indexes)
+}
+
 /// Return the LayoutInfo for a BoxLayout with the given cells.
 pub fn box_layout_info(
     cells: Slice<BoxLayoutCellData>,
@@ -658,6 +673,209 @@ pub fn box_layout_info_ortho(cells: Slice<BoxLayoutCellData>, padding: &Padding)
     fold
 }
 
+#[repr(C)]
+#[derive(Debug)]
+/// The FlexboxLayoutData represents a FlexboxLayout along its main axis (the direction of the layout).
+/// Like for the BoxLayoutData, the width/height x/y correspond to that of a layout in the `row` direction
+/// and are inverted for the `column` direction.
+pub struct FlexboxLayoutData<'a> {
+    pub size: Coord,
+    pub spacing: Coord,
+    pub padding: Padding,
+    pub alignment: LayoutAlignment,
+    pub wrap: FlexboxLayoutWrap,
+    pub cells: Slice<'a, BoxLayoutCellData>,
+}
+
+/// Split the items of a FlexboxLayout in lines. Returns the range of items of each line.
+fn flexbox_lines(
+    layout_data: &[grid_internal::LayoutData],
+    data: &FlexboxLayoutData,
+) -> Vec<core::ops::Range<usize>> {
+    if data.wrap == FlexboxLayoutWrap::NoWrap {
+        return alloc::vec![0..layout_data.len()];
+    }
+    let size_without_padding = data.size - data.padding.begin - data.padding.end;
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    let mut line_size = 0 as Coord;
+    for (idx, it) in layout_data.iter().enumerate() {
+        if idx > line_start && line_size + data.spacing + it.pref > size_without_padding {
+            lines.push(line_start..idx);
+            line_start = idx;
+            line_size = it.pref;
+        } else if idx > line_start {
+            line_size += data.spacing + it.pref;
+        } else {
+            line_size = it.pref;
+        }
+    }
+    lines.push(line_start..layout_data.len());
+    lines
+}
+
+/// Solve a FlexboxLayout along its main axis
+pub fn solve_flexbox_layout(
+    data: &FlexboxLayoutData,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    let mut layout_data = box_layout_items(data.cells.as_slice(), data.size);
+    for line in flexbox_lines(&layout_data, data) {
+        align_box_items(
+            &mut layout_data[line],
+            data.size,
+            data.spacing,
+            &data.padding,
+            data.alignment,
+        );
+    }
+    layout_cache_from_items(&layout_data, repeater_indexes)
+}
+
+/// Solve a FlexboxLayout along its cross axis (perpendicular to the direction of the layout).
+///
+/// `data` is the data of the main axis, which is used to split the items in lines, and `cells`
+/// contains the constraint of the items along the cross axis.
+pub fn solve_flexbox_layout_cross(
+    data: &FlexboxLayoutData,
+    cells: Slice<BoxLayoutCellData>,
+    size: Coord,
+    spacing: Coord,
+    padding: &Padding,
+    align_items: FlexboxLayoutAlignItems,
+    repeater_indexes: Slice<u32>,
+) -> SharedVector<Coord> {
+    debug_assert_eq!(data.cells.len(), cells.len());
+    let lines = flexbox_lines(&box_layout_items(data.cells.as_slice(), data.size), data);
+    let mut layout_data = box_layout_items(cells.as_slice(), size);
+    if layout_data.is_empty() {
+        return layout_cache_from_items(&layout_data, repeater_indexes);
+    }
+
+    let mut line_sizes = lines
+        .iter()
+        .map(|line| layout_data[line.clone()].iter().map(|it| it.pref).fold(0 as Coord, Coord::max))
+        .collect::<Vec<_>>();
+    // The lines are stretched to fill the available space
+    let available_size = size - padding.begin - padding.end - spacing * (lines.len() - 1) as Coord;
+    let lines_size: Coord = line_sizes.iter().copied().sum();
+    if available_size > lines_size {
+        let extra = (available_size - lines_size) / lines.len() as Coord;
+        for s in &mut line_sizes {
+            *s += extra;
+        }
+    }
+
+    let mut pos = padding.begin;
+    for (line, line_size) in lines.into_iter().zip(line_sizes) {
+        for it in &mut layout_data[line] {
+            it.size = match align_items {
+                FlexboxLayoutAlignItems::Stretch => line_size.min(it.max).max(it.min),
+                _ => it.pref.min(line_size).max(it.min),
+            };
+            it.pos = pos
+                + match align_items {
+                    FlexboxLayoutAlignItems::Stretch | FlexboxLayoutAlignItems::Start => 0 as _,
+                    FlexboxLayoutAlignItems::Center => (line_size - it.size) / 2 as Coord,
+                    FlexboxLayoutAlignItems::End => line_size - it.size,
+                };
+        }
+        pos += line_size + spacing;
+    }
+    layout_cache_from_items(&layout_data, repeater_indexes)
+}
+
+/// Return the LayoutInfo for a FlexboxLayout along its main axis.
+pub fn flexbox_layout_info(
+    cells: Slice<BoxLayoutCellData>,
+    spacing: Coord,
+    padding: &Padding,
+    alignment: LayoutAlignment,
+    wrap: FlexboxLayoutWrap,
+) -> LayoutInfo {
+    let info = box_layout_info(cells, spacing, padding, alignment);
+    if wrap == FlexboxLayoutWrap::NoWrap || cells.is_empty() {
+        return info;
+    }
+    // Each item can be placed on its own line
+    let min = cells.iter().map(|c| c.constraint.min).fold(0 as Coord, Coord::max)
+        + padding.begin
+        + padding.end;
+    LayoutInfo { min, ..info }
+}
+
+/// Return the LayoutInfo for a FlexboxLayout along its cross axis.
+///
+/// The items are split in lines according to the size in `data`.
+pub fn flexbox_layout_info_cross(
+    data: &FlexboxLayoutData,
+    cells: Slice<BoxLayoutCellData>,
+    spacing: Coord,
+    padding: &Padding,
+) -> LayoutInfo {
+    let lines = flexbox_lines(&box_layout_items(data.cells.as_slice(), data.size), data);
+    if lines.len() <= 1 {
+        return box_layout_info_ortho(cells, padding);
+    }
+    let extra_w = padding.begin + padding.end + spacing * (lines.len() - 1) as Coord;
+    let mut result = LayoutInfo {
+        min: extra_w,
+        max: extra_w,
+        preferred: extra_w,
+        stretch: f32::MAX,
+        ..Default::default()
+    };
+    for line in lines {
+        let line_info =
+            box_layout_info_ortho(Slice::from_slice(&cells.as_slice()[line]), &Padding::default());
+        result.min += line_info.min;
+        result.max = Saturating::add(result.max, line_info.max);
+        result.preferred += line_info.preferred;
+        result.stretch = result.stretch.min(line_info.stretch);
+    }
+    result
+}
+
+#[test]
+#[allow(clippy::float_cmp)] // We want bit-wise equality here
+fn test_solve_flexbox_layout() {
+    let cell = |preferred: Coord| BoxLayoutCellData {
+        constraint: LayoutInfo { preferred, stretch: 1., ..Default::default() },
+    };
+    let main_cells = [cell(40.), cell(40.), cell(40.)];
+    let cross_cells = [cell(10.), cell(20.), cell(10.)];
+    let data = FlexboxLayoutData {
+        size: 100.,
+        spacing: 10.,
+        padding: Padding::default(),
+        alignment: LayoutAlignment::Start,
+        wrap: FlexboxLayoutWrap::Wrap,
+        cells: Slice::from_slice(&main_cells),
+    };
+    let main = solve_flexbox_layout(&data, Slice::default());
+    assert_eq!(main.as_slice(), &[0., 40., 50., 40., 0., 40.]);
+
+    let cross = solve_flexbox_layout_cross(
+        &data,
+        Slice::from_slice(&cross_cells),
+        50.,
+        5.,
+        &Padding::default(),
+        FlexboxLayoutAlignItems::Start,
+        Slice::default(),
+    );
+    // The remaining space is distributed between the lines
+    assert_eq!(cross.as_slice(), &[0., 10., 0., 20., 32.5, 10.]);
+
+    let info =
+        flexbox_layout_info_cross(&data, Slice::from_slice(&cross_cells), 5., &Padding::default());
+    assert_eq!(info.preferred, 35.);
+
+    let data = FlexboxLayoutData { size: 150., wrap: FlexboxLayoutWrap::NoWrap, ..data };
+    let main = solve_flexbox_layout(&data, Slice::default());
+    assert_eq!(main.as_slice(), &[0., 40., 50., 40., 100., 40.]);
+}
+
 /// Given the cells of a layout of a Dialog, re-order the button according to the platform
 ///
 /// This function assume that the `roles` contains the roles of the button which are the first `cells`
@@ -779,6 +997,60 @@ pub extern "C" fn slint_box_layout_info_ortho(
         super::box_layout_info_ortho(cells, padding)
     }
 
+    #[no_mangle]
+    pub extern "C" fn slint_solve_flexbox_layout(
+        data: &FlexboxLayoutData,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flexbox_layout(data, repeater_indexes)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_solve_flexbox_layout_cross(
+        data: &FlexboxLayoutData,
+        cells: Slice<BoxLayoutCellData>,
+        size: Coord,
+        spacing: Coord,
+        padding: &Padding,
+        align_items: FlexboxLayoutAlignItems,
+        repeater_indexes: Slice<u32>,
+        result: &mut SharedVector<Coord>,
+    ) {
+        *result = super::solve_flexbox_layout_cross(
+            data,
+            cells,
+            size,
+            spacing,
+            padding,
+            align_items,
+            repeater_indexes,
+        )
+    }
+
+    #[no_mangle]
+    /// Return the LayoutInfo for a FlexboxLayout along its main axis.
+    pub extern "C" fn slint_flexbox_layout_info(
+        cells: Slice<BoxLayoutCellData>,
+        spacing: Coord,
+        padding: &Padding,
+        alignment: LayoutAlignment,
+        wrap: FlexboxLayoutWrap,
+    ) -> LayoutInfo {
+        super::flexbox_layout_info(cells, spacing, padding, alignment, wrap)
+    }
+
+    #[no_mangle]
+    /// Return the LayoutInfo for a FlexboxLayout along its cross axis.
+    pub extern "C" fn slint_flexbox_layout_info_cross(
+        data: &FlexboxLayoutData,
+        cells: Slice<BoxLayoutCellData>,
+        spacing: Coord,
+        padding: &Padding,
+    ) -> LayoutInfo {
+        super::flexbox_layout_info_cross(data, cells, spacing, padding)
+    }
+
     /// Calls [`reorder_dialog_button_layout`].
     ///
     /// Safety: `cells` must be a pointer to a mutable array of cell data, the array must have at
@@ -45,6 +45,9 @@
 #[cfg(feature = "software-renderer")]
 pub mod software_renderer;
 pub mod string;
+pub mod styled_text;
+#[cfg(feature = "std")]
+mod svg_renderer;
 pub mod tests;
 pub mod textlayout;
 pub mod timers;
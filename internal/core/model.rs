@@ -7,11 +7,13 @@
 
 use crate::item_tree::ItemTreeVTable;
 use crate::item_tree::TraversalOrder;
-pub use crate::items::{StandardListViewItem, TableColumn};
+pub use crate::items::{StandardListViewItem, StandardTreeViewItem, TableColumn};
 use crate::layout::Orientation;
 use crate::lengths::{LogicalLength, RectLengths};
 use crate::{Coord, Property, SharedString, SharedVector};
 pub use adapters::{FilterModel, MapModel, ReverseModel, SortModel};
+#[cfg(target_has_atomic = "ptr")]
+pub use async_model::AsyncModel;
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use alloc::vec::Vec;
@@ -21,11 +23,15 @@
 #[allow(unused)]
 use euclid::num::{Ceil, Floor};
 pub use model_peer::*;
+pub use tree::*;
 use once_cell::unsync::OnceCell;
 use pin_project::pin_project;
 
 mod adapters;
+#[cfg(target_has_atomic = "ptr")]
+mod async_model;
 mod model_peer;
+mod tree;
 
 type ItemTreeRc<C> = vtable::VRc<crate::item_tree::ItemTreeVTable, C>;
 
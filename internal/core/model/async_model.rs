@@ -0,0 +1,273 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the [`AsyncModel`], a model that loads its rows in pages
+//! in the background.
+
+use super::*;
+use alloc::collections::BTreeMap;
+use core::future::Future;
+use core::ops::Range;
+
+type FetchFunction<T> = dyn Fn(Range<usize>) -> Pin<Box<dyn Future<Output = Vec<T>>>>;
+
+enum Page<T> {
+    /// The page was requested. The number identifies the request, so that the result of a
+    /// request that was made before a reset is discarded.
+    Loading(usize),
+    Loaded(Vec<T>),
+}
+
+struct AsyncModelInner<T> {
+    row_count: Cell<usize>,
+    page_size: Cell<usize>,
+    placeholder: T,
+    pages: RefCell<BTreeMap<usize, Page<T>>>,
+    next_request: Cell<usize>,
+    fetch: Box<FetchFunction<T>>,
+    notify: ModelNotify,
+}
+
+impl<T> AsyncModelInner<T> {
+    fn page_loaded(&self, page: usize, request: usize, mut rows: Vec<T>) {
+        let range = {
+            let mut pages = self.pages.borrow_mut();
+            if !matches!(pages.get(&page), Some(Page::Loading(r)) if *r == request) {
+                return;
+            }
+            let range = self.page_range(page);
+            rows.truncate(range.len());
+            pages.insert(page, Page::Loaded(rows));
+            range
+        };
+        for row in range {
+            self.notify.row_changed(row);
+        }
+    }
+
+    /// Calls the fetch function for the request, unless the model was reset since
+    fn fetch(
+        &self,
+        page: usize,
+        request: usize,
+        range: Range<usize>,
+    ) -> Option<Pin<Box<dyn Future<Output = Vec<T>>>>> {
+        let pending =
+            matches!(self.pages.borrow().get(&page), Some(Page::Loading(r)) if *r == request);
+        pending.then(|| (self.fetch)(range))
+    }
+
+    fn page_range(&self, page: usize) -> Range<usize> {
+        let page_size = self.page_size.get();
+        let start = page * page_size;
+        start.min(self.row_count.get())..(start + page_size).min(self.row_count.get())
+    }
+}
+
+/// A model that loads its rows asynchronously, in pages of consecutive rows.
+///
+/// The rows are fetched by a function that receives the range of the requested rows and returns
+/// a future that resolves to their data. The function is called and the future is run in the Slint
+/// event loop with [`spawn_local()`](crate::SlintContext::spawn_local), so the user interface stays
+/// responsive while the data is loaded, for example from a database.
+///
+/// Pages are only requested when the view accesses one of their rows. Until a page was loaded,
+/// its rows are represented by a placeholder value. When the data arrives, the view is notified
+/// that the rows changed. Loaded pages are kept until [`Self::reset()`] is called.
+///
+/// In combination with a `ListView`, which only instantiates the visible rows, this makes it
+/// possible to show huge datasets.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use slint::{AsyncModel, ModelRc, StandardListViewItem};
+/// # async fn query_database(range: core::ops::Range<usize>) -> Vec<String> {
+/// #     range.map(|i| i.to_string()).collect()
+/// # }
+/// let model = AsyncModel::new(
+///     1_000_000,
+///     StandardListViewItem::from("Loading..."),
+///     |range| async move {
+///         query_database(range)
+///             .await
+///             .into_iter()
+///             .map(|text| StandardListViewItem::from(text.as_str()))
+///             .collect()
+///     },
+/// );
+/// let model = ModelRc::new(model);
+/// ```
+pub struct AsyncModel<T>(Rc<AsyncModelInner<T>>);
+
+impl<T: Clone + 'static> AsyncModel<T> {
+    /// The default number of rows that are fetched at once
+    pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+    /// Creates a new model with `row_count` rows, where the rows that are not yet loaded are
+    /// represented by `placeholder`.
+    ///
+    /// `fetch` is called with the range of rows of a page and should return a future that
+    /// resolves to the data of these rows. If it resolves to fewer rows, the remaining rows
+    /// of the page keep the placeholder.
+    pub fn new<F, Fut>(row_count: usize, placeholder: T, fetch: F) -> Self
+    where
+        F: Fn(Range<usize>) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        Self(Rc::new(AsyncModelInner {
+            row_count: Cell::new(row_count),
+            page_size: Cell::new(Self::DEFAULT_PAGE_SIZE),
+            placeholder,
+            pages: Default::default(),
+            next_request: Default::default(),
+            fetch: Box::new(move |range| Box::pin(fetch(range))),
+            notify: Default::default(),
+        }))
+    }
+
+    /// Returns the number of rows that are fetched at once
+    pub fn page_size(&self) -> usize {
+        self.0.page_size.get()
+    }
+
+    /// Sets the number of rows that are fetched at once. This discards all loaded rows.
+    ///
+    /// Panics if `page_size` is zero.
+    pub fn set_page_size(&self, page_size: usize) {
+        assert!(page_size > 0, "The page size of an AsyncModel must not be zero");
+        self.0.page_size.set(page_size);
+        self.reset();
+    }
+
+    /// Changes the number of rows of the model. Rows that were already loaded are kept,
+    /// and the new rows will be fetched when they are accessed.
+    pub fn set_row_count(&self, row_count: usize) {
+        let old_count = self.0.row_count.replace(row_count);
+        let page_size = self.0.page_size.get();
+        // The last page may have been fetched partially, or not at all, so it needs to be
+        // fetched again.
+        self.0
+            .pages
+            .borrow_mut()
+            .retain(|page, _| (*page + 1) * page_size <= old_count.min(row_count));
+        if row_count > old_count {
+            self.0.notify.row_added(old_count, row_count - old_count);
+        } else if row_count < old_count {
+            self.0.notify.row_removed(row_count, old_count - row_count);
+        }
+    }
+
+    /// Discards all the loaded rows, so that they are fetched again when they are accessed.
+    /// Requests that are still in progress are ignored.
+    pub fn reset(&self) {
+        self.0.pages.borrow_mut().clear();
+        self.0.notify.reset();
+    }
+
+    /// Returns true if the data of the row was loaded, and false if the row is represented
+    /// by the placeholder.
+    pub fn is_row_loaded(&self, row: usize) -> bool {
+        if row >= self.0.row_count.get() {
+            return false;
+        }
+        let page_size = self.0.page_size.get();
+        matches!(self.0.pages.borrow().get(&(row / page_size)),
+            Some(Page::Loaded(rows)) if row % page_size < rows.len())
+    }
+
+    fn request_page(&self, page: usize) {
+        let request = self.0.next_request.get();
+        self.0.next_request.set(request.wrapping_add(1));
+        // The page is marked as loading first, in case the future is polled right away
+        self.0.pages.borrow_mut().insert(page, Page::Loading(request));
+        let range = self.0.page_range(page);
+        let inner = Rc::downgrade(&self.0);
+        // `fetch` is only called from the spawned future, so that it's not called again for
+        // every access to the page when the future can't be spawned
+        let spawned = crate::context::with_global_context(
+            || Err(crate::platform::PlatformError::NoPlatform),
+            |ctx| {
+                ctx.spawn_local(async move {
+                    let Some(rows) =
+                        inner.upgrade().and_then(|inner| inner.fetch(page, request, range))
+                    else {
+                        return;
+                    };
+                    let rows = rows.await;
+                    if let Some(inner) = inner.upgrade() {
+                        inner.page_loaded(page, request, rows);
+                    }
+                })
+            },
+        );
+        // Without an event loop, the placeholder is shown and the page is requested again on the
+        // next access.
+        if !matches!(spawned, Ok(Ok(_))) {
+            self.0.pages.borrow_mut().remove(&page);
+        }
+    }
+}
+
+impl<T: Clone + 'static> Model for AsyncModel<T> {
+    type Data = T;
+
+    fn row_count(&self) -> usize {
+        self.0.row_count.get()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        if row >= self.0.row_count.get() {
+            return None;
+        }
+        let page_size = self.0.page_size.get();
+        let page = row / page_size;
+        match self.0.pages.borrow().get(&page) {
+            Some(Page::Loaded(rows)) => {
+                return Some(rows.get(row % page_size).unwrap_or(&self.0.placeholder).clone())
+            }
+            Some(Page::Loading(_)) => return Some(self.0.placeholder.clone()),
+            None => {}
+        }
+        self.request_page(page);
+        Some(self.0.placeholder.clone())
+    }
+
+    /// Sets the data of a row that was already loaded. Rows that were not loaded yet
+    /// are not changed.
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        let page_size = self.0.page_size.get();
+        match self.0.pages.borrow_mut().get_mut(&(row / page_size)) {
+            Some(Page::Loaded(rows)) if row % page_size < rows.len() => {
+                rows[row % page_size] = data;
+            }
+            _ => return,
+        }
+        self.0.notify.row_changed(row);
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[test]
+fn async_model_without_event_loop() {
+    let fetched = Rc::new(Cell::new(0));
+    let model = AsyncModel::new(10, 0, {
+        let fetched = fetched.clone();
+        move |range: Range<usize>| {
+            fetched.set(fetched.get() + 1);
+            core::future::ready(range.collect())
+        }
+    });
+    assert_eq!(model.row_data(1), Some(0));
+    assert_eq!(model.row_data(2), Some(0));
+    assert!(!model.is_row_loaded(1));
+    // The fetch function is only called once the request runs in the event loop
+    assert_eq!(fetched.get(), 0);
+}
@@ -0,0 +1,659 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains the hierarchical models and the adapter that flattens
+//! them for the StandardTreeView.
+
+use super::*;
+use alloc::rc::Weak;
+
+/// A listener for the changes of a [`TreeModel`].
+///
+/// All the paths are relative to the root of the tree: `[]` is the root, `[2]` is the third top level
+/// row and `[2, 0]` is the first child of that row.
+pub trait TreeModelChangeListener {
+    /// Notify the listener that the data of the row at `path` was changed
+    fn row_changed(&self, path: &[usize]);
+    /// Notify the listener that `count` rows were added at `index` in the children of `parent`
+    fn row_added(&self, parent: &[usize], index: usize, count: usize);
+    /// Notify the listener that `count` rows were removed at `index` in the children of `parent`
+    fn row_removed(&self, parent: &[usize], index: usize, count: usize);
+    /// Notify the listener that the whole tree was changed
+    fn reset(&self);
+}
+
+/// This trait defines the interface that users of a tree model can use to track changes
+/// to it. It is supplied via [`TreeModel::model_tracker`] and implementations usually
+/// return a reference to their [`TreeModelNotify`] field.
+pub trait TreeModelTracker {
+    /// Attach a listener. It will be notified when the model changes until it is dropped.
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>);
+}
+
+impl TreeModelTracker for () {
+    fn attach_listener(&self, _listener: Weak<dyn TreeModelChangeListener>) {}
+}
+
+/// Dispatch notifications from a [`TreeModel`] to its listeners.
+/// Typically, you would want to put this in the implementation of the TreeModel.
+#[derive(Default)]
+pub struct TreeModelNotify {
+    listeners: RefCell<Vec<Weak<dyn TreeModelChangeListener>>>,
+}
+
+impl TreeModelNotify {
+    /// Notify the listeners that the row at `path` was changed
+    pub fn row_changed(&self, path: &[usize]) {
+        self.for_each_listener(|l| l.row_changed(path))
+    }
+    /// Notify the listeners that `count` rows were added at `index` in the children of `parent`
+    pub fn row_added(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.row_added(parent, index, count))
+    }
+    /// Notify the listeners that `count` rows were removed at `index` in the children of `parent`
+    pub fn row_removed(&self, parent: &[usize], index: usize, count: usize) {
+        self.for_each_listener(|l| l.row_removed(parent, index, count))
+    }
+    /// Notify the listeners that the tree was changed and everything needs to be reloaded
+    pub fn reset(&self) {
+        self.for_each_listener(|l| l.reset())
+    }
+
+    fn for_each_listener(&self, f: impl Fn(&dyn TreeModelChangeListener)) {
+        // Don't keep the RefCell borrowed while calling the listeners, they may attach new listeners
+        let listeners = {
+            let mut listeners = self.listeners.borrow_mut();
+            listeners.retain(|l| l.strong_count() > 0);
+            listeners.clone()
+        };
+        for listener in listeners.iter().filter_map(|l| l.upgrade()) {
+            f(&*listener)
+        }
+    }
+}
+
+impl TreeModelTracker for TreeModelNotify {
+    fn attach_listener(&self, listener: Weak<dyn TreeModelChangeListener>) {
+        self.listeners.borrow_mut().push(listener)
+    }
+}
+
+/// A TreeModel is a hierarchical model: each row can have children rows.
+///
+/// Rows are identified by their path from the root of the tree: the path `[]` is the root,
+/// `[2]` is the third top level row and `[2, 0]` is the first child of that row.
+///
+/// Use a [`FlattenedTreeModel`] to show a TreeModel in a `StandardTreeView`.
+///
+/// ## Example
+///
+/// A tree model listing the contents of directories could load the children of a row lazily,
+/// when the row is expanded for the first time, by implementing [`TreeModel::set_expanded`].
+pub trait TreeModel {
+    /// The model data: each row of the tree has this data
+    type Data;
+    /// The number of children of the row at `parent`. Use `&[]` for the number of top level rows.
+    fn row_count(&self, parent: &[usize]) -> usize;
+    /// Returns the data for the row at `path`, or None if there is no such row.
+    fn row_data(&self, path: &[usize]) -> Option<Self::Data>;
+    /// Returns true if the row at `path` has children.
+    ///
+    /// The default implementation returns true if `row_count(path)` is not zero. Re-implement it
+    /// for models that load their children lazily to show that a row can be expanded before its
+    /// children are loaded.
+    fn has_children(&self, path: &[usize]) -> bool {
+        self.row_count(path) > 0
+    }
+    /// Called when the row at `path` is expanded or collapsed in a view.
+    ///
+    /// When `expanded` is true, this is called before the children of the row are queried,
+    /// so it is a good place to load them lazily. The default implementation does nothing.
+    fn set_expanded(&self, _path: &[usize], _expanded: bool) {}
+    /// The implementation should return a reference to its [`TreeModelNotify`] field.
+    ///
+    /// You can return `&()` if your `TreeModel` is constant and does not have a TreeModelNotify field.
+    fn model_tracker(&self) -> &dyn TreeModelTracker;
+}
+
+impl<M: TreeModel> TreeModel for Rc<M> {
+    type Data = M::Data;
+
+    fn row_count(&self, parent: &[usize]) -> usize {
+        (**self).row_count(parent)
+    }
+
+    fn row_data(&self, path: &[usize]) -> Option<Self::Data> {
+        (**self).row_data(path)
+    }
+
+    fn has_children(&self, path: &[usize]) -> bool {
+        (**self).has_children(path)
+    }
+
+    fn set_expanded(&self, path: &[usize], expanded: bool) {
+        (**self).set_expanded(path, expanded)
+    }
+
+    fn model_tracker(&self) -> &dyn TreeModelTracker {
+        (**self).model_tracker()
+    }
+}
+
+/// A row of a [`VecTreeModel`] with its children.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeNode<T> {
+    /// The data of the row
+    pub data: T,
+    /// The children rows
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Creates a node without children
+    pub fn new(data: T) -> Self {
+        Self { data, children: Vec::new() }
+    }
+
+    /// Creates a node with the given children
+    pub fn with_children(data: T, children: impl IntoIterator<Item = TreeNode<T>>) -> Self {
+        Self { data, children: children.into_iter().collect() }
+    }
+}
+
+/// A [`TreeModel`] backed by a `Vec<TreeNode<T>>`
+///
+/// ```
+/// # use slint::{TreeModel, TreeNode, VecTreeModel};
+/// let model = VecTreeModel::from(vec![
+///     TreeNode::with_children("Fruits", [TreeNode::new("Apple"), TreeNode::new("Pear")]),
+///     TreeNode::new("Vegetables"),
+/// ]);
+/// model.push(&[1], TreeNode::new("Carrot"));
+/// assert_eq!(model.row_count(&[]), 2);
+/// assert_eq!(model.row_data(&[0, 1]), Some("Pear"));
+/// assert_eq!(model.row_data(&[1, 0]), Some("Carrot"));
+/// ```
+#[derive(Default)]
+pub struct VecTreeModel<T> {
+    roots: RefCell<Vec<TreeNode<T>>>,
+    notify: TreeModelNotify,
+}
+
+impl<T> VecTreeModel<T> {
+    /// Inserts a row with its children at position `index` in the children of `parent`.
+    ///
+    /// Panics if `parent` doesn't exist or if `index` is greater than its number of children.
+    pub fn insert(&self, parent: &[usize], index: usize, node: TreeNode<T>) {
+        with_children_mut(&mut self.roots.borrow_mut(), parent, |children| {
+            children.insert(index, node)
+        });
+        self.notify.row_added(parent, index, 1)
+    }
+
+    /// Adds a row with its children after the last child of `parent`.
+    ///
+    /// Panics if `parent` doesn't exist.
+    pub fn push(&self, parent: &[usize], node: TreeNode<T>) {
+        let index = with_children_mut(&mut self.roots.borrow_mut(), parent, |children| {
+            children.push(node);
+            children.len() - 1
+        });
+        self.notify.row_added(parent, index, 1)
+    }
+
+    /// Removes the row at `path` and returns it with its children.
+    ///
+    /// Panics if `path` is empty or doesn't exist.
+    pub fn remove(&self, path: &[usize]) -> TreeNode<T> {
+        let (index, parent) = path.split_last().expect("cannot remove the root of the tree");
+        let node = with_children_mut(&mut self.roots.borrow_mut(), parent, |children| {
+            children.remove(*index)
+        });
+        self.notify.row_removed(parent, *index, 1);
+        node
+    }
+
+    /// Sets the data of the row at `path`.
+    ///
+    /// Panics if `path` is empty or doesn't exist.
+    pub fn set_row_data(&self, path: &[usize], data: T) {
+        let (index, parent) = path.split_last().expect("the root of the tree has no data");
+        with_children_mut(&mut self.roots.borrow_mut(), parent, |children| {
+            children[*index].data = data
+        });
+        self.notify.row_changed(path)
+    }
+
+    /// Replaces the whole tree
+    pub fn set_vec(&self, roots: impl Into<Vec<TreeNode<T>>>) {
+        *self.roots.borrow_mut() = roots.into();
+        self.notify.reset()
+    }
+}
+
+fn with_children_mut<T, R>(
+    roots: &mut Vec<TreeNode<T>>,
+    parent: &[usize],
+    f: impl FnOnce(&mut Vec<TreeNode<T>>) -> R,
+) -> R {
+    let children = parent.iter().fold(roots, |children, index| &mut children[*index].children);
+    f(children)
+}
+
+fn find_node<'a, T>(roots: &'a [TreeNode<T>], path: &[usize]) -> Option<&'a TreeNode<T>> {
+    let (first, rest) = path.split_first()?;
+    rest.iter().try_fold(roots.get(*first)?, |node, index| node.children.get(*index))
+}
+
+impl<T> From<Vec<TreeNode<T>>> for VecTreeModel<T> {
+    fn from(roots: Vec<TreeNode<T>>) -> Self {
+        Self { roots: RefCell::new(roots), notify: Default::default() }
+    }
+}
+
+impl<T: Clone> TreeModel for VecTreeModel<T> {
+    type Data = T;
+
+    fn row_count(&self, parent: &[usize]) -> usize {
+        let roots = self.roots.borrow();
+        if parent.is_empty() {
+            roots.len()
+        } else {
+            find_node(&roots, parent).map_or(0, |node| node.children.len())
+        }
+    }
+
+    fn row_data(&self, path: &[usize]) -> Option<Self::Data> {
+        find_node(&self.roots.borrow(), path).map(|node| node.data.clone())
+    }
+
+    fn model_tracker(&self) -> &dyn TreeModelTracker {
+        &self.notify
+    }
+}
+
+struct FlatRow {
+    path: Vec<usize>,
+    expanded: bool,
+}
+
+struct FlattenedTreeInner<M> {
+    tree: M,
+    // The visible rows, in order, with their path in the tree
+    rows: RefCell<Vec<FlatRow>>,
+    notify: ModelNotify,
+}
+
+impl<M: TreeModel> FlattenedTreeInner<M> {
+    fn top_level_rows(&self) -> Vec<FlatRow> {
+        (0..self.tree.row_count(&[])).map(|i| FlatRow { path: vec![i], expanded: false }).collect()
+    }
+
+    /// Returns the index of the row with that path, if it is visible
+    fn find_row(&self, path: &[usize]) -> Option<usize> {
+        self.rows.borrow().iter().position(|r| r.path == path)
+    }
+
+    /// Returns the range of the visible rows that are descendants of `parent`,
+    /// or None if the children of parent are not visible.
+    fn children_range(&self, parent: &[usize]) -> Option<core::ops::Range<usize>> {
+        let rows = self.rows.borrow();
+        let start = if parent.is_empty() {
+            0
+        } else {
+            let index = rows.iter().position(|r| r.path == parent)?;
+            if !rows[index].expanded {
+                return None;
+            }
+            index + 1
+        };
+        let end = rows[start..]
+            .iter()
+            .position(|r| !r.path.starts_with(parent))
+            .map_or(rows.len(), |p| start + p);
+        Some(start..end)
+    }
+}
+
+impl<M: TreeModel> TreeModelChangeListener for FlattenedTreeInner<M> {
+    fn row_changed(&self, path: &[usize]) {
+        if let Some(row) = self.find_row(path) {
+            self.notify.row_changed(row);
+        }
+    }
+
+    fn row_added(&self, parent: &[usize], index: usize, count: usize) {
+        let Some(range) = self.children_range(parent) else {
+            // The parent may be visible but collapsed, and now has children
+            if let Some(row) = self.find_row(parent) {
+                self.notify.row_changed(row);
+            }
+            return;
+        };
+        let depth = parent.len();
+        let insert_at = {
+            let mut rows = self.rows.borrow_mut();
+            let insert_at = rows[range.clone()]
+                .iter()
+                .position(|r| r.path[depth] >= index)
+                .map_or(range.end, |p| range.start + p);
+            for r in &mut rows[insert_at..range.end] {
+                r.path[depth] += count;
+            }
+            rows.splice(
+                insert_at..insert_at,
+                (index..index + count).map(|i| {
+                    let mut path = parent.to_vec();
+                    path.push(i);
+                    FlatRow { path, expanded: false }
+                }),
+            );
+            insert_at
+        };
+        self.notify.row_added(insert_at, count);
+    }
+
+    fn row_removed(&self, parent: &[usize], index: usize, count: usize) {
+        let Some(range) = self.children_range(parent) else {
+            if let Some(row) = self.find_row(parent) {
+                self.notify.row_changed(row);
+            }
+            return;
+        };
+        let depth = parent.len();
+        let removed = {
+            let mut rows = self.rows.borrow_mut();
+            let slice = &rows[range.clone()];
+            let start = slice
+                .iter()
+                .position(|r| r.path[depth] >= index)
+                .map_or(range.end, |p| range.start + p);
+            let end = slice
+                .iter()
+                .position(|r| r.path[depth] >= index + count)
+                .map_or(range.end, |p| range.start + p);
+            for r in &mut rows[end..range.end] {
+                r.path[depth] -= count;
+            }
+            rows.drain(start..end);
+            start..end
+        };
+        if !removed.is_empty() {
+            self.notify.row_removed(removed.start, removed.len());
+        }
+    }
+
+    fn reset(&self) {
+        *self.rows.borrow_mut() = self.top_level_rows();
+        self.notify.reset();
+    }
+}
+
+/// Provides the visible rows of a [`TreeModel`] as a flat list of [`StandardTreeViewItem`]s,
+/// the model of the `StandardTreeView` widget.
+///
+/// Only the top level rows and the children of expanded rows are part of the model.
+/// Rows are expanded or collapsed with [`FlattenedTreeModel::set_expanded`], or by setting
+/// the `expanded` field of the item with [`Model::set_row_data`], which is what the
+/// `StandardTreeView` does when the user expands or collapses a row.
+///
+/// When the tree model is updated, the `FlattenedTreeModel` is updated accordingly.
+///
+/// ## Example
+///
+/// ```
+/// # use slint::{FlattenedTreeModel, Model, StandardTreeViewItem, TreeNode, VecTreeModel};
+/// let tree = VecTreeModel::from(vec![
+///     TreeNode::with_children("Fruits", [TreeNode::new("Apple"), TreeNode::new("Pear")]),
+///     TreeNode::new("Vegetables"),
+/// ]);
+/// let model = FlattenedTreeModel::new(tree);
+/// assert_eq!(model.row_count(), 2);
+/// model.set_expanded(0, true);
+/// assert_eq!(model.row_count(), 4);
+/// let item = model.row_data(1).unwrap();
+/// assert_eq!((item.text.as_str(), item.level), ("Apple", 1));
+/// assert_eq!(model.path(2), Some(vec![0, 1]));
+/// ```
+pub struct FlattenedTreeModel<M>(Rc<FlattenedTreeInner<M>>);
+
+impl<M> FlattenedTreeModel<M>
+where
+    M: TreeModel + 'static,
+    M::Data: Into<StandardListViewItem>,
+{
+    /// Creates a new FlattenedTreeModel showing the top level rows of `tree`
+    pub fn new(tree: M) -> Self {
+        let inner = Rc::new(FlattenedTreeInner {
+            tree,
+            rows: Default::default(),
+            notify: Default::default(),
+        });
+        *inner.rows.borrow_mut() = inner.top_level_rows();
+        let listener: Rc<dyn TreeModelChangeListener> = inner.clone();
+        inner.tree.model_tracker().attach_listener(Rc::downgrade(&listener));
+        Self(inner)
+    }
+
+    /// Returns a reference to the tree model
+    pub fn source_model(&self) -> &M {
+        &self.0.tree
+    }
+
+    /// Returns the path in the tree model of the visible row at `row`
+    pub fn path(&self, row: usize) -> Option<Vec<usize>> {
+        self.0.rows.borrow().get(row).map(|r| r.path.clone())
+    }
+
+    /// Expands or collapses the visible row at `row`.
+    ///
+    /// Expanding a row shows its children, and calls [`TreeModel::set_expanded`] before querying them.
+    /// Collapsing a row hides all its descendants.
+    pub fn set_expanded(&self, row: usize, expanded: bool) {
+        let inner = &self.0;
+        let Some(path) =
+            inner.rows.borrow().get(row).filter(|r| r.expanded != expanded).map(|r| r.path.clone())
+        else {
+            return;
+        };
+        // Call the tree model without borrowing the rows, as it may add children and notify us
+        inner.tree.set_expanded(&path, expanded);
+        let Some(row) = inner.find_row(&path) else { return };
+        inner.rows.borrow_mut()[row].expanded = expanded;
+        inner.notify.row_changed(row);
+        let range = if expanded {
+            let count = inner.tree.row_count(&path);
+            inner.rows.borrow_mut().splice(
+                row + 1..row + 1,
+                (0..count).map(|i| {
+                    let mut path = path.clone();
+                    path.push(i);
+                    FlatRow { path, expanded: false }
+                }),
+            );
+            row + 1..row + 1 + count
+        } else {
+            let mut rows = inner.rows.borrow_mut();
+            let count = rows[row + 1..].iter().take_while(|r| r.path.starts_with(&path)).count();
+            rows.drain(row + 1..row + 1 + count);
+            row + 1..row + 1 + count
+        };
+        if !range.is_empty() {
+            if expanded {
+                inner.notify.row_added(range.start, range.len());
+            } else {
+                inner.notify.row_removed(range.start, range.len());
+            }
+        }
+    }
+}
+
+impl<M> Model for FlattenedTreeModel<M>
+where
+    M: TreeModel + 'static,
+    M::Data: Into<StandardListViewItem>,
+{
+    type Data = StandardTreeViewItem;
+
+    fn row_count(&self) -> usize {
+        self.0.rows.borrow().len()
+    }
+
+    fn row_data(&self, row: usize) -> Option<Self::Data> {
+        let (path, expanded) =
+            self.0.rows.borrow().get(row).map(|r| (r.path.clone(), r.expanded))?;
+        let item: StandardListViewItem = self.0.tree.row_data(&path)?.into();
+        Some(StandardTreeViewItem {
+            text: item.text,
+            level: path.len() as i32 - 1,
+            has_children: self.0.tree.has_children(&path),
+            expanded,
+        })
+    }
+
+    /// Only the `expanded` field of the item is taken into account, to expand or collapse the row.
+    fn set_row_data(&self, row: usize, data: Self::Data) {
+        self.set_expanded(row, data.expanded)
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        &self.0.notify
+    }
+
+    fn as_any(&self) -> &dyn core::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(model: &FlattenedTreeModel<Rc<VecTreeModel<SharedString>>>) -> Vec<(String, i32)> {
+        model.iter().map(|item| (item.text.to_string(), item.level)).collect()
+    }
+
+    fn tree() -> Rc<VecTreeModel<SharedString>> {
+        let node = |text: &str| TreeNode::new(SharedString::from(text));
+        Rc::new(VecTreeModel::from(vec![
+            TreeNode::with_children("a".into(), [node("a0"), node("a1")]),
+            TreeNode::with_children(
+                "b".into(),
+                [TreeNode::with_children("b0".into(), [node("b00")]), node("b1")],
+            ),
+            node("c"),
+        ]))
+    }
+
+    #[test]
+    fn test_expand_collapse() {
+        let model = FlattenedTreeModel::new(tree());
+        assert_eq!(texts(&model), [("a".into(), 0), ("b".into(), 0), ("c".into(), 0)]);
+        let item = model.row_data(1).unwrap();
+        assert!(item.has_children && !item.expanded);
+        assert!(!model.row_data(2).unwrap().has_children);
+
+        model.set_expanded(1, true);
+        model.set_expanded(2, true);
+        assert_eq!(
+            texts(&model),
+            [
+                ("a".into(), 0),
+                ("b".into(), 0),
+                ("b0".into(), 1),
+                ("b00".into(), 2),
+                ("b1".into(), 1),
+                ("c".into(), 0)
+            ]
+        );
+        assert_eq!(model.path(3), Some(vec![1, 0, 0]));
+
+        // Collapsing hides all the descendants, setting the row data does the same
+        let mut item = model.row_data(1).unwrap();
+        item.expanded = false;
+        model.set_row_data(1, item);
+        assert_eq!(texts(&model), [("a".into(), 0), ("b".into(), 0), ("c".into(), 0)]);
+    }
+
+    #[test]
+    fn test_tree_changes() {
+        let tree = tree();
+        let model = Rc::new(FlattenedTreeModel::new(tree.clone()));
+        let checker = super::super::adapters::ModelChecker::new(model.clone());
+        model.set_expanded(0, true);
+        model.set_expanded(3, true);
+        checker.check();
+        assert_eq!(texts(&model).len(), 7);
+
+        tree.insert(&[0], 1, TreeNode::new("new".into()));
+        checker.check();
+        assert_eq!(texts(&model)[2], ("new".into(), 1));
+        assert_eq!(model.path(3), Some(vec![0, 2]));
+
+        tree.remove(&[0]);
+        checker.check();
+        assert_eq!(
+            texts(&model),
+            [("b".into(), 0), ("b0".into(), 1), ("b1".into(), 1), ("c".into(), 0)]
+        );
+        assert_eq!(model.path(2), Some(vec![0, 1]));
+
+        // Adding children to a collapsed row doesn't show them
+        tree.push(&[0, 0, 0], TreeNode::new("hidden".into()));
+        checker.check();
+        assert_eq!(model.row_count(), 4);
+
+        tree.set_row_data(&[0, 1], "renamed".into());
+        checker.check();
+        assert_eq!(texts(&model)[2], ("renamed".into(), 1));
+
+        tree.set_vec(vec![TreeNode::new("x".into())]);
+        checker.check();
+        assert_eq!(texts(&model), [("x".into(), 0)]);
+    }
+
+    #[test]
+    fn test_lazy_loading() {
+        struct LazyTree {
+            loaded: RefCell<Vec<usize>>,
+            notify: TreeModelNotify,
+        }
+        impl TreeModel for LazyTree {
+            type Data = SharedString;
+            fn row_count(&self, parent: &[usize]) -> usize {
+                match parent {
+                    [] => 2,
+                    [i] if self.loaded.borrow().contains(i) => 3,
+                    _ => 0,
+                }
+            }
+            fn row_data(&self, path: &[usize]) -> Option<SharedString> {
+                Some(crate::format!("{path:?}"))
+            }
+            fn has_children(&self, path: &[usize]) -> bool {
+                path.len() == 1
+            }
+            fn set_expanded(&self, path: &[usize], expanded: bool) {
+                if expanded && !self.loaded.borrow().contains(&path[0]) {
+                    self.loaded.borrow_mut().push(path[0]);
+                    self.notify.row_added(path, 0, 3);
+                }
+            }
+            fn model_tracker(&self) -> &dyn TreeModelTracker {
+                &self.notify
+            }
+        }
+
+        let model = FlattenedTreeModel::new(LazyTree {
+            loaded: Default::default(),
+            notify: Default::default(),
+        });
+        assert!(model.row_data(1).unwrap().has_children);
+        model.set_expanded(1, true);
+        assert_eq!(model.row_count(), 5);
+        assert_eq!(model.row_data(4).unwrap().text, "[1, 2]");
+        model.set_expanded(1, false);
+        model.set_expanded(1, true);
+        assert_eq!(model.row_count(), 5);
+    }
+}
@@ -9,12 +9,15 @@
 
 pub use crate::api::PlatformError;
 use crate::api::{LogicalPosition, LogicalSize};
+pub use crate::items::InputType;
 pub use crate::renderer::Renderer;
 #[cfg(feature = "software-renderer")]
 pub use crate::software_renderer;
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
 use crate::unsafe_single_threaded::OnceCell;
-pub use crate::window::{LayoutConstraints, WindowAdapter, WindowProperties};
+pub use crate::window::{
+    InputMethodProperties, InputMethodRequest, LayoutConstraints, WindowAdapter, WindowProperties,
+};
 use crate::SharedString;
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
@@ -315,6 +318,37 @@ pub enum WindowEvent {
     },
     /// The pointer exited the window.
     PointerExited,
+    /// A finger touched the screen.
+    ///
+    /// The first finger is handled like the left button of a mouse, and the two first fingers
+    /// form pinch, rotation and pan gestures.
+    TouchPressed {
+        /// The id of the touch point, which must stay the same until it is released.
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger that touches the screen has moved.
+    TouchMoved {
+        /// The id of the touch point, as given in [`WindowEvent::TouchPressed`].
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// A finger was lifted from the screen.
+    TouchReleased {
+        /// The id of the touch point, as given in [`WindowEvent::TouchPressed`].
+        touch_id: u64,
+        position: LogicalPosition,
+    },
+    /// The system cancelled the touch of a finger, for example because the window lost the
+    /// focus or a system gesture took over.
+    ///
+    /// Unlike [`WindowEvent::TouchReleased`], this doesn't click: the press of the finger is
+    /// cancelled, and so is the gesture it is part of.
+    TouchCancelled {
+        /// The id of the touch point, as given in [`WindowEvent::TouchPressed`].
+        touch_id: u64,
+        position: LogicalPosition,
+    },
     /// A key was pressed.
     KeyPressed {
         /// The unicode representation of the key pressed.
@@ -378,6 +412,36 @@ pub enum WindowEvent {
     /// The backend should dispatch this event with true when the window gains focus
     /// and false when the window loses focus.
     WindowActiveChanged(bool),
+    /// The input method updated the text being composed, also known as the pre-edit text.
+    ///
+    /// The pre-edit text is shown at the cursor position of the focused text input, but not
+    /// yet part of its text. An empty `text` removes the pre-edit text.
+    ImePreedit {
+        /// The text being composed.
+        text: SharedString,
+        /// The byte offset in `text` where the range highlighted by the input method starts.
+        selection_start: usize,
+        /// The byte offset in `text` where the range highlighted by the input method ends.
+        /// Equal to `selection_start` if the input method only places a cursor.
+        selection_end: usize,
+    },
+    /// The input method committed text. The pre-edit text is removed and `text` is inserted
+    /// at the cursor position of the focused text input.
+    ImeCommit {
+        /// The text to insert.
+        text: SharedString,
+    },
+    /// The input method requested to delete text around the cursor of the focused text input,
+    /// for example before committing a correction. This also removes the pre-edit text.
+    ///
+    /// When the input method sends several changes at once, dispatch this event first, followed
+    /// by [`WindowEvent::ImeCommit`] and then [`WindowEvent::ImePreedit`].
+    ImeDeleteSurrounding {
+        /// The number of bytes to delete before the cursor.
+        before_length: usize,
+        /// The number of bytes to delete after the cursor.
+        after_length: usize,
+    },
 }
 
 impl WindowEvent {
@@ -388,6 +452,10 @@ pub fn position(&self) -> Option<LogicalPosition> {
             WindowEvent::PointerReleased { position, .. } => Some(*position),
             WindowEvent::PointerMoved { position } => Some(*position),
             WindowEvent::PointerScrolled { position, .. } => Some(*position),
+            WindowEvent::TouchPressed { position, .. } => Some(*position),
+            WindowEvent::TouchMoved { position, .. } => Some(*position),
+            WindowEvent::TouchReleased { position, .. } => Some(*position),
+            WindowEvent::TouchCancelled { position, .. } => Some(*position),
             _ => None,
         }
     }
@@ -418,6 +418,8 @@ fn register_self_as_dependency(
 fn alloc_binding_holder<B: BindingCallable + 'static>(binding: B) -> *mut BindingHolder {
     /// Safety: _self must be a pointer that comes from a `Box<BindingHolder<B>>::into_raw()`
     unsafe fn binding_drop<B>(_self: *mut BindingHolder) {
+        #[cfg(feature = "binding-profiler")]
+        profiler::binding_dropped(_self);
         drop(Box::from_raw(_self as *mut BindingHolder<B>));
     }
 
@@ -625,6 +627,8 @@ unsafe fn update<T>(&self, value: *mut T) {
                 if binding.dirty.get() {
                     // clear all the nodes so that we can start from scratch
                     binding.dep_nodes.set(Default::default());
+                    #[cfg(feature = "binding-profiler")]
+                    let _scope = profiler::EvaluationScope::new(self, &*binding);
                     let r = (binding.vtable.evaluate)(
                         binding.as_mut().get_unchecked_mut() as *mut BindingHolder,
                         value as *mut (),
@@ -699,6 +703,8 @@ fn set_constant(&self) {
 
 impl Drop for PropertyHandle {
     fn drop(&mut self) {
+        #[cfg(feature = "binding-profiler")]
+        profiler::property_dropped(self);
         self.remove_binding();
         debug_assert!(self.handle.get() & 0b11 == 0);
         if self.handle.get() as *const u32 != (&CONSTANT_PROPERTY_SENTINEL) as *const u32 {
@@ -967,6 +973,15 @@ pub fn mark_dirty(&self) {
     pub fn set_constant(&self) {
         self.handle.set_constant();
     }
+
+    /// Set the name of this property, as reported by the [binding profiler](profiler).
+    ///
+    /// The property must not be moved afterwards. This does nothing if the `binding-profiler`
+    /// feature is not enabled.
+    pub fn set_profiler_name(&self, _name: &str) {
+        #[cfg(feature = "binding-profiler")]
+        profiler::set_property_name(&self.handle, _name);
+    }
 }
 
 #[test]
@@ -1314,6 +1329,8 @@ fn property_two_ways_binding_of_two_two_way_bindings() {
 
 mod change_tracker;
 pub use change_tracker::*;
+#[cfg(feature = "binding-profiler")]
+pub mod profiler;
 mod properties_animations;
 pub use crate::items::StateInfo;
 pub use properties_animations::*;
@@ -152,6 +152,55 @@ pub extern "C" fn slint_property_mark_dirty(handle: &PropertyHandleOpaque) {
     handle.0.mark_dirty()
 }
 
+/// Set the name of the property, as reported by the binding profiler.
+/// `name` must be a nul-terminated UTF-8 string.
+/// Does nothing if the `binding-profiler` feature is not enabled.
+#[no_mangle]
+pub unsafe extern "C" fn slint_property_set_profiler_name(
+    handle: &PropertyHandleOpaque,
+    name: *const core::ffi::c_char,
+) {
+    #[cfg(feature = "binding-profiler")]
+    if let Ok(name) = core::ffi::CStr::from_ptr(name).to_str() {
+        super::profiler::set_property_name(&handle.0, name);
+    }
+    #[cfg(not(feature = "binding-profiler"))]
+    let _ = (handle, name);
+}
+
+/// Start recording the evaluations of the bindings
+#[cfg(feature = "binding-profiler")]
+#[no_mangle]
+pub extern "C" fn slint_binding_profiler_start() {
+    super::profiler::start()
+}
+
+/// Stop recording the evaluations of the bindings
+#[cfg(feature = "binding-profiler")]
+#[no_mangle]
+pub extern "C" fn slint_binding_profiler_stop() {
+    super::profiler::stop()
+}
+
+/// Write the statistics of the evaluations recorded since the start, as JSON, in `out`
+#[cfg(feature = "binding-profiler")]
+#[no_mangle]
+pub extern "C" fn slint_binding_profiler_statistics_as_json(out: &mut crate::SharedString) {
+    *out = super::profiler::statistics_as_json().into();
+}
+
+/// Write the dependency graph of the properties in `out`, in the DOT format if `dot` is true,
+/// as JSON otherwise
+#[cfg(feature = "binding-profiler")]
+#[no_mangle]
+pub extern "C" fn slint_binding_profiler_dependency_graph(
+    dot: bool,
+    out: &mut crate::SharedString,
+) {
+    let graph = super::profiler::dependency_graph();
+    *out = if dot { graph.to_dot() } else { graph.to_json() }.into();
+}
+
 /// Destroy handle
 #[no_mangle]
 pub unsafe extern "C" fn slint_property_drop(handle: *mut PropertyHandleOpaque) {
@@ -0,0 +1,388 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+    Binding profiler and dependency graph introspection.
+
+    This module is only available with the `binding-profiler` feature. When that feature is enabled,
+    the property engine remembers the name of the properties (set by the generated code when the
+    `.slint` files are compiled with debug info, that is with the `SLINT_EMIT_DEBUG_INFO` environment
+    variable set) and which property owns each binding.
+
+    Between [`start()`] and [`stop()`], each evaluation of a binding is recorded, and [`statistics()`]
+    returns how many times, and for how long, the binding of each property was evaluated.
+    [`dependency_graph()`] returns the current dependencies between the properties.
+
+    Properties of different instances of the same component have the same name. Their statistics are
+    merged, but they are different nodes in the dependency graph.
+*/
+
+use super::{BindingHolder, DependencyListHead, PropertyHandle, CONSTANT_PROPERTY_SENTINEL};
+use crate::SharedString;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::fmt::Write;
+use core::time::Duration;
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+#[derive(Default)]
+struct ProfilerState {
+    /// The name of the properties, by address of their handle
+    names: HashMap<usize, SharedString>,
+    /// The property that owns a binding, by address of the binding
+    binding_owners: HashMap<usize, usize>,
+    /// The statistics recorded since `start()`, by property name
+    statistics: HashMap<SharedString, BindingStatistics>,
+    /// For each binding being evaluated, the time spent evaluating nested bindings
+    nested_durations: Vec<Duration>,
+}
+
+thread_local! {
+    static STATE: RefCell<ProfilerState> = RefCell::default();
+    static RECORDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The statistics of the evaluations of the binding of a property, as returned by [`statistics()`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BindingStatistics {
+    /// The name of the property: `Component::element-id.property` for properties of elements, and
+    /// `Component::property` for properties declared in the component.
+    /// Properties without name are named by their address.
+    pub property: SharedString,
+    /// How many times the binding was evaluated
+    pub evaluations: u64,
+    /// The total time spent evaluating the binding, including the evaluation of the bindings it depends on
+    pub total_duration: Duration,
+    /// The time spent evaluating the binding, excluding the evaluation of the bindings it depends on
+    pub self_duration: Duration,
+    /// The longest evaluation
+    pub max_duration: Duration,
+}
+
+/// Start recording the evaluations of the bindings, discarding the previously recorded statistics.
+pub fn start() {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.statistics.clear();
+        state.nested_durations.clear();
+    });
+    RECORDING.with(|r| r.set(true));
+}
+
+/// Stop recording the evaluations of the bindings. The statistics recorded so far are kept.
+pub fn stop() {
+    RECORDING.with(|r| r.set(false));
+}
+
+/// Returns true between [`start()`] and [`stop()`]
+pub fn is_recording() -> bool {
+    RECORDING.with(|r| r.get())
+}
+
+/// Returns the statistics recorded since [`start()`], sorted by decreasing total duration.
+pub fn statistics() -> Vec<BindingStatistics> {
+    let mut statistics =
+        STATE.with(|state| state.borrow().statistics.values().cloned().collect::<Vec<_>>());
+    statistics.sort_by(|a, b| {
+        b.total_duration.cmp(&a.total_duration).then_with(|| a.property.cmp(&b.property))
+    });
+    statistics
+}
+
+/// Returns the statistics recorded since [`start()`] as a JSON array, with the durations in nanoseconds.
+pub fn statistics_as_json() -> String {
+    let mut json = String::from("[");
+    for (i, s) in statistics().iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str("\n  {\"property\": ");
+        write_json_string(&mut json, &s.property);
+        let _ = write!(
+            json,
+            ", \"evaluations\": {}, \"total_ns\": {}, \"self_ns\": {}, \"max_ns\": {}}}",
+            s.evaluations,
+            s.total_duration.as_nanos(),
+            s.self_duration.as_nanos(),
+            s.max_duration.as_nanos()
+        );
+    }
+    json.push_str("\n]\n");
+    json
+}
+
+/// A node of the [`DependencyGraph`]: a property, or a binding that isn't the binding of a property
+/// (such as the one that tracks whether a window needs to be redrawn)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DependencyNode {
+    /// The name of the property (see [`BindingStatistics::property`]), or `<tracker 0x...>` for bindings
+    /// that don't belong to a property.
+    pub name: SharedString,
+}
+
+/// The dependencies between the properties, as returned by [`dependency_graph()`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DependencyGraph {
+    /// The properties
+    pub nodes: Vec<DependencyNode>,
+    /// The dependencies, as indices in `nodes`: `(a, b)` means that the binding of `b` reads the property
+    /// `a`, so that a change of `a` causes `b` to be re-evaluated.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl DependencyGraph {
+    /// Format the graph in the DOT format of [Graphviz](https://graphviz.org)
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph dependencies {\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let _ = write!(dot, "  n{i} [label=");
+            write_json_string(&mut dot, &node.name);
+            dot.push_str("];\n");
+        }
+        for (from, to) in &self.edges {
+            let _ = writeln!(dot, "  n{from} -> n{to};");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Format the graph as a JSON object with `nodes` and `edges` arrays, where the edges refer to the nodes
+    /// by their index.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n  \"nodes\": [");
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str("\n    {\"name\": ");
+            write_json_string(&mut json, &node.name);
+            json.push('}');
+        }
+        json.push_str("\n  ],\n  \"edges\": [");
+        for (i, (from, to)) in self.edges.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let _ = write!(json, "\n    {{\"from\": {from}, \"to\": {to}}}");
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+/// Returns the current dependencies between the properties that are named, or that have a binding.
+///
+/// Only the dependencies registered by the last evaluation of each binding are known, so a binding
+/// that was never evaluated has no dependencies.
+pub fn dependency_graph() -> DependencyGraph {
+    STATE.with(|state| {
+        let state = state.borrow();
+        let mut graph = DependencyGraph::default();
+        let mut node_indices = HashMap::<usize, usize>::new();
+        let mut node = |address: usize, name: SharedString| {
+            *node_indices.entry(address).or_insert_with(|| {
+                graph.nodes.push(DependencyNode { name });
+                graph.nodes.len() - 1
+            })
+        };
+
+        let mut properties =
+            state.names.keys().chain(state.binding_owners.values()).copied().collect::<Vec<_>>();
+        properties.sort_unstable();
+        properties.dedup();
+
+        let mut edges = Vec::new();
+        for property in properties {
+            // Safety: the addresses are removed from the state when the property or the binding is dropped
+            let handle = unsafe { &*(property as *const PropertyHandle) };
+            if handle.lock_flag() {
+                // The binding of this property is being evaluated
+                continue;
+            }
+            let from = node(property, state.property_name(property));
+            let dependencies = handle.dependencies();
+            // Safety: `dependencies()` returns a pointer to the dependency list of the property
+            unsafe {
+                if core::ptr::eq(
+                    *(dependencies as *mut *const u32),
+                    &CONSTANT_PROPERTY_SENTINEL as *const u32,
+                ) {
+                    continue;
+                }
+                DependencyListHead::for_each(&*dependencies, |binding| {
+                    let binding = *binding as usize;
+                    let to = match state.binding_owners.get(&binding) {
+                        Some(owner) => node(*owner, state.property_name(*owner)),
+                        None => node(binding, crate::format!("<tracker {binding:#x}>")),
+                    };
+                    edges.push((from, to));
+                });
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+        graph.edges = edges;
+        graph
+    })
+}
+
+impl ProfilerState {
+    fn property_name(&self, property: usize) -> SharedString {
+        self.names.get(&property).cloned().unwrap_or_else(|| crate::format!("{property:#x}"))
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+pub(super) fn set_property_name(handle: &PropertyHandle, name: &str) {
+    // Safety: the handle is part of a property, which removes its name when it is dropped
+    unsafe { set_name_of_property_at(handle as *const PropertyHandle as *const u8, name) };
+}
+
+/// Name the `Property<T>` at the given address, for the interpreter which doesn't know the type
+/// of the properties statically.
+///
+/// # Safety
+///
+/// `property` must point to a pinned `Property<T>` that stays alive at that address until it is
+/// dropped: [`dependency_graph()`] dereferences the addresses of the named properties, and the name
+/// is only removed when the property is dropped.
+#[doc(hidden)]
+pub unsafe fn set_name_of_property_at(property: *const u8, name: &str) {
+    STATE.with(|state| state.borrow_mut().names.insert(property as usize, name.into()));
+}
+
+pub(super) fn property_dropped(handle: &PropertyHandle) {
+    let address = handle as *const PropertyHandle as usize;
+    let _ = STATE.try_with(|state| state.borrow_mut().names.remove(&address));
+}
+
+pub(super) fn binding_dropped(binding: *const BindingHolder) {
+    let _ = STATE.try_with(|state| state.borrow_mut().binding_owners.remove(&(binding as usize)));
+}
+
+/// Created before the evaluation of the binding of a property, and dropped after it, to record the
+/// owner of the binding, and the duration of the evaluation.
+pub(super) struct EvaluationScope {
+    property: usize,
+    start: Option<Instant>,
+}
+
+impl EvaluationScope {
+    pub(super) fn new(handle: &PropertyHandle, binding: *const BindingHolder) -> Self {
+        let property = handle as *const PropertyHandle as usize;
+        let recording = is_recording();
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            state.binding_owners.insert(binding as usize, property);
+            if recording {
+                state.nested_durations.push(Duration::ZERO);
+            }
+        });
+        Self { property, start: recording.then(Instant::now) }
+    }
+}
+
+impl Drop for EvaluationScope {
+    fn drop(&mut self) {
+        let Some(start) = self.start else { return };
+        let duration = start.elapsed();
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let nested = state.nested_durations.pop().unwrap_or_default();
+            if let Some(parent) = state.nested_durations.last_mut() {
+                *parent += duration;
+            }
+            let name = state.property_name(self.property);
+            let statistics = state
+                .statistics
+                .entry(name.clone())
+                .or_insert_with(|| BindingStatistics { property: name, ..Default::default() });
+            statistics.evaluations += 1;
+            statistics.total_duration += duration;
+            statistics.self_duration += duration.saturating_sub(nested);
+            statistics.max_duration = statistics.max_duration.max(duration);
+        });
+    }
+}
+
+#[test]
+fn profile_bindings() {
+    use super::Property;
+    use std::rc::Rc;
+
+    let a = Rc::pin(Property::new(1));
+    let b = Rc::pin(Property::new(0));
+    let c = Rc::pin(Property::new(0));
+    a.set_profiler_name("App::a");
+    b.set_profiler_name("App::b");
+    c.set_profiler_name("App::text.c");
+    b.set_binding({
+        let a = a.clone();
+        move || a.as_ref().get() * 2
+    });
+    c.set_binding({
+        let (a, b) = (a.clone(), b.clone());
+        move || a.as_ref().get() + b.as_ref().get()
+    });
+
+    start();
+    assert_eq!(c.as_ref().get(), 3);
+    a.set(2);
+    assert_eq!(c.as_ref().get(), 6);
+    stop();
+    a.set(3);
+    assert_eq!(c.as_ref().get(), 9);
+
+    let statistics = statistics();
+    assert_eq!(statistics.len(), 2);
+    let of = |name: &str| statistics.iter().find(|s| s.property == name).unwrap();
+    assert_eq!(of("App::text.c").evaluations, 2);
+    assert_eq!(of("App::b").evaluations, 2);
+    // The evaluation of `b` is nested in the one of `c`
+    assert!(of("App::b").total_duration <= of("App::text.c").total_duration);
+    assert!(of("App::text.c").self_duration <= of("App::text.c").total_duration);
+    assert!(statistics_as_json().contains("\"property\": \"App::b\", \"evaluations\": 2"));
+
+    let graph = dependency_graph();
+    let index = |name: &str| graph.nodes.iter().position(|n| n.name == name).unwrap();
+    let mut edges = graph.edges.clone();
+    edges.sort();
+    let mut expected = vec![
+        (index("App::a"), index("App::b")),
+        (index("App::a"), index("App::text.c")),
+        (index("App::b"), index("App::text.c")),
+    ];
+    expected.sort();
+    assert_eq!(edges, expected);
+    assert!(graph.to_dot().contains(&format!(
+        "n{} -> n{};",
+        index("App::b"),
+        index("App::text.c")
+    )));
+    assert!(graph.to_json().contains("{\"name\": \"App::text.c\"}"));
+
+    drop(c);
+    let graph = dependency_graph();
+    assert!(!graph.nodes.iter().any(|n| n.name == "App::text.c"));
+    assert_eq!(graph.edges.len(), 1);
+}
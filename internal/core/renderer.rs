@@ -68,6 +68,32 @@ fn text_input_cursor_rect_for_byte_offset(
         scale_factor: ScaleFactor,
     ) -> LogicalRect;
 
+    /// Returns the size of the given styled text in logical pixels, like [`Self::text_size`].
+    /// The default implementation measures the plain text, ignoring the styles.
+    fn styled_text_size(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        styled_string: &crate::styled_text::StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        self.text_size(font_request, &styled_string.text, max_width, scale_factor, text_wrap)
+    }
+
+    /// Returns the index of the span in `styled_string` that is rendered at the given position
+    /// of the StyledText element, if any. This is used to find the link that was clicked.
+    fn styled_text_span_at_position(
+        &self,
+        _styled_text: Pin<&crate::items::StyledText>,
+        _styled_string: &crate::styled_text::StyledString,
+        _pos: LogicalPoint,
+        _font_request: crate::graphics::FontRequest,
+        _scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        None
+    }
+
     /// Clear the caches for the items that are being removed
     fn free_graphics_resources(
         &self,
@@ -38,12 +38,15 @@ macro_rules! declare_ValueType_2 {
             crate::PathData,
             crate::animations::EasingCurve,
             crate::model::StandardListViewItem,
+            crate::model::StandardTreeViewItem,
             crate::model::TableColumn,
             crate::input::KeyEvent,
             crate::Brush,
             crate::graphics::Point,
             crate::items::PointerEvent,
             crate::items::PointerScrollEvent,
+            crate::items::DropEvent,
+            crate::items::MenuEntry,
             crate::lengths::LogicalLength,
             crate::component_factory::ComponentFactory,
             crate::api::LogicalPosition,
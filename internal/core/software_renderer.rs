@@ -19,16 +19,19 @@
 use crate::api::PlatformError;
 use crate::graphics::rendering_metrics_collector::{RefreshMode, RenderingMetricsCollector};
 use crate::graphics::{
-    BorderRadius, PixelFormat, Rgba8Pixel, SharedImageBuffer, SharedPixelBuffer,
+    BorderRadius, GradientSpread, PixelFormat, Rgba8Pixel, SharedImageBuffer, SharedPixelBuffer,
+};
+use crate::item_rendering::{
+    CachedRenderingData, DirtyRegion, RenderBorderRectangle, RenderImage, RenderText,
 };
-use crate::item_rendering::{CachedRenderingData, DirtyRegion, RenderBorderRectangle, RenderImage};
 use crate::items::{ItemRc, TextOverflow, TextWrap};
 use crate::lengths::{
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     PhysicalPx, PointLengths, RectLengths, ScaleFactor, SizeLengths,
 };
 use crate::renderer::RendererSealed;
-use crate::textlayout::{AbstractFont, FontMetrics, TextParagraphLayout};
+use crate::styled_text::StyledString;
+use crate::textlayout::{AbstractFont, FontMetrics, StyledParagraphLayout, TextParagraphLayout};
 use crate::window::{WindowAdapter, WindowInner};
 use crate::{Brush, Color, Coord, ImageInner, StaticTextures};
 use alloc::rc::{Rc, Weak};
@@ -404,6 +407,7 @@ pub struct SoftwareRenderer {
     maybe_window_adapter: RefCell<Option<Weak<dyn crate::window::WindowAdapter>>>,
     rotation: Cell<RenderingRotation>,
     rendering_metrics_collector: Option<Rc<RenderingMetricsCollector>>,
+    gradient_images: RefCell<GradientImageCache>,
 }
 
 impl Default for SoftwareRenderer {
@@ -417,6 +421,7 @@ fn default() -> Self {
             maybe_window_adapter: Default::default(),
             rotation: Default::default(),
             rendering_metrics_collector: RenderingMetricsCollector::new("software"),
+            gradient_images: Default::default(),
         }
     }
 }
@@ -537,6 +542,7 @@ pub fn render(&self, buffer: &mut [impl TargetPixel], pixel_stride: usize) -> Ph
                 dirty_region: Default::default(),
             },
             rotation,
+            &self.gradient_images,
         );
         let mut renderer = crate::item_rendering::PartialRenderer::new(
             &self.partial_cache,
@@ -684,6 +690,43 @@ fn font_metrics(
         fonts::font_metrics(font_request, scale_factor)
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: crate::graphics::FontRequest,
+        styled_string: &StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        fonts::styled_text_size(font_request, styled_string, max_width, scale_factor, text_wrap)
+    }
+
+    fn styled_text_span_at_position(
+        &self,
+        styled_text: Pin<&crate::items::StyledText>,
+        styled_string: &StyledString,
+        pos: LogicalPoint,
+        font_request: crate::graphics::FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let runs = fonts::match_styled_fonts(&font_request, styled_string, scale_factor);
+        let paragraph = StyledParagraphLayout {
+            string: &styled_string.text,
+            runs: &runs,
+            max_width: (styled_text.width().cast() * scale_factor).cast(),
+            max_height: (styled_text.height().cast() * scale_factor).cast(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+        };
+
+        let pos: PhysicalPoint = (pos.cast() * scale_factor)
+            .clamp(euclid::point2(0., 0.), euclid::point2(i16::MAX, i16::MAX).cast())
+            .cast();
+
+        paragraph.run_at_position((pos.x_length(), pos.y_length()))
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&crate::items::TextInput>,
@@ -1013,6 +1056,7 @@ fn prepare_scene(
         window,
         PrepareScene::default(),
         software_renderer.rotation.get(),
+        &software_renderer.gradient_images,
     );
     let mut renderer = crate::item_rendering::PartialRenderer::new(
         &software_renderer.partial_cache,
@@ -1265,6 +1309,7 @@ struct SceneBuilder<'a, T> {
     scale_factor: ScaleFactor,
     window: &'a WindowInner,
     rotation: RotationInfo,
+    gradient_images: &'a RefCell<GradientImageCache>,
 }
 
 impl<'a, T: ProcessScene> SceneBuilder<'a, T> {
@@ -1274,7 +1319,9 @@ fn new(
         window: &'a WindowInner,
         processor: T,
         orientation: RenderingRotation,
+        gradient_images: &'a RefCell<GradientImageCache>,
     ) -> Self {
+        gradient_images.borrow_mut().start_frame();
         Self {
             processor,
             state_stack: vec![],
@@ -1289,6 +1336,7 @@ fn new(
             scale_factor,
             window,
             rotation: RotationInfo { orientation, screen_size },
+            gradient_images,
         }
     }
 
@@ -1498,115 +1546,20 @@ fn draw_text_paragraph<Font>(
                     }
                     for positioned_glyph in glyphs {
                         let glyph = paragraph.layout.font.render_glyph(positioned_glyph.glyph_id);
-
-                        let target_rect = PhysicalRect::new(
-                            PhysicalPoint::from_lengths(
-                                line_x + positioned_glyph.x + glyph.x,
-                                baseline_y - glyph.y - glyph.height,
-                            ),
-                            glyph.size(),
-                        )
-                        .cast();
-
                         let color = match &selection {
                             Some(s) if s.selection.contains(&positioned_glyph.text_byte_offset) => {
                                 s.selection_color
                             }
                             _ => color,
                         };
-
-                        let Some(clipped_target) = physical_clip.intersection(&target_rect) else {
-                            continue;
-                        };
-                        let geometry = clipped_target.translate(offset).round();
-                        let origin = (geometry.origin - offset.round()).round().cast::<i16>();
-                        let actual_x = (origin.x - target_rect.origin.x as i16) as usize;
-                        let actual_y = (origin.y - target_rect.origin.y as i16) as usize;
-                        let pixel_stride = glyph.pixel_stride;
-                        let mut geometry = geometry.cast();
-                        if geometry.size.width > glyph.width.get() - (actual_x as i16) {
-                            geometry.size.width = glyph.width.get() - (actual_x as i16)
-                        }
-                        if geometry.size.height > glyph.height.get() - (actual_y as i16) {
-                            geometry.size.height = glyph.height.get() - (actual_y as i16)
-                        }
-                        let source_size = geometry.size;
-                        if source_size.is_empty() {
-                            continue;
-                        }
-                        match &glyph.alpha_map {
-                            fonts::GlyphAlphaMap::Static(data) => {
-                                let texture = if !glyph.sdf {
-                                    SceneTexture {
-                                        data: &data[actual_x + actual_y * pixel_stride as usize..],
-                                        pixel_stride,
-                                        format: PixelFormat::AlphaMap,
-                                        extra: SceneTextureExtra {
-                                            colorize: color,
-                                            // color already is mixed with global alpha
-                                            alpha: color.alpha(),
-                                            rotation: self.rotation.orientation,
-                                            dx: Fixed::from_integer(1),
-                                            dy: Fixed::from_integer(1),
-                                            off_x: Fixed::from_integer(0),
-                                            off_y: Fixed::from_integer(0),
-                                        },
-                                    }
-                                } else {
-                                    let dx = Fixed::from_integer(pixel_stride - 1)
-                                        / (glyph.width.get() as u16 - 1);
-                                    let dy = Fixed::from_integer(
-                                        (data.len() as u16 - 1) / pixel_stride - 1,
-                                    ) / (glyph.height.get() as u16 - 1);
-                                    let off_x = Fixed::<i32, 8>::from_fixed(dx)
-                                        * (clipped_target.origin.x - target_rect.origin.x) as i32;
-                                    let off_y = Fixed::<i32, 8>::from_fixed(dy)
-                                        * (clipped_target.origin.y - target_rect.origin.y) as i32;
-                                    SceneTexture {
-                                        data: data,
-                                        pixel_stride,
-                                        format: PixelFormat::SignedDistanceField,
-                                        extra: SceneTextureExtra {
-                                            colorize: color,
-                                            // color already is mixed with global alpha
-                                            alpha: color.alpha(),
-                                            rotation: self.rotation.orientation,
-                                            dx,
-                                            dy,
-                                            off_x: Fixed::try_from_fixed(off_x).unwrap(),
-                                            off_y: Fixed::try_from_fixed(off_y).unwrap(),
-                                        },
-                                    }
-                                };
-                                self.processor
-                                    .process_texture(geometry.transformed(self.rotation), texture);
-                            }
-                            fonts::GlyphAlphaMap::Shared(data) => {
-                                self.processor.process_shared_image_buffer(
-                                    geometry.transformed(self.rotation),
-                                    SharedBufferCommand {
-                                        buffer: SharedBufferData::AlphaMap {
-                                            data: data.clone(),
-                                            width: pixel_stride,
-                                        },
-                                        source_rect: PhysicalRect::new(
-                                            PhysicalPoint::new(actual_x as _, actual_y as _),
-                                            source_size,
-                                        ),
-                                        extra: SceneTextureExtra {
-                                            colorize: color,
-                                            // color already is mixed with global alpha
-                                            alpha: color.alpha(),
-                                            rotation: self.rotation.orientation,
-                                            dx: Fixed::from_integer(1),
-                                            dy: Fixed::from_integer(1),
-                                            off_x: Fixed::from_integer(0),
-                                            off_y: Fixed::from_integer(0),
-                                        },
-                                    },
-                                );
-                            }
-                        }
+                        self.draw_glyph(
+                            &glyph,
+                            line_x + positioned_glyph.x,
+                            baseline_y,
+                            color,
+                            physical_clip,
+                            offset,
+                        );
                     }
                     core::ops::ControlFlow::Continue(())
                 },
@@ -1615,6 +1568,114 @@ fn draw_text_paragraph<Font>(
             .ok();
     }
 
+    /// Draws a glyph with its origin at `x` and the baseline at `baseline_y`, both relative to the
+    /// current item.
+    fn draw_glyph(
+        &mut self,
+        glyph: &fonts::RenderableGlyph,
+        x: PhysicalLength,
+        baseline_y: PhysicalLength,
+        color: Color,
+        physical_clip: euclid::Rect<f32, PhysicalPx>,
+        offset: euclid::Vector2D<f32, PhysicalPx>,
+    ) {
+        let target_rect = PhysicalRect::new(
+            PhysicalPoint::from_lengths(x + glyph.x, baseline_y - glyph.y - glyph.height),
+            glyph.size(),
+        )
+        .cast();
+
+        let Some(clipped_target) = physical_clip.intersection(&target_rect) else {
+            return;
+        };
+        let geometry = clipped_target.translate(offset).round();
+        let origin = (geometry.origin - offset.round()).round().cast::<i16>();
+        let actual_x = (origin.x - target_rect.origin.x as i16) as usize;
+        let actual_y = (origin.y - target_rect.origin.y as i16) as usize;
+        let pixel_stride = glyph.pixel_stride;
+        let mut geometry = geometry.cast();
+        if geometry.size.width > glyph.width.get() - (actual_x as i16) {
+            geometry.size.width = glyph.width.get() - (actual_x as i16)
+        }
+        if geometry.size.height > glyph.height.get() - (actual_y as i16) {
+            geometry.size.height = glyph.height.get() - (actual_y as i16)
+        }
+        let source_size = geometry.size;
+        if source_size.is_empty() {
+            return;
+        }
+        match &glyph.alpha_map {
+            fonts::GlyphAlphaMap::Static(data) => {
+                let texture = if !glyph.sdf {
+                    SceneTexture {
+                        data: &data[actual_x + actual_y * pixel_stride as usize..],
+                        pixel_stride,
+                        format: PixelFormat::AlphaMap,
+                        extra: SceneTextureExtra {
+                            colorize: color,
+                            // color already is mixed with global alpha
+                            alpha: color.alpha(),
+                            rotation: self.rotation.orientation,
+                            dx: Fixed::from_integer(1),
+                            dy: Fixed::from_integer(1),
+                            off_x: Fixed::from_integer(0),
+                            off_y: Fixed::from_integer(0),
+                        },
+                    }
+                } else {
+                    let dx = Fixed::from_integer(pixel_stride - 1) / (glyph.width.get() as u16 - 1);
+                    let dy = Fixed::from_integer((data.len() as u16 - 1) / pixel_stride - 1)
+                        / (glyph.height.get() as u16 - 1);
+                    let off_x = Fixed::<i32, 8>::from_fixed(dx)
+                        * (clipped_target.origin.x - target_rect.origin.x) as i32;
+                    let off_y = Fixed::<i32, 8>::from_fixed(dy)
+                        * (clipped_target.origin.y - target_rect.origin.y) as i32;
+                    SceneTexture {
+                        data,
+                        pixel_stride,
+                        format: PixelFormat::SignedDistanceField,
+                        extra: SceneTextureExtra {
+                            colorize: color,
+                            // color already is mixed with global alpha
+                            alpha: color.alpha(),
+                            rotation: self.rotation.orientation,
+                            dx,
+                            dy,
+                            off_x: Fixed::try_from_fixed(off_x).unwrap(),
+                            off_y: Fixed::try_from_fixed(off_y).unwrap(),
+                        },
+                    }
+                };
+                self.processor.process_texture(geometry.transformed(self.rotation), texture);
+            }
+            fonts::GlyphAlphaMap::Shared(data) => {
+                self.processor.process_shared_image_buffer(
+                    geometry.transformed(self.rotation),
+                    SharedBufferCommand {
+                        buffer: SharedBufferData::AlphaMap {
+                            data: data.clone(),
+                            width: pixel_stride,
+                        },
+                        source_rect: PhysicalRect::new(
+                            PhysicalPoint::new(actual_x as _, actual_y as _),
+                            source_size,
+                        ),
+                        extra: SceneTextureExtra {
+                            colorize: color,
+                            // color already is mixed with global alpha
+                            alpha: color.alpha(),
+                            rotation: self.rotation.orientation,
+                            dx: Fixed::from_integer(1),
+                            dy: Fixed::from_integer(1),
+                            off_x: Fixed::from_integer(0),
+                            off_y: Fixed::from_integer(0),
+                        },
+                    },
+                );
+            }
+        }
+    }
+
     /// Returns the color, mixed with the current_state's alpha
     fn alpha_color(&self, color: Color) -> Color {
         if self.current_state.alpha < 1.0 {
@@ -1628,6 +1689,80 @@ fn alpha_color(&self, color: Color) -> Color {
             color
         }
     }
+
+    /// Draws a gradient that has no native support in the scene (radial, conic, or with a
+    /// spread other than `Pad`) by rendering it into an image first.
+    ///
+    /// This is only used for the background of a `Rectangle`: the rounded rectangles, the borders
+    /// and the texts use the color of the first stop of these gradients.
+    fn draw_gradient_as_image(&mut self, brush: &Brush, geom: LogicalRect) {
+        let img =
+            self.gradient_images.borrow_mut().get_or_render(brush, geom.size, self.scale_factor);
+        let source_rect = euclid::rect(0, 0, img.width() as _, img.height() as _);
+        let physical_clip = (self.current_state.clip.cast() * self.scale_factor).cast();
+
+        if let Some(clipped_src) = source_rect.intersection(&physical_clip) {
+            let geometry = clipped_src
+                .translate(
+                    (self.current_state.offset.cast() * self.scale_factor).to_vector().cast(),
+                )
+                .round_in();
+
+            self.processor.process_shared_image_buffer(
+                geometry.cast().transformed(self.rotation),
+                SharedBufferCommand {
+                    buffer: SharedBufferData::SharedImage(img),
+                    source_rect,
+                    extra: SceneTextureExtra {
+                        colorize: Default::default(),
+                        alpha: (self.current_state.alpha * 255.) as u8,
+                        rotation: self.rotation.orientation,
+                        dx: Fixed::from_integer(1),
+                        dy: Fixed::from_integer(1),
+                        off_x: Fixed::from_integer(clipped_src.min_x() as _),
+                        off_y: Fixed::from_integer(clipped_src.min_y() as _),
+                    },
+                },
+            );
+        }
+    }
+}
+
+/// The images rendered by [`SceneBuilder::draw_gradient_as_image`], so that the gradients are not
+/// rendered again in the next frame if they and their size don't change.
+#[derive(Default)]
+struct GradientImageCache {
+    previous_frame: Vec<(Brush, LogicalSize, ScaleFactor, SharedImageBuffer)>,
+    current_frame: Vec<(Brush, LogicalSize, ScaleFactor, SharedImageBuffer)>,
+}
+
+impl GradientImageCache {
+    /// Drops the images that weren't used in the last frame
+    fn start_frame(&mut self) {
+        self.previous_frame = core::mem::take(&mut self.current_frame);
+    }
+
+    fn get_or_render(
+        &mut self,
+        brush: &Brush,
+        size: LogicalSize,
+        scale_factor: ScaleFactor,
+    ) -> SharedImageBuffer {
+        let matches = |(b, s, f, _): &(Brush, LogicalSize, ScaleFactor, SharedImageBuffer)| {
+            b == brush && *s == size && *f == scale_factor
+        };
+        if let Some((.., image)) = self.current_frame.iter().find(|entry| matches(entry)) {
+            return image.clone();
+        }
+        let image = match self.previous_frame.iter().position(matches) {
+            Some(index) => self.previous_frame.swap_remove(index).3,
+            None => SharedImageBuffer::RGBA8(
+                brush.to_pixel_buffer(size.cast().to_untyped(), scale_factor.get()),
+            ),
+        };
+        self.current_frame.push((brush.clone(), size, scale_factor, image.clone()));
+        image
+    }
 }
 
 struct SelectionInfo {
@@ -1659,6 +1794,12 @@ fn draw_rectangle(
             };
 
             let background = rect.background();
+            if matches!(background, Brush::RadialGradient(_) | Brush::ConicGradient(_))
+                || matches!(&background, Brush::LinearGradient(g) if g.spread() != GradientSpread::Pad)
+            {
+                self.draw_gradient_as_image(&background, geom);
+                return;
+            }
             if let Brush::LinearGradient(g) = background {
                 let geom2 = (geom.cast() * self.scale_factor).transformed(self.rotation);
                 let clipped2 = (clipped.cast() * self.scale_factor).transformed(self.rotation);
@@ -2125,6 +2266,84 @@ fn draw_text_input(
         }
     }
 
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&crate::items::StyledText>,
+        _: &ItemRc,
+        size: LogicalSize,
+    ) {
+        let styled_string = styled_text.styled_string();
+        if styled_string.text.trim().is_empty() {
+            return;
+        }
+        let geom = LogicalRect::from(size);
+        if !self.should_draw(&geom) {
+            return;
+        }
+
+        let font_request = styled_text.font_request(self.window);
+        let max_size = (geom.size.cast() * self.scale_factor).cast();
+
+        // Clip glyphs against the StyledText's geometry, like in draw_text
+        let physical_clip = if let Some(logical_clip) = self.current_state.clip.intersection(&geom)
+        {
+            logical_clip.cast() * self.scale_factor
+        } else {
+            return; // This should have been caught earlier already
+        };
+        let offset = self.current_state.offset.to_vector().cast() * self.scale_factor;
+
+        let runs = fonts::match_styled_fonts(&font_request, &styled_string, self.scale_factor);
+        let paragraph = StyledParagraphLayout {
+            string: &styled_string.text,
+            runs: &runs,
+            max_width: max_size.width_length(),
+            max_height: max_size.height_length(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+        };
+
+        paragraph
+            .layout_fragments::<()>(|fragment, glyphs| {
+                let style = &styled_string.spans[fragment.run_index].style;
+                let font = &runs[fragment.run_index].1;
+                let color = self.alpha_color(styled_text.span_color(style).color());
+
+                for positioned_glyph in glyphs {
+                    let glyph = font.render_glyph(positioned_glyph.glyph_id);
+                    self.draw_glyph(
+                        &glyph,
+                        fragment.x + positioned_glyph.x,
+                        fragment.baseline_y,
+                        color,
+                        physical_clip,
+                        offset,
+                    );
+                }
+
+                let thickness = (font.height() / 16).max(PhysicalLength::new(1));
+                let underline = style.is_underlined().then_some(fragment.baseline_y + thickness);
+                let strikethrough =
+                    style.strikethrough.then(|| fragment.baseline_y - font.x_height() / 2);
+                for line_y in underline.into_iter().chain(strikethrough) {
+                    let geometry = euclid::rect(
+                        fragment.x.get(),
+                        line_y.get(),
+                        fragment.width.get(),
+                        thickness.get(),
+                    );
+                    if let Some(clipped_src) = geometry.intersection(&physical_clip.cast()) {
+                        let geometry =
+                            clipped_src.translate(offset.cast()).transformed(self.rotation);
+                        self.processor.process_rectangle(geometry, color.into());
+                    }
+                }
+                core::ops::ControlFlow::Continue(())
+            })
+            .ok();
+    }
+
     #[cfg(feature = "std")]
     fn draw_path(&mut self, _path: Pin<&crate::items::Path>, _: &ItemRc, _size: LogicalSize) {
         // TODO
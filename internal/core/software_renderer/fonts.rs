@@ -5,6 +5,7 @@
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 use core::cell::RefCell;
+use core::ops::Range;
 
 #[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
 use crate::thread_local_ as thread_local;
@@ -13,7 +14,8 @@
 use crate::graphics::{BitmapFont, FontRequest};
 use crate::items::TextWrap;
 use crate::lengths::{LogicalLength, LogicalSize, ScaleFactor};
-use crate::textlayout::{FontMetrics, TextLayout};
+use crate::styled_text::StyledString;
+use crate::textlayout::{FontMetrics, Glyph, StyledParagraphLayout, TextLayout, TextShaper};
 use crate::Coord;
 
 thread_local! {
@@ -105,6 +107,48 @@ fn cap_height(&self) -> PhysicalLength {
     }
 }
 
+impl TextShaper for Font {
+    type LengthPrimitive = i16;
+    type Length = PhysicalLength;
+    fn shape_text<GlyphStorage: core::iter::Extend<Glyph<PhysicalLength>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.shape_text(text, glyphs),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.shape_text(text, glyphs),
+        }
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<Glyph<PhysicalLength>> {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.glyph_for_char(ch),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.glyph_for_char(ch),
+        }
+    }
+
+    fn max_lines(&self, max_height: PhysicalLength) -> usize {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.max_lines(max_height),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.max_lines(max_height),
+        }
+    }
+}
+
+impl GlyphRenderer for Font {
+    fn render_glyph(&self, glyph_id: core::num::NonZeroU16) -> RenderableGlyph {
+        match self {
+            Font::PixelFont(pixel_font) => pixel_font.render_glyph(glyph_id),
+            #[cfg(feature = "software-renderer-systemfonts")]
+            Font::VectorFont(vector_font) => vector_font.render_glyph(glyph_id),
+        }
+    }
+}
+
 pub fn match_font(request: &FontRequest, scale_factor: ScaleFactor) -> Font {
     let requested_weight = request
         .weight
@@ -216,6 +260,44 @@ pub fn text_size(
     (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
 }
 
+/// Returns the font to use for each span of the styled string
+pub fn match_styled_fonts(
+    font_request: &FontRequest,
+    styled_string: &StyledString,
+    scale_factor: ScaleFactor,
+) -> Vec<(Range<usize>, Font)> {
+    styled_string
+        .spans
+        .iter()
+        .map(|span| {
+            (span.range.clone(), match_font(&span.style.font_request(font_request), scale_factor))
+        })
+        .collect()
+}
+
+pub fn styled_text_size(
+    font_request: FontRequest,
+    styled_string: &StyledString,
+    max_width: Option<LogicalLength>,
+    scale_factor: ScaleFactor,
+    text_wrap: TextWrap,
+) -> LogicalSize {
+    let runs = match_styled_fonts(&font_request, styled_string, scale_factor);
+    let paragraph = StyledParagraphLayout {
+        string: &styled_string.text,
+        runs: &runs,
+        max_width: max_width.map_or(PhysicalLength::new(i16::MAX), |max_width| {
+            (max_width.cast() * scale_factor).cast()
+        }),
+        max_height: PhysicalLength::new(i16::MAX),
+        horizontal_alignment: Default::default(),
+        vertical_alignment: Default::default(),
+        wrap: if max_width.is_some() { text_wrap } else { TextWrap::NoWrap },
+    };
+    let (longest_line_width, height) = paragraph.text_size();
+    (PhysicalSize::from_lengths(longest_line_width, height).cast() / scale_factor).cast()
+}
+
 pub fn font_metrics(
     font_request: FontRequest,
     scale_factor: ScaleFactor,
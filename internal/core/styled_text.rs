@@ -0,0 +1,468 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+/*!
+This module contains the model of text with inline styling, as used by the `StyledText` element,
+as well as the parser for the markdown subset that the element accepts.
+
+The following markup is recognized:
+ - `**bold**` and `__bold__`
+ - `*italic*` and `_italic_`
+ - `~~strikethrough~~`
+ - `<u>underline</u>`
+ - `<font color="#rrggbb">colored</font>` (also `#rgb` and `#rrggbbaa`)
+ - `[link text](url)`
+ - A backslash escapes the following ASCII punctuation character.
+
+Everything else, including new lines, is kept as is in the resulting plain text.
+*/
+
+use crate::graphics::{Color, FontRequest};
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::thread_local;
+use crate::SharedString;
+use alloc::rc::Rc;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::ops::Range;
+
+/// The number of texts of which [`StyledString::parse_markdown_cached`] keeps the result
+const PARSED_MARKDOWN_CACHE_SIZE: usize = 32;
+
+thread_local! {
+    /// The texts parsed by [`StyledString::parse_markdown_cached`], the most recently used last
+    static PARSED_MARKDOWN: RefCell<Vec<(SharedString, Rc<StyledString>)>> = RefCell::default()
+}
+
+/// The style attributes applied to a span of styled text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    /// The color of the span, or None to use the color of the element.
+    pub color: Option<Color>,
+    /// The target of the link, if the span is part of a link.
+    pub link: Option<SharedString>,
+}
+
+impl SpanStyle {
+    /// Returns the font request for this span, derived from the element's font request.
+    pub fn font_request(&self, base: &FontRequest) -> FontRequest {
+        let mut request = base.clone();
+        if self.bold {
+            request.weight = Some(request.weight.unwrap_or(400).max(700));
+        }
+        request.italic |= self.italic;
+        request
+    }
+
+    /// Returns true if a line should be drawn under the glyphs of this span.
+    pub fn is_underlined(&self) -> bool {
+        self.underline || self.link.is_some()
+    }
+}
+
+/// A range of the plain text together with its style.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledSpan {
+    /// The byte range in [`StyledString::text`].
+    pub range: Range<usize>,
+    pub style: SpanStyle,
+}
+
+/// Plain text with style information. The spans don't overlap, are sorted, and cover the
+/// whole text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyledString {
+    pub text: SharedString,
+    pub spans: Vec<StyledSpan>,
+}
+
+impl StyledString {
+    /// Creates a styled string that has the default style for the entire text.
+    pub fn plain(text: SharedString) -> Self {
+        let spans = if text.is_empty() {
+            Vec::new()
+        } else {
+            alloc::vec![StyledSpan { range: 0..text.len(), style: SpanStyle::default() }]
+        };
+        Self { text, spans }
+    }
+
+    /// Parses the markdown subset described in the [module documentation](self).
+    pub fn parse_markdown(markdown: &str) -> Self {
+        let mut parser = Parser { src: markdown, pos: 0, text: String::new(), spans: Vec::new() };
+        parser.parse_inline(&SpanStyle::default(), None);
+        Self { text: parser.text.into(), spans: parser.spans }
+    }
+
+    /// Like [`Self::parse_markdown`], but returns the result of a previous call with the same
+    /// markdown if there was one recently. The elements call this every time they are laid out
+    /// or rendered, while their text rarely changes.
+    pub fn parse_markdown_cached(markdown: &SharedString) -> Rc<Self> {
+        PARSED_MARKDOWN.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            let parsed = match cache.iter().position(|(text, _)| text == markdown) {
+                Some(index) => cache.remove(index).1,
+                None => {
+                    if cache.len() >= PARSED_MARKDOWN_CACHE_SIZE {
+                        cache.remove(0);
+                    }
+                    Rc::new(Self::parse_markdown(markdown))
+                }
+            };
+            cache.push((markdown.clone(), parsed.clone()));
+            parsed
+        })
+    }
+
+    /// Returns the index of the span that contains the given byte offset.
+    pub fn span_at_byte_offset(&self, byte_offset: usize) -> Option<usize> {
+        self.spans.iter().position(|span| span.range.contains(&byte_offset))
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    text: String,
+    spans: Vec<StyledSpan>,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.src[self.pos..]
+    }
+
+    fn previous_char(&self) -> Option<char> {
+        self.src[..self.pos].chars().next_back()
+    }
+
+    fn push_text(&mut self, s: &str, style: &SpanStyle) {
+        if s.is_empty() {
+            return;
+        }
+        let start = self.text.len();
+        self.text.push_str(s);
+        match self.spans.last_mut() {
+            Some(last) if last.range.end == start && last.style == *style => {
+                last.range.end = self.text.len()
+            }
+            _ => {
+                self.spans.push(StyledSpan { range: start..self.text.len(), style: style.clone() })
+            }
+        }
+    }
+
+    /// Whether the delimiter at the current position can close an emphasis
+    fn can_close(&self, delimiter: &str) -> bool {
+        if !self.rest().starts_with(delimiter) {
+            return false;
+        }
+        if delimiter.starts_with('<') || delimiter == "]" {
+            return true;
+        }
+        if self.previous_char().map_or(true, char::is_whitespace) {
+            return false;
+        }
+        !delimiter.starts_with('_')
+            || !self.rest()[delimiter.len()..].chars().next().is_some_and(char::is_alphanumeric)
+    }
+
+    /// Whether the delimiter at the current position can open an emphasis
+    fn can_open(&self, delimiter: &str) -> bool {
+        if !self.rest()[delimiter.len()..].chars().next().is_some_and(|c| !c.is_whitespace()) {
+            return false;
+        }
+        !delimiter.starts_with('_') || !self.previous_char().is_some_and(char::is_alphanumeric)
+    }
+
+    /// Skips the opener of length `opener_len` and parses the content until `closer`.
+    /// If the closer isn't found, the parser is rolled back and false is returned.
+    fn parse_nested(&mut self, opener_len: usize, closer: &str, style: SpanStyle) -> bool {
+        let (pos, text_len, spans_len) = (self.pos, self.text.len(), self.spans.len());
+        self.pos += opener_len;
+        if self.parse_inline(&style, Some(closer)) {
+            return true;
+        }
+        self.pos = pos;
+        self.text.truncate(text_len);
+        self.spans.truncate(spans_len);
+        if let Some(last) = self.spans.last_mut() {
+            last.range.end = last.range.end.min(text_len);
+        }
+        false
+    }
+
+    /// Parses the content of an element until `closer`, or until the end if closer is None.
+    /// Returns false, if the closer wasn't found, in which case the caller needs to roll back.
+    fn parse_inline(&mut self, style: &SpanStyle, closer: Option<&str>) -> bool {
+        while let Some(c) = self.rest().chars().next() {
+            if let Some(closer) = closer {
+                if self.can_close(closer) {
+                    self.pos += closer.len();
+                    return true;
+                }
+            }
+
+            if c == '\\' {
+                if let Some(escaped) =
+                    self.rest()[1..].chars().next().filter(char::is_ascii_punctuation)
+                {
+                    self.pos += 1 + escaped.len_utf8();
+                    self.push_text(escaped.encode_utf8(&mut [0; 4]), style);
+                    continue;
+                }
+            }
+
+            let handled = match c {
+                '*' | '_' | '~' => {
+                    let rest = &self.src[self.pos..];
+                    let double = if c == '~' {
+                        "~~"
+                    } else if c == '*' {
+                        "**"
+                    } else {
+                        "__"
+                    };
+                    let delimiter = if rest.starts_with(double) { double } else { &double[..1] };
+                    if delimiter == "~" || !self.can_open(delimiter) {
+                        false
+                    } else {
+                        let mut nested_style = style.clone();
+                        match delimiter {
+                            "**" | "__" => nested_style.bold = true,
+                            "~~" => nested_style.strikethrough = true,
+                            _ => nested_style.italic = true,
+                        }
+                        self.parse_nested(delimiter.len(), delimiter, nested_style)
+                    }
+                }
+                '<' if self.rest().starts_with("<u>") => {
+                    let mut nested_style = style.clone();
+                    nested_style.underline = true;
+                    self.parse_nested("<u>".len(), "</u>", nested_style)
+                }
+                '<' if self.rest().starts_with("<font ") => match parse_font_tag(self.rest()) {
+                    Some((len, color)) => {
+                        let mut nested_style = style.clone();
+                        nested_style.color = Some(color);
+                        self.parse_nested(len, "</font>", nested_style)
+                    }
+                    None => false,
+                },
+                '[' => match find_link_target(self.rest()) {
+                    Some(url) => {
+                        let mut nested_style = style.clone();
+                        nested_style.link = Some(url.into());
+                        let url_len = url.len();
+                        self.parse_nested(1, "]", nested_style) && {
+                            // skip the `(url)` that follows the closing bracket
+                            self.pos += url_len + 2;
+                            true
+                        }
+                    }
+                    None => false,
+                },
+                _ => false,
+            };
+
+            if !handled {
+                self.pos += c.len_utf8();
+                self.push_text(c.encode_utf8(&mut [0; 4]), style);
+            }
+        }
+        closer.is_none()
+    }
+}
+
+/// If `s` starts with `[label](url)`, returns the url
+fn find_link_target(s: &str) -> Option<&str> {
+    let mut chars = s.char_indices().skip(1);
+    let label_end = loop {
+        match chars.next()? {
+            (_, '\\') => {
+                chars.next();
+            }
+            (_, '[') => return None,
+            (i, ']') => break i,
+            _ => {}
+        }
+    };
+    let target = s[label_end + 1..].strip_prefix('(')?;
+    let url = &target[..target.find(')')?];
+    (!url.contains(char::is_whitespace)).then_some(url)
+}
+
+/// Parses `<font color="...">` at the start of `s` and returns its length and the color
+fn parse_font_tag(s: &str) -> Option<(usize, Color)> {
+    let end = s.find('>')?;
+    let attributes = s["<font ".len()..end].trim();
+    let value = attributes.strip_prefix("color=")?;
+    let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value);
+    Some((end + 1, parse_hex_color(value)?))
+}
+
+fn parse_hex_color(s: &str) -> Option<Color> {
+    let hex = s.strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None;
+    }
+    let digit = |i: usize, len: usize| u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).ok();
+    let (r, g, b, a) = match hex.len() {
+        3 => (digit(0, 1)? * 17, digit(1, 1)? * 17, digit(2, 1)? * 17, 0xff),
+        6 => (digit(0, 2)?, digit(1, 2)?, digit(2, 2)?, 0xff),
+        8 => (digit(0, 2)?, digit(1, 2)?, digit(2, 2)?, digit(3, 2)?),
+        _ => return None,
+    };
+    Some(Color::from_argb_u8(a, r, g, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(styled: &StyledString) -> Vec<(&str, SpanStyle)> {
+        styled.spans.iter().map(|s| (&styled.text[s.range.clone()], s.style.clone())).collect()
+    }
+
+    #[test]
+    fn plain_text() {
+        let styled = StyledString::parse_markdown("Hello World\nSecond line");
+        assert_eq!(styled.text, "Hello World\nSecond line");
+        assert_eq!(spans(&styled), [("Hello World\nSecond line", SpanStyle::default())]);
+        assert!(StyledString::parse_markdown("").spans.is_empty());
+    }
+
+    #[test]
+    fn emphasis() {
+        let bold = SpanStyle { bold: true, ..Default::default() };
+        let italic = SpanStyle { italic: true, ..Default::default() };
+        let bold_italic = SpanStyle { bold: true, italic: true, ..Default::default() };
+
+        let styled = StyledString::parse_markdown("a **b** *c* __d__ _e_");
+        assert_eq!(styled.text, "a b c d e");
+        assert_eq!(
+            spans(&styled),
+            [
+                ("a ", SpanStyle::default()),
+                ("b", bold.clone()),
+                (" ", SpanStyle::default()),
+                ("c", italic.clone()),
+                (" ", SpanStyle::default()),
+                ("d", bold.clone()),
+                (" ", SpanStyle::default()),
+                ("e", italic.clone()),
+            ]
+        );
+
+        let styled = StyledString::parse_markdown("**bold *both***");
+        assert_eq!(styled.text, "bold both");
+        assert_eq!(spans(&styled), [("bold ", bold), ("both", bold_italic)]);
+
+        let styled = StyledString::parse_markdown("~~gone~~");
+        assert_eq!(
+            spans(&styled),
+            [("gone", SpanStyle { strikethrough: true, ..Default::default() })]
+        );
+    }
+
+    #[test]
+    fn unmatched_delimiters() {
+        for s in
+            ["2 * 3 * 4", "snake_case_name", "**not closed", "a ** b **", "~single~", "[a](b c)"]
+        {
+            let styled = StyledString::parse_markdown(s);
+            assert_eq!(styled.text, s);
+            assert_eq!(spans(&styled), [(s, SpanStyle::default())]);
+        }
+    }
+
+    #[test]
+    fn escapes() {
+        let styled = StyledString::parse_markdown(r"\*not italic\* \[x\] a\b");
+        assert_eq!(styled.text, r"*not italic* [x] a\b");
+        assert_eq!(styled.spans.len(), 1);
+    }
+
+    #[test]
+    fn links() {
+        let styled = StyledString::parse_markdown("See [the **docs**](https://slint.dev) now");
+        assert_eq!(styled.text, "See the docs now");
+        let link = Some(SharedString::from("https://slint.dev"));
+        assert_eq!(
+            spans(&styled),
+            [
+                ("See ", SpanStyle::default()),
+                ("the ", SpanStyle { link: link.clone(), ..Default::default() }),
+                ("docs", SpanStyle { link: link.clone(), bold: true, ..Default::default() }),
+                (" now", SpanStyle::default()),
+            ]
+        );
+        assert_eq!(styled.span_at_byte_offset(5), Some(1));
+        assert!(styled.spans[1].style.is_underlined());
+        assert_eq!(styled.span_at_byte_offset(100), None);
+    }
+
+    #[test]
+    fn tags() {
+        let styled = StyledString::parse_markdown(
+            "<u>under</u> <font color=\"#f00\">red</font> <font color='#00ff0080'>green</font>",
+        );
+        assert_eq!(styled.text, "under red green");
+        assert_eq!(
+            spans(&styled),
+            [
+                ("under", SpanStyle { underline: true, ..Default::default() }),
+                (" ", SpanStyle::default()),
+                (
+                    "red",
+                    SpanStyle { color: Some(Color::from_rgb_u8(255, 0, 0)), ..Default::default() }
+                ),
+                (" ", SpanStyle::default()),
+                (
+                    "green",
+                    SpanStyle {
+                        color: Some(Color::from_argb_u8(0x80, 0, 255, 0)),
+                        ..Default::default()
+                    }
+                ),
+            ]
+        );
+
+        let styled = StyledString::parse_markdown("<font color=\"nope\">x</font>");
+        assert_eq!(styled.text, "<font color=\"nope\">x</font>");
+    }
+
+    #[test]
+    fn parse_markdown_cached() {
+        let markdown = SharedString::from("**bold**");
+        let first = StyledString::parse_markdown_cached(&markdown);
+        assert_eq!(*first, StyledString::parse_markdown("**bold**"));
+        assert!(Rc::ptr_eq(&first, &StyledString::parse_markdown_cached(&"**bold**".into())));
+        for i in 0..PARSED_MARKDOWN_CACHE_SIZE {
+            StyledString::parse_markdown_cached(&crate::format!("text {i}"));
+        }
+        // Evicted by the other texts
+        assert!(!Rc::ptr_eq(&first, &StyledString::parse_markdown_cached(&markdown)));
+    }
+
+    #[test]
+    fn font_request() {
+        let base = FontRequest { weight: Some(300), ..Default::default() };
+        let style = SpanStyle { bold: true, italic: true, ..Default::default() };
+        let request = style.font_request(&base);
+        assert_eq!(request.weight, Some(700));
+        assert!(request.italic);
+        assert_eq!(SpanStyle::default().font_request(&base), base);
+    }
+}
@@ -0,0 +1,985 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! This module contains an [`ItemRenderer`] that produces a SVG document out of the items of a window.
+//!
+//! Unlike the other renderers, it doesn't produce pixels: texts are kept as `<text>` elements,
+//! paths as `<path>` elements, and the gradients and clips are mapped to their SVG counterparts.
+//! Only the images are embedded as PNG data.
+
+use crate::graphics::{
+    normalize_stops, GradientSpread, GradientStop, Image, IntRect, Rgba8Pixel, SharedPixelBuffer,
+};
+use crate::item_rendering::{
+    CachedRenderingData, ItemRenderer, RenderBorderRectangle, RenderImage, RenderText,
+};
+use crate::items::{
+    BoxShadow, FillRule, ImageFit, ItemRc, Path, Rectangle, TextHorizontalAlignment, TextInput,
+    TextOverflow, TextVerticalAlignment, TextWrap,
+};
+use crate::lengths::{
+    LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
+    LogicalVector, ScaleFactor, SizeLengths,
+};
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Brush, Color, Coord};
+use base64::display::Base64Display;
+use base64::prelude::BASE64_STANDARD;
+use core::fmt::Write;
+use core::pin::Pin;
+use std::rc::Rc;
+
+/// Renders the content of the window, including the popups that are shown in it, as a SVG document.
+pub fn render_window_to_svg(window: &WindowInner) -> String {
+    let window_adapter = window.window_adapter();
+    let window_item = window.window_item();
+    let size = match &window_item {
+        Some(window_item) => {
+            let window_item = window_item.as_pin_ref();
+            LogicalSize::from_lengths(window_item.width(), window_item.height())
+        }
+        None => {
+            let size = window_adapter.size().to_logical(window.scale_factor());
+            LogicalSize::new(size.width as _, size.height as _)
+        }
+    };
+
+    let mut renderer = SvgRenderer::new(window, window_adapter);
+    renderer.current_state.clip = LogicalRect::new(LogicalPoint::default(), size);
+
+    if let Some(window_item) = window_item {
+        let background = window_item.as_pin_ref().background();
+        let rect = LogicalRect::new(LogicalPoint::default(), size);
+        renderer.write_shape(
+            ShapeKind::Rect(rect, LogicalBorderRadius::default()),
+            background,
+            None,
+        );
+    }
+
+    window.draw_contents(|components| {
+        for (component, origin) in components {
+            crate::item_rendering::render_component_items(component, &mut renderer, *origin);
+        }
+    });
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = Num(size.width as f32),
+        h = Num(size.height as f32)
+    );
+    svg.push_str(&renderer.output);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[derive(Clone, Default)]
+struct RenderState {
+    /// The translation that wasn't applied with a group yet
+    offset: LogicalVector,
+    /// The clip, in the coordinate of the current item
+    clip: LogicalRect,
+    /// The number of `<g>` elements opened since the last `save_state()`
+    open_groups: usize,
+}
+
+enum ShapeKind<'a> {
+    Rect(LogicalRect, LogicalBorderRadius),
+    Path(&'a str, LogicalSize),
+}
+
+struct SvgRenderer<'a> {
+    window: &'a WindowInner,
+    window_adapter: Rc<dyn WindowAdapter>,
+    output: String,
+    current_state: RenderState,
+    state_stack: Vec<RenderState>,
+    next_id: usize,
+    indent: usize,
+}
+
+impl<'a> SvgRenderer<'a> {
+    fn new(window: &'a WindowInner, window_adapter: Rc<dyn WindowAdapter>) -> Self {
+        Self {
+            window,
+            window_adapter,
+            output: String::new(),
+            current_state: Default::default(),
+            state_stack: Default::default(),
+            next_id: 0,
+            indent: 1,
+        }
+    }
+
+    fn new_id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{prefix}{}", self.next_id)
+    }
+
+    fn start_line(&mut self) {
+        for _ in 0..self.indent {
+            self.output.push_str("  ");
+        }
+    }
+
+    fn open_group(&mut self, attributes: core::fmt::Arguments) {
+        self.start_line();
+        let _ = writeln!(self.output, "<g {attributes}>");
+        self.indent += 1;
+        self.current_state.open_groups += 1;
+    }
+
+    /// Returns the rectangle translated by the offset that is not yet applied by a group
+    fn to_output_rect(&self, rect: LogicalRect) -> euclid::default::Rect<f32> {
+        rect.translate(self.current_state.offset).cast::<f32>().to_untyped()
+    }
+
+    /// Returns the value of a fill or stroke attribute for the brush, and writes the
+    /// definition of the gradient if needed. `rect` is the bounding box of the shape in output coordinate.
+    fn paint(&mut self, brush: &Brush, rect: euclid::default::Rect<f32>) -> Option<String> {
+        if brush.is_transparent() {
+            return None;
+        }
+        match brush {
+            Brush::SolidColor(color) => Some(paint_for_color(*color)),
+            Brush::LinearGradient(g) => {
+                let (start, end) = crate::graphics::line_for_angle(g.angle(), rect.size);
+                let (from, to, stops) = normalize_stops(g.stops(), g.spread());
+                let (start, end) = (start.lerp(end, from), start.lerp(end, to));
+                let id = self.new_id("gradient");
+                self.start_line();
+                let _ = write!(
+                    self.output,
+                    r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}"{}>"#,
+                    Num(rect.origin.x + start.x),
+                    Num(rect.origin.y + start.y),
+                    Num(rect.origin.x + end.x),
+                    Num(rect.origin.y + end.y),
+                    SpreadMethod(g.spread())
+                );
+                self.write_gradient_stops(&stops);
+                self.output.push_str("</linearGradient>\n");
+                Some(format!("url(#{id})"))
+            }
+            Brush::RadialGradient(g) => {
+                let id = self.new_id("gradient");
+                let (cx, cy) = g.center();
+                let radius = g.radius_for_size(rect.size);
+                let (from, to, stops) = normalize_stops(g.stops(), g.spread());
+                self.start_line();
+                let _ = write!(
+                    self.output,
+                    r#"<radialGradient id="{id}" gradientUnits="userSpaceOnUse" cx="{}" cy="{}" r="{}""#,
+                    Num(rect.origin.x + cx * rect.width()),
+                    Num(rect.origin.y + cy * rect.height()),
+                    Num(radius * to)
+                );
+                if from > 0. {
+                    let _ = write!(self.output, r#" fr="{}""#, Num(radius * from));
+                }
+                let _ = write!(self.output, "{}>", SpreadMethod(g.spread()));
+                self.write_gradient_stops(&stops);
+                self.output.push_str("</radialGradient>\n");
+                Some(format!("url(#{id})"))
+            }
+            Brush::ConicGradient(_) => {
+                // SVG has no conic gradient, so use a pattern with the pixels of the gradient
+                let buffer = brush.to_pixel_buffer(rect.size, self.window.scale_factor());
+                let png = encode_png(&buffer)?;
+                let id = self.new_id("gradient");
+                self.start_line();
+                let _ = writeln!(
+                    self.output,
+                    r#"<pattern id="{id}" patternUnits="userSpaceOnUse" x="{x}" y="{y}" width="{w}" height="{h}"><image width="{w}" height="{h}" preserveAspectRatio="none" href="data:image/png;base64,{}"/></pattern>"#,
+                    Base64Display::new(&png, &BASE64_STANDARD),
+                    x = Num(rect.origin.x),
+                    y = Num(rect.origin.y),
+                    w = Num(rect.width()),
+                    h = Num(rect.height()),
+                );
+                Some(format!("url(#{id})"))
+            }
+        }
+    }
+
+    fn write_gradient_stops(&mut self, stops: &[GradientStop]) {
+        for stop in stops {
+            let _ = write!(
+                self.output,
+                r#"<stop offset="{}" stop-color="{}""#,
+                Num(stop.position),
+                ColorHex(stop.color)
+            );
+            if stop.color.alpha() != 255 {
+                let _ = write!(
+                    self.output,
+                    r#" stop-opacity="{}""#,
+                    Num(stop.color.alpha() as f32 / 255.)
+                );
+            }
+            self.output.push_str("/>");
+        }
+    }
+
+    /// Writes a shape filled with `fill`, and outlined with the `stroke` brush and width
+    fn write_shape(
+        &mut self,
+        shape: ShapeKind,
+        fill: Brush,
+        stroke: Option<(Brush, LogicalLength)>,
+    ) {
+        let bounds = match &shape {
+            ShapeKind::Rect(rect, _) => self.to_output_rect(*rect),
+            ShapeKind::Path(_, size) => {
+                self.to_output_rect(LogicalRect::new(LogicalPoint::default(), *size))
+            }
+        };
+        let fill = self.paint(&fill, bounds);
+        let stroke = stroke
+            .filter(|(_, width)| width.get() > 0 as _)
+            .and_then(|(brush, width)| Some((self.paint(&brush, bounds)?, width)));
+        if fill.is_none() && stroke.is_none() {
+            return;
+        }
+
+        self.start_line();
+        match shape {
+            ShapeKind::Rect(_, radius) => {
+                if let Some(radius) = radius.as_uniform() {
+                    let _ = write!(
+                        self.output,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}""#,
+                        Num(bounds.origin.x),
+                        Num(bounds.origin.y),
+                        Num(bounds.width()),
+                        Num(bounds.height())
+                    );
+                    if radius > 0 as _ {
+                        let _ = write!(self.output, r#" rx="{}""#, Num(radius as f32));
+                    }
+                } else {
+                    let _ = write!(
+                        self.output,
+                        r#"<path d="{}""#,
+                        rounded_rect_path(bounds, radius.cast())
+                    );
+                }
+            }
+            ShapeKind::Path(data, _) => {
+                let _ = write!(self.output, r#"<path d="{data}""#);
+            }
+        }
+        match fill {
+            Some(fill) => {
+                let _ = write!(self.output, r#" fill="{fill}""#);
+            }
+            None => self.output.push_str(r#" fill="none""#),
+        }
+        if let Some((stroke, width)) = stroke {
+            let _ = write!(
+                self.output,
+                r#" stroke="{stroke}" stroke-width="{}""#,
+                Num(width.get() as f32)
+            );
+        }
+        self.output.push_str("/>\n");
+    }
+
+    /// Writes the text, broken into lines that fit into `size`
+    #[allow(clippy::too_many_arguments)]
+    fn write_text(
+        &mut self,
+        string: &str,
+        font_request: crate::graphics::FontRequest,
+        color: Brush,
+        size: LogicalSize,
+        (horizontal_alignment, vertical_alignment): (
+            TextHorizontalAlignment,
+            TextVerticalAlignment,
+        ),
+        wrap: TextWrap,
+        overflow: TextOverflow,
+    ) {
+        if string.is_empty() || size.width <= 0 as _ || size.height <= 0 as _ {
+            return;
+        }
+        let bounds = self.to_output_rect(LogicalRect::new(LogicalPoint::default(), size));
+        let Some(fill) = self.paint(&color, bounds) else { return };
+
+        let renderer = self.window_adapter.renderer();
+        let scale_factor = ScaleFactor::new(self.window.scale_factor());
+        let metrics = renderer.font_metrics(font_request.clone(), scale_factor);
+        let font = MeasuredFont {
+            measure: &|text: &str| {
+                renderer
+                    .text_size(font_request.clone(), text, None, scale_factor, TextWrap::NoWrap)
+                    .width as f32
+            },
+            ascent: metrics.ascent as f32,
+            descent: metrics.descent as f32,
+        };
+        let layout = crate::textlayout::TextParagraphLayout {
+            string,
+            layout: crate::textlayout::TextLayout { font: &font, letter_spacing: None },
+            max_width: size.width as f32,
+            max_height: size.height as f32,
+            horizontal_alignment,
+            vertical_alignment,
+            wrap,
+            overflow,
+            single_line: false,
+        };
+        // The text of the lines, and the position of their baseline
+        let mut lines = Vec::new();
+        let _ = layout.layout_lines::<()>(
+            |glyphs, line_x, line_y, line, _| {
+                let line = glyphs
+                    // Skips the trailing white spaces
+                    .filter(|glyph| {
+                        glyph.glyph_id == ELLIPSIS_GLYPH
+                            || line.byte_range.contains(&glyph.text_byte_offset)
+                    })
+                    .map(|glyph| match glyph.glyph_id {
+                        ELLIPSIS_GLYPH => '…',
+                        _ => string[glyph.text_byte_offset..].chars().next().unwrap_or_default(),
+                    })
+                    .collect::<String>();
+                lines.push((
+                    line,
+                    line_x + bounds.origin.x,
+                    line_y + font.ascent + bounds.origin.y,
+                ));
+                core::ops::ControlFlow::Continue(())
+            },
+            None,
+        );
+
+        self.start_line();
+        self.output.push_str("<text");
+        if let Some(family) = &font_request.family {
+            let _ = write!(self.output, r#" font-family="{}""#, Escaped(family));
+        }
+        if let Some(pixel_size) = font_request.pixel_size {
+            let _ = write!(self.output, r#" font-size="{}""#, Num(pixel_size.get() as f32));
+        }
+        if let Some(weight) = font_request.weight {
+            let _ = write!(self.output, r#" font-weight="{weight}""#);
+        }
+        if font_request.italic {
+            self.output.push_str(r#" font-style="italic""#);
+        }
+        if let Some(letter_spacing) = font_request.letter_spacing.filter(|s| s.get() != 0 as _) {
+            let _ =
+                write!(self.output, r#" letter-spacing="{}""#, Num(letter_spacing.get() as f32));
+        }
+        let _ = write!(self.output, r#" fill="{fill}" xml:space="preserve">"#);
+        for (line, x, y) in &lines {
+            let _ = write!(
+                self.output,
+                r#"<tspan x="{}" y="{}">{}</tspan>"#,
+                Num(*x),
+                Num(*y),
+                Escaped(line)
+            );
+        }
+        self.output.push_str("</text>\n");
+    }
+
+    /// Writes an `<image>` element showing the `source_rect` of the buffer, stretched into `target`
+    fn write_pixels(
+        &mut self,
+        buffer: &SharedPixelBuffer<Rgba8Pixel>,
+        source_rect: IntRect,
+        target: euclid::default::Rect<f32>,
+    ) {
+        let Some(png) = encode_png(buffer) else { return };
+        self.write_image_data(
+            "image/png",
+            &png,
+            buffer.width(),
+            buffer.height(),
+            source_rect,
+            target,
+        );
+    }
+
+    fn write_image_data(
+        &mut self,
+        mime_type: &str,
+        data: &[u8],
+        width: u32,
+        height: u32,
+        source_rect: IntRect,
+        target: euclid::default::Rect<f32>,
+    ) {
+        if target.is_empty() || source_rect.is_empty() {
+            return;
+        }
+        self.start_line();
+        let _ = write!(
+            self.output,
+            r#"<svg x="{}" y="{}" width="{}" height="{}" viewBox="{} {} {} {}" preserveAspectRatio="none">"#,
+            Num(target.origin.x),
+            Num(target.origin.y),
+            Num(target.width()),
+            Num(target.height()),
+            source_rect.origin.x,
+            source_rect.origin.y,
+            source_rect.width(),
+            source_rect.height()
+        );
+        let _ = write!(
+            self.output,
+            r#"<image width="{width}" height="{height}" href="data:{mime_type};base64,{}"/></svg>"#,
+            Base64Display::new(data, &BASE64_STANDARD)
+        );
+        self.output.push('\n');
+    }
+}
+
+impl<'a> ItemRenderer for SvgRenderer<'a> {
+    fn draw_rectangle(&mut self, rect: Pin<&Rectangle>, _self_rc: &ItemRc, size: LogicalSize) {
+        let geometry = LogicalRect::new(LogicalPoint::default(), size);
+        self.write_shape(
+            ShapeKind::Rect(geometry, LogicalBorderRadius::default()),
+            rect.background(),
+            None,
+        );
+    }
+
+    fn draw_border_rectangle(
+        &mut self,
+        rect: Pin<&dyn RenderBorderRectangle>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+        _cache: &CachedRenderingData,
+    ) {
+        let border_width =
+            rect.border_width().min(size.width_length().min(size.height_length()) / 2 as Coord);
+        let radius = rect.border_radius();
+        let border_color = rect.border_color();
+        if border_width.get() > 0 as _ && !border_color.is_transparent() {
+            // The border is drawn inside of the rectangle
+            let half_border = border_width / 2 as Coord;
+            let geometry = LogicalRect::new(LogicalPoint::default(), size)
+                .inflate(-half_border.get(), -half_border.get());
+            self.write_shape(
+                ShapeKind::Rect(geometry, radius.inner(half_border)),
+                rect.background(),
+                Some((border_color, border_width)),
+            );
+        } else {
+            let geometry = LogicalRect::new(LogicalPoint::default(), size);
+            self.write_shape(ShapeKind::Rect(geometry, radius), rect.background(), None);
+        }
+    }
+
+    fn draw_image(
+        &mut self,
+        image: Pin<&dyn RenderImage>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+        _cache: &CachedRenderingData,
+    ) {
+        let source = image.source();
+        let image_size = source.size();
+        if image_size.is_empty() || size.is_empty() {
+            return;
+        }
+        let source_rect = image
+            .source_clip()
+            .unwrap_or_else(|| IntRect::from_size(image_size.cast()))
+            .intersection(&IntRect::from_size(image_size.cast()))
+            .unwrap_or_default();
+        if source_rect.is_empty() {
+            return;
+        }
+        let fit = crate::graphics::fit(
+            image.image_fit(),
+            size.cast::<f32>().cast_unit(),
+            source_rect,
+            ScaleFactor::new(1.),
+            image.alignment(),
+            Default::default(),
+        );
+        let target = euclid::default::Rect::new(fit.offset.to_untyped(), fit.size.to_untyped())
+            .translate(self.current_state.offset.cast::<f32>().to_untyped());
+
+        let colorize = image.colorize();
+        let colorize = (!colorize.is_transparent()).then(|| colorize.color());
+
+        if colorize.is_none() && image.image_fit() != ImageFit::Preserve {
+            // Embed the SVG files as they are to keep them as vector graphics
+            if let Some(path) = source
+                .path()
+                .filter(|p| p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("svg")))
+            {
+                if let Ok(data) = std::fs::read(path) {
+                    self.write_image_data(
+                        "image/svg+xml",
+                        &data,
+                        image_size.width,
+                        image_size.height,
+                        fit.clip_rect,
+                        target,
+                    );
+                    return;
+                }
+            }
+        }
+
+        let Some(mut buffer) = source.to_rgba8() else { return };
+        if let Some(color) = colorize {
+            for pixel in buffer.make_mut_slice() {
+                let alpha = (pixel.a as u16 * color.alpha() as u16 / 255) as u8;
+                *pixel = Rgba8Pixel { r: color.red(), g: color.green(), b: color.blue(), a: alpha };
+            }
+        }
+        self.write_pixels(&buffer, fit.clip_rect, target);
+    }
+
+    fn draw_text(
+        &mut self,
+        text: Pin<&dyn RenderText>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+        _cache: &CachedRenderingData,
+    ) {
+        let font_request = text.font_request(self.window);
+        self.write_text(
+            text.text().as_str(),
+            font_request,
+            text.color(),
+            size,
+            text.alignment(),
+            text.wrap(),
+            text.overflow(),
+        );
+    }
+
+    fn draw_text_input(
+        &mut self,
+        text_input: Pin<&TextInput>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+    ) {
+        let font_request = text_input.font_request(&self.window_adapter);
+        let visual_representation = text_input.visual_representation(None);
+        self.write_text(
+            &visual_representation.text,
+            font_request,
+            visual_representation.text_color,
+            size,
+            (text_input.horizontal_alignment(), text_input.vertical_alignment()),
+            text_input.wrap(),
+            TextOverflow::Clip,
+        );
+    }
+
+    fn draw_path(&mut self, path: Pin<&Path>, item_rc: &ItemRc, size: LogicalSize) {
+        let Some((offset, path_events)) = path.fitted_path_events(item_rc) else { return };
+        let offset = (offset + self.current_state.offset).cast::<f32>();
+
+        let mut data = String::new();
+        let point = |data: &mut String, p: lyon_path::math::Point| {
+            let _ = write!(data, "{} {} ", Num(p.x + offset.x), Num(p.y + offset.y));
+        };
+        for event in path_events.iter() {
+            match event {
+                lyon_path::Event::Begin { at } => {
+                    data.push_str("M ");
+                    point(&mut data, at);
+                }
+                lyon_path::Event::Line { to, .. } => {
+                    data.push_str("L ");
+                    point(&mut data, to);
+                }
+                lyon_path::Event::Quadratic { ctrl, to, .. } => {
+                    data.push_str("Q ");
+                    point(&mut data, ctrl);
+                    point(&mut data, to);
+                }
+                lyon_path::Event::Cubic { ctrl1, ctrl2, to, .. } => {
+                    data.push_str("C ");
+                    point(&mut data, ctrl1);
+                    point(&mut data, ctrl2);
+                    point(&mut data, to);
+                }
+                lyon_path::Event::End { close, .. } => {
+                    if close {
+                        data.push_str("Z ");
+                    }
+                }
+            }
+        }
+        let data = data.trim_end();
+        if data.is_empty() {
+            return;
+        }
+
+        if path.fill_rule() == FillRule::Evenodd {
+            self.open_group(format_args!(r#"fill-rule="evenodd""#));
+        }
+        self.write_shape(
+            ShapeKind::Path(data, size),
+            path.fill(),
+            Some((path.stroke(), path.stroke_width())),
+        );
+        if path.fill_rule() == FillRule::Evenodd {
+            self.indent -= 1;
+            self.current_state.open_groups -= 1;
+            self.start_line();
+            self.output.push_str("</g>\n");
+        }
+    }
+
+    fn draw_box_shadow(
+        &mut self,
+        box_shadow: Pin<&BoxShadow>,
+        _self_rc: &ItemRc,
+        size: LogicalSize,
+    ) {
+        let color = box_shadow.color();
+        if color.alpha() == 0 {
+            return;
+        }
+        let geometry = LogicalRect::new(
+            LogicalPoint::new(box_shadow.offset_x().get(), box_shadow.offset_y().get()),
+            size,
+        );
+        let blur = box_shadow.blur().get() as f32;
+        let filter = (blur > 0.).then(|| {
+            let id = self.new_id("shadow");
+            self.start_line();
+            let _ = writeln!(
+                self.output,
+                r#"<filter id="{id}" x="-50%" y="-50%" width="200%" height="200%"><feGaussianBlur stdDeviation="{}"/></filter>"#,
+                Num(blur / 2.)
+            );
+            id
+        });
+        if let Some(filter) = &filter {
+            self.open_group(format_args!(r#"filter="url(#{filter})""#));
+        }
+        self.write_shape(
+            ShapeKind::Rect(geometry, LogicalBorderRadius::from_length(box_shadow.border_radius())),
+            Brush::SolidColor(color),
+            None,
+        );
+        if filter.is_some() {
+            self.indent -= 1;
+            self.current_state.open_groups -= 1;
+            self.start_line();
+            self.output.push_str("</g>\n");
+        }
+    }
+
+    fn combine_clip(
+        &mut self,
+        rect: LogicalRect,
+        radius: LogicalBorderRadius,
+        _border_width: LogicalLength,
+    ) -> bool {
+        let clip = self.current_state.clip.intersection(&rect).unwrap_or_default();
+        self.current_state.clip = clip;
+        if clip.is_empty() {
+            return false;
+        }
+
+        let bounds = self.to_output_rect(rect);
+        let id = self.new_id("clip");
+        self.start_line();
+        let _ = write!(self.output, r#"<clipPath id="{id}">"#);
+        match radius.as_uniform() {
+            Some(radius) => {
+                let _ = write!(
+                    self.output,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}"/>"#,
+                    Num(bounds.origin.x),
+                    Num(bounds.origin.y),
+                    Num(bounds.width()),
+                    Num(bounds.height()),
+                    Num(radius as f32)
+                );
+            }
+            None => {
+                let _ = write!(
+                    self.output,
+                    r#"<path d="{}"/>"#,
+                    rounded_rect_path(bounds, radius.cast())
+                );
+            }
+        }
+        self.output.push_str("</clipPath>\n");
+        self.open_group(format_args!(r#"clip-path="url(#{id})""#));
+        true
+    }
+
+    fn get_current_clip(&self) -> LogicalRect {
+        self.current_state.clip
+    }
+
+    fn translate(&mut self, distance: LogicalVector) {
+        self.current_state.offset += distance;
+        self.current_state.clip = self.current_state.clip.translate(-distance);
+    }
+
+    fn translation(&self) -> LogicalVector {
+        self.current_state.offset
+    }
+
+    fn rotate(&mut self, angle_in_degrees: f32) {
+        let offset = self.current_state.offset.cast::<f32>();
+        self.open_group(format_args!(
+            r#"transform="translate({} {}) rotate({})""#,
+            Num(offset.x),
+            Num(offset.y),
+            Num(angle_in_degrees)
+        ));
+        self.current_state.offset = LogicalVector::default();
+        // The clip can't be expressed in the rotated coordinate, so don't filter the children
+        self.current_state.clip = LogicalRect::new(
+            LogicalPoint::new(-1_000_000 as _, -1_000_000 as _),
+            LogicalSize::new(2_000_000 as _, 2_000_000 as _),
+        );
+    }
+
+    fn apply_opacity(&mut self, opacity: f32) {
+        if opacity < 1. {
+            self.open_group(format_args!(r#"opacity="{}""#, Num(opacity)));
+        }
+    }
+
+    fn save_state(&mut self) {
+        self.state_stack.push(self.current_state.clone());
+        self.current_state.open_groups = 0;
+    }
+
+    fn restore_state(&mut self) {
+        for _ in 0..self.current_state.open_groups {
+            self.indent -= 1;
+            self.start_line();
+            self.output.push_str("</g>\n");
+        }
+        self.current_state = self.state_stack.pop().unwrap_or_default();
+    }
+
+    fn scale_factor(&self) -> f32 {
+        self.window.scale_factor()
+    }
+
+    fn draw_cached_pixmap(
+        &mut self,
+        _item_cache: &ItemRc,
+        update_fn: &dyn Fn(&mut dyn FnMut(u32, u32, &[u8])),
+    ) {
+        let scale_factor = self.window.scale_factor();
+        let offset = self.current_state.offset.cast::<f32>().to_untyped();
+        update_fn(&mut |width, height, data| {
+            let mut buffer = SharedPixelBuffer::<Rgba8Pixel>::clone_from_slice(data, width, height);
+            // The data is premultiplied
+            for pixel in buffer.make_mut_slice() {
+                if pixel.a != 0 && pixel.a != 255 {
+                    let unpremultiply = |c: u8| (c as u16 * 255 / pixel.a as u16).min(255) as u8;
+                    pixel.r = unpremultiply(pixel.r);
+                    pixel.g = unpremultiply(pixel.g);
+                    pixel.b = unpremultiply(pixel.b);
+                }
+            }
+            let target = euclid::default::Rect::new(
+                offset.to_point(),
+                euclid::size2(width as f32 / scale_factor, height as f32 / scale_factor),
+            );
+            self.write_pixels(
+                &buffer,
+                IntRect::from_size(euclid::size2(width as _, height as _)),
+                target,
+            );
+        });
+    }
+
+    fn draw_string(&mut self, string: &str, color: Color) {
+        let size = self.current_state.clip.size;
+        self.write_text(
+            string,
+            Default::default(),
+            color.into(),
+            size,
+            Default::default(),
+            TextWrap::NoWrap,
+            TextOverflow::Clip,
+        );
+    }
+
+    fn draw_image_direct(&mut self, image: Image) {
+        let Some(buffer) = image.to_rgba8() else { return };
+        let size = image.size().cast::<f32>();
+        let target = euclid::default::Rect::new(
+            self.current_state.offset.cast::<f32>().to_untyped().to_point(),
+            size,
+        );
+        self.write_pixels(&buffer, IntRect::from_size(image.size().cast()), target);
+    }
+
+    fn window(&self) -> &WindowInner {
+        self.window
+    }
+
+    fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
+        None
+    }
+}
+
+/// The glyph id of the ellipsis, the other characters use [`CHARACTER_GLYPH`]
+const ELLIPSIS_GLYPH: core::num::NonZeroU16 = core::num::NonZeroU16::MAX;
+const CHARACTER_GLYPH: core::num::NonZeroU16 = core::num::NonZeroU16::MIN;
+
+/// A font for [`crate::textlayout`] that measures each character with the renderer of the
+/// window. The glyphs only refer back to the characters, as the SVG is rendered by the viewer.
+struct MeasuredFont<'a> {
+    measure: &'a dyn Fn(&str) -> f32,
+    ascent: f32,
+    descent: f32,
+}
+
+impl crate::textlayout::TextShaper for MeasuredFont<'_> {
+    type LengthPrimitive = f32;
+    type Length = f32;
+
+    fn shape_text<GlyphStorage: core::iter::Extend<crate::textlayout::Glyph<f32>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        glyphs.extend(text.char_indices().map(|(text_byte_offset, c)| crate::textlayout::Glyph {
+            advance: (self.measure)(&text[text_byte_offset..text_byte_offset + c.len_utf8()]),
+            glyph_id: Some(CHARACTER_GLYPH),
+            text_byte_offset,
+            ..Default::default()
+        }));
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<crate::textlayout::Glyph<f32>> {
+        let glyph_id = if ch == '…' { ELLIPSIS_GLYPH } else { CHARACTER_GLYPH };
+        Some(crate::textlayout::Glyph {
+            advance: (self.measure)(ch.encode_utf8(&mut [0; 4])),
+            glyph_id: Some(glyph_id),
+            ..Default::default()
+        })
+    }
+
+    fn max_lines(&self, max_height: f32) -> usize {
+        use crate::textlayout::FontMetrics;
+        (max_height / self.height()).floor() as _
+    }
+}
+
+impl crate::textlayout::FontMetrics<f32> for MeasuredFont<'_> {
+    fn ascent(&self) -> f32 {
+        self.ascent
+    }
+
+    fn descent(&self) -> f32 {
+        self.descent
+    }
+
+    fn x_height(&self) -> f32 {
+        self.ascent / 2.
+    }
+
+    fn cap_height(&self) -> f32 {
+        self.ascent
+    }
+}
+
+fn rounded_rect_path(
+    rect: euclid::default::Rect<f32>,
+    radius: crate::graphics::BorderRadius<f32, LogicalPx>,
+) -> String {
+    let (x0, y0, x1, y1) = (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y());
+    let mut data = String::new();
+    let _ = write!(data, "M {} {}", Num(x0 + radius.top_left), Num(y0));
+    // Draws a line to (lx, ly), and then the arc of the corner that ends in (ax, ay)
+    let mut corner = |r: f32, (lx, ly): (f32, f32), (ax, ay): (f32, f32)| {
+        let _ = write!(data, " L {} {}", Num(lx), Num(ly));
+        if r > 0. {
+            let _ = write!(data, " A {r} {r} 0 0 1 {} {}", Num(ax), Num(ay), r = Num(r));
+        }
+    };
+    corner(radius.top_right, (x1 - radius.top_right, y0), (x1, y0 + radius.top_right));
+    corner(radius.bottom_right, (x1, y1 - radius.bottom_right), (x1 - radius.bottom_right, y1));
+    corner(radius.bottom_left, (x0 + radius.bottom_left, y1), (x0, y1 - radius.bottom_left));
+    corner(radius.top_left, (x0, y0 + radius.top_left), (x0 + radius.top_left, y0));
+    data.push_str(" Z");
+    data
+}
+
+fn paint_for_color(color: Color) -> String {
+    if color.alpha() == 255 {
+        ColorHex(color).to_string()
+    } else {
+        format!(
+            "rgba({},{},{},{})",
+            color.red(),
+            color.green(),
+            color.blue(),
+            Num(color.alpha() as f32 / 255.)
+        )
+    }
+}
+
+fn encode_png(buffer: &SharedPixelBuffer<Rgba8Pixel>) -> Option<Vec<u8>> {
+    use image::ImageEncoder;
+    let mut png = Vec::new();
+    image::codecs::png::PngEncoder::new(&mut png)
+        .write_image(buffer.as_bytes(), buffer.width(), buffer.height(), image::ColorType::Rgba8)
+        .ok()?;
+    Some(png)
+}
+
+/// Formats a number with at most three decimals
+struct Num(f32);
+impl core::fmt::Display for Num {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Adding 0 turns -0 into 0
+        write!(f, "{}", (self.0 * 1000.).round() / 1000. + 0.)
+    }
+}
+
+struct SpreadMethod(GradientSpread);
+impl core::fmt::Display for SpreadMethod {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            GradientSpread::Repeat => f.write_str(r#" spreadMethod="repeat""#),
+            GradientSpread::Reflect => f.write_str(r#" spreadMethod="reflect""#),
+            _ => Ok(()),
+        }
+    }
+}
+
+struct ColorHex(Color);
+impl core::fmt::Display for ColorHex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0.red(), self.0.green(), self.0.blue())
+    }
+}
+
+/// Escapes the characters that have a special meaning in XML
+struct Escaped<'a>(&'a str);
+impl core::fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
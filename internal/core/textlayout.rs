@@ -49,6 +49,9 @@
 
 pub use linebreaker::TextLineBreaker;
 
+mod styled;
+pub use styled::{StyledParagraphLayout, StyledTextFragment};
+
 pub struct TextLayout<'a, Font: AbstractFont> {
     pub font: &'a Font,
     pub letter_spacing: Option<<Font as TextShaper>::Length>,
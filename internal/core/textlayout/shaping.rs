@@ -177,15 +177,35 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
     where
         Font: AbstractFont<Length = Length>,
         Length: Copy + core::ops::AddAssign,
+    {
+        Self::new_with_fonts(
+            text,
+            core::iter::once((0..text.len(), layout.font)),
+            layout.letter_spacing,
+        )
+    }
+
+    /// Shapes the text with a different font for each of the given byte ranges. The ranges
+    /// must be sorted and cover the entire text.
+    pub fn new_with_fonts<'a, Font>(
+        text: &str,
+        fonts: impl IntoIterator<Item = (Range<usize>, &'a Font)>,
+        letter_spacing: Option<Length>,
+    ) -> Self
+    where
+        Font: AbstractFont<Length = Length> + 'a,
+        Length: Copy + core::ops::AddAssign,
     {
         let mut glyphs = Vec::new();
-        let text_runs = ShapeBoundaries::new(text)
-            .scan(0, |run_start, run_end| {
+        let mut text_runs = Vec::new();
+        for (font_range, font) in fonts {
+            let font_text = &text[font_range.clone()];
+            let runs = ShapeBoundaries::new(font_text).scan(0, |run_start, run_end| {
                 let glyphs_start = glyphs.len();
 
-                layout.font.shape_text(&text[*run_start..run_end], &mut glyphs);
+                font.shape_text(&font_text[*run_start..run_end], &mut glyphs);
 
-                if let Some(letter_spacing) = layout.letter_spacing {
+                if let Some(letter_spacing) = letter_spacing {
                     if glyphs.len() > glyphs_start {
                         let mut last_byte_offset = glyphs[glyphs_start].text_byte_offset;
                         for index in glyphs_start + 1..glyphs.len() {
@@ -202,14 +222,18 @@ pub fn new<Font>(layout: &TextLayout<Font>, text: &str) -> Self
                 }
 
                 let run = TextRun {
-                    byte_range: Range { start: *run_start, end: run_end },
+                    byte_range: Range {
+                        start: font_range.start + *run_start,
+                        end: font_range.start + run_end,
+                    },
                     glyph_range: Range { start: glyphs_start, end: glyphs.len() },
                 };
                 *run_start = run_end;
 
                 Some(run)
-            })
-            .collect();
+            });
+            text_runs.extend(runs);
+        }
 
         Self { glyphs, text_runs }
     }
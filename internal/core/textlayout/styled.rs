@@ -0,0 +1,254 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Layout of text where different runs of the text are shaped with different fonts.
+//!
+//! The whole text is shaped into a single [`ShapeBuffer`], so that line breaking happens across
+//! the boundaries of the runs. All lines have the same height, which is the largest height of the
+//! fonts, and the glyphs of all runs share the same baseline.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::{ControlFlow, Range};
+
+use euclid::num::{One, Zero};
+
+use super::{AbstractFont, PositionedGlyph, ShapeBuffer, TextLine, TextLineBreaker};
+use crate::items::{TextHorizontalAlignment, TextVerticalAlignment, TextWrap};
+
+/// A part of a line that was shaped with a single font.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyledTextFragment<Length> {
+    /// The index of the run in [`StyledParagraphLayout::runs`]
+    pub run_index: usize,
+    /// The range of the fragment's text in [`StyledParagraphLayout::string`]
+    pub byte_range: Range<usize>,
+    pub x: Length,
+    pub width: Length,
+    /// The top of the line
+    pub line_y: Length,
+    pub baseline_y: Length,
+}
+
+pub struct StyledParagraphLayout<'a, Font: AbstractFont> {
+    pub string: &'a str,
+    /// The font for each run of the string. The ranges must be sorted and cover the entire string.
+    pub runs: &'a [(Range<usize>, Font)],
+    pub max_width: Font::Length,
+    pub max_height: Font::Length,
+    pub horizontal_alignment: TextHorizontalAlignment,
+    pub vertical_alignment: TextVerticalAlignment,
+    pub wrap: TextWrap,
+}
+
+impl<'a, Font: AbstractFont> StyledParagraphLayout<'a, Font> {
+    /// Returns the height of a line and the distance of the baseline from the top of the line.
+    pub fn line_metrics(&self) -> (Font::Length, Font::Length) {
+        self.runs.iter().fold((Font::Length::zero(), Font::Length::zero()), |(h, a), (_, font)| {
+            (euclid::approxord::max(h, font.height()), euclid::approxord::max(a, font.ascent()))
+        })
+    }
+
+    fn shape(&self) -> ShapeBuffer<Font::Length> {
+        ShapeBuffer::new_with_fonts(
+            self.string,
+            self.runs.iter().map(|(range, font)| (range.clone(), font)),
+            None,
+        )
+    }
+
+    fn lines(&self, shape_buffer: &ShapeBuffer<Font::Length>) -> Vec<TextLine<Font::Length>> {
+        let max_width = (self.wrap != TextWrap::NoWrap).then_some(self.max_width);
+        TextLineBreaker::<Font>::new(self.string, shape_buffer, max_width, None, self.wrap)
+            .collect()
+    }
+
+    /// Returns the width of the longest line and the height of all lines.
+    pub fn text_size(&self) -> (Font::Length, Font::Length) {
+        let shape_buffer = self.shape();
+        let lines = self.lines(&shape_buffer);
+        let width = lines.iter().fold(Font::Length::zero(), |width, line| {
+            euclid::approxord::max(width, line.text_width)
+        });
+        (width, self.line_metrics().0 * (lines.len() as i16).into())
+    }
+
+    /// Layout the string in lines, and call the `fragment_callback` for every fragment of a line,
+    /// together with the glyphs of the fragment. The x position of the glyphs is relative to the
+    /// fragment.
+    pub fn layout_fragments<R>(
+        &self,
+        mut fragment_callback: impl FnMut(
+            &StyledTextFragment<Font::Length>,
+            &mut dyn Iterator<Item = PositionedGlyph<Font::Length>>,
+        ) -> ControlFlow<R>,
+    ) -> Result<(), R> {
+        let shape_buffer = self.shape();
+        let lines = self.lines(&shape_buffer);
+        let (line_height, ascent) = self.line_metrics();
+        let two = <Font::LengthPrimitive as One>::one() + One::one();
+        let text_height = line_height * (lines.len() as i16).into();
+
+        let mut line_y = match self.vertical_alignment {
+            TextVerticalAlignment::Top => Font::Length::zero(),
+            TextVerticalAlignment::Center => self.max_height / two - text_height / two,
+            TextVerticalAlignment::Bottom => self.max_height - text_height,
+        };
+
+        for line in &lines {
+            let mut x = match self.horizontal_alignment {
+                TextHorizontalAlignment::Left => Font::Length::zero(),
+                TextHorizontalAlignment::Center => self.max_width / two - line.text_width / two,
+                TextHorizontalAlignment::Right => self.max_width - line.text_width,
+            };
+
+            for text_run in shape_buffer.text_runs.iter().filter(|run| {
+                run.glyph_range.start < line.glyph_range.end
+                    && line.glyph_range.start < run.glyph_range.end
+            }) {
+                // Trailing whitespace is not part of the line's byte range
+                let byte_range = text_run.byte_range.start.max(line.byte_range.start)
+                    ..text_run.byte_range.end.min(line.byte_range.end);
+                if byte_range.is_empty() {
+                    continue;
+                }
+                let run_index =
+                    self.runs.partition_point(|(range, _)| range.end <= text_run.byte_range.start);
+
+                let glyph_range = text_run.glyph_range.start.max(line.glyph_range.start)
+                    ..text_run.glyph_range.end.min(line.glyph_range.end);
+                let glyphs = shape_buffer.glyphs[glyph_range].iter().filter(|glyph| {
+                    byte_range.contains(&(text_run.byte_range.start + glyph.text_byte_offset))
+                });
+
+                let width =
+                    glyphs.clone().fold(Font::Length::zero(), |width, glyph| width + glyph.advance);
+                let fragment = StyledTextFragment {
+                    run_index,
+                    byte_range: byte_range.clone(),
+                    x,
+                    width,
+                    line_y,
+                    baseline_y: line_y + ascent,
+                };
+
+                let mut glyph_x = Font::Length::zero();
+                let mut positioned_glyphs = glyphs.filter_map(|glyph| {
+                    let x = glyph_x;
+                    glyph_x += glyph.advance;
+                    glyph.glyph_id.map(|glyph_id| PositionedGlyph {
+                        x,
+                        y: Font::Length::zero(),
+                        advance: glyph.advance,
+                        glyph_id,
+                        text_byte_offset: text_run.byte_range.start + glyph.text_byte_offset,
+                    })
+                });
+
+                if let ControlFlow::Break(break_val) =
+                    fragment_callback(&fragment, &mut positioned_glyphs)
+                {
+                    return Err(break_val);
+                }
+                x += width;
+            }
+            line_y += line_height;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the index of the run under the given position, if any.
+    pub fn run_at_position(&self, (pos_x, pos_y): (Font::Length, Font::Length)) -> Option<usize> {
+        let line_height = self.line_metrics().0;
+        self.layout_fragments(|fragment, _| {
+            if pos_y >= fragment.line_y
+                && pos_y < fragment.line_y + line_height
+                && pos_x >= fragment.x
+                && pos_x < fragment.x + fragment.width
+            {
+                ControlFlow::Break(fragment.run_index)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .err()
+    }
+}
+
+#[test]
+fn test_styled_fragments() {
+    use super::FixedTestFont;
+
+    let text = "Hello bold World";
+    let runs = [(0..6, FixedTestFont), (6..10, FixedTestFont), (10..16, FixedTestFont)];
+    let paragraph = StyledParagraphLayout {
+        string: text,
+        runs: &runs,
+        max_width: 120.,
+        max_height: 100.,
+        horizontal_alignment: TextHorizontalAlignment::Left,
+        vertical_alignment: TextVerticalAlignment::Top,
+        wrap: TextWrap::WordWrap,
+    };
+
+    assert_eq!(paragraph.text_size(), (100., 20.));
+
+    let mut fragments = Vec::new();
+    paragraph
+        .layout_fragments::<()>(|fragment, glyphs| {
+            let glyphs = glyphs.map(|g| (g.x, g.text_byte_offset)).collect::<Vec<_>>();
+            assert_eq!(glyphs.first(), Some(&(0., fragment.byte_range.start)));
+            fragments.push(fragment.clone());
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+
+    let fragment = |run_index, byte_range: Range<usize>, x, width, line_y| StyledTextFragment {
+        run_index,
+        byte_range,
+        x,
+        width,
+        line_y,
+        baseline_y: line_y + 5.,
+    };
+    assert_eq!(
+        fragments,
+        [
+            fragment(0, 0..6, 0., 60., 0.),
+            fragment(1, 6..10, 60., 40., 0.),
+            fragment(2, 11..16, 0., 50., 10.),
+        ]
+    );
+
+    assert_eq!(paragraph.run_at_position((65., 5.)), Some(1));
+    assert_eq!(paragraph.run_at_position((5., 15.)), Some(2));
+    assert_eq!(paragraph.run_at_position((105., 5.)), None);
+    assert_eq!(paragraph.run_at_position((5., 25.)), None);
+}
+
+#[test]
+fn test_styled_alignment() {
+    use super::FixedTestFont;
+
+    let text = "ab cd";
+    let runs = [(0..3, FixedTestFont), (3..5, FixedTestFont)];
+    let paragraph = StyledParagraphLayout {
+        string: text,
+        runs: &runs,
+        max_width: 100.,
+        max_height: 30.,
+        horizontal_alignment: TextHorizontalAlignment::Right,
+        vertical_alignment: TextVerticalAlignment::Bottom,
+        wrap: TextWrap::NoWrap,
+    };
+
+    let mut fragments = Vec::new();
+    paragraph
+        .layout_fragments::<()>(|fragment, _| {
+            fragments.push((fragment.x, fragment.line_y, fragment.width));
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+    assert_eq!(fragments, [(50., 20., 30.), (80., 20., 20.)]);
+}
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
 use crate::SharedString;
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::thread_local;
 use core::fmt::Display;
 pub use formatter::FormatArgs;
 
@@ -171,6 +173,18 @@ fn from_name<'b>(&'b self, name: &str) -> Option<Self::Output<'b>> {
     }
 }
 
+fn format_plural(translated: &str, arguments: &(impl FormatArgs + ?Sized), n: i32) -> SharedString {
+    let mut output = SharedString::default();
+    use core::fmt::Write;
+    write!(output, "{}", formatter::format(translated, &WithPlural(arguments, n))).unwrap();
+    output
+}
+
+/// The plural rule of the original strings, which are in english
+fn english_plural_rule(n: i32) -> usize {
+    (n != 1) as usize
+}
+
 /// Do the translation and formatting
 pub fn translate(
     original: &str,
@@ -245,6 +259,138 @@ pub fn mark_all_translations_dirty() {
     })
 }
 
+thread_local! {
+    /// The languages of the bundled translations (the first one being the original language),
+    /// and the index of the one that is selected
+    static BUNDLED_LANGUAGES: core::cell::RefCell<(Option<alloc::vec::Vec<SharedString>>, usize)>
+        = Default::default()
+}
+
+/// Returns the index of the bundled language currently selected, and makes the current
+/// binding depend on the language so that it gets re-evaluated when the language changes
+fn selected_bundled_language() -> usize {
+    crate::context::GLOBAL_CONTEXT.with(|ctx| {
+        let Some(ctx) = ctx.get() else { return };
+        ctx.0.translations_dirty.as_ref().get();
+    });
+    BUNDLED_LANGUAGES.with(|languages| languages.borrow().1)
+}
+
+/// Translate and format a string with the translations bundled in the binary.
+///
+/// `strs` contains the translation in each of the bundled language, the first one being the
+/// original string. `None` means that the string is not translated in that language.
+pub fn translate_from_bundle(
+    strs: &[Option<&str>],
+    arguments: &(impl FormatArgs + ?Sized),
+) -> SharedString {
+    let translated = strs
+        .get(selected_bundled_language())
+        .copied()
+        .flatten()
+        .or_else(|| strs.first().copied().flatten())
+        .unwrap_or_default();
+    let mut output = SharedString::default();
+    use core::fmt::Write;
+    write!(output, "{}", formatter::format(translated, arguments)).unwrap();
+    output
+}
+
+/// Function returning the index of the plural form to use for a given `n` in a bundled language.
+pub type PluralRule = fn(i32) -> usize;
+
+/// Translate and format a string with plural forms with the translations bundled in the binary.
+///
+/// `strs` contains all the forms in each of the bundled language, the first one being the original
+/// singular and plural. `plural_rules` contains for each language the function that returns the
+/// index of the form to use for `n`.
+pub fn translate_from_bundle_with_plural(
+    strs: &[Option<&[&str]>],
+    plural_rules: &[Option<PluralRule>],
+    arguments: &(impl FormatArgs + ?Sized),
+    n: i32,
+) -> SharedString {
+    let idx = selected_bundled_language();
+    let (forms, form) =
+        match (strs.get(idx).copied().flatten(), plural_rules.get(idx).copied().flatten()) {
+            (Some(forms), Some(rule)) if idx > 0 => (forms, rule(n)),
+            _ => (strs.first().copied().flatten().unwrap_or_default(), english_plural_rule(n)),
+        };
+    format_plural(forms.get(form).or(forms.last()).copied().unwrap_or_default(), arguments, n)
+}
+
+/// Register the languages for which the translations are bundled.
+///
+/// This is called by the generated code. The first language is the original language of the strings.
+pub fn set_bundled_languages(languages: &[&str]) {
+    BUNDLED_LANGUAGES.with(|bundled_languages| {
+        let mut bundled_languages = bundled_languages.borrow_mut();
+        if bundled_languages.0.is_none() {
+            bundled_languages.0 = Some(languages.iter().map(|l| SharedString::from(*l)).collect());
+        }
+    })
+}
+
+/// Error type returned by [`select_bundled_translation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SelectBundledTranslationError {
+    /// The language was not found. The list of available languages is included in this variant.
+    LanguageNotFound { available_languages: alloc::vec::Vec<SharedString> },
+    /// There are no bundled translations. Either the translations were not bundled by the compiler,
+    /// or no component was created yet.
+    NoTranslationsBundled,
+}
+
+impl Display for SelectBundledTranslationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SelectBundledTranslationError::LanguageNotFound { available_languages } => {
+                write!(f, "The specified language was not found. Available languages are:")?;
+                for language in available_languages.iter().filter(|l| !l.is_empty()) {
+                    write!(f, " {language}")?;
+                }
+                Ok(())
+            }
+            SelectBundledTranslationError::NoTranslationsBundled => {
+                write!(f, "There are no bundled translations")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SelectBundledTranslationError {}
+
+/// Select the language of the translations that were bundled in the binary by the compiler,
+/// and re-translate all the strings.
+///
+/// The language is the name of the directory that contains the `.po` file, such as `"de"` or `"fr_CH"`.
+/// If there is no translation for `"fr_CH"`, the translation for `"fr"` is used.
+/// The empty string selects the original strings.
+pub fn select_bundled_translation(language: &str) -> Result<(), SelectBundledTranslationError> {
+    let changed = BUNDLED_LANGUAGES.with(|bundled_languages| {
+        let mut bundled_languages = bundled_languages.borrow_mut();
+        let (Some(languages), selected) = &mut *bundled_languages else {
+            return Err(SelectBundledTranslationError::NoTranslationsBundled);
+        };
+        let idx = languages.iter().position(|l| l == language).or_else(|| {
+            let base_language = language.split(['_', '-', '.', '@']).next().unwrap_or_default();
+            languages.iter().position(|l| l == base_language)
+        });
+        let Some(idx) = idx else {
+            return Err(SelectBundledTranslationError::LanguageNotFound {
+                available_languages: languages.clone(),
+            });
+        };
+        Ok(core::mem::replace(selected, idx) != idx)
+    })?;
+    if changed {
+        mark_all_translations_dirty();
+    }
+    Ok(())
+}
+
 #[cfg(feature = "gettext-rs")]
 /// Initialize the translation by calling the [`bindtextdomain`](https://man7.org/linux/man-pages/man3/bindtextdomain.3.html) function from gettext
 pub fn gettext_bindtextdomain(_domain: &str, _dirname: std::path::PathBuf) -> std::io::Result<()> {
@@ -285,4 +431,71 @@ pub extern "C" fn slint_translate(
     pub extern "C" fn slint_translations_mark_dirty() {
         mark_all_translations_dirty();
     }
+
+    /// Safety: the pointer must be null or point to a nul-terminated string that outlives `'a`
+    unsafe fn c_str<'a>(s: *const core::ffi::c_char) -> Option<&'a str> {
+        if s.is_null() {
+            None
+        } else {
+            core::ffi::CStr::from_ptr(s).to_str().ok()
+        }
+    }
+
+    /// Perform the translation and formatting with the bundled translations.
+    /// `strs` contains the translation in each language (or null)
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_translate_from_bundle(
+        strs: Slice<*const core::ffi::c_char>,
+        arguments: Slice<SharedString>,
+        output: &mut SharedString,
+    ) {
+        let strs = strs.iter().map(|s| c_str(*s)).collect::<alloc::vec::Vec<_>>();
+        *output = translate_from_bundle(&strs, arguments.as_slice());
+    }
+
+    /// Perform the translation and formatting of a plural form with the bundled translations.
+    /// `strs` contains the same number of forms for each of the language, padded with null.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_translate_from_bundle_with_plural(
+        strs: Slice<*const core::ffi::c_char>,
+        plural_rules: Slice<Option<extern "C" fn(i32) -> usize>>,
+        arguments: Slice<SharedString>,
+        n: i32,
+        output: &mut SharedString,
+    ) {
+        let forms_count = strs.len() / plural_rules.len().max(1);
+        let forms_for_language = |language: usize| {
+            let forms = strs.get(language * forms_count..(language + 1) * forms_count)?;
+            let forms = forms.iter().map_while(|s| c_str(*s)).collect::<alloc::vec::Vec<&str>>();
+            (!forms.is_empty()).then_some(forms)
+        };
+        let idx = selected_bundled_language();
+        let (forms, form) =
+            match (forms_for_language(idx), plural_rules.get(idx).copied().flatten()) {
+                (Some(forms), Some(rule)) if idx > 0 => (forms, rule(n)),
+                _ => (forms_for_language(0).unwrap_or_default(), english_plural_rule(n)),
+            };
+        *output = format_plural(
+            forms.get(form).or(forms.last()).copied().unwrap_or_default(),
+            arguments.as_slice(),
+            n,
+        );
+    }
+
+    /// Register the languages of the bundled translations
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_translate_set_bundled_languages(
+        languages: Slice<*const core::ffi::c_char>,
+    ) {
+        let languages =
+            languages.iter().map(|l| c_str(*l).unwrap_or_default()).collect::<alloc::vec::Vec<_>>();
+        set_bundled_languages(&languages);
+    }
+
+    /// Select the bundled translation. Returns false if the language was not found.
+    #[no_mangle]
+    pub extern "C" fn slint_translate_select_bundled_translation(language: Slice<u8>) -> bool {
+        let Ok(language) = core::str::from_utf8(&language) else { return false };
+        select_bundled_translation(language).is_ok()
+    }
 }
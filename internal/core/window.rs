@@ -13,9 +13,9 @@
 use crate::graphics::Point;
 use crate::input::{
     key_codes, ClickState, InternalKeyboardModifierState, KeyEvent, KeyEventType, MouseEvent,
-    MouseInputState, TextCursorBlinker,
+    MouseInputState, TextCursorBlinker, TouchState,
 };
-use crate::item_tree::ItemRc;
+use crate::item_tree::{ItemRc, ItemVisitorResult};
 use crate::item_tree::{ItemTreeRc, ItemTreeRef, ItemTreeVTable, ItemTreeWeak};
 use crate::items::PopupClosePolicy;
 use crate::items::{ColorScheme, InputType, ItemRef, MouseCursor};
@@ -27,8 +27,10 @@
 use alloc::boxed::Box;
 use alloc::rc::{Rc, Weak};
 use core::cell::{Cell, RefCell};
+use core::num::NonZeroU32;
 use core::pin::Pin;
 use euclid::num::Zero;
+use i_slint_common::key_sequence::KeySequence;
 use vtable::VRcMapped;
 
 pub mod popup;
@@ -37,10 +39,6 @@ fn next_focus_item(item: ItemRc) -> ItemRc {
     item.next_focus_item()
 }
 
-fn previous_focus_item(item: ItemRc) -> ItemRc {
-    item.previous_focus_item()
-}
-
 /// This trait represents the adaptation layer between the [`Window`] API and then
 /// windowing specific window representation, such as a Win32 `HWND` handle or a `wayland_surface_t`.
 ///
@@ -136,6 +134,19 @@ fn request_redraw(&self) {}
     /// be called again.
     fn update_window_properties(&self, _properties: WindowProperties<'_>) {}
 
+    /// Re-implement this function to connect editable text input fields with the input method of
+    /// the platform, such as an on-screen keyboard or a composition window for CJK text.
+    ///
+    /// Slint calls this function when a text input gains or loses focus and when its text, cursor,
+    /// or pre-edit text changes. Deliver the text the input method composes back to Slint by
+    /// dispatching [`WindowEvent::ImePreedit`](crate::platform::WindowEvent::ImePreedit),
+    /// [`WindowEvent::ImeCommit`](crate::platform::WindowEvent::ImeCommit), and
+    /// [`WindowEvent::ImeDeleteSurrounding`](crate::platform::WindowEvent::ImeDeleteSurrounding)
+    /// using [`Window::dispatch_event()`].
+    ///
+    /// The default implementation does nothing.
+    fn input_method_request(&self, _request: InputMethodRequest) {}
+
     #[doc(hidden)]
     fn internal(&self, _: crate::InternalToken) -> Option<&dyn WindowAdapterInternal> {
         None
@@ -186,13 +197,24 @@ fn create_popup(&self, _geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>>
         None
     }
 
+    /// Show the menu of a `ContextMenu` item with a native menu.
+    ///
+    /// `position` is the location of the menu in the window coordinate.
+    ///
+    /// If this function return false (the default implementation), then the
+    /// menu will be shown by the style in a popup rendered by Slint.
+    fn show_native_popup_menu(
+        &self,
+        _context_menu: Pin<&crate::items::ContextMenu>,
+        _position: crate::api::LogicalPosition,
+    ) -> bool {
+        false
+    }
+
     /// Set the mouse cursor
     // TODO: Make the enum public and make public
     fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
 
-    /// This method allow editable input field to communicate with the platform about input methods
-    fn input_method_request(&self, _: InputMethodRequest) {}
-
     /// Return self as any so the backend can upcast
     // TODO: consider using the as_any crate, or deriving the traint from Any to provide a better default
     fn as_any(&self) -> &dyn core::any::Any {
@@ -203,6 +225,15 @@ fn as_any(&self) -> &dyn core::any::Any {
     // used for accessibility
     fn handle_focus_change(&self, _old: Option<ItemRc>, _new: Option<ItemRc>) {}
 
+    /// Called by [`Window::dispatch_event()`](crate::api::Window::dispatch_event) before the event is processed
+    // used by the testing backend to record input
+    fn event_dispatched(&self, _event: &crate::platform::WindowEvent) {}
+
+    /// Called when the pointer leaves the window during a drag and drop operation started by a
+    /// `DragArea`, so that the backend can continue it as a drag and drop operation of the
+    /// windowing system.
+    fn drag_left_window(&self, _data: &crate::items::DropEvent) {}
+
     /// returns the color scheme used
     fn color_scheme(&self) -> ColorScheme {
         ColorScheme::Unknown
@@ -232,7 +263,7 @@ fn bring_to_front(&self) -> Result<(), PlatformError> {
     }
 }
 
-/// This is the parameter from [`WindowAdapterInternal::input_method_request()`] which lets the editable text input field
+/// This is the parameter from [`WindowAdapter::input_method_request()`] which lets the editable text input field
 /// communicate with the platform about input methods.
 #[non_exhaustive]
 #[derive(Debug, Clone)]
@@ -374,8 +405,9 @@ fn notify(self: Pin<&Self>) {
 
 /// This enum describes the different ways a popup can be rendered by the back-end.
 enum PopupWindowLocation {
-    /// The popup is rendered in its own top-level window that is know to the windowing system.
-    TopLevel(Rc<dyn WindowAdapter>),
+    /// The popup is rendered in its own top-level window that is know to the windowing system,
+    /// at the given position relative to this window.
+    TopLevel(Rc<dyn WindowAdapter>, LogicalPoint),
     /// The popup is rendered as an embedded child window at the given position.
     ChildWindow(LogicalPoint),
 }
@@ -383,6 +415,8 @@ enum PopupWindowLocation {
 /// This structure defines a graphical element that is designed to pop up from the surrounding
 /// UI content, for example to show a context menu.
 struct PopupWindow {
+    /// The id returned by [`WindowInner::show_popup`], to close the popup again.
+    popup_id: NonZeroU32,
     /// The location defines where the pop up is rendered.
     location: PopupWindowLocation,
     /// The component that is responsible for providing the popup content.
@@ -404,6 +438,15 @@ struct WindowPinnedFields {
     active: Property<bool>,
     #[pin]
     text_input_focused: Property<bool>,
+    #[pin]
+    text_input_type: Property<InputType>,
+}
+
+/// A `Shortcut` item of a window, see [`WindowInner::find_shortcut`]
+struct ShortcutEntry {
+    item: crate::item_tree::ItemWeak,
+    /// The `keys` of the item, and the key sequence parsed from them, or None if they aren't valid
+    parsed: RefCell<(SharedString, Option<KeySequence>)>,
 }
 
 /// Inner datastructure for the [`crate::api::Window`]
@@ -431,10 +474,21 @@ pub struct WindowInner {
     maximized: Cell<bool>,
     minimized: Cell<bool>,
 
-    active_popup: RefCell<Option<PopupWindow>>,
-    had_popup_on_press: Cell<bool>,
+    /// The popups that are currently shown, from the bottom-most to the top-most one.
+    /// A popup opened from an item of another popup is stacked on top of it.
+    popups: RefCell<Vec<PopupWindow>>,
+    next_popup_id: Cell<NonZeroU32>,
+    /// The `Shortcut` items of the popups and of the window, in the order in which they are looked up.
+    /// The tracker becomes dirty when the repeaters and conditional elements need to be updated.
+    shortcuts: RefCell<Option<(Pin<Box<PropertyTracker>>, Rc<[ShortcutEntry]>)>>,
+    /// The id of the top-most popup when the mouse was pressed. Popups with a greater id
+    /// were opened afterwards.
+    top_popup_id_on_press: Cell<Option<NonZeroU32>>,
     close_requested: Callback<(), CloseRequestResponse>,
     click_state: ClickState,
+    touch_state: RefCell<TouchState>,
+    /// The data of the drag and drop operation in progress, if any
+    drag_data: RefCell<Option<crate::items::DropEvent>>,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
 
@@ -482,6 +536,10 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
                     false,
                     "i_slint_core::Window::text_input_focused",
                 ),
+                text_input_type: Property::new_named(
+                    Default::default(),
+                    "i_slint_core::Window::text_input_type",
+                ),
             }),
             #[cfg(feature = "std")]
             fullscreen: Cell::new(std::env::var("SLINT_FULLSCREEN").is_ok()),
@@ -492,10 +550,14 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             focus_item: Default::default(),
             last_ime_text: Default::default(),
             cursor_blinker: Default::default(),
-            active_popup: Default::default(),
-            had_popup_on_press: Default::default(),
+            popups: Default::default(),
+            shortcuts: Default::default(),
+            next_popup_id: Cell::new(NonZeroU32::MIN),
+            top_popup_id_on_press: Default::default(),
             close_requested: Default::default(),
             click_state: ClickState::default(),
+            touch_state: Default::default(),
+            drag_data: Default::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
             // (for example in test_empty_window)
@@ -508,11 +570,12 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
     /// Associates this window with the specified component. Further event handling and rendering, etc. will be
     /// done with that component.
     pub fn set_component(&self, component: &ItemTreeRc) {
-        self.close_popup();
+        self.close_all_popups();
         self.focus_item.replace(Default::default());
         self.mouse_input_state.replace(Default::default());
         self.modifiers.replace(Default::default());
         self.component.replace(ItemTreeRc::downgrade(component));
+        self.invalidate_shortcuts();
         self.pinned_fields.window_properties_tracker.set_dirty(); // component changed, layout constraints for sure must be re-calculated
         let window_adapter = self.window_adapter();
         window_adapter.renderer().set_window_adapter(&window_adapter);
@@ -565,6 +628,7 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         let pressed_event = matches!(event, MouseEvent::Pressed { .. });
         let released_event = matches!(event, MouseEvent::Released { .. });
+        let exit_event = matches!(event, MouseEvent::Exit);
 
         let window_adapter = self.window_adapter();
         let mut mouse_input_state = self.mouse_input_state.take();
@@ -574,36 +638,47 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
                 crate::input::process_delayed_event(&window_adapter, mouse_input_state);
         }
 
+        let top_popup_id = self.popups.borrow().last().map(|popup| popup.popup_id);
         if pressed_event {
-            self.had_popup_on_press.set(self.active_popup.borrow().is_some());
+            self.top_popup_id_on_press.set(top_popup_id);
         }
 
-        let close_policy = self.close_policy();
-        let mut mouse_inside_popup = false;
+        // The id of the top-most embedded popup under the mouse
+        let mut mouse_inside_popup = None;
 
         mouse_input_state = if let Some(mut event) =
             crate::input::handle_mouse_grab(event, &window_adapter, &mut mouse_input_state)
         {
-            let (item_tree, offset) = if let Some(PopupWindow {
-                location: PopupWindowLocation::ChildWindow(coordinates),
-                component,
-                ..
-            }) = self.active_popup.borrow().as_ref()
+            // The drag may also have been started by the grabber while handling this event
+            if self.drag_data.borrow().is_some() {
+                event = event.into_drag_event();
+            }
+            let popups = self.popups.borrow();
+            let (item_tree, offset) = if popups
+                .iter()
+                .any(|popup| matches!(popup.location, PopupWindowLocation::ChildWindow(..)))
             {
-                let geom = ItemTreeRc::borrow_pin(component).as_ref().item_geometry(0);
+                let popup_under_mouse = popups.iter().rev().find_map(|popup| {
+                    let PopupWindowLocation::ChildWindow(coordinates) = popup.location else {
+                        return None;
+                    };
+                    let geom = ItemTreeRc::borrow_pin(&popup.component).as_ref().item_geometry(0);
+                    event
+                        .position()
+                        .map_or(true, |pos| geom.contains(pos - coordinates.to_vector()))
+                        .then_some((popup, coordinates))
+                });
 
-                mouse_inside_popup = event
-                    .position()
-                    .map_or(true, |pos| geom.contains(pos - coordinates.to_vector()));
+                mouse_inside_popup = popup_under_mouse.map(|(popup, _)| popup.popup_id);
 
-                if mouse_inside_popup {
-                    (Some(component.clone()), *coordinates)
-                } else {
-                    (None, LogicalPoint::default())
+                match popup_under_mouse {
+                    Some((popup, coordinates)) => (Some(popup.component.clone()), coordinates),
+                    None => (None, LogicalPoint::default()),
                 }
             } else {
                 (self.component.borrow().upgrade(), LogicalPoint::default())
             };
+            drop(popups);
 
             if let Some(item_tree) = item_tree {
                 event.translate(-offset.to_vector());
@@ -637,25 +712,100 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
 
         self.mouse_input_state.set(mouse_input_state);
 
-        match close_policy {
-            PopupClosePolicy::CloseOnClick => {
-                if (mouse_inside_popup && released_event && self.had_popup_on_press.get())
-                    || (!mouse_inside_popup && pressed_event)
-                {
-                    self.close_popup();
+        if released_event || exit_event {
+            // The data was dropped, or the drag left the window
+            if let Some(data) = self.drag_data.take() {
+                if exit_event {
+                    if let Some(internal) = window_adapter.internal(crate::InternalToken) {
+                        internal.drag_left_window(&data);
+                    }
                 }
             }
-            PopupClosePolicy::CloseOnClickOutside => {
-                if !mouse_inside_popup && pressed_event {
-                    self.close_popup();
+        }
+
+        // Find the bottom-most popup that needs to be closed, closing it also closes the ones
+        // stacked on top of it. A click in a popup counts as inside of the popups below it, as
+        // they're its parents. Popups opened while handling this event are left alone.
+        let popup_to_close = self
+            .popups
+            .borrow()
+            .iter()
+            .take_while(|popup| top_popup_id.is_some_and(|id| popup.popup_id <= id))
+            .find(|popup| {
+                let inside = mouse_inside_popup.is_some_and(|id| id >= popup.popup_id);
+                match popup.close_policy {
+                    PopupClosePolicy::CloseOnClick => {
+                        let had_popup_on_press =
+                            self.top_popup_id_on_press.get().is_some_and(|id| id >= popup.popup_id);
+                        (inside && released_event && had_popup_on_press)
+                            || (!inside && pressed_event)
+                    }
+                    PopupClosePolicy::CloseOnClickOutside => !inside && pressed_event,
+                    PopupClosePolicy::NoAutoClose => false,
                 }
+            })
+            .map(|popup| popup.popup_id);
+        if let Some(popup_id) = popup_to_close {
+            self.close_popup(popup_id);
+        }
+
+        crate::properties::ChangeTracker::run_change_handlers();
+    }
+
+    /// Receive a touch event and pass it to the items of the component.
+    ///
+    /// `touch_id` identifies the finger, `kind` is `Down` when it touches the screen, `Move`
+    /// when it moves, `Up` when it is lifted, and `Cancel` when the touch was cancelled.
+    /// The first finger is handled like the left mouse button, and the two first fingers
+    /// together produce [`MouseEvent::TouchGesture`] events.
+    pub fn process_touch_input(
+        &self,
+        touch_id: u64,
+        position: LogicalPoint,
+        kind: crate::items::PointerEventKind,
+    ) {
+        let events = self.touch_state.borrow_mut().process(touch_id, position, kind);
+        for event in events {
+            if event == MouseEvent::Exit {
+                self.cancel_mouse_input();
+            } else {
+                self.process_mouse_input(event);
             }
-            PopupClosePolicy::NoAutoClose => {}
-        };
+        }
+    }
 
+    /// Sends an exit event to the items under the mouse, releasing the grab, and discards
+    /// any event the items delayed.
+    fn cancel_mouse_input(&self) {
+        crate::input::cancel_mouse_input(self.mouse_input_state.take(), &self.window_adapter());
+        self.click_state.reset();
+        self.drag_data.take();
         crate::properties::ChangeTracker::run_change_handlers();
     }
 
+    /// Starts a drag and drop operation with the given data. Until the pointer is released,
+    /// its moves are dispatched as [`MouseEvent::DragMove`] and the release as [`MouseEvent::Drop`].
+    ///
+    /// This is called by the `DragArea` element, or by the backends when data is dragged into the
+    /// window from another application.
+    pub fn start_drag(&self, mime_type: SharedString, data: SharedString) {
+        *self.drag_data.borrow_mut() =
+            Some(crate::items::DropEvent { mime_type, data, position: Default::default() });
+    }
+
+    /// Cancels the drag and drop operation in progress, if any.
+    pub fn cancel_drag(&self) {
+        if self.drag_data.take().is_some() {
+            self.process_mouse_input(MouseEvent::Exit);
+        }
+    }
+
+    /// Returns the data of the drag and drop operation in progress, if any.
+    /// The position of the returned event is not set.
+    pub fn drag_data(&self) -> Option<crate::items::DropEvent> {
+        self.drag_data.borrow().clone()
+    }
+
     /// Called by the input code's internal timer to send an event that was delayed
     pub(crate) fn process_delayed_event(&self) {
         self.mouse_input_state.set(crate::input::process_delayed_event(
@@ -682,6 +832,15 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
 
         event.modifiers = self.modifiers.get().into();
 
+        if let Some(shortcut) = self.find_shortcut(&event) {
+            crate::items::Shortcut::FIELD_OFFSETS
+                .activated
+                .apply_pin(shortcut.as_pin_ref())
+                .call(&());
+            crate::properties::ChangeTracker::run_change_handlers();
+            return;
+        }
+
         let mut item = self.focus_item.borrow().clone().upgrade();
         while let Some(focus_item) = item {
             if !focus_item.is_visible() {
@@ -713,10 +872,84 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
             && !extra_mod
         {
             self.focus_previous_item();
+        } else if event.event_type == KeyEventType::KeyPressed
+            && !extra_mod
+            && !event.modifiers.shift
+        {
+            // Make the arrow keys move the focus within a FocusGroup
+            self.move_focus_in_group(&event.text);
         }
         crate::properties::ChangeTracker::run_change_handlers();
     }
 
+    /// Returns the first enabled and visible `Shortcut` item that matches the key event.
+    /// The shortcuts of the popups take precedence over the ones of the window, starting with the top-most popup.
+    fn find_shortcut(
+        &self,
+        event: &KeyEvent,
+    ) -> Option<VRcMapped<ItemTreeVTable, crate::items::Shortcut>> {
+        if event.event_type != KeyEventType::KeyPressed {
+            return None;
+        }
+        // Not borrowed while evaluating the properties, which may instantiate item trees
+        let shortcuts = self
+            .shortcuts
+            .borrow()
+            .as_ref()
+            .filter(|(tracker, _)| !tracker.is_dirty())
+            .map(|(_, shortcuts)| shortcuts.clone());
+        let shortcuts = shortcuts.unwrap_or_else(|| {
+            let tracker = Box::pin(PropertyTracker::default());
+            let shortcuts =
+                tracker.as_ref().evaluate_as_dependency_root(|| self.collect_shortcuts());
+            *self.shortcuts.borrow_mut() = Some((tracker, shortcuts.clone()));
+            shortcuts
+        });
+        shortcuts.iter().find_map(|entry| {
+            let item_rc = entry.item.upgrade()?;
+            let shortcut = item_rc.downcast::<crate::items::Shortcut>()?;
+            let keys = shortcut.as_pin_ref().keys();
+            let sequence = {
+                let mut parsed = entry.parsed.borrow_mut();
+                if parsed.0 != keys {
+                    *parsed = (keys.clone(), KeySequence::parse(&keys).ok());
+                }
+                parsed.1?
+            };
+            (shortcut.as_pin_ref().matches(event, &sequence) && item_rc.is_visible())
+                .then_some(shortcut)
+        })
+    }
+
+    /// Visits the item trees of the popups and of the window for their `Shortcut` items.
+    fn collect_shortcuts(&self) -> Rc<[ShortcutEntry]> {
+        let popup_components =
+            self.popups.borrow().iter().rev().map(|p| p.component.clone()).collect::<Vec<_>>();
+        let mut shortcuts = Vec::new();
+        for component in popup_components.into_iter().chain(self.try_component()) {
+            crate::item_tree::visit_items(
+                &component,
+                crate::item_tree::TraversalOrder::BackToFront,
+                |item_tree, item, index, _| {
+                    if ItemRef::downcast_pin::<crate::items::Shortcut>(item).is_some() {
+                        shortcuts.push(ShortcutEntry {
+                            item: ItemRc::new(item_tree.clone(), index).downgrade(),
+                            parsed: Default::default(),
+                        });
+                    }
+                    ItemVisitorResult::Continue(())
+                },
+                (),
+            );
+        }
+        shortcuts.into()
+    }
+
+    /// Called when the popups or the component of the window change, as they may contain `Shortcut` items.
+    fn invalidate_shortcuts(&self) {
+        self.shortcuts.take();
+    }
+
     /// Installs a binding on the specified property that's toggled whenever the text cursor is supposed to be visible or not.
     pub fn set_cursor_blink_binding(&self, prop: &crate::Property<bool>) {
         let existing_blinker = self.cursor_blinker.borrow().clone();
@@ -733,7 +966,12 @@ pub fn set_cursor_blink_binding(&self, prop: &crate::Property<bool>) {
 
     /// Sets the focus to the item pointed to by item_ptr. This will remove the focus from any
     /// currently focused item. If set_focus is false, the focus is cleared.
-    pub fn set_focus_item(&self, new_focus_item: &ItemRc, set_focus: bool) {
+    pub fn set_focus_item(
+        &self,
+        new_focus_item: &ItemRc,
+        set_focus: bool,
+        reason: crate::input::FocusReason,
+    ) {
         if self.prevent_focus_change.get() {
             return;
         }
@@ -752,8 +990,11 @@ pub fn set_focus_item(&self, new_focus_item: &ItemRc, set_focus: bool) {
         }
 
         let old = self.take_focus_item();
-        let new =
-            if set_focus { self.move_focus(new_focus_item.clone(), next_focus_item) } else { None };
+        let new = if set_focus {
+            self.move_focus(new_focus_item.clone(), next_focus_item, reason)
+        } else {
+            None
+        };
         let window_adapter = self.window_adapter();
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
             window_adapter.handle_focus_change(old, new);
@@ -781,12 +1022,16 @@ fn take_focus_item(&self) -> Option<ItemRc> {
     /// Publish the new focus_item to this Window and return the FocusEventResult
     ///
     /// This sends a FocusIn event!
-    fn publish_focus_item(&self, item: &Option<ItemRc>) -> crate::input::FocusEventResult {
+    fn publish_focus_item(
+        &self,
+        item: &Option<ItemRc>,
+        reason: crate::input::FocusReason,
+    ) -> crate::input::FocusEventResult {
         match item {
             Some(item) => {
                 *self.focus_item.borrow_mut() = item.downgrade();
                 item.borrow().as_ref().focus_event(
-                    &crate::input::FocusEvent::FocusIn,
+                    &crate::input::FocusEvent::FocusIn(reason),
                     &self.window_adapter(),
                     item,
                 )
@@ -798,13 +1043,18 @@ fn publish_focus_item(&self, item: &Option<ItemRc>) -> crate::input::FocusEventR
         }
     }
 
-    fn move_focus(&self, start_item: ItemRc, forward: impl Fn(ItemRc) -> ItemRc) -> Option<ItemRc> {
+    fn move_focus(
+        &self,
+        start_item: ItemRc,
+        forward: impl Fn(ItemRc) -> ItemRc,
+        reason: crate::input::FocusReason,
+    ) -> Option<ItemRc> {
         let mut current_item = start_item;
         let mut visited = alloc::vec::Vec::new();
 
         loop {
             if current_item.is_visible()
-                && self.publish_focus_item(&Some(current_item.clone()))
+                && self.publish_focus_item(&Some(current_item.clone()), reason)
                     == crate::input::FocusEventResult::FocusAccepted
             {
                 return Some(current_item); // Item was just published.
@@ -818,30 +1068,80 @@ fn move_focus(&self, start_item: ItemRc, forward: impl Fn(ItemRc) -> ItemRc) ->
         }
     }
 
-    /// Move keyboard focus to the next item
-    pub fn focus_next_item(&self) {
-        let component = self.component();
-        let start_item = self
-            .take_focus_item()
-            .map(next_focus_item)
-            .unwrap_or_else(|| ItemRc::new(component, 0));
-        let end_item = self.move_focus(start_item.clone(), next_focus_item);
+    /// Returns the item that contains the items reached with Tab and Backtab: the closest
+    /// `FocusGroup` with `trap-focus` around the focus item, otherwise the top-most popup, or the window.
+    fn focus_chain_root(&self) -> ItemRc {
+        let focus_item = self.focus_item.borrow().upgrade();
+        if let Some(group) = focus_item
+            .and_then(|item| crate::item_focus::find_focus_group(&item, |group| group.trap_focus()))
+        {
+            return group;
+        }
+        let popup_component = self
+            .popups
+            .borrow()
+            .iter()
+            .rev()
+            .find(|popup| matches!(popup.location, PopupWindowLocation::ChildWindow(_)))
+            .map(|popup| popup.component.clone());
+        ItemRc::new(popup_component.unwrap_or_else(|| self.component()), 0)
+    }
+
+    /// Moves the keyboard focus to the next item of the focus chain of `root` that accepts the
+    /// focus, or the previous one if `forward` is false.
+    fn move_focus_in_chain(&self, root: ItemRc, forward: bool) {
+        let mut chain = crate::item_focus::focus_chain(&root);
+        if !forward {
+            chain.reverse();
+        }
+        let old_item = self.take_focus_item();
+        let start = old_item
+            .as_ref()
+            .and_then(|old_item| chain.iter().position(|item| item == old_item))
+            .map_or(0, |index| index + 1);
+        let new_item = chain[start..]
+            .iter()
+            .chain(&chain[..start])
+            .find(|item| {
+                item.is_visible()
+                    && self.publish_focus_item(
+                        &Some((*item).clone()),
+                        crate::input::FocusReason::KeyboardNavigation,
+                    ) == crate::input::FocusEventResult::FocusAccepted
+            })
+            .cloned();
+        if new_item.is_none() {
+            self.publish_focus_item(&None, crate::input::FocusReason::KeyboardNavigation);
+        }
         let window_adapter = self.window_adapter();
         if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
-            window_adapter.handle_focus_change(Some(start_item), end_item);
+            window_adapter.handle_focus_change(old_item, new_item);
         }
     }
 
+    /// Move keyboard focus to the next item
+    pub fn focus_next_item(&self) {
+        self.move_focus_in_chain(self.focus_chain_root(), true);
+    }
+
     /// Move keyboard focus to the previous item.
     pub fn focus_previous_item(&self) {
-        let component = self.component();
-        let start_item = previous_focus_item(
-            self.take_focus_item().unwrap_or_else(|| ItemRc::new(component, 0)),
-        );
-        let end_item = self.move_focus(start_item.clone(), previous_focus_item);
-        let window_adapter = self.window_adapter();
-        if let Some(window_adapter) = window_adapter.internal(crate::InternalToken) {
-            window_adapter.handle_focus_change(Some(start_item), end_item);
+        self.move_focus_in_chain(self.focus_chain_root(), false);
+    }
+
+    /// Moves the focus within the closest `FocusGroup` with `arrow-key-navigation` around the
+    /// focus item, when `text` is an arrow key.
+    fn move_focus_in_group(&self, text: &str) {
+        let forward = match text.chars().next() {
+            Some(key_codes::RightArrow | key_codes::DownArrow) => true,
+            Some(key_codes::LeftArrow | key_codes::UpArrow) => false,
+            _ => return,
+        };
+        let group = self.focus_item.borrow().upgrade().and_then(|item| {
+            crate::item_focus::find_focus_group(&item, |group| group.arrow_key_navigation())
+        });
+        if let Some(group) = group {
+            self.move_focus_in_chain(group, forward);
         }
     }
 
@@ -902,22 +1202,26 @@ pub fn draw_contents<T>(
         let draw_fn = || {
             let component_rc = self.try_component()?;
 
-            let popup_component =
-                self.active_popup.borrow().as_ref().and_then(|popup| match popup.location {
+            let popup_components = self
+                .popups
+                .borrow()
+                .iter()
+                .filter_map(|popup| match popup.location {
                     PopupWindowLocation::TopLevel(..) => None,
                     PopupWindowLocation::ChildWindow(coordinates) => {
                         Some((popup.component.clone(), coordinates))
                     }
-                });
-
-            Some(if let Some((popup_component, popup_coordinates)) = popup_component {
-                render_components(&[
-                    (&component_rc, LogicalPoint::default()),
-                    (&popup_component, popup_coordinates),
-                ])
-            } else {
-                render_components(&[(&component_rc, LogicalPoint::default())])
-            })
+                })
+                .collect::<Vec<_>>();
+
+            let components = core::iter::once((&component_rc, LogicalPoint::default()))
+                .chain(
+                    popup_components
+                        .iter()
+                        .map(|(component, coordinates)| (component, *coordinates)),
+                )
+                .collect::<Vec<_>>();
+            Some(render_components(&components))
         };
 
         self.pinned_fields
@@ -972,17 +1276,52 @@ pub fn color_scheme(&self) -> ColorScheme {
             .map_or(ColorScheme::Unknown, |x| x.color_scheme())
     }
 
-    /// Show a popup at the given position relative to the item
+    /// Returns the index in the popup stack of the popup that contains the item, or None if the
+    /// item isn't in a popup.
+    fn popup_index_of_item(&self, item: &ItemRc) -> Option<usize> {
+        let mut root = item.clone();
+        while let Some(parent) = root.parent_item() {
+            root = parent;
+        }
+        self.popups
+            .borrow()
+            .iter()
+            .position(|popup| vtable::VRc::ptr_eq(&popup.component, root.item_tree()))
+    }
+
+    /// Show a popup at the given position relative to the item and returns its id, to be used with
+    /// [`Self::close_popup`].
+    ///
+    /// When the item is in another popup, the new popup is stacked on top of it and the popups that
+    /// were previously opened from that one are closed. Otherwise, all the popups are closed first.
     pub fn show_popup(
         &self,
         popup_componentrc: &ItemTreeRc,
         position: Point,
         close_policy: PopupClosePolicy,
         parent_item: &ItemRc,
-    ) {
+    ) -> NonZeroU32 {
+        let parent_popup_index = self.popup_index_of_item(parent_item);
+        let popup_to_close = {
+            let popups = self.popups.borrow();
+            popups.get(parent_popup_index.map_or(0, |index| index + 1)).map(|p| p.popup_id)
+        };
+        if let Some(popup_id) = popup_to_close {
+            self.close_popup(popup_id);
+        }
+
+        // Items of a popup are positioned relative to the popup
+        let parent_popup_origin = parent_popup_index
+            .and_then(|index| {
+                self.popups.borrow().get(index).map(|popup| match popup.location {
+                    PopupWindowLocation::TopLevel(_, origin) => origin,
+                    PopupWindowLocation::ChildWindow(origin) => origin,
+                })
+            })
+            .unwrap_or_default();
         let position = parent_item.map_to_window(
             parent_item.geometry().origin + LogicalPoint::from_untyped(position).to_vector(),
-        );
+        ) + parent_popup_origin.to_vector();
         let popup_component = ItemTreeRc::borrow_pin(popup_componentrc);
         let popup_root = popup_component.as_ref().get_item_ref(0);
 
@@ -1038,21 +1377,35 @@ pub fn show_popup(
             }
             Some(window_adapter) => {
                 WindowInner::from_pub(window_adapter.window()).set_component(popup_componentrc);
-                PopupWindowLocation::TopLevel(window_adapter)
+                PopupWindowLocation::TopLevel(window_adapter, position)
             }
         };
 
-        self.active_popup.replace(Some(PopupWindow {
+        let popup_id = self.next_popup_id.get();
+        self.next_popup_id.set(popup_id.checked_add(1).unwrap_or(NonZeroU32::MIN));
+        self.popups.borrow_mut().push(PopupWindow {
+            popup_id,
             location,
             component: popup_componentrc.clone(),
             close_policy,
-        }));
+        });
+        self.invalidate_shortcuts();
+        popup_id
     }
 
-    /// Removes any active popup.
-    /// TODO: this function should take a component ref as parameter, to close a specific popup - i.e. when popup menus create a hierarchy of popups.
-    pub fn close_popup(&self) {
-        if let Some(current_popup) = self.active_popup.replace(None) {
+    /// Closes the popup with the given id, as returned by [`Self::show_popup`], together with the
+    /// popups stacked on top of it. Does nothing if the popup was already closed.
+    pub fn close_popup(&self, popup_id: NonZeroU32) {
+        let closed_popups = {
+            let mut popups = self.popups.borrow_mut();
+            let Some(index) = popups.iter().position(|popup| popup.popup_id == popup_id) else {
+                return;
+            };
+            popups.drain(index..).collect::<Vec<_>>()
+        };
+        self.invalidate_shortcuts();
+        // Close from the top-most popup down
+        for current_popup in closed_popups.into_iter().rev() {
             match current_popup.location {
                 PopupWindowLocation::ChildWindow(offset) => {
                     // Refresh the area that was previously covered by the popup.
@@ -1068,18 +1421,34 @@ pub fn close_popup(&self) {
                         window_adapter.request_redraw();
                     }
                 }
-                PopupWindowLocation::TopLevel(adapter) => {
+                PopupWindowLocation::TopLevel(adapter, _) => {
                     let _ = adapter.set_visible(false);
                 }
             }
         }
     }
 
-    /// Returns the close policy of the active popup. PopupClosePolicy::NoAutoClose if there is no active popup.
+    /// Closes the top-most popup, if any.
+    pub fn close_top_popup(&self) {
+        let popup_id = self.popups.borrow().last().map(|popup| popup.popup_id);
+        if let Some(popup_id) = popup_id {
+            self.close_popup(popup_id);
+        }
+    }
+
+    /// Closes all the popups of this window.
+    pub fn close_all_popups(&self) {
+        let popup_id = self.popups.borrow().first().map(|popup| popup.popup_id);
+        if let Some(popup_id) = popup_id {
+            self.close_popup(popup_id);
+        }
+    }
+
+    /// Returns the close policy of the top-most popup. PopupClosePolicy::NoAutoClose if there is no popup.
     pub fn close_policy(&self) -> PopupClosePolicy {
-        self.active_popup
+        self.popups
             .borrow()
-            .as_ref()
+            .last()
             .map_or(PopupClosePolicy::NoAutoClose, |popup| popup.close_policy)
     }
 
@@ -1103,6 +1472,16 @@ pub fn set_text_input_focused(&self, value: bool) {
         self.pinned_fields.text_input_focused.set(value)
     }
 
+    /// Reads the global property `TextInputInterface.input-type`
+    pub fn text_input_type(&self) -> InputType {
+        self.pinned_fields.as_ref().project_ref().text_input_type.get()
+    }
+
+    /// Sets the global property `TextInputInterface.input-type`, when a text input gains the focus
+    pub fn set_text_input_type(&self, value: InputType) {
+        self.pinned_fields.text_input_type.set(value)
+    }
+
     /// Returns true if the window is visible
     pub fn is_visible(&self) -> bool {
         self.strong_component_ref.borrow().is_some()
@@ -1314,6 +1693,15 @@ pub enum GraphicsAPI {
         WindowInner::from_pub(window_adapter.window()).set_text_input_focused(value)
     }
 
+    /// Returns the input type of the text input that has the focus.
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_get_text_input_type(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> InputType {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).text_input_type()
+    }
+
     /// Sets the focus item.
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_set_focus_item(
@@ -1322,7 +1710,11 @@ pub enum GraphicsAPI {
         set_focus: bool,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).set_focus_item(focus_item, set_focus)
+        WindowInner::from_pub(window_adapter.window()).set_focus_item(
+            focus_item,
+            set_focus,
+            crate::input::FocusReason::Programmatic,
+        )
     }
 
     /// Associates the window with the given component.
@@ -1343,20 +1735,22 @@ pub enum GraphicsAPI {
         position: crate::graphics::Point,
         close_policy: PopupClosePolicy,
         parent_item: &ItemRc,
-    ) {
+    ) -> u32 {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).show_popup(
-            popup,
-            position,
-            close_policy,
-            parent_item,
-        );
+        WindowInner::from_pub(window_adapter.window())
+            .show_popup(popup, position, close_policy, parent_item)
+            .get()
     }
-    /// Close the current popup
+    /// Close the popup with the given id, as returned by slint_windowrc_show_popup
     #[no_mangle]
-    pub unsafe extern "C" fn slint_windowrc_close_popup(handle: *const WindowAdapterRcOpaque) {
+    pub unsafe extern "C" fn slint_windowrc_close_popup(
+        handle: *const WindowAdapterRcOpaque,
+        popup_id: u32,
+    ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
-        WindowInner::from_pub(window_adapter.window()).close_popup();
+        if let Some(popup_id) = NonZeroU32::new(popup_id) {
+            WindowInner::from_pub(window_adapter.window()).close_popup(popup_id);
+        }
     }
 
     /// C binding to the set_rendering_notifier() API of Window
@@ -1553,6 +1947,47 @@ fn call(&self) -> CloseRequestResponse {
         });
     }
 
+    /// Dispatch an input method pre-edit event
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_dispatch_ime_preedit_event(
+        handle: *const WindowAdapterRcOpaque,
+        text: &SharedString,
+        selection_start: usize,
+        selection_end: usize,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().dispatch_event(crate::platform::WindowEvent::ImePreedit {
+            text: text.clone(),
+            selection_start,
+            selection_end,
+        });
+    }
+
+    /// Dispatch an input method commit event
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_dispatch_ime_commit_event(
+        handle: *const WindowAdapterRcOpaque,
+        text: &SharedString,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter
+            .window()
+            .dispatch_event(crate::platform::WindowEvent::ImeCommit { text: text.clone() });
+    }
+
+    /// Dispatch an input method event that deletes text around the cursor
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_dispatch_ime_delete_surrounding_event(
+        handle: *const WindowAdapterRcOpaque,
+        before_length: usize,
+        after_length: usize,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().dispatch_event(
+            crate::platform::WindowEvent::ImeDeleteSurrounding { before_length, after_length },
+        );
+    }
+
     /// Dispatch a mouse event
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_dispatch_pointer_event(
@@ -1615,6 +2050,17 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().set_minimized(value)
     }
 
+    /// Renders the window contents as a SVG document, see [`crate::api::Window::export_svg`].
+    #[cfg(feature = "std")]
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_export_svg(
+        handle: *const WindowAdapterRcOpaque,
+        out: &mut SharedString,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        *out = window_adapter.window().export_svg().into();
+    }
+
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_set_maximized(
         handle: *const WindowAdapterRcOpaque,
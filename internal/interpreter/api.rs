@@ -7,7 +7,7 @@
 use i_slint_core::component_factory::FactoryContext;
 use i_slint_core::graphics::euclid::approxeq::ApproxEq as _;
 use i_slint_core::model::{Model, ModelRc};
-#[cfg(feature = "internal")]
+#[cfg(any(feature = "internal", feature = "live-reload"))]
 use i_slint_core::window::WindowInner;
 use i_slint_core::{PathData, SharedVector};
 use std::borrow::Cow;
@@ -27,7 +27,7 @@
 use i_slint_core::items::*;
 
 use crate::dynamic_item_tree::ErasedItemTreeBox;
-#[cfg(any(feature = "internal", target_arch = "wasm32"))]
+#[cfg(any(feature = "internal", feature = "live-reload", target_arch = "wasm32"))]
 use crate::dynamic_item_tree::WindowOptions;
 
 /// This enum represents the different public variants of the [`Value`] enum, without
@@ -652,7 +652,7 @@ pub async fn build_from_source(
 /// This is the entry point of the crate, it can be used to load a `.slint` file and
 /// compile it into a [`CompilationResult`].
 pub struct Compiler {
-    config: i_slint_compiler::CompilerConfiguration,
+    pub(crate) config: i_slint_compiler::CompilerConfiguration,
 }
 
 impl Default for Compiler {
@@ -951,6 +951,22 @@ pub fn create_with_existing_window(
         })
     }
 
+    /// Instantiate the component in the window of `previous`, which replaces the previous instance
+    /// in that window, or in a new window if `previous` is None.
+    #[cfg(feature = "live-reload")]
+    pub(crate) fn create_in_window_of(
+        &self,
+        previous: Option<&ComponentInstance>,
+    ) -> Result<ComponentInstance, PlatformError> {
+        let Some(previous) = previous else { return self.create() };
+        generativity::make_guard!(guard);
+        Ok(ComponentInstance {
+            inner: self.inner.unerase(guard).clone().create(WindowOptions::UseExistingWindow(
+                WindowInner::from_pub(previous.window()).window_adapter(),
+            ))?,
+        })
+    }
+
     /// List of publicly declared properties or callback.
     ///
     /// This is internal because it exposes the `Type` from compilerlib.
@@ -980,6 +996,19 @@ pub fn properties(&self) -> impl Iterator<Item = (String, ValueType)> + '_ {
         })
     }
 
+    /// Returns the names of the public properties that can be set, that is all the properties but
+    /// the `out` ones, of the component or of the exported global singleton `global_name`.
+    #[cfg(feature = "live-reload")]
+    pub(crate) fn settable_properties(&self, global_name: Option<&str>) -> Vec<String> {
+        generativity::make_guard!(guard);
+        self.inner
+            .unerase(guard)
+            .settable_properties(global_name)
+            .filter(|(_, prop_type)| prop_type.is_property_type())
+            .map(|(prop_name, _)| prop_name.to_string())
+            .collect()
+    }
+
     /// Returns the names of all publicly declared callbacks.
     pub fn callbacks(&self) -> impl Iterator<Item = String> + '_ {
         // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
@@ -4,6 +4,7 @@
 use crate::api::{CompilationResult, ComponentDefinition, Value};
 use crate::global_component::CompiledGlobalCollection;
 use crate::{dynamic_type, eval};
+use core::num::NonZeroU32;
 use core::ptr::NonNull;
 use dynamic_type::{Instance, InstanceBox};
 use i_slint_compiler::expression_tree::{Expression, NamedReference};
@@ -302,6 +303,8 @@ pub(crate) struct ComponentExtraData {
     pub(crate) globals: OnceCell<crate::global_component::GlobalStorage>,
     pub(crate) self_weak: OnceCell<ErasedItemTreeBoxWeak>,
     pub(crate) embedding_position: OnceCell<(ItemTreeWeak, u32)>,
+    /// The ids of the shown popups declared in this component, by index in its `popup_windows`
+    pub(crate) popup_ids: core::cell::RefCell<HashMap<usize, NonZeroU32>>,
     #[cfg(target_arch = "wasm32")]
     pub(crate) canvas_id: OnceCell<String>,
 }
@@ -472,6 +475,34 @@ pub fn properties(
         internal_properties_to_public(self.public_properties.iter())
     }
 
+    /// The public properties that are not `out` properties, and the ones of the exported global
+    /// singleton `global_name` if it is set
+    #[cfg(feature = "live-reload")]
+    pub fn settable_properties(
+        &self,
+        global_name: Option<&str>,
+    ) -> impl Iterator<Item = (SmolStr, i_slint_compiler::langtype::Type)> + '_ {
+        let properties: Box<dyn Iterator<Item = (&SmolStr, &PropertyDeclaration)>> =
+            match global_name {
+                None => Box::new(self.public_properties.iter()),
+                Some(name) => {
+                    let g =
+                        self.compiled_globals.as_ref().expect("Root component should have globals");
+                    match g
+                        .exported_globals_by_name
+                        .get(crate::normalize_identifier(name).as_ref())
+                        .and_then(|global_idx| g.compiled_globals.get(*global_idx))
+                    {
+                        Some(global) => Box::new(global.public_properties()),
+                        None => Box::new(core::iter::empty()),
+                    }
+                }
+            };
+        internal_properties_to_public(
+            properties.filter(|(_, v)| v.visibility != object_tree::PropertyVisibility::Output),
+        )
+    }
+
     /// List names of exported global singletons
     pub fn global_names(&self) -> impl Iterator<Item = SmolStr> + '_ {
         self.compiled_globals
@@ -948,12 +979,22 @@ fn generate_rtti() -> HashMap<&'static str, Rc<ItemRTTI>> {
             rtti_for::<ClippedImage>(),
             rtti_for::<ComplexText>(),
             rtti_for::<SimpleText>(),
+            rtti_for::<StyledText>(),
             rtti_for::<Rectangle>(),
             rtti_for::<BasicBorderRectangle>(),
             rtti_for::<BorderRectangle>(),
             rtti_for::<TouchArea>(),
             rtti_for::<FocusScope>(),
+            rtti_for::<FocusGroup>(),
+            rtti_for::<VirtualKeyboardKey>(),
             rtti_for::<SwipeGestureHandler>(),
+            rtti_for::<PinchGestureHandler>(),
+            rtti_for::<RotateGestureHandler>(),
+            rtti_for::<TwoFingerPanGestureHandler>(),
+            rtti_for::<DragArea>(),
+            rtti_for::<DropArea>(),
+            rtti_for::<Shortcut>(),
+            rtti_for::<ContextMenu>(),
             rtti_for::<Path>(),
             rtti_for::<Flickable>(),
             rtti_for::<WindowItem>(),
@@ -1538,6 +1579,33 @@ pub fn instantiate(
         }
     }
 
+    #[cfg(feature = "binding-profiler")]
+    {
+        use i_slint_core::properties::profiler::set_name_of_property_at;
+        let component_name = &description.original.id;
+        // Safety: the properties are pinned in the instance, which is not moved anymore, and
+        // remove their name when they are dropped
+        for (prop_name, p) in &description.custom_properties {
+            unsafe {
+                let ptr = instance_ref.as_ptr().add(p.offset);
+                set_name_of_property_at(ptr, &format!("{component_name}::{prop_name}"));
+            }
+        }
+        for (elem_name, item) in &description.items {
+            let elem = item.elem.borrow();
+            let qualified_id = match elem.debug.first().and_then(|d| d.qualified_id.as_ref()) {
+                Some(id) => id.to_string(),
+                None => format!("{component_name}::{elem_name}"),
+            };
+            for (prop_name, prop_rtti) in &item.rtti.properties {
+                unsafe {
+                    let ptr = instance_ref.as_ptr().add(item.offset + prop_rtti.offset());
+                    set_name_of_property_at(ptr, &format!("{qualified_id}.{prop_name}"));
+                }
+            }
+        }
+    }
+
     generator::handle_property_bindings_init(
         &description.original,
         |elem, prop_name, binding| unsafe {
@@ -2334,7 +2402,7 @@ pub fn show_popup(
     parent_comp: ErasedItemTreeBoxWeak,
     parent_window_adapter: WindowAdapterRc,
     parent_item: &ItemRc,
-) {
+) -> NonZeroU32 {
     generativity::make_guard!(guard);
     // FIXME: we should compile once and keep the cached compiled component
     let compiled = generate_item_tree(&popup.component, None, guard);
@@ -2358,7 +2426,7 @@ pub fn show_popup(
         pos,
         close_policy,
         parent_item,
-    );
+    )
 }
 
 pub fn update_timers(instance: InstanceRef) {
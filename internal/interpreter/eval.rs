@@ -4,13 +4,15 @@
 use crate::api::{SetPropertyError, Struct, Value};
 use crate::dynamic_item_tree::InstanceRef;
 use core::pin::Pin;
-use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
+use corelib::graphics::{
+    ConicGradientBrush, GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush,
+};
 use corelib::items::{ColorScheme, ItemRef, PropertyAnimation};
 use corelib::model::{Model, ModelExt, ModelRc, VecModel};
 use corelib::rtti::AnimatedBindingKind;
 use corelib::{Brush, Color, PathData, SharedString, SharedVector};
 use i_slint_compiler::expression_tree::{
-    BuiltinFunction, EasingCurve, Expression, MinMaxOp, Path as ExprPath,
+    BuiltinFunction, EasingCurve, Expression, GradientSpread, MinMaxOp, Path as ExprPath,
     PathElement as ExprPathElement,
 };
 use i_slint_compiler::langtype::Type;
@@ -206,6 +208,37 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             }
             v
         }
+        Expression::ForLoop { model_data, index, model, body } => {
+            let iterate = |data: Value, row: usize, local_context: &mut EvalLocalContext| {
+                local_context.local_variables.insert(model_data.clone(), data);
+                if let Some(index) = index {
+                    local_context.local_variables.insert(index.clone(), Value::Number(row as f64));
+                }
+                eval_expression(body, local_context);
+            };
+            match eval_expression(model, local_context) {
+                Value::Model(model) => {
+                    model.model_tracker().track_row_count_changes();
+                    for row in 0..model.row_count() {
+                        let data = model.row_data_tracked(row).unwrap_or_default();
+                        iterate(data, row, local_context);
+                    }
+                }
+                Value::Number(count) => {
+                    for row in 0..(count.max(0.) as usize) {
+                        iterate(Value::Number(row as f64), row, local_context);
+                    }
+                }
+                _ => {}
+            }
+            Value::Void
+        }
+        Expression::WhileLoop { condition, body } => {
+            while eval_expression(condition, local_context).try_into().unwrap_or(false) {
+                eval_expression(body, local_context);
+            }
+            Value::Void
+        }
         Expression::FunctionCall { function, arguments, source_location: _ } => match &**function {
             Expression::FunctionReference(nr, _) => {
                 let args = arguments.iter().map(|e| eval_expression(e, local_context)).collect::<Vec<_>>();
@@ -343,20 +376,33 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
         }),
-        Expression::LinearGradient{angle, stops} => {
+        Expression::LinearGradient{angle, stops, spread} => {
             let angle = eval_expression(angle, local_context);
             Value::Brush(Brush::LinearGradient(LinearGradientBrush::new(angle.try_into().unwrap(), stops.iter().map(|(color, stop)| {
                 let color = eval_expression(color, local_context).try_into().unwrap();
                 let position = eval_expression(stop, local_context).try_into().unwrap();
                 GradientStop{ color, position }
-            }))))
+            })).with_spread(convert_gradient_spread(*spread))))
         }
-        Expression::RadialGradient{stops} => {
+        Expression::RadialGradient{center_x, center_y, radius, stops, spread} => {
+            let center_x = eval_expression(center_x, local_context).try_into().unwrap();
+            let center_y = eval_expression(center_y, local_context).try_into().unwrap();
+            let radius = eval_expression(radius, local_context).try_into().unwrap();
             Value::Brush(Brush::RadialGradient(RadialGradientBrush::new_circle(stops.iter().map(|(color, stop)| {
                 let color = eval_expression(color, local_context).try_into().unwrap();
                 let position = eval_expression(stop, local_context).try_into().unwrap();
                 GradientStop{ color, position }
-            }))))
+            })).with_center(center_x, center_y).with_radius(radius).with_spread(convert_gradient_spread(*spread))))
+        }
+        Expression::ConicGradient{from_angle, center_x, center_y, stops, spread} => {
+            let from_angle = eval_expression(from_angle, local_context).try_into().unwrap();
+            let center_x = eval_expression(center_x, local_context).try_into().unwrap();
+            let center_y = eval_expression(center_y, local_context).try_into().unwrap();
+            Value::Brush(Brush::ConicGradient(ConicGradientBrush::new(from_angle, stops.iter().map(|(color, stop)| {
+                let color = eval_expression(color, local_context).try_into().unwrap();
+                let position = eval_expression(stop, local_context).try_into().unwrap();
+                GradientStop{ color, position }
+            })).with_center(center_x, center_y).with_spread(convert_gradient_spread(*spread))))
         }
         Expression::EnumerationValue(value) => {
             Value::EnumerationValue(value.enumeration.name.to_string(), value.to_string())
@@ -525,6 +571,7 @@ fn call_builtin_function(
                             item_info.item_index(),
                         ),
                         true,
+                        corelib::input::FocusReason::Programmatic,
                     )
                 });
                 Value::Void
@@ -562,6 +609,7 @@ fn call_builtin_function(
                             item_info.item_index(),
                         ),
                         false,
+                        corelib::input::FocusReason::Programmatic,
                     )
                 });
                 Value::Void
@@ -591,8 +639,11 @@ fn call_builtin_function(
                     .upgrade()
                     .unwrap();
                 let popup_list = parent_component.popup_windows.borrow();
-                let popup =
-                    popup_list.iter().find(|p| Rc::ptr_eq(&p.component, &pop_comp)).unwrap();
+                let (popup_index, popup) = popup_list
+                    .iter()
+                    .enumerate()
+                    .find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp))
+                    .unwrap();
 
                 generativity::make_guard!(guard);
                 let enclosing_component =
@@ -613,7 +664,7 @@ fn call_builtin_function(
                 .try_into()
                 .expect("Invalid internal enumeration representation for close policy");
 
-                crate::dynamic_item_tree::show_popup(
+                let popup_id = crate::dynamic_item_tree::show_popup(
                     popup,
                     |instance_ref| {
                         let comp = ComponentInstance::InstanceRef(instance_ref);
@@ -631,22 +682,63 @@ fn call_builtin_function(
                     component.window_adapter(),
                     &parent_item,
                 );
+                enclosing_component
+                    .description
+                    .extra_data_offset
+                    .apply(enclosing_component.as_ref())
+                    .popup_ids
+                    .borrow_mut()
+                    .insert(popup_index, popup_id);
                 Value::Void
             } else {
                 panic!("internal error: argument to SetFocusItem must be an element")
             }
         }
         BuiltinFunction::ClosePopupWindow => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to ClosePopupWindow")
+            }
             let component = match local_context.component_instance {
                 ComponentInstance::InstanceRef(c) => c,
                 ComponentInstance::GlobalComponent(_) => {
-                    panic!("Cannot show popup from a global component")
+                    panic!("Cannot close popup from a global component")
                 }
             };
+            if let Expression::ElementReference(popup_window) = &arguments[0] {
+                let popup_window = popup_window.upgrade().unwrap();
+                let pop_comp = popup_window.borrow().enclosing_component.upgrade().unwrap();
+                let parent_component = pop_comp
+                    .parent_element
+                    .upgrade()
+                    .unwrap()
+                    .borrow()
+                    .enclosing_component
+                    .upgrade()
+                    .unwrap();
+                let popup_list = parent_component.popup_windows.borrow();
+                let (popup_index, popup) = popup_list
+                    .iter()
+                    .enumerate()
+                    .find(|(_, p)| Rc::ptr_eq(&p.component, &pop_comp))
+                    .unwrap();
 
-            component.access_window(|window| window.close_popup());
-
-            Value::Void
+                generativity::make_guard!(guard);
+                let enclosing_component =
+                    enclosing_component_for_element(&popup.parent_element, component, guard);
+                let popup_id = enclosing_component
+                    .description
+                    .extra_data_offset
+                    .apply(enclosing_component.as_ref())
+                    .popup_ids
+                    .borrow_mut()
+                    .remove(&popup_index);
+                if let Some(popup_id) = popup_id {
+                    component.access_window(|window| window.close_popup(popup_id));
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to ClosePopupWindow must be an element")
+            }
         }
         BuiltinFunction::SetSelectionOffsets => {
             if arguments.len() != 3 {
@@ -748,6 +840,15 @@ fn call_builtin_function(
                         "cancel" => s.cancel(&window_adapter, &item_rc),
                         _ => panic!("internal: Unknown member function {name} called on SwipeGestureHandler"),
                     }
+                } else if let Some(s) =
+                    ItemRef::downcast_pin::<corelib::items::ContextMenu>(item_ref)
+                {
+                    match &*name {
+                        "open-menu" => s.open_menu(&window_adapter, &item_rc),
+                        _ => {
+                            panic!("internal: Unknown member function {name} called on ContextMenu")
+                        }
+                    }
                 } else {
                     panic!(
                         "internal error: member function {name} called on element that doesn't have it: {}",
@@ -1044,6 +1145,14 @@ fn call_builtin_function(
                 panic!("Cannot get the window from a global component")
             }
         },
+        BuiltinFunction::TextInputType => match local_context.component_instance {
+            ComponentInstance::InstanceRef(component) => {
+                component.access_window(|window| window.text_input_type()).into()
+            }
+            ComponentInstance::GlobalComponent(_) => {
+                panic!("Cannot get the window from a global component")
+            }
+        },
         BuiltinFunction::SetTextInputFocused => match local_context.component_instance {
             ComponentInstance::InstanceRef(component) => {
                 component.access_window(|window| {
@@ -1599,6 +1708,14 @@ pub fn new_struct_with_bindings<ElementType: 'static + Default + corelib::rtti::
     element
 }
 
+fn convert_gradient_spread(spread: GradientSpread) -> corelib::graphics::GradientSpread {
+    match spread {
+        GradientSpread::Pad => corelib::graphics::GradientSpread::Pad,
+        GradientSpread::Repeat => corelib::graphics::GradientSpread::Repeat,
+        GradientSpread::Reflect => corelib::graphics::GradientSpread::Reflect,
+    }
+}
+
 fn convert_from_lyon_path<'a>(
     events_it: impl IntoIterator<Item = &'a i_slint_compiler::expression_tree::Expression>,
     points_it: impl IntoIterator<Item = &'a i_slint_compiler::expression_tree::Expression>,
@@ -51,8 +51,14 @@ pub(crate) fn compute_layout_info(
             core_layout::grid_layout_info(Slice::from(cells.as_slice()), spacing, &padding).into()
         }
         Layout::BoxLayout(box_layout) => {
-            let (cells, alignment) =
-                box_layout_data(box_layout, orientation, component, &expr_eval, None);
+            let (cells, alignment) = box_layout_data(
+                &box_layout.elems,
+                box_layout.geometry.alignment.as_ref(),
+                orientation,
+                component,
+                &expr_eval,
+                None,
+            );
             let (padding, spacing) =
                 padding_and_spacing(&box_layout.geometry, orientation, &expr_eval);
             if orientation == box_layout.orientation {
@@ -67,6 +73,36 @@ pub(crate) fn compute_layout_info(
             }
             .into()
         }
+        Layout::FlexboxLayout(flexbox_layout) => {
+            let main = flexbox_main_axis_data(flexbox_layout, component, &expr_eval, None);
+            if orientation == flexbox_layout.orientation {
+                core_layout::flexbox_layout_info(
+                    Slice::from(main.cells.as_slice()),
+                    main.spacing,
+                    &main.padding,
+                    main.alignment,
+                    main.wrap,
+                )
+            } else {
+                let (cells, _) = box_layout_data(
+                    &flexbox_layout.elems,
+                    None,
+                    orientation,
+                    component,
+                    &expr_eval,
+                    None,
+                );
+                let (padding, spacing) =
+                    padding_and_spacing(&flexbox_layout.geometry, orientation, &expr_eval);
+                core_layout::flexbox_layout_info_cross(
+                    &main.as_data(),
+                    Slice::from(cells.as_slice()),
+                    spacing,
+                    &padding,
+                )
+            }
+            .into()
+        }
     }
 }
 
@@ -111,7 +147,8 @@ pub(crate) fn solve_layout(
         Layout::BoxLayout(box_layout) => {
             let mut repeated_indices = Vec::new();
             let (cells, alignment) = box_layout_data(
-                box_layout,
+                &box_layout.elems,
+                box_layout.geometry.alignment.as_ref(),
                 orientation,
                 component,
                 &expr_eval,
@@ -135,9 +172,111 @@ pub(crate) fn solve_layout(
             )
             .into()
         }
+        Layout::FlexboxLayout(flexbox_layout) => {
+            let mut repeated_indices = Vec::new();
+            if orientation == flexbox_layout.orientation {
+                let main = flexbox_main_axis_data(
+                    flexbox_layout,
+                    component,
+                    &expr_eval,
+                    Some(&mut repeated_indices),
+                );
+                core_layout::solve_flexbox_layout(
+                    &main.as_data(),
+                    Slice::from(repeated_indices.as_slice()),
+                )
+            } else {
+                let main = flexbox_main_axis_data(flexbox_layout, component, &expr_eval, None);
+                let (cells, _) = box_layout_data(
+                    &flexbox_layout.elems,
+                    None,
+                    orientation,
+                    component,
+                    &expr_eval,
+                    Some(&mut repeated_indices),
+                );
+                let (padding, spacing) =
+                    padding_and_spacing(&flexbox_layout.geometry, orientation, &expr_eval);
+                let size_ref = flexbox_layout.geometry.rect.size_reference(orientation);
+                core_layout::solve_flexbox_layout_cross(
+                    &main.as_data(),
+                    Slice::from(cells.as_slice()),
+                    size_ref.map(expr_eval).unwrap_or(0.),
+                    spacing,
+                    &padding,
+                    load_enum_property(component, flexbox_layout.align_items.as_ref()),
+                    Slice::from(repeated_indices.as_slice()),
+                )
+            }
+            .into()
+        }
+    }
+}
+
+/// The data of a FlexboxLayout along its main axis
+struct FlexboxMainAxisData {
+    size: f32,
+    spacing: f32,
+    padding: core_layout::Padding,
+    alignment: i_slint_core::items::LayoutAlignment,
+    wrap: i_slint_core::items::FlexboxLayoutWrap,
+    cells: Vec<core_layout::BoxLayoutCellData>,
+}
+
+impl FlexboxMainAxisData {
+    fn as_data(&self) -> core_layout::FlexboxLayoutData<'_> {
+        core_layout::FlexboxLayoutData {
+            size: self.size,
+            spacing: self.spacing,
+            padding: self.padding,
+            alignment: self.alignment,
+            wrap: self.wrap,
+            cells: Slice::from(self.cells.as_slice()),
+        }
     }
 }
 
+fn flexbox_main_axis_data(
+    flexbox_layout: &i_slint_compiler::layout::FlexboxLayout,
+    component: InstanceRef,
+    expr_eval: &impl Fn(&NamedReference) -> f32,
+    repeater_indices: Option<&mut Vec<u32>>,
+) -> FlexboxMainAxisData {
+    let orientation = flexbox_layout.orientation;
+    let (cells, alignment) = box_layout_data(
+        &flexbox_layout.elems,
+        flexbox_layout.justify_content.as_ref(),
+        orientation,
+        component,
+        expr_eval,
+        repeater_indices,
+    );
+    let (padding, spacing) = padding_and_spacing(&flexbox_layout.geometry, orientation, expr_eval);
+    let size_ref = flexbox_layout.geometry.rect.size_reference(orientation);
+    FlexboxMainAxisData {
+        size: size_ref.map(expr_eval).unwrap_or(0.),
+        spacing,
+        padding,
+        alignment,
+        wrap: load_enum_property(component, flexbox_layout.wrap.as_ref()),
+        cells,
+    }
+}
+
+/// Load the value of a property of enum type, or the default value if there is no such property
+fn load_enum_property<T: Default + TryFrom<Value>>(
+    component: InstanceRef,
+    nr: Option<&NamedReference>,
+) -> T {
+    nr.map(|nr| {
+        eval::load_property(component, &nr.element(), nr.name())
+            .unwrap()
+            .try_into()
+            .unwrap_or_default()
+    })
+    .unwrap_or_default()
+}
+
 fn padding_and_spacing(
     layout_geometry: &LayoutGeometry,
     orientation: Orientation,
@@ -181,15 +320,16 @@ fn grid_layout_data(
 }
 
 fn box_layout_data(
-    box_layout: &i_slint_compiler::layout::BoxLayout,
+    elems: &[i_slint_compiler::layout::LayoutItem],
+    alignment: Option<&NamedReference>,
     orientation: Orientation,
     component: InstanceRef,
     expr_eval: &impl Fn(&NamedReference) -> f32,
     mut repeater_indices: Option<&mut Vec<u32>>,
 ) -> (Vec<core_layout::BoxLayoutCellData>, i_slint_core::items::LayoutAlignment) {
     let window_adapter = component.window_adapter();
-    let mut cells = Vec::with_capacity(box_layout.elems.len());
-    for cell in &box_layout.elems {
+    let mut cells = Vec::with_capacity(elems.len());
+    for cell in elems {
         if cell.element.borrow().repeated.is_some() {
             generativity::make_guard!(guard);
             let rep = crate::dynamic_item_tree::get_repeater_by_name(
@@ -229,18 +369,7 @@ fn box_layout_data(
             cells.push(core_layout::BoxLayoutCellData { constraint: layout_info });
         }
     }
-    let alignment = box_layout
-        .geometry
-        .alignment
-        .as_ref()
-        .map(|nr| {
-            eval::load_property(component, &nr.element(), nr.name())
-                .unwrap()
-                .try_into()
-                .unwrap_or_default()
-        })
-        .unwrap_or_default();
-    (cells, alignment)
+    (cells, load_enum_property(component, alignment))
 }
 
 pub(crate) fn fill_layout_info_constraints(
@@ -84,11 +84,27 @@
 mod global_component;
 #[cfg(feature = "highlight")]
 pub mod highlight;
+#[cfg(feature = "live-reload")]
+mod live_reload;
 mod value_model;
 
 #[doc(inline)]
 pub use api::*;
 
+#[cfg(feature = "live-reload")]
+pub use live_reload::LiveComponent;
+
+/// Record the evaluations of the property bindings and inspect the dependency graph of the properties.
+///
+/// The properties are named after their component, element id and property name.
+#[cfg(feature = "binding-profiler")]
+pub mod binding_profiler {
+    pub use i_slint_core::properties::profiler::{
+        dependency_graph, is_recording, start, statistics, statistics_as_json, stop,
+        BindingStatistics, DependencyGraph, DependencyNode,
+    };
+}
+
 #[cfg(feature = "internal")]
 #[doc(hidden)]
 pub use eval::default_value_for_type;
@@ -0,0 +1,604 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Hot reload of a component when its `.slint` files change on disk
+
+use crate::api::{
+    CompilationResult, Compiler, ComponentDefinition, ComponentInstance, Diagnostic,
+    DiagnosticLevel, GetPropertyError, InvokeError, SetCallbackError, SetPropertyError, Value,
+};
+use i_slint_core::api::{ComponentHandle, PlatformError};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+type CallbackHandler = Rc<dyn Fn(&[Value]) -> Value>;
+
+thread_local! {
+    /// The live components of this thread, by id, so that the file watcher thread can reload them
+    static LIVE_COMPONENTS: RefCell<HashMap<usize, Weak<LiveComponentInner>>> = Default::default();
+}
+
+/// What is currently shown in the window
+enum Shown {
+    /// The instance of the component
+    Component(ComponentInstance),
+    /// The compilation failed, and the diagnostics are shown instead
+    Diagnostics(ComponentInstance),
+}
+
+impl Shown {
+    fn instance(&self) -> &ComponentInstance {
+        match self {
+            Shown::Component(instance) | Shown::Diagnostics(instance) => instance,
+        }
+    }
+}
+
+struct LiveComponentInner {
+    id: usize,
+    compiler: Compiler,
+    path: PathBuf,
+    component_name: String,
+    shown: RefCell<Shown>,
+    diagnostics: RefCell<Vec<Diagnostic>>,
+    /// The values set with [`LiveComponent::set_property`], and the ones the previous instances
+    /// changed themselves
+    properties: RefCell<HashMap<String, Value>>,
+    /// The handlers set with [`LiveComponent::set_callback`]
+    callbacks: RefCell<HashMap<String, CallbackHandler>>,
+    /// The values set with [`LiveComponent::set_global_property`], and the ones the previous
+    /// instances changed themselves, by global and property name
+    global_properties: RefCell<HashMap<(String, String), Value>>,
+    /// The handlers set with [`LiveComponent::set_global_callback`], by global and callback name
+    global_callbacks: RefCell<HashMap<(String, String), CallbackHandler>>,
+    /// The values of the properties when the current instance was created, to find the ones that
+    /// changed since
+    initial_values: RefCell<PropertyValues>,
+    /// Kept alive for as long as the component lives
+    _watcher: Arc<Mutex<notify::RecommendedWatcher>>,
+}
+
+impl Drop for LiveComponentInner {
+    fn drop(&mut self) {
+        let _ = LIVE_COMPONENTS.try_with(|c| c.borrow_mut().remove(&self.id));
+    }
+}
+
+/// A component that is re-compiled and re-created when one of its `.slint` files changes.
+///
+/// The `.slint` file and all the files it imports are watched. When one of them changes, the file
+/// is compiled again and the new instance replaces the previous one in the same window. The values
+/// of the properties and the callback handlers set with this type's functions are carried over to the
+/// new instance, as long as the new component still has an `in` or `in-out` property, or a callback,
+/// with the same name. Before a reload, the public properties of the previous instance and of its
+/// exported globals that changed since the instance was created are recorded as well, so that the
+/// values the `.slint` code or the user assigned, for example to an `in-out` property bound to a
+/// text field, are carried over. The other properties get the default values of the new code.
+///
+/// If the compilation fails, the window shows the diagnostics instead of the component, until the
+/// files are fixed.
+///
+/// The reload happens in the event loop, so the event loop needs to run.
+///
+/// ## Example
+///
+/// ```no_run
+/// use slint_interpreter::{Compiler, LiveComponent, Value};
+///
+/// let live = LiveComponent::new(Compiler::default(), "ui/app-window.slint", "AppWindow").unwrap();
+/// live.set_property("counter", Value::from(42)).unwrap();
+/// live.set_callback("clicked", |_| {
+///     println!("clicked");
+///     Value::Void
+/// })
+/// .unwrap();
+/// live.run().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct LiveComponent {
+    inner: Rc<LiveComponentInner>,
+}
+
+impl LiveComponent {
+    /// Compile the component `component_name` exported by the `.slint` file at `path`, and create
+    /// an instance of it in a new window.
+    ///
+    /// The file loader of the `compiler`, if any, is still used to load the files.
+    pub fn new(
+        mut compiler: Compiler,
+        path: impl Into<PathBuf>,
+        component_name: impl Into<String>,
+    ) -> Result<Self, PlatformError> {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let path = path.into();
+
+        let watcher = Arc::new(Mutex::new(start_watcher(id).map_err(|e| {
+            PlatformError::Other(format!("Could not watch {}: {e}", path.display()))
+        })?));
+        watch(&watcher, &path);
+        let watcher_ = watcher.clone();
+        let file_loader = compiler.config.open_import_fallback.take();
+        compiler.config.open_import_fallback = Some(Rc::new(move |file| {
+            watch(&watcher_, Path::new(&file));
+            match &file_loader {
+                Some(file_loader) => file_loader(file),
+                None => Box::pin(std::future::ready(None)),
+            }
+        }));
+
+        let result = spin_on::spin_on(compiler.build_from_path(&path));
+        let component_name = component_name.into();
+        let mut initial_values = PropertyValues::default();
+        let shown = match result.component(&component_name) {
+            Some(definition) => {
+                let instance = definition.create()?;
+                initial_values = PropertyValues::read(&instance);
+                Shown::Component(instance)
+            }
+            None => Shown::Diagnostics(create_diagnostics(None, &result, &path, &component_name)?),
+        };
+
+        let inner = Rc::new(LiveComponentInner {
+            id,
+            compiler,
+            path,
+            component_name,
+            shown: RefCell::new(shown),
+            diagnostics: RefCell::new(result.diagnostics().collect()),
+            properties: Default::default(),
+            callbacks: Default::default(),
+            global_properties: Default::default(),
+            global_callbacks: Default::default(),
+            initial_values: RefCell::new(initial_values),
+            _watcher: watcher,
+        });
+        LIVE_COMPONENTS.with(|c| c.borrow_mut().insert(id, Rc::downgrade(&inner)));
+        Ok(Self { inner })
+    }
+
+    /// Compile the files again and replace the instance, as if a file had changed
+    pub fn reload(&self) -> Result<(), PlatformError> {
+        self.inner.reload()
+    }
+
+    /// Returns the current instance of the component, or None if the compilation failed and the
+    /// window shows the diagnostics.
+    ///
+    /// The instance is replaced on each reload, so don't keep it for longer than needed.
+    pub fn instance(&self) -> Option<ComponentInstance> {
+        match &*self.inner.shown.borrow() {
+            Shown::Component(instance) => Some(instance.clone_strong()),
+            Shown::Diagnostics(_) => None,
+        }
+    }
+
+    /// Returns the diagnostics of the last compilation
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.inner.diagnostics.borrow().clone()
+    }
+
+    /// Return the value of a public property of the current instance.
+    pub fn get_property(&self, name: &str) -> Result<Value, GetPropertyError> {
+        match &*self.inner.shown.borrow() {
+            Shown::Component(instance) => instance.get_property(name),
+            Shown::Diagnostics(_) => self
+                .inner
+                .properties
+                .borrow()
+                .get(name)
+                .cloned()
+                .ok_or(GetPropertyError::NoSuchProperty),
+        }
+    }
+
+    /// Set the value of a public property. The value is set again on the instances created by later reloads.
+    ///
+    /// While the diagnostics are shown, the value is only recorded for the next instance.
+    pub fn set_property(&self, name: &str, value: Value) -> Result<(), SetPropertyError> {
+        if let Shown::Component(instance) = &*self.inner.shown.borrow() {
+            instance.set_property(name, value.clone())?;
+        }
+        self.inner.properties.borrow_mut().insert(name.into(), value);
+        Ok(())
+    }
+
+    /// Set the handler of a callback. The handler is also set on the instances created by later reloads.
+    ///
+    /// See [`ComponentInstance::set_callback`]
+    pub fn set_callback(
+        &self,
+        name: &str,
+        callback: impl Fn(&[Value]) -> Value + 'static,
+    ) -> Result<(), SetCallbackError> {
+        let callback: CallbackHandler = Rc::new(callback);
+        if let Shown::Component(instance) = &*self.inner.shown.borrow() {
+            let callback = callback.clone();
+            instance.set_callback(name, move |args| callback(args))?;
+        }
+        self.inner.callbacks.borrow_mut().insert(name.into(), callback);
+        Ok(())
+    }
+
+    /// Call the given callback or function of the current instance.
+    ///
+    /// Returns [`InvokeError::NoSuchCallable`] while the diagnostics are shown.
+    pub fn invoke(&self, name: &str, args: &[Value]) -> Result<Value, InvokeError> {
+        // Don't hold the borrow while invoking, as the callback may cause a reload
+        let instance = self.instance().ok_or(InvokeError::NoSuchCallable)?;
+        instance.invoke(name, args)
+    }
+
+    /// Return the value of a property of an exported global singleton of the current instance.
+    pub fn get_global_property(
+        &self,
+        global: &str,
+        property: &str,
+    ) -> Result<Value, GetPropertyError> {
+        match &*self.inner.shown.borrow() {
+            Shown::Component(instance) => instance.get_global_property(global, property),
+            Shown::Diagnostics(_) => self
+                .inner
+                .global_properties
+                .borrow()
+                .get(&(global.into(), property.into()))
+                .cloned()
+                .ok_or(GetPropertyError::NoSuchProperty),
+        }
+    }
+
+    /// Set the value of a property of an exported global singleton.
+    /// The value is set again on the instances created by later reloads.
+    pub fn set_global_property(
+        &self,
+        global: &str,
+        property: &str,
+        value: Value,
+    ) -> Result<(), SetPropertyError> {
+        if let Shown::Component(instance) = &*self.inner.shown.borrow() {
+            instance.set_global_property(global, property, value.clone())?;
+        }
+        self.inner.global_properties.borrow_mut().insert((global.into(), property.into()), value);
+        Ok(())
+    }
+
+    /// Set the handler of a callback of an exported global singleton.
+    /// The handler is also set on the instances created by later reloads.
+    pub fn set_global_callback(
+        &self,
+        global: &str,
+        name: &str,
+        callback: impl Fn(&[Value]) -> Value + 'static,
+    ) -> Result<(), SetCallbackError> {
+        let callback: CallbackHandler = Rc::new(callback);
+        if let Shown::Component(instance) = &*self.inner.shown.borrow() {
+            let callback = callback.clone();
+            instance.set_global_callback(global, name, move |args| callback(args))?;
+        }
+        self.inner.global_callbacks.borrow_mut().insert((global.into(), name.into()), callback);
+        Ok(())
+    }
+
+    /// Call the given callback or function of an exported global singleton of the current instance.
+    pub fn invoke_global(
+        &self,
+        global: &str,
+        callable_name: &str,
+        args: &[Value],
+    ) -> Result<Value, InvokeError> {
+        let instance = self.instance().ok_or(InvokeError::NoSuchCallable)?;
+        instance.invoke_global(global, callable_name, args)
+    }
+
+    /// Show the window. See [`ComponentHandle::show`]
+    pub fn show(&self) -> Result<(), PlatformError> {
+        self.inner.shown.borrow().instance().show()
+    }
+
+    /// Hide the window. See [`ComponentHandle::hide`]
+    pub fn hide(&self) -> Result<(), PlatformError> {
+        self.inner.shown.borrow().instance().hide()
+    }
+
+    /// Show the window, run the event loop, and hide the window once the event loop exits.
+    /// See [`ComponentHandle::run`]
+    pub fn run(&self) -> Result<(), PlatformError> {
+        self.show()?;
+        crate::run_event_loop()?;
+        self.hide()
+    }
+}
+
+impl LiveComponentInner {
+    fn reload(&self) -> Result<(), PlatformError> {
+        let result = spin_on::spin_on(self.compiler.build_from_path(&self.path));
+        *self.diagnostics.borrow_mut() = result.diagnostics().collect();
+
+        let Some(definition) = result.component(&self.component_name) else {
+            let mut shown = self.shown.borrow_mut();
+            if let Shown::Component(previous) = &*shown {
+                self.save_state(previous);
+            }
+            let diagnostics = create_diagnostics(
+                Some(shown.instance()),
+                &result,
+                &self.path,
+                &self.component_name,
+            )?;
+            *shown = Shown::Diagnostics(diagnostics);
+            return Ok(());
+        };
+
+        let instance = {
+            let shown = self.shown.borrow();
+            if let Shown::Component(previous) = &*shown {
+                self.save_state(previous);
+            }
+            definition.create_in_window_of(Some(shown.instance()))?
+        };
+        self.restore_state(&instance, &definition);
+        *self.initial_values.borrow_mut() = PropertyValues::read(&instance);
+        *self.shown.borrow_mut() = Shown::Component(instance);
+        Ok(())
+    }
+
+    /// Record the values of the public properties that changed since the instance was created,
+    /// including the ones that were not set with the functions of [`LiveComponent`]
+    fn save_state(&self, instance: &ComponentInstance) {
+        let current = PropertyValues::read(instance);
+        let initial = self.initial_values.borrow();
+        let mut properties = self.properties.borrow_mut();
+        for (name, value) in current.properties {
+            if properties.contains_key(&name) || initial.properties.get(&name) != Some(&value) {
+                properties.insert(name, value);
+            }
+        }
+        let mut global_properties = self.global_properties.borrow_mut();
+        for (key, value) in current.global_properties {
+            if global_properties.contains_key(&key)
+                || initial.global_properties.get(&key) != Some(&value)
+            {
+                global_properties.insert(key, value);
+            }
+        }
+    }
+
+    /// Set the recorded properties and callbacks on a new instance, ignoring the ones that don't
+    /// exist anymore or whose type changed
+    fn restore_state(&self, instance: &ComponentInstance, definition: &ComponentDefinition) {
+        let property_types = definition.properties().collect::<HashMap<_, _>>();
+        for (name, value) in self.properties.borrow().iter() {
+            let name = i_slint_compiler::parser::normalize_identifier(name);
+            if property_types.get(name.as_str()) == Some(&value.value_type()) {
+                let _ = instance.set_property(&name, value.clone());
+            }
+        }
+        for (name, callback) in self.callbacks.borrow().iter() {
+            let callback = callback.clone();
+            let _ = instance.set_callback(name, move |args| callback(args));
+        }
+        for ((global, name), value) in self.global_properties.borrow().iter() {
+            let type_matches = definition.global_properties(global).is_some_and(|mut props| {
+                let name = i_slint_compiler::parser::normalize_identifier(name);
+                props.any(|(n, ty)| n.as_str() == name.as_str() && ty == value.value_type())
+            });
+            if type_matches {
+                let _ = instance.set_global_property(global, name, value.clone());
+            }
+        }
+        for ((global, name), callback) in self.global_callbacks.borrow().iter() {
+            let callback = callback.clone();
+            let _ = instance.set_global_callback(global, name, move |args| callback(args));
+        }
+    }
+}
+
+/// The values of the public properties of an instance and of its exported globals, except the
+/// `out` properties which can't be set on the next instance anyway
+#[derive(Default)]
+struct PropertyValues {
+    properties: HashMap<String, Value>,
+    global_properties: HashMap<(String, String), Value>,
+}
+
+impl PropertyValues {
+    fn read(instance: &ComponentInstance) -> Self {
+        let definition = instance.definition();
+        let properties = definition
+            .settable_properties(None)
+            .into_iter()
+            .filter_map(|name| Some((name.clone(), instance.get_property(&name).ok()?)))
+            .collect();
+        let mut global_properties = HashMap::new();
+        for global in definition.globals() {
+            for name in definition.settable_properties(Some(&global)) {
+                if let Ok(value) = instance.get_global_property(&global, &name) {
+                    global_properties.insert((global.clone(), name), value);
+                }
+            }
+        }
+        Self { properties, global_properties }
+    }
+}
+
+/// Create the component that shows the diagnostics in place of the component that failed to compile
+fn create_diagnostics(
+    previous: Option<&ComponentInstance>,
+    result: &CompilationResult,
+    path: &Path,
+    component_name: &str,
+) -> Result<ComponentInstance, PlatformError> {
+    let mut text = result
+        .diagnostics()
+        .filter(|d| d.level() == DiagnosticLevel::Error)
+        .map(|d| d.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if text.is_empty() {
+        text = format!("Component {component_name} not found in {}", path.display());
+    }
+
+    let source = r#"
+export component Diagnostics inherits Window {
+    in property <string> diagnostics;
+    background: #3b1218;
+    preferred-width: 600px;
+    preferred-height: 400px;
+    Flickable {
+        viewport-height: max(self.height, label.preferred-height + 20px);
+        label := Text {
+            x: 10px;
+            y: 10px;
+            width: parent.width - 20px;
+            text: diagnostics;
+            color: #ffd8dc;
+            wrap: word-wrap;
+            vertical-alignment: top;
+        }
+    }
+}
+"#;
+    let definition = spin_on::spin_on(
+        Compiler::default().build_from_source(source.into(), "<live-reload-diagnostics>".into()),
+    )
+    .component("Diagnostics")
+    .expect("the diagnostics component must compile");
+    let instance = definition.create_in_window_of(previous)?;
+    instance
+        .set_property("diagnostics", Value::String(text.into()))
+        .expect("the diagnostics component has a diagnostics property");
+    Ok(instance)
+}
+
+/// Create a watcher that reloads the live component with the given id when a file changes
+fn start_watcher(id: usize) -> notify::Result<notify::RecommendedWatcher> {
+    // Only one reload is queued at a time, even if many files change at once
+    let pending = Arc::new(AtomicBool::new(false));
+    notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        use notify::EventKind::*;
+        let Ok(event) = event else { return };
+        if !matches!(event.kind, Modify(_) | Remove(_) | Create(_))
+            || pending.swap(true, Ordering::SeqCst)
+        {
+            return;
+        }
+        let pending_ = pending.clone();
+        let r = i_slint_core::api::invoke_from_event_loop(move || {
+            pending_.store(false, Ordering::SeqCst);
+            let Some(inner) = LIVE_COMPONENTS.with(|c| c.borrow().get(&id).and_then(Weak::upgrade))
+            else {
+                return;
+            };
+            if let Err(e) = inner.reload() {
+                eprintln!("Error while reloading {}: {e}", inner.path.display());
+            }
+        });
+        if r.is_err() {
+            // The event loop is gone, nothing will be reloaded
+            pending.store(false, Ordering::SeqCst);
+        }
+    })
+}
+
+fn watch(watcher: &Mutex<notify::RecommendedWatcher>, path: &Path) {
+    // Editors often save files by replacing them, so watch the directory instead of the file itself
+    let dir = path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    if let Err(err) = notify::Watcher::watch(
+        &mut *watcher.lock().unwrap(),
+        dir,
+        notify::RecursiveMode::NonRecursive,
+    ) {
+        eprintln!("Warning: error while watching {}: {err}", path.display());
+    }
+}
+
+#[test]
+fn live_component_reload() {
+    i_slint_backend_testing::init_no_event_loop();
+    let dir = std::env::temp_dir().join(format!("slint-live-reload-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("app.slint");
+    std::fs::write(
+        &path,
+        r#"
+        export global Logic {
+            in-out property <int> count: 1;
+            in-out property <bool> flag;
+            pure callback hello() -> string;
+        }
+        export component App inherits Window {
+            in property <string> name: "default";
+            in property <int> untouched: 1;
+            in-out property <string> text: "a";
+            in-out property <int> value;
+            callback clicked(int) -> int;
+            out property <string> greeting: Logic.hello();
+        }"#,
+    )
+    .unwrap();
+
+    let live = LiveComponent::new(Compiler::default(), &path, "App").unwrap();
+    live.set_property("name", Value::String("World".into())).unwrap();
+    live.set_property("value", Value::Number(1.)).unwrap();
+    live.set_callback("clicked", |args| {
+        Value::Number(f64::try_from(args[0].clone()).unwrap() * 2.)
+    })
+    .unwrap();
+    live.set_global_property("Logic", "count", Value::Number(5.)).unwrap();
+    live.set_global_callback("Logic", "hello", |_| Value::String("Hi".into())).unwrap();
+    assert_eq!(live.get_property("greeting").unwrap(), Value::String("Hi".into()));
+    // The instance changes an in-out property itself
+    live.instance().unwrap().set_property("value", Value::Number(7.)).unwrap();
+    // Properties that were never set through the LiveComponent
+    live.instance().unwrap().set_property("text", Value::String("typed".into())).unwrap();
+    live.instance().unwrap().set_global_property("Logic", "flag", Value::Bool(true)).unwrap();
+
+    // `name` becomes an int: its value can't be carried over
+    std::fs::write(
+        &path,
+        r#"
+        export global Logic {
+            in-out property <int> count;
+            in-out property <bool> flag;
+            pure callback hello() -> string;
+        }
+        export component App inherits Window {
+            in property <int> name: 3;
+            in property <int> untouched: 2;
+            in-out property <string> text: "b";
+            in-out property <int> value;
+            callback clicked(int) -> int;
+            out property <string> greeting: Logic.hello() + "!";
+        }"#,
+    )
+    .unwrap();
+    live.reload().unwrap();
+    assert!(live.diagnostics().is_empty());
+    assert_eq!(live.get_property("name").unwrap(), Value::Number(3.));
+    assert_eq!(live.get_property("value").unwrap(), Value::Number(7.));
+    assert_eq!(live.get_property("text").unwrap(), Value::String("typed".into()));
+    // The new default applies to the properties that didn't change
+    assert_eq!(live.get_property("untouched").unwrap(), Value::Number(2.));
+    assert_eq!(live.get_global_property("Logic", "flag").unwrap(), Value::Bool(true));
+    assert_eq!(live.invoke("clicked", &[Value::Number(21.)]).unwrap(), Value::Number(42.));
+    assert_eq!(live.get_global_property("Logic", "count").unwrap(), Value::Number(5.));
+    assert_eq!(live.get_property("greeting").unwrap(), Value::String("Hi!".into()));
+
+    // A compilation error shows the diagnostics, but the state is kept for the next reload
+    std::fs::write(&path, "export component App inherits Window { foo: bar; }").unwrap();
+    live.reload().unwrap();
+    assert!(live.instance().is_none());
+    assert!(live.diagnostics().iter().any(|d| d.level() == DiagnosticLevel::Error));
+    live.set_property("value", Value::Number(8.)).unwrap();
+
+    std::fs::write(&path, "export component App inherits Window { in-out property <int> value; }")
+        .unwrap();
+    live.reload().unwrap();
+    assert_eq!(live.get_property("value").unwrap(), Value::Number(8.));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -44,3 +44,37 @@ fn reuse_window() {
         instance
     };
 }
+
+#[cfg(feature = "binding-profiler")]
+#[test]
+fn binding_profiler_names() {
+    i_slint_backend_testing::init_no_event_loop();
+    use crate::{binding_profiler, Compiler, ComponentHandle, Value};
+    let code = r#"
+        export component Main {
+            in property <int> input: 1;
+            out property <int> output: txt.text.to-float() * 2;
+            txt := Text { text: input + 1; }
+        }
+    "#;
+    let result =
+        spin_on::spin_on(Compiler::default().build_from_source(code.into(), Default::default()));
+    assert!(!result.has_errors(), "{:?}", result.diagnostics().collect::<Vec<_>>());
+    let instance = result.component("Main").unwrap().create().unwrap();
+
+    binding_profiler::start();
+    assert_eq!(instance.get_property("output").unwrap(), Value::Number(4.));
+    instance.set_property("input", Value::Number(2.)).unwrap();
+    assert_eq!(instance.get_property("output").unwrap(), Value::Number(6.));
+    binding_profiler::stop();
+
+    let statistics = binding_profiler::statistics();
+    let output = statistics.iter().find(|s| s.property == "Main::output").unwrap();
+    assert_eq!(output.evaluations, 2);
+    let text = statistics.iter().find(|s| s.property == "Main::txt.text").unwrap();
+    assert_eq!(text.evaluations, 2);
+
+    let dot = binding_profiler::dependency_graph().to_dot();
+    assert!(dot.contains("\"Main::input\""), "{dot}");
+    assert!(dot.contains("\"Main::txt.text\""), "{dot}");
+}
@@ -11,9 +11,12 @@
 use i_slint_core::items::{TextHorizontalAlignment, TextOverflow, TextVerticalAlignment, TextWrap};
 use i_slint_core::lengths::PointLengths;
 use i_slint_core::lengths::{LogicalLength, LogicalSize, ScaleFactor, SizeLengths};
+use i_slint_core::styled_text::StyledString;
+use i_slint_core::textlayout::StyledParagraphLayout;
 use i_slint_core::{SharedString, SharedVector};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
 use super::{PhysicalLength, PhysicalPoint, PhysicalSize};
 
@@ -128,6 +131,134 @@ pub(crate) fn font_metrics(
     }
 }
 
+/// A font together with the paint for it, that implements the text shaping interface of
+/// the core text layout, used for styled text.
+pub(crate) struct ShapingFont {
+    font: Font,
+    paint: femtovg::Paint,
+    metrics: femtovg::FontMetrics,
+    x_height: f32,
+    cap_height: f32,
+}
+
+impl ShapingFont {
+    pub fn new(font_request: &FontRequest, scale_factor: ScaleFactor, text: &str) -> Self {
+        let font = FONT_CACHE
+            .with(|cache| cache.borrow_mut().font(font_request.clone(), scale_factor, text));
+        let mut paint = font.init_paint(PhysicalLength::default(), femtovg::Paint::default());
+        paint.set_text_baseline(femtovg::Baseline::Alphabetic);
+        let metrics = font.text_context.measure_font(&paint).unwrap();
+        let design_metrics = font_metrics(font_request.clone());
+        Self {
+            font,
+            paint,
+            metrics,
+            x_height: design_metrics.x_height * scale_factor.get(),
+            cap_height: design_metrics.cap_height * scale_factor.get(),
+        }
+    }
+
+    /// Returns the given paint, with the font of this font set, for drawing text at the baseline
+    pub fn init_paint(&self, paint: femtovg::Paint) -> femtovg::Paint {
+        let mut paint = self.font.init_paint(PhysicalLength::default(), paint);
+        paint.set_text_baseline(femtovg::Baseline::Alphabetic);
+        paint
+    }
+}
+
+impl i_slint_core::textlayout::TextShaper for ShapingFont {
+    type LengthPrimitive = f32;
+    type Length = f32;
+
+    fn shape_text<GlyphStorage: std::iter::Extend<i_slint_core::textlayout::Glyph<f32>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        let Ok(metrics) = self.font.text_context.measure_text(0., 0., text, &self.paint) else {
+            return;
+        };
+        glyphs.extend(metrics.glyphs.iter().map(|glyph| i_slint_core::textlayout::Glyph {
+            advance: glyph.advance_x,
+            offset_x: 0.,
+            offset_y: 0.,
+            // The glyph ids are not used, as femtovg draws the text of the fragments
+            glyph_id: core::num::NonZeroU16::new(glyph.codepoint.max(1) as u16),
+            text_byte_offset: glyph.byte_index,
+        }));
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<i_slint_core::textlayout::Glyph<f32>> {
+        let mut glyphs = Vec::new();
+        self.shape_text(ch.encode_utf8(&mut [0; 4]), &mut glyphs);
+        glyphs.pop()
+    }
+
+    fn max_lines(&self, max_height: f32) -> usize {
+        (max_height / self.metrics.height()).floor() as _
+    }
+}
+
+impl i_slint_core::textlayout::FontMetrics<f32> for ShapingFont {
+    fn height(&self) -> f32 {
+        self.metrics.height()
+    }
+
+    fn ascent(&self) -> f32 {
+        self.metrics.ascender()
+    }
+
+    fn descent(&self) -> f32 {
+        self.metrics.descender()
+    }
+
+    fn x_height(&self) -> f32 {
+        self.x_height
+    }
+
+    fn cap_height(&self) -> f32 {
+        self.cap_height
+    }
+}
+
+/// Returns the font to use for each span of the styled string
+pub(crate) fn styled_shaping_fonts(
+    font_request: &FontRequest,
+    styled_string: &StyledString,
+    scale_factor: ScaleFactor,
+) -> Vec<(Range<usize>, ShapingFont)> {
+    styled_string
+        .spans
+        .iter()
+        .map(|span| {
+            let font_request = span.style.font_request(font_request);
+            let text = &styled_string.text[span.range.clone()];
+            (span.range.clone(), ShapingFont::new(&font_request, scale_factor, text))
+        })
+        .collect()
+}
+
+pub(crate) fn styled_text_size(
+    font_request: &FontRequest,
+    scale_factor: ScaleFactor,
+    styled_string: &StyledString,
+    max_width: Option<LogicalLength>,
+    text_wrap: TextWrap,
+) -> LogicalSize {
+    let runs = styled_shaping_fonts(font_request, styled_string, scale_factor);
+    let paragraph = StyledParagraphLayout {
+        string: &styled_string.text,
+        runs: &runs,
+        max_width: max_width.map_or(f32::MAX, |max_width| (max_width * scale_factor).get()),
+        max_height: f32::MAX,
+        horizontal_alignment: Default::default(),
+        vertical_alignment: Default::default(),
+        wrap: if max_width.is_some() { text_wrap } else { TextWrap::NoWrap },
+    };
+    let (width, height) = paragraph.text_size();
+    PhysicalSize::new(width, height) / scale_factor
+}
+
 #[derive(Clone)]
 struct LoadedFont {
     femtovg_font_id: femtovg::FontId,
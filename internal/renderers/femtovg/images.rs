@@ -154,6 +154,15 @@ pub fn new_from_image(
 
         Some(Self::adopt(canvas, image_id))
     }
+
+    pub fn new_from_image_buffer(
+        buffer: &SharedImageBuffer,
+        canvas: &CanvasRc,
+    ) -> Option<Rc<Self>> {
+        let (image_source, flags) = image_buffer_to_image_source(buffer);
+        let image_id = canvas.borrow_mut().create_image(image_source, flags).ok()?;
+        Some(Self::adopt(canvas, image_id))
+    }
 }
 
 impl Drop for Texture {
@@ -10,7 +10,7 @@
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self};
 use i_slint_core::graphics::rendering_metrics_collector::RenderingMetrics;
-use i_slint_core::graphics::{IntRect, Point, Size};
+use i_slint_core::graphics::{GradientSpread, IntRect, Point, SharedImageBuffer, Size};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderBorderRectangle, RenderImage, RenderText,
 };
@@ -22,6 +22,7 @@
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalRect, LogicalSize, LogicalVector,
     RectLengths, ScaleFactor, SizeLengths,
 };
+use i_slint_core::textlayout::{FontMetrics, StyledParagraphLayout};
 use i_slint_core::window::WindowInner;
 use i_slint_core::{Brush, Color, ImageInner, SharedString};
 
@@ -380,6 +381,73 @@ fn draw_text(
         );
     }
 
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&items::StyledText>,
+        _: &ItemRc,
+        size: LogicalSize,
+    ) {
+        let max_width = size.width_length() * self.scale_factor;
+        let max_height = size.height_length() * self.scale_factor;
+
+        if max_width.get() <= 0. || max_height.get() <= 0. {
+            return;
+        }
+
+        if self.global_alpha_transparent() {
+            return;
+        }
+
+        let styled_string = styled_text.styled_string();
+        let font_request = styled_text.font_request(WindowInner::from_pub(self.window));
+        let runs = fonts::styled_shaping_fonts(&font_request, &styled_string, self.scale_factor);
+
+        let text_path = rect_to_path((size * self.scale_factor).into());
+        let paints = styled_string
+            .spans
+            .iter()
+            .zip(&runs)
+            .map(|(span, (_, font))| {
+                self.brush_to_paint(styled_text.span_color(&span.style), &text_path)
+                    .map(|paint| font.init_paint(paint))
+            })
+            .collect::<Vec<_>>();
+
+        let paragraph = StyledParagraphLayout {
+            string: &styled_string.text,
+            runs: &runs,
+            max_width: max_width.get(),
+            max_height: max_height.get(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+        };
+
+        let mut canvas = self.canvas.borrow_mut();
+        paragraph
+            .layout_fragments::<()>(|fragment, _| {
+                let Some(paint) = &paints[fragment.run_index] else {
+                    return core::ops::ControlFlow::Continue(());
+                };
+                let style = &styled_string.spans[fragment.run_index].style;
+                let font = &runs[fragment.run_index].1;
+                let text = &styled_string.text[fragment.byte_range.clone()];
+                canvas.fill_text(fragment.x, fragment.baseline_y, text, paint).unwrap();
+
+                let thickness = (font.height() / 16.).max(1.);
+                let underline = style.is_underlined().then_some(fragment.baseline_y + thickness);
+                let strikethrough =
+                    style.strikethrough.then(|| fragment.baseline_y - font.x_height() / 2.);
+                for line_y in underline.into_iter().chain(strikethrough) {
+                    let mut path = femtovg::Path::new();
+                    path.rect(fragment.x, line_y, fragment.width, thickness);
+                    canvas.fill_path(&path, paint);
+                }
+                core::ops::ControlFlow::Continue(())
+            })
+            .ok();
+    }
+
     fn draw_text_input(
         &mut self,
         text_input: Pin<&items::TextInput>,
@@ -1513,7 +1581,7 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
         }
         Some(match brush {
             Brush::SolidColor(color) => femtovg::Paint::color(to_femtovg_color(&color)),
-            Brush::LinearGradient(gradient) => {
+            Brush::LinearGradient(gradient) if gradient.spread() == GradientSpread::Pad => {
                 let path_bounds = path_bounding_box(&self.canvas, path);
 
                 let path_width = path_bounds.width();
@@ -1528,22 +1596,44 @@ fn brush_to_paint(&self, brush: Brush, path: &femtovg::Path) -> Option<femtovg::
                     gradient.stops().map(|stop| (stop.position, to_femtovg_color(&stop.color)));
                 femtovg::Paint::linear_gradient_stops(start.x, start.y, end.x, end.y, stops)
             }
-            Brush::RadialGradient(gradient) => {
+            Brush::RadialGradient(gradient) if gradient.spread() == GradientSpread::Pad => {
                 let path_bounds = path_bounding_box(&self.canvas, path);
 
                 let path_width = path_bounds.width();
                 let path_height = path_bounds.height();
 
+                let (cx, cy) = gradient.center();
+                let radius = match gradient.radius() {
+                    r if r > 0. => r * self.scale_factor.get(),
+                    _ => path_width.max(path_height) / 2.,
+                };
+
                 let stops =
                     gradient.stops().map(|stop| (stop.position, to_femtovg_color(&stop.color)));
                 femtovg::Paint::radial_gradient_stops(
-                    path_width / 2.,
-                    path_height / 2.,
+                    cx * path_width,
+                    cy * path_height,
                     0.,
-                    (path_width + path_height) / 4.,
+                    radius,
                     stops,
                 )
             }
+            Brush::LinearGradient(_) | Brush::RadialGradient(_) | Brush::ConicGradient(_) => {
+                // femtovg has no support for these, so render the gradient into a texture.
+                let path_bounds = path_bounding_box(&self.canvas, path);
+                let scale_factor = self.scale_factor.get();
+                let buffer = SharedImageBuffer::RGBA8(
+                    brush.to_pixel_buffer(
+                        [path_bounds.width() / scale_factor, path_bounds.height() / scale_factor]
+                            .into(),
+                        scale_factor,
+                    ),
+                );
+                let texture = Texture::new_from_image_buffer(&buffer, &self.canvas)?;
+                let paint = texture.as_paint();
+                self.textures_to_delete_after_flush.borrow_mut().push(texture);
+                paint
+            }
             _ => return None,
         })
     }
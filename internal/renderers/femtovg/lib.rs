@@ -21,6 +21,8 @@
 };
 use i_slint_core::platform::PlatformError;
 use i_slint_core::renderer::RendererSealed;
+use i_slint_core::styled_text::StyledString;
+use i_slint_core::textlayout::StyledParagraphLayout;
 use i_slint_core::window::{WindowAdapter, WindowInner};
 use i_slint_core::Brush;
 
@@ -365,6 +367,45 @@ fn font_metrics(
         crate::fonts::font_metrics(font_request)
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: FontRequest,
+        styled_string: &StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        crate::fonts::styled_text_size(
+            &font_request,
+            scale_factor,
+            styled_string,
+            max_width,
+            text_wrap,
+        )
+    }
+
+    fn styled_text_span_at_position(
+        &self,
+        styled_text: Pin<&i_slint_core::items::StyledText>,
+        styled_string: &StyledString,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let runs = crate::fonts::styled_shaping_fonts(&font_request, styled_string, scale_factor);
+        let paragraph = StyledParagraphLayout {
+            string: &styled_string.text,
+            runs: &runs,
+            max_width: (styled_text.width() * scale_factor).get(),
+            max_height: (styled_text.height() * scale_factor).get(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+        };
+        let pos = pos * scale_factor;
+        paragraph.run_at_position((pos.x, pos.y))
+    }
+
     fn text_input_byte_offset_for_position(
         &self,
         text_input: Pin<&i_slint_core::items::TextInput>,
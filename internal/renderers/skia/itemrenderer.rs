@@ -9,7 +9,7 @@
 use i_slint_core::graphics::boxshadowcache::BoxShadowCache;
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::euclid::{self, Vector2D};
-use i_slint_core::graphics::ApproxEq;
+use i_slint_core::graphics::{normalize_stops, ApproxEq, GradientSpread};
 use i_slint_core::item_rendering::{
     CachedRenderingData, ItemCache, ItemRenderer, RenderImage, RenderText,
 };
@@ -20,6 +20,7 @@
     LogicalBorderRadius, LogicalLength, LogicalPoint, LogicalPx, LogicalRect, LogicalSize,
     LogicalVector, PhysicalPx, RectLengths, ScaleFactor, SizeLengths,
 };
+use i_slint_core::textlayout::{FontMetrics, StyledParagraphLayout};
 use i_slint_core::window::WindowInner;
 use i_slint_core::{Brush, Color};
 use skia_safe::{Matrix, TileMode};
@@ -78,8 +79,13 @@ fn brush_to_paint(
         width: PhysicalLength,
         height: PhysicalLength,
     ) -> Option<skia_safe::Paint> {
-        let (mut paint, shader) =
-            Self::brush_to_shader(self.default_paint().unwrap_or_default(), brush, width, height)?;
+        let (mut paint, shader) = Self::brush_to_shader(
+            self.default_paint().unwrap_or_default(),
+            brush,
+            width,
+            height,
+            self.scale_factor,
+        )?;
         paint.set_shader(Some(shader));
 
         Some(paint)
@@ -90,6 +96,7 @@ fn brush_to_shader(
         brush: Brush,
         width: PhysicalLength,
         height: PhysicalLength,
+        scale_factor: ScaleFactor,
     ) -> Option<(skia_safe::Paint, skia_safe::Shader)> {
         if brush.is_transparent() {
             return None;
@@ -103,8 +110,10 @@ fn brush_to_shader(
                     g.angle(),
                     [width.get(), height.get()].into(),
                 );
+                let (from, to, stops) = normalize_stops(g.stops(), g.spread());
                 let (colors, pos): (Vec<_>, Vec<_>) =
-                    g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                    stops.iter().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                let (start, end) = (start.lerp(end, from), start.lerp(end, to));
 
                 paint.set_dither(true);
 
@@ -112,28 +121,54 @@ fn brush_to_shader(
                     (skia_safe::Point::new(start.x, start.y), skia_safe::Point::new(end.x, end.y)),
                     skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
                     Some(&*pos),
-                    TileMode::Clamp,
+                    to_skia_tile_mode(g.spread()),
                     skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
                     &skia_safe::Matrix::new_identity(),
                 )
             }
             Brush::RadialGradient(g) => {
+                let (from, to, stops) = normalize_stops(g.stops(), g.spread());
+                let (colors, pos): (Vec<_>, Vec<_>) =
+                    stops.iter().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                let (cx, cy) = g.center();
+                let center = skia_safe::Point::new(cx * width.get(), cy * height.get());
+                let circle_scale = match g.radius() {
+                    r if r > 0. => r * scale_factor.get(),
+                    _ => width.max(height).get() / 2.,
+                };
+
+                paint.set_dither(true);
+
+                skia_safe::gradient_shader::two_point_conical(
+                    center,
+                    circle_scale * from,
+                    center,
+                    circle_scale * to,
+                    skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
+                    Some(&*pos),
+                    to_skia_tile_mode(g.spread()),
+                    skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
+                    &skia_safe::Matrix::new_identity(),
+                )
+            }
+            Brush::ConicGradient(g) => {
+                let (from, to, stops) = normalize_stops(g.stops(), g.spread());
                 let (colors, pos): (Vec<_>, Vec<_>) =
-                    g.stops().map(|s| (to_skia_color(&s.color), s.position)).unzip();
-                let circle_scale = width.max(height) / 2.;
+                    stops.iter().map(|s| (to_skia_color(&s.color), s.position)).unzip();
+                let (cx, cy) = g.center();
+                let center = skia_safe::Point::new(cx * width.get(), cy * height.get());
 
                 paint.set_dither(true);
 
-                skia_safe::gradient_shader::radial(
-                    skia_safe::Point::new(0., 0.),
-                    1.,
+                // Skia's sweep gradients start at 3 o'clock, ours start at the top.
+                skia_safe::gradient_shader::sweep(
+                    center,
                     skia_safe::gradient_shader::GradientShaderColors::Colors(&colors),
                     Some(&*pos),
-                    TileMode::Clamp,
+                    to_skia_tile_mode(g.spread()),
+                    Some((from * 360., to * 360.)),
                     skia_safe::gradient_shader::Flags::INTERPOLATE_COLORS_IN_PREMUL,
-                    skia_safe::Matrix::scale((circle_scale.get(), circle_scale.get()))
-                        .post_translate((width.get() / 2., height.get() / 2.))
-                        as &skia_safe::Matrix,
+                    &skia_safe::Matrix::rotate_deg_pivot(g.from_angle() - 90., center),
                 )
             }
             _ => None,
@@ -158,6 +193,7 @@ fn colorize_image(
             colorize_brush,
             PhysicalLength::new(image.width() as f32),
             PhysicalLength::new(image.height() as f32),
+            self.scale_factor,
         )
         .map(|(mut paint, colorize_shader)| {
             let mut surface = self.canvas.new_surface(&image_info, None)?;
@@ -582,6 +618,71 @@ fn draw_text(
         };
     }
 
+    fn draw_styled_text(
+        &mut self,
+        styled_text: Pin<&i_slint_core::items::StyledText>,
+        _self_rc: &i_slint_core::items::ItemRc,
+        size: LogicalSize,
+    ) {
+        let max_width = size.width_length() * self.scale_factor;
+        let max_height = size.height_length() * self.scale_factor;
+
+        if max_width.get() <= 0. || max_height.get() <= 0. {
+            return;
+        }
+
+        let styled_string = styled_text.styled_string();
+        let font_request = styled_text.font_request(WindowInner::from_pub(self.window));
+        let runs = super::textlayout::styled_shaping_fonts(
+            &font_request,
+            &styled_string,
+            self.scale_factor,
+        );
+
+        let paints = styled_string
+            .spans
+            .iter()
+            .map(|span| {
+                self.brush_to_paint(styled_text.span_color(&span.style), max_width, max_height)
+            })
+            .collect::<Vec<_>>();
+
+        let paragraph = StyledParagraphLayout {
+            string: &styled_string.text,
+            runs: &runs,
+            max_width: max_width.get(),
+            max_height: max_height.get(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+        };
+
+        let canvas = self.canvas;
+        paragraph
+            .layout_fragments::<()>(|fragment, _| {
+                let Some(paint) = &paints[fragment.run_index] else {
+                    return core::ops::ControlFlow::Continue(());
+                };
+                let style = &styled_string.spans[fragment.run_index].style;
+                let font = &runs[fragment.run_index].1;
+                let text = &styled_string.text[fragment.byte_range.clone()];
+                canvas.draw_str(text, (fragment.x, fragment.baseline_y), font.font(), paint);
+
+                let thickness = (font.height() / 16.).max(1.);
+                let underline = style.is_underlined().then_some(fragment.baseline_y + thickness);
+                let strikethrough =
+                    style.strikethrough.then(|| fragment.baseline_y - font.x_height() / 2.);
+                for line_y in underline.into_iter().chain(strikethrough) {
+                    canvas.draw_rect(
+                        skia_safe::Rect::from_xywh(fragment.x, line_y, fragment.width, thickness),
+                        paint,
+                    );
+                }
+                core::ops::ControlFlow::Continue(())
+            })
+            .ok();
+    }
+
     fn draw_text_input(
         &mut self,
         text_input: Pin<&i_slint_core::items::TextInput>,
@@ -1018,6 +1119,14 @@ pub fn to_skia_color(col: &Color) -> skia_safe::Color {
     skia_safe::Color::from_argb(col.alpha(), col.red(), col.green(), col.blue())
 }
 
+fn to_skia_tile_mode(spread: GradientSpread) -> TileMode {
+    match spread {
+        GradientSpread::Repeat => TileMode::Repeat,
+        GradientSpread::Reflect => TileMode::Mirror,
+        _ => TileMode::Clamp,
+    }
+}
+
 fn adjust_rect_and_border_for_inner_drawing(
     rect: &mut PhysicalRect,
     border_width: &mut PhysicalLength,
@@ -19,6 +19,8 @@
     LogicalLength, LogicalPoint, LogicalRect, LogicalSize, PhysicalPx, ScaleFactor,
 };
 use i_slint_core::platform::PlatformError;
+use i_slint_core::styled_text::StyledString;
+use i_slint_core::textlayout::StyledParagraphLayout;
 use i_slint_core::window::{WindowAdapter, WindowInner};
 use i_slint_core::{Brush, OpenGLAPI};
 
@@ -439,6 +441,50 @@ fn text_size(
             / scale_factor
     }
 
+    fn styled_text_size(
+        &self,
+        font_request: FontRequest,
+        styled_string: &StyledString,
+        max_width: Option<LogicalLength>,
+        scale_factor: ScaleFactor,
+        text_wrap: TextWrap,
+    ) -> LogicalSize {
+        let runs = textlayout::styled_shaping_fonts(&font_request, styled_string, scale_factor);
+        let paragraph = StyledParagraphLayout {
+            string: &styled_string.text,
+            runs: &runs,
+            max_width: max_width.map_or(f32::MAX, |max_width| (max_width * scale_factor).get()),
+            max_height: f32::MAX,
+            horizontal_alignment: Default::default(),
+            vertical_alignment: Default::default(),
+            wrap: if max_width.is_some() { text_wrap } else { TextWrap::NoWrap },
+        };
+        let (width, height) = paragraph.text_size();
+        PhysicalSize::new(width.ceil(), height.ceil()) / scale_factor
+    }
+
+    fn styled_text_span_at_position(
+        &self,
+        styled_text: std::pin::Pin<&i_slint_core::items::StyledText>,
+        styled_string: &StyledString,
+        pos: LogicalPoint,
+        font_request: FontRequest,
+        scale_factor: ScaleFactor,
+    ) -> Option<usize> {
+        let runs = textlayout::styled_shaping_fonts(&font_request, styled_string, scale_factor);
+        let paragraph = StyledParagraphLayout {
+            string: &styled_string.text,
+            runs: &runs,
+            max_width: (styled_text.width() * scale_factor).get(),
+            max_height: (styled_text.height() * scale_factor).get(),
+            horizontal_alignment: styled_text.horizontal_alignment(),
+            vertical_alignment: styled_text.vertical_alignment(),
+            wrap: styled_text.wrap(),
+        };
+        let pos = pos * scale_factor;
+        paragraph.run_at_position((pos.x, pos.y))
+    }
+
     fn font_metrics(
         &self,
         font_request: i_slint_core::graphics::FontRequest,
@@ -3,11 +3,13 @@
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::ops::Range;
 
 use i_slint_core::graphics::euclid::num::Zero;
 use i_slint_core::graphics::FontRequest;
 use i_slint_core::items::{TextHorizontalAlignment, TextVerticalAlignment};
 use i_slint_core::lengths::{LogicalLength, ScaleFactor};
+use i_slint_core::styled_text::StyledString;
 use i_slint_core::{items, Color};
 
 use super::itemrenderer::to_skia_color;
@@ -70,6 +72,118 @@ fn font_style_for_request(font_request: &FontRequest) -> skia_safe::FontStyle {
     )
 }
 
+/// A skia font that implements the text shaping interface of the core text layout, used
+/// for styled text.
+pub struct ShapingFont {
+    font: skia_safe::Font,
+    metrics: skia_safe::FontMetrics,
+}
+
+impl ShapingFont {
+    pub fn new(font_request: &FontRequest, scale_factor: ScaleFactor) -> Self {
+        let pixel_size = font_request.pixel_size.unwrap_or(DEFAULT_FONT_SIZE) * scale_factor;
+        let font_style = font_style_for_request(font_request);
+        let typeface = FONT_CACHE.with(|font_cache| {
+            let families = font_request.family.iter().map(|f| f.as_str()).collect::<Vec<_>>();
+            font_cache
+                .font_collection
+                .borrow_mut()
+                .find_typefaces(&families, font_style)
+                .into_iter()
+                .next()
+                .or_else(|| font_cache.font_mgr.legacy_make_typeface(None, font_style))
+        });
+        let mut font = match typeface {
+            Some(typeface) => skia_safe::Font::new(typeface, pixel_size.get()),
+            None => skia_safe::Font::default(),
+        };
+        font.set_size(pixel_size.get());
+        let (_, metrics) = font.metrics();
+        Self { font, metrics }
+    }
+
+    pub fn font(&self) -> &skia_safe::Font {
+        &self.font
+    }
+}
+
+impl i_slint_core::textlayout::TextShaper for ShapingFont {
+    type LengthPrimitive = f32;
+    type Length = f32;
+
+    fn shape_text<GlyphStorage: std::iter::Extend<i_slint_core::textlayout::Glyph<f32>>>(
+        &self,
+        text: &str,
+        glyphs: &mut GlyphStorage,
+    ) {
+        let byte_offsets = text.char_indices().map(|(offset, _)| offset).collect::<Vec<_>>();
+        let unichars = text.chars().map(|ch| ch as skia_safe::Unichar).collect::<Vec<_>>();
+        let mut glyph_ids = vec![0; unichars.len()];
+        self.font.unichar_to_glyphs(&unichars, &mut glyph_ids);
+        let mut widths = vec![0.; glyph_ids.len()];
+        self.font.get_widths(&glyph_ids, &mut widths);
+        glyphs.extend(byte_offsets.into_iter().zip(glyph_ids).zip(widths).map(
+            |((text_byte_offset, glyph_id), advance)| i_slint_core::textlayout::Glyph {
+                advance,
+                offset_x: 0.,
+                offset_y: 0.,
+                glyph_id: core::num::NonZeroU16::new(glyph_id),
+                text_byte_offset,
+            },
+        ));
+    }
+
+    fn glyph_for_char(&self, ch: char) -> Option<i_slint_core::textlayout::Glyph<f32>> {
+        let mut glyphs = Vec::new();
+        self.shape_text(ch.encode_utf8(&mut [0; 4]), &mut glyphs);
+        glyphs.pop()
+    }
+
+    fn max_lines(&self, max_height: f32) -> usize {
+        use i_slint_core::textlayout::FontMetrics;
+        (max_height / self.height()).floor() as _
+    }
+}
+
+impl i_slint_core::textlayout::FontMetrics<f32> for ShapingFont {
+    fn height(&self) -> f32 {
+        self.metrics.descent - self.metrics.ascent + self.metrics.leading
+    }
+
+    // Skia's ascent is negative and descent positive, the core text layout expects the opposite.
+    fn ascent(&self) -> f32 {
+        -self.metrics.ascent
+    }
+
+    fn descent(&self) -> f32 {
+        -self.metrics.descent
+    }
+
+    fn x_height(&self) -> f32 {
+        self.metrics.x_height
+    }
+
+    fn cap_height(&self) -> f32 {
+        self.metrics.cap_height
+    }
+}
+
+/// Returns the font to use for each span of the styled string
+pub fn styled_shaping_fonts(
+    font_request: &FontRequest,
+    styled_string: &StyledString,
+    scale_factor: ScaleFactor,
+) -> Vec<(Range<usize>, ShapingFont)> {
+    styled_string
+        .spans
+        .iter()
+        .map(|span| {
+            let font_request = span.style.font_request(font_request);
+            (span.range.clone(), ShapingFont::new(&font_request, scale_factor))
+        })
+        .collect()
+}
+
 pub fn create_layout(
     font_request: FontRequest,
     scale_factor: ScaleFactor,
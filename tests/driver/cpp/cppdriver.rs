@@ -17,6 +17,8 @@ pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>>
         .collect::<std::collections::HashMap<_, _>>();
 
     let cpp_namespace = test_driver_lib::extract_cpp_namespace(&source);
+    let bundle_translations = test_driver_lib::extract_bundle_translations(&source)
+        .map(|path| testcase.absolute_path.parent().unwrap().join(path));
 
     let mut diag = BuildDiagnostics::default();
     let syntax_node = parser::parse(source.clone(), Some(&testcase.absolute_path), &mut diag);
@@ -30,6 +32,7 @@ pub fn test(testcase: &test_driver_lib::TestCase) -> Result<(), Box<dyn Error>>
     compiler_config.library_paths = library_paths;
     compiler_config.style = testcase.requested_style.map(str::to_string);
     compiler_config.debug_info = true;
+    compiler_config.translation_path_bundle = bundle_translations;
     let (root_component, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
@@ -264,3 +264,24 @@ fn test_extract_cpp_namespace() {
     let r = extract_cpp_namespace(source);
     assert_eq!(r, Some("ui".to_string()));
 }
+
+/// Extract the directory of the translations to bundle from a `//bundle-translations:` comment
+/// in the source if present. The path is relative to the test case.
+pub fn extract_bundle_translations(source: &str) -> Option<&str> {
+    lazy_static::lazy_static! {
+        static ref RX: Regex = Regex::new(r"//bundle-translations:\s*(.+)\s*\n").unwrap();
+    }
+    RX.captures(source).map(|mat| mat.get(1).unwrap().as_str().trim())
+}
+
+#[test]
+fn test_extract_bundle_translations() {
+    assert!(extract_bundle_translations("something").is_none());
+
+    let source = r"
+    //bundle-translations: translations
+    Blah {}
+";
+
+    assert_eq!(extract_bundle_translations(source), Some("translations"));
+}
@@ -72,6 +72,11 @@ fn generate_macro(
         output.write_all(b"#[test] #[ignore] fn ignored_because_string_template() {{}}")?;
         return Ok(false);
     }
+    if test_driver_lib::extract_bundle_translations(source).is_some() {
+        // The slint! macro has no option to bundle the translations
+        output.write_all(b"#[test] #[ignore] fn ignored_because_bundle_translations() {{}}")?;
+        return Ok(false);
+    }
     // to silence all the warnings in .slint files that would be turned into errors
     output.write_all(b"#![allow(deprecated)]")?;
     let include_paths = test_driver_lib::extract_include_paths(source);
@@ -132,6 +137,8 @@ fn generate_source(
     let library_paths = test_driver_lib::extract_library_paths(source)
         .map(|(k, v)| (k.to_string(), std::path::PathBuf::from(v)))
         .collect::<std::collections::HashMap<_, _>>();
+    let bundle_translations = test_driver_lib::extract_bundle_translations(source)
+        .map(|path| testcase.absolute_path.parent().unwrap().join(path));
 
     let mut diag = BuildDiagnostics::default();
     let syntax_node = parser::parse(source.to_owned(), Some(&testcase.absolute_path), &mut diag);
@@ -141,6 +148,7 @@ fn generate_source(
     compiler_config.library_paths = library_paths;
     compiler_config.style = Some(testcase.requested_style.unwrap_or("fluent").to_string());
     compiler_config.debug_info = true;
+    compiler_config.translation_path_bundle = bundle_translations;
     let (root_component, diag, loader) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
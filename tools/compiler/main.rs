@@ -67,6 +67,24 @@ struct Cli {
     #[arg(long = "translation-domain", action)]
     translation_domain: Option<String>,
 
+    /// Bundle the translations from the `<lang>/LC_MESSAGES/<domain>.po` files of this directory in the generated code
+    #[arg(long = "bundle-translations", name = "path", action)]
+    bundle_translations: Option<std::path::PathBuf>,
+
+    /// Instead of generating code, extract the `@tr(...)` strings of the file and all the files it imports
+    /// into this `.pot` file. If the file already exists, the new strings are merged into it.
+    #[arg(long = "extract-translations", name = "pot file", action)]
+    extract_translations: Option<std::path::PathBuf>,
+
+    /// Instead of generating code, format the file and write the result to the output file.
+    /// `.rs` and `.md` files have their `slint!` macros and slint code blocks formatted.
+    #[arg(long = "format-source", action)]
+    format_source: bool,
+
+    /// With `--format-source`, don't write anything but exit with an error if the file is not formatted
+    #[arg(long = "check", requires = "format_source", action)]
+    check: bool,
+
     /// C++ namespace
     #[arg(long = "cpp-namespace", name = "C++ namespace")]
     cpp_namespace: Option<String>,
@@ -79,6 +97,9 @@ struct Cli {
 fn main() -> std::io::Result<()> {
     proc_macro2::fallback::force(); // avoid a abort if panic=abort is set
     let args = Cli::parse();
+    if args.format_source {
+        return format_file(&args.path, &args.output, args.check);
+    }
     let mut diag = BuildDiagnostics::default();
     let syntax_node = parser::parse_file(&args.path, &mut diag);
     //println!("{:#?}", syntax_node);
@@ -119,6 +140,7 @@ fn main() -> std::io::Result<()> {
 
     let mut compiler_config = CompilerConfiguration::new(format.clone());
     compiler_config.translation_domain = args.translation_domain;
+    compiler_config.translation_path_bundle = args.bundle_translations;
 
     // Override defaults from command line:
     if let Some(embed) = args.embed_resources {
@@ -148,6 +170,12 @@ fn main() -> std::io::Result<()> {
 
     let diag = diag.check_and_exit_on_error();
 
+    if let Some(pot_file) = args.extract_translations {
+        extract_translations(&pot_file, &doc, &loader)?;
+        diag.print_warnings_and_exit_on_error();
+        return Ok(());
+    }
+
     if args.output == std::path::Path::new("-") {
         generator::generate(format, &mut std::io::stdout(), &doc, &loader.compiler_config)?;
     } else {
@@ -180,3 +208,77 @@ fn main() -> std::io::Result<()> {
     diag.print_warnings_and_exit_on_error();
     Ok(())
 }
+
+/// Write all the `@tr(...)` strings of the document and the (non-builtin) files it imports into the .pot file
+fn extract_translations(
+    pot_file: &std::path::Path,
+    doc: &object_tree::Document,
+    loader: &typeloader::TypeLoader,
+) -> std::io::Result<()> {
+    use i_slint_compiler::translations::extract;
+
+    let mut messages = if pot_file.exists() {
+        polib::po_file::parse(pot_file).map_err(std::io::Error::other)?
+    } else {
+        polib::catalog::Catalog::new(extract::template_metadata(
+            None,
+            None,
+            chrono::Utc::now().format("%Y-%m-%d %H:%M%z").to_string(),
+        ))
+    };
+
+    let current_dir = std::env::current_dir().unwrap_or_default();
+    let documents = doc
+        .node
+        .iter()
+        .chain(loader.all_documents().filter_map(|doc| doc.node.as_ref()))
+        .filter(|node| {
+            !fileaccess::load_file(node.source_file.path()).is_some_and(|f| f.is_builtin())
+        })
+        // The root document may also be known to the loader, under its absolute path
+        .map(|node| (pathutils::clean_path(&current_dir.join(node.source_file.path())), node))
+        .collect::<std::collections::BTreeMap<_, _>>();
+    for (path, node) in documents {
+        let path = path.strip_prefix(&current_dir).unwrap_or(&path);
+        extract::extract_messages(node, path, &mut messages);
+    }
+
+    polib::po_file::write(&messages, pot_file)
+}
+
+/// Format the file, or with `check`, exit with an error if the file is not formatted
+fn format_file(
+    path: &std::path::Path,
+    output: &std::path::Path,
+    check: bool,
+) -> std::io::Result<()> {
+    let mut diag = BuildDiagnostics::default();
+    let source = match diagnostics::load_from_path(path) {
+        Ok(source) => source,
+        Err(d) => {
+            diag.push_internal_error(d);
+            diag.print();
+            std::process::exit(-1);
+        }
+    };
+
+    if check {
+        let formatted = fmt::is_formatted(&source, path, &mut diag);
+        diag.print_warnings_and_exit_on_error();
+        if !formatted {
+            eprintln!("{} is not formatted", path.display());
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Format into a buffer so that the output file can be the same as the input file
+    let mut formatted = Vec::new();
+    fmt::format_file(source, path, &mut formatted, &mut diag)?;
+    diag.print_warnings_and_exit_on_error();
+    if output == std::path::Path::new("-") {
+        std::io::stdout().write_all(&formatted)
+    } else {
+        std::fs::write(output, formatted)
+    }
+}
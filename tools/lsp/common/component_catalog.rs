@@ -12,7 +12,8 @@
 
 #[cfg(feature = "preview-engine")]
 fn builtin_component_info(name: &str) -> ComponentInformation {
-    let is_layout = matches!(name, "GridLayout" | "HorizontalLayout" | "VerticalLayout");
+    let is_layout =
+        matches!(name, "GridLayout" | "HorizontalLayout" | "VerticalLayout" | "FlexboxLayout");
 
     let default_properties = match name {
         "Text" | "TextInput" => vec![PropertyChange::new("text", format!("\"{name}\""))],
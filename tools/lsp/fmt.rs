@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
 
-pub mod fmt;
+pub use i_slint_compiler::fmt;
+pub use i_slint_compiler::fmt::writer;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod tool;
-pub mod writer;
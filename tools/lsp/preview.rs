@@ -1359,7 +1359,7 @@ fn set_preview_factory(
     behavior: LoadBehavior,
 ) {
     // Ensure that the popup is closed as it is related to the old factory
-    i_slint_core::window::WindowInner::from_pub(ui.window()).close_popup();
+    i_slint_core::window::WindowInner::from_pub(ui.window()).close_all_popups();
 
     let factory = slint::ComponentFactory::new(move |ctx: FactoryContext| {
         let instance = compiled.create_embedded(ctx).unwrap();
@@ -0,0 +1,304 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-2.0 OR LicenseRef-Slint-Software-3.0
+
+//! Tests that start the `slint-lsp` binary and drive it over stdio, like an editor would.
+
+use lsp_server::{Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::Notification as _;
+use lsp_types::{notification, request, Position, TextDocumentIdentifier, Url};
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+/// A minimal LSP client talking to a `slint-lsp` process
+struct LspClient {
+    process: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: i32,
+    /// Notifications received from the server while waiting for a response
+    notifications: Vec<Notification>,
+}
+
+impl LspClient {
+    /// Start the server and do the `initialize` handshake
+    fn start() -> Self {
+        let mut process = Command::new(env!("CARGO_BIN_EXE_slint-lsp"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("could not start slint-lsp");
+        let stdin = process.stdin.take().unwrap();
+        let stdout = BufReader::new(process.stdout.take().unwrap());
+        let mut client = Self { process, stdin, stdout, next_id: 0, notifications: Vec::new() };
+
+        let result = client.request::<request::Initialize>(lsp_types::InitializeParams::default());
+        assert!(result.capabilities.completion_provider.is_some());
+        client.notify::<notification::Initialized>(lsp_types::InitializedParams {});
+        client
+    }
+
+    fn send(&mut self, message: Message) {
+        message.write(&mut self.stdin).expect("could not write to slint-lsp");
+    }
+
+    fn receive(&mut self) -> Message {
+        Message::read(&mut self.stdout)
+            .expect("could not read from slint-lsp")
+            .expect("slint-lsp closed its output")
+    }
+
+    fn notify<N: notification::Notification>(&mut self, params: N::Params) {
+        self.send(Message::Notification(Notification::new(N::METHOD.into(), params)));
+    }
+
+    /// Send a request and wait for its result. Requests from the server are answered with an error.
+    fn request<R: request::Request>(&mut self, params: R::Params) -> R::Result {
+        self.next_id += 1;
+        let id = RequestId::from(self.next_id);
+        self.send(Message::Request(Request::new(id.clone(), R::METHOD.into(), params)));
+        loop {
+            match self.receive() {
+                Message::Response(Response { id: response_id, result, error }) => {
+                    assert_eq!(response_id, id);
+                    if let Some(error) = error {
+                        panic!("{} failed: {}", R::METHOD, error.message);
+                    }
+                    return serde_json::from_value(result.unwrap_or_default()).unwrap();
+                }
+                Message::Notification(n) => self.notifications.push(n),
+                Message::Request(r) => self.send(Message::Response(Response::new_err(
+                    r.id,
+                    lsp_server::ErrorCode::MethodNotFound as i32,
+                    "not supported by the test client".into(),
+                ))),
+            }
+        }
+    }
+
+    /// Wait until the server publishes the diagnostics of the given document
+    fn wait_for_diagnostics(&mut self, uri: &Url) -> Vec<lsp_types::Diagnostic> {
+        let is_diagnostics_for_uri = |n: &Notification| {
+            n.method == notification::PublishDiagnostics::METHOD
+                && n.params.get("uri").and_then(|u| u.as_str()) == Some(uri.as_str())
+        };
+        let notification = loop {
+            if let Some(pos) = self.notifications.iter().position(is_diagnostics_for_uri) {
+                break self.notifications.remove(pos);
+            }
+            match self.receive() {
+                Message::Notification(n) => self.notifications.push(n),
+                Message::Request(r) => self.send(Message::Response(Response::new_err(
+                    r.id,
+                    lsp_server::ErrorCode::MethodNotFound as i32,
+                    "not supported by the test client".into(),
+                ))),
+                Message::Response(r) => panic!("unexpected response {r:?}"),
+            }
+        };
+        serde_json::from_value::<lsp_types::PublishDiagnosticsParams>(notification.params)
+            .unwrap()
+            .diagnostics
+    }
+
+    fn open(&mut self, uri: &Url, text: &str) {
+        self.notify::<notification::DidOpenTextDocument>(lsp_types::DidOpenTextDocumentParams {
+            text_document: lsp_types::TextDocumentItem {
+                uri: uri.clone(),
+                language_id: "slint".into(),
+                version: 1,
+                text: text.into(),
+            },
+        });
+    }
+
+    fn change(&mut self, uri: &Url, version: i32, text: &str) {
+        self.notify::<notification::DidChangeTextDocument>(
+            lsp_types::DidChangeTextDocumentParams {
+                text_document: lsp_types::VersionedTextDocumentIdentifier {
+                    uri: uri.clone(),
+                    version,
+                },
+                content_changes: vec![lsp_types::TextDocumentContentChangeEvent {
+                    range: None,
+                    range_length: None,
+                    text: text.into(),
+                }],
+            },
+        );
+    }
+
+    /// Shut the server down and check that it exits cleanly
+    fn shutdown(mut self) {
+        self.request::<request::Shutdown>(());
+        self.notify::<notification::Exit>(());
+        let status = self.process.wait().unwrap();
+        assert!(status.success(), "slint-lsp exited with {status}");
+    }
+}
+
+/// Write the files in a new directory, and return the path of that directory
+fn create_project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("slint-lsp-stdio").join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    for (file, content) in files {
+        std::fs::write(dir.join(file), content).unwrap();
+    }
+    dir
+}
+
+fn position_params(uri: &Url, line: u32, character: u32) -> lsp_types::TextDocumentPositionParams {
+    lsp_types::TextDocumentPositionParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        position: Position::new(line, character),
+    }
+}
+
+const LIB: &str = r#"export component Lib inherits Rectangle {
+    in property <string> label;
+}
+"#;
+
+const MAIN: &str = r#"import { Lib } from "lib.slint";
+export component Main inherits Window {
+    in-out property <int> counter: 42;
+    lib := Lib {
+        label: "Hello";
+    }
+    Text {
+        text: counter;
+    }
+}
+"#;
+
+#[test]
+fn diagnostics_on_change() {
+    let dir = create_project("diagnostics", &[("lib.slint", LIB), ("main.slint", MAIN)]);
+    let uri = Url::from_file_path(dir.join("main.slint")).unwrap();
+
+    let mut client = LspClient::start();
+    client.open(&uri, MAIN);
+    assert_eq!(client.wait_for_diagnostics(&uri), vec![]);
+
+    client.change(&uri, 2, &MAIN.replace("text: counter;", "text: unknown-property;"));
+    let diagnostics = client.wait_for_diagnostics(&uri);
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert!(diagnostics[0].message.contains("unknown-property"), "{diagnostics:?}");
+    assert_eq!(diagnostics[0].range.start.line, 7);
+
+    client.change(&uri, 3, MAIN);
+    assert_eq!(client.wait_for_diagnostics(&uri), vec![]);
+    client.shutdown();
+}
+
+#[test]
+fn completion_hover_and_goto() {
+    let dir = create_project("completion", &[("lib.slint", LIB), ("main.slint", MAIN)]);
+    let uri = Url::from_file_path(dir.join("main.slint")).unwrap();
+
+    let mut client = LspClient::start();
+    client.open(&uri, MAIN);
+    client.wait_for_diagnostics(&uri);
+
+    let mut complete = |line, character| {
+        let completion = client.request::<request::Completion>(lsp_types::CompletionParams {
+            text_document_position: position_params(&uri, line, character),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+            context: None,
+        });
+        match completion {
+            Some(lsp_types::CompletionResponse::Array(items)) => {
+                items.into_iter().map(|i| i.label).collect::<Vec<_>>()
+            }
+            r => panic!("unexpected completion response {r:?}"),
+        }
+    };
+    // In the `Text` element, at the start of the `text: counter;` line
+    let labels = complete(7, 8);
+    for expected in ["text", "color", "width"] {
+        assert!(labels.iter().any(|l| l == expected), "{expected} not in {labels:?}");
+    }
+    // At the start of the `Text {` line, in the `Main` component
+    let labels = complete(6, 4);
+    for expected in ["Rectangle", "Text", "Lib"] {
+        assert!(labels.iter().any(|l| l == expected), "{expected} not in {labels:?}");
+    }
+
+    // On `counter` in `text: counter;`
+    let hover = client
+        .request::<request::HoverRequest>(lsp_types::HoverParams {
+            text_document_position_params: position_params(&uri, 7, 16),
+            work_done_progress_params: Default::default(),
+        })
+        .expect("no hover for counter");
+    let lsp_types::HoverContents::Markup(markup) = hover.contents else {
+        panic!("unexpected hover {hover:?}")
+    };
+    assert!(markup.value.contains("property <int> counter"), "{}", markup.value);
+
+    // On `Lib` in `lib := Lib {`
+    let definition = client
+        .request::<request::GotoDefinition>(lsp_types::GotoDefinitionParams {
+            text_document_position_params: position_params(&uri, 3, 12),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .expect("no definition for Lib");
+    let target = match definition {
+        lsp_types::GotoDefinitionResponse::Link(links) => links[0].target_uri.clone(),
+        lsp_types::GotoDefinitionResponse::Scalar(location) => location.uri,
+        lsp_types::GotoDefinitionResponse::Array(locations) => locations[0].uri.clone(),
+    };
+    assert_eq!(target, Url::from_file_path(dir.join("lib.slint")).unwrap());
+
+    client.shutdown();
+}
+
+#[test]
+fn rename_and_document_symbols() {
+    let dir = create_project("rename", &[("lib.slint", LIB), ("main.slint", MAIN)]);
+    let uri = Url::from_file_path(dir.join("main.slint")).unwrap();
+
+    let mut client = LspClient::start();
+    client.open(&uri, MAIN);
+    client.wait_for_diagnostics(&uri);
+
+    let symbols = client
+        .request::<request::DocumentSymbolRequest>(lsp_types::DocumentSymbolParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .expect("no document symbols");
+    let lsp_types::DocumentSymbolResponse::Nested(symbols) = symbols else {
+        panic!("unexpected document symbols {symbols:?}")
+    };
+    assert_eq!(symbols.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(), ["Main"]);
+
+    // Rename the `lib` element id
+    let edit = client
+        .request::<request::Rename>(lsp_types::RenameParams {
+            text_document_position: position_params(&uri, 3, 5),
+            new_name: "my-lib".into(),
+            work_done_progress_params: Default::default(),
+        })
+        .expect("no rename edit");
+    let edits: Vec<lsp_types::TextEdit> = match edit.document_changes {
+        Some(lsp_types::DocumentChanges::Edits(edits)) => edits
+            .into_iter()
+            .flat_map(|e| e.edits)
+            .map(|e| match e {
+                lsp_types::OneOf::Left(e) => e,
+                lsp_types::OneOf::Right(e) => e.text_edit,
+            })
+            .collect(),
+        _ => edit.changes.unwrap().remove(&uri).unwrap(),
+    };
+    assert_eq!(edits.len(), 1, "{edits:?}");
+    assert_eq!(edits[0].new_text, "my-lib");
+    assert_eq!(edits[0].range.start, Position::new(3, 4));
+
+    client.shutdown();
+}